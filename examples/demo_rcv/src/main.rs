@@ -30,6 +30,7 @@ use error::errors::{DemoError, Result};
 
 use sacn::packet::ACN_SDT_MULTICAST_PORT;
 use sacn::receive::{DMXData, DiscoveredSacnSource, SacnReceiver};
+use sacn::types::Universe;
 
 use std::env;
 use std::fs::File;
@@ -284,7 +285,7 @@ fn handle_input(dmx_recv: &mut SacnReceiver) -> Result<bool> {
                         )
                         .into());
                     }
-                    let universe: u16 = split_input[1].parse().unwrap();
+                    let universe = Universe::try_from(split_input[1].parse::<u16>().unwrap())?;
                     dmx_recv.listen_universes(&[universe])?;
                 }
                 ACTION_STOP_LISTEN_UNIVERSE => {
@@ -296,7 +297,7 @@ fn handle_input(dmx_recv: &mut SacnReceiver) -> Result<bool> {
                         )
                         .into());
                     }
-                    let universe: u16 = split_input[1].parse().unwrap();
+                    let universe = Universe::try_from(split_input[1].parse::<u16>().unwrap())?;
 
                     dmx_recv.mute_universe(universe)?;
                 }
@@ -32,6 +32,7 @@ use error::errors::Result;
 
 use sacn::packet::{ACN_SDT_MULTICAST_PORT, UNIVERSE_CHANNEL_CAPACITY};
 use sacn::source::SacnSource;
+use sacn::types::{Priority, StartCode, SyncAddress, Universe};
 
 use std::env;
 use std::io;
@@ -41,7 +42,7 @@ use std::thread::sleep;
 use std::time::{Duration, Instant};
 
 /// The start code used in termination packets.
-const TERMINATE_START_CODE: u8 = 0;
+const TERMINATE_START_CODE: StartCode = StartCode::NULL;
 
 /// The period between updates to the values send during the shape generation command.
 /// Default value is approximately 30 updates per second choosen fairly arbitarily to be less than the DMX refresh rate (44 fps).
@@ -129,9 +130,9 @@ const TEST_PRESET_RAPID_CHANGE_PERIOD: usize = 10;
 const TEST_PRESET_HIGH_DATA_RATE_VARIATION_RANGE: f64 = 10.0;
 
 /// The 2 universes used for the acceptance test.
-/// ACCEPT_TEST_UNI_1 contains the backlight fixtures and ACCEPT_TEST_UNI_2 the frontlight fixtures.
-const ACCEPT_TEST_UNI_1: u16 = 1;
-const ACCEPT_TEST_UNI_2: u16 = 2;
+/// `ACCEPT_TEST_UNI_1` contains the backlight fixtures and `ACCEPT_TEST_UNI_2` the frontlight fixtures.
+const ACCEPT_TEST_UNI_1: Universe = Universe::new(1).unwrap();
+const ACCEPT_TEST_UNI_2: Universe = Universe::new(2).unwrap();
 
 /// The start addresses for each of the fixtures in the acceptance test (universe 1).
 /// These are the backlights which are the colour changing lights far from the camera.
@@ -287,11 +288,11 @@ fn handle_full_data_option(src: &mut SacnSource, split_input: Vec<&str>) -> Resu
         .into());
     }
 
-    let universe: u16 = split_input[1].parse().unwrap();
+    let universe = Universe::try_from(split_input[1].parse::<u16>().unwrap())?;
 
     let sync_uni: u16 = split_input[2].parse().unwrap();
 
-    let priority: u8 = split_input[3].parse().unwrap();
+    let priority = Priority::try_from(split_input[3].parse::<u8>().unwrap())?;
 
     let mut data: [u8; 513] = [0; 513];
 
@@ -302,7 +303,13 @@ fn handle_full_data_option(src: &mut SacnSource, split_input: Vec<&str>) -> Resu
     if sync_uni == 0 {
         src.send(&[universe], &data, Some(priority), None, None)?;
     } else {
-        src.send(&[universe], &data, Some(priority), None, Some(sync_uni))?;
+        src.send(
+            &[universe],
+            &data,
+            Some(priority),
+            None,
+            Some(SyncAddress::try_from(sync_uni)?),
+        )?;
     }
 
     Ok(true)
@@ -327,7 +334,7 @@ fn handle_all_data_option(src: &mut SacnSource, split_input: Vec<&str>) -> Resul
         .into());
     }
 
-    let universe: u16 = split_input[1].parse().unwrap();
+    let universe = Universe::try_from(split_input[1].parse::<u16>().unwrap())?;
 
     let value: u8 = split_input[2].parse().unwrap();
 
@@ -355,7 +362,7 @@ fn handle_all_data_option(src: &mut SacnSource, split_input: Vec<&str>) -> Resul
 ///
 /// The rest of the input is expected to be the data to send.
 fn handle_data_option(src: &mut SacnSource, split_input: Vec<&str>) -> Result<bool> {
-    let universe: u16 = split_input[1].parse().unwrap();
+    let universe = Universe::try_from(split_input[1].parse::<u16>().unwrap())?;
 
     if split_input.len() < 4 {
         return Err(std::io::Error::new(
@@ -367,7 +374,7 @@ fn handle_data_option(src: &mut SacnSource, split_input: Vec<&str>) -> Result<bo
 
     let sync_uni: u16 = split_input[2].parse().unwrap();
 
-    let priority: u8 = split_input[3].parse().unwrap();
+    let priority = Priority::try_from(split_input[3].parse::<u8>().unwrap())?;
 
     let mut data: Vec<u8> = Vec::new();
 
@@ -378,7 +385,13 @@ fn handle_data_option(src: &mut SacnSource, split_input: Vec<&str>) -> Result<bo
     if sync_uni == 0 {
         src.send(&[universe], &data, Some(priority), None, None)?;
     } else {
-        src.send(&[universe], &data, Some(priority), None, Some(sync_uni))?;
+        src.send(
+            &[universe],
+            &data,
+            Some(priority),
+            None,
+            Some(SyncAddress::try_from(sync_uni)?),
+        )?;
     }
 
     Ok(true)
@@ -402,7 +415,7 @@ fn handle_data_option(src: &mut SacnSource, split_input: Vec<&str>) -> Result<bo
 ///
 /// The rest of the input is expected to be the data to send.
 fn handle_unicast_option(src: &mut SacnSource, split_input: Vec<&str>) -> Result<bool> {
-    let universe: u16 = split_input[1].parse().unwrap();
+    let universe = Universe::try_from(split_input[1].parse::<u16>().unwrap())?;
 
     if split_input.len() < 5 {
         return Err(std::io::Error::new(
@@ -414,7 +427,7 @@ fn handle_unicast_option(src: &mut SacnSource, split_input: Vec<&str>) -> Result
 
     let sync_uni: u16 = split_input[2].parse().unwrap();
 
-    let priority: u8 = split_input[3].parse().unwrap();
+    let priority = Priority::try_from(split_input[3].parse::<u8>().unwrap())?;
 
     let dst_ip = split_input[4];
 
@@ -439,10 +452,11 @@ fn handle_unicast_option(src: &mut SacnSource, split_input: Vec<&str>) -> Result
             &[universe],
             &data,
             Some(priority),
-            Some(
-                SocketAddr::new(IpAddr::V4(dst_ip.parse().unwrap()), ACN_SDT_MULTICAST_PORT).into(),
-            ),
-            Some(sync_uni),
+            Some(SocketAddr::new(
+                IpAddr::V4(dst_ip.parse().unwrap()),
+                ACN_SDT_MULTICAST_PORT,
+            )),
+            Some(SyncAddress::try_from(sync_uni)?),
         )?;
     }
 
@@ -471,9 +485,9 @@ fn handle_data_over_time_option(src: &mut SacnSource, split_input: Vec<&str>) ->
         .into());
     }
 
-    let universe: u16 = split_input[1].parse().unwrap();
+    let universe = Universe::try_from(split_input[1].parse::<u16>().unwrap())?;
     let duration_millis: u64 = split_input[2].parse().unwrap();
-    let priority: u8 = split_input[3].parse().unwrap();
+    let priority = Priority::try_from(split_input[3].parse::<u8>().unwrap())?;
 
     let duration: Duration = Duration::from_millis(duration_millis);
 
@@ -517,7 +531,7 @@ fn handle_test_preset_option(src: &mut SacnSource, split_input: Vec<&str>) -> Re
     }
 
     let preset: usize = split_input[1].parse().unwrap();
-    let universe: u16 = split_input[2].parse().unwrap();
+    let universe = Universe::try_from(split_input[2].parse::<u16>().unwrap())?;
 
     match preset {
         TEST_PRESET_MOVING_CHANNELS => {
@@ -538,7 +552,7 @@ fn handle_test_preset_option(src: &mut SacnSource, split_input: Vec<&str>) -> Re
                 .into());
             }
 
-            let universe_2: u16 = split_input[3].parse().unwrap();
+            let universe_2 = Universe::try_from(split_input[3].parse::<u16>().unwrap())?;
 
             run_test_2_universes_distinct_values(
                 src,
@@ -558,7 +572,7 @@ fn handle_test_preset_option(src: &mut SacnSource, split_input: Vec<&str>) -> Re
                 .into());
             }
 
-            let universe_2: u16 = split_input[3].parse().unwrap();
+            let universe_2 = Universe::try_from(split_input[3].parse::<u16>().unwrap())?;
             let addr = SocketAddr::new(split_input[4].parse().unwrap(), ACN_SDT_MULTICAST_PORT);
 
             run_test_2_universes_distinct_values(
@@ -602,8 +616,8 @@ fn handle_test_preset_option(src: &mut SacnSource, split_input: Vec<&str>) -> Re
 /// dst_ip: None to use multicast or Some(addr) to use unicast to a specific address.
 fn run_test_2_universes_distinct_values(
     src: &mut SacnSource,
-    uni_1: u16,
-    uni_2: u16,
+    uni_1: Universe,
+    uni_2: Universe,
     uni1_val: u8,
     uni2_val: u8,
     dst_ip: Option<SocketAddr>,
@@ -631,7 +645,7 @@ fn run_test_2_universes_distinct_values(
 /// src: A mutable reference to the SacnSource to use as the sender in this test.
 ///
 /// universe: The universe to send data on in the test.
-fn run_test_moving_channel_preset(src: &mut SacnSource, universe: u16) -> Result<()> {
+fn run_test_moving_channel_preset(src: &mut SacnSource, universe: Universe) -> Result<()> {
     let start_time = Instant::now();
 
     let mut data: [u8; UNIVERSE_CHANNEL_CAPACITY] = [0; UNIVERSE_CHANNEL_CAPACITY];
@@ -660,7 +674,7 @@ fn run_test_moving_channel_preset(src: &mut SacnSource, universe: u16) -> Result
 /// src: A mutable reference to the SacnSource to use as the sender in this test.
 ///
 /// universe: The universe to send data on in the test.
-fn run_test_rapid_changes_preset(src: &mut SacnSource, universe: u16) -> Result<()> {
+fn run_test_rapid_changes_preset(src: &mut SacnSource, universe: Universe) -> Result<()> {
     let start_time = Instant::now();
 
     let mut counter = 0;
@@ -694,7 +708,7 @@ fn run_test_rapid_changes_preset(src: &mut SacnSource, universe: u16) -> Result<
 /// universe_count: The number of universes starting at the start_universe (inclusive) to send data on.
 fn run_test_high_data_rate(
     src: &mut SacnSource,
-    start_universe: u16,
+    start_universe: Universe,
     universe_count: u16,
 ) -> Result<()> {
     let start_time = Instant::now();
@@ -702,13 +716,13 @@ fn run_test_high_data_rate(
     let mut counter: f64 = 0.0;
 
     while start_time.elapsed() < TEST_PRESET_DURATION {
-        for universe in start_universe..start_universe + universe_count {
-            let d = ((universe - start_universe) as f64)
+        for universe in start_universe.get()..start_universe.get() + universe_count {
+            let d = ((universe - start_universe.get()) as f64)
                 * (TEST_PRESET_HIGH_DATA_RATE_VARIATION_RANGE * counter.sin());
             let mut data: [u8; UNIVERSE_CHANNEL_CAPACITY] = [d as u8; UNIVERSE_CHANNEL_CAPACITY];
             // Use a zero startcode.
             data[0] = 0;
-            src.send(&[universe], &data, None, None, None)?;
+            src.send(&[Universe::try_from(universe)?], &data, None, None, None)?;
         }
 
         counter = counter + 0.05;
@@ -1131,8 +1145,8 @@ fn handle_input(src: &mut SacnSource) -> Result<bool> {
                 ACTION_UNICAST_OPTION => handle_unicast_option(src, split_input),
                 ACTION_DATA_OVER_TIME_OPTION => handle_data_over_time_option(src, split_input),
                 ACTION_SYNC_OPTION => {
-                    let universe: u16 = split_input[1].parse().unwrap();
-                    src.send_sync_packet(universe, None)?;
+                    let sync_addr = SyncAddress::try_from(split_input[1].parse::<u16>().unwrap())?;
+                    src.send_sync_packet(sync_addr, None)?;
                     Ok(true)
                 }
                 ACTION_UNICAST_SYNC_OPTION => {
@@ -1144,16 +1158,13 @@ fn handle_input(src: &mut SacnSource) -> Result<bool> {
                         .into());
                     }
 
-                    let universe: u16 = split_input[1].parse().unwrap();
+                    let sync_addr = SyncAddress::try_from(split_input[1].parse::<u16>().unwrap())?;
                     let dst_ip = split_input[2];
-                    src.send_sync_packet(
-                        universe,
-                        Some(SocketAddr::from_str(dst_ip).unwrap().into()),
-                    )?;
+                    src.send_sync_packet(sync_addr, Some(SocketAddr::from_str(dst_ip).unwrap()))?;
                     Ok(true)
                 }
                 ACTION_REGISTER_OPTION => {
-                    let universe: u16 = split_input[1].parse().unwrap();
+                    let universe = Universe::try_from(split_input[1].parse::<u16>().unwrap())?;
                     src.register_universe(universe)?;
                     Ok(true)
                 }
@@ -1175,7 +1186,7 @@ fn handle_input(src: &mut SacnSource) -> Result<bool> {
                     if universe == 0 {
                         return Ok(false);
                     } else {
-                        src.terminate_stream(universe, TERMINATE_START_CODE)?;
+                        src.terminate_stream(Universe::try_from(universe)?, TERMINATE_START_CODE)?;
                     }
                     Ok(true)
                 }
@@ -15,10 +15,11 @@ use std::time::{Duration, Instant};
 
 use sacn::packet::ACN_SDT_MULTICAST_PORT;
 use sacn::receive::{DMXData, SacnReceiver};
+use sacn::types::Universe;
 
 fn main() {
     let interface_ip = "127.0.0.1";
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
     let duration = Duration::from_secs(12);
     let max_wait = Duration::from_secs(1);
     let mut dmx_recv = SacnReceiver::with_ip(
@@ -9,6 +9,7 @@
 
 use sacn::packet::{ACN_SDT_MULTICAST_PORT, UNIVERSE_CHANNEL_CAPACITY};
 use sacn::source::SacnSource;
+use sacn::types::Universe;
 
 use std::net::SocketAddr;
 use std::thread::sleep;
@@ -17,12 +18,12 @@ use std::time::{Duration, Instant};
 fn main() {
     let interface_ip = "127.0.0.1";
     let source_name = "sine-wave-sender";
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
     let duration = Duration::from_secs(12);
     let refresh_rate = Duration::from_millis(33);
 
     // Uses the next along port to allow usage on the same machine as a receiver which is using the ACN_SDT port.
-    let mut src = SacnSource::with_ip(
+    let src = SacnSource::with_ip(
         source_name,
         SocketAddr::new(interface_ip.parse().unwrap(), ACN_SDT_MULTICAST_PORT + 1),
     )
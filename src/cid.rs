@@ -0,0 +1,134 @@
+#![warn(missing_docs)]
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Stable Component Identifiers (CIDs) for sources.
+//!
+//! ANSI E1.17-2015 Section 2.1 expects the CID of a component to stay the same for its lifetime, including across restarts. A source
+//! given a new random CID each time it starts appears to receivers as a new source, resetting merges and filling source tables.
+//!
+//! A CID can either be derived deterministically from a name using `derive_cid`, or generated once and kept in a file using a
+//! `CidStore`.
+//!
+//! ```
+//! use sacn::cid::{SACN_CID_NAMESPACE, derive_cid};
+//!
+//! let cid = derive_cid(&SACN_CID_NAMESPACE, "stage-left-dimmers");
+//! assert_eq!(cid, derive_cid(&SACN_CID_NAMESPACE, "stage-left-dimmers"));
+//! assert_ne!(cid, derive_cid(&SACN_CID_NAMESPACE, "stage-right-dimmers"));
+//! ```
+
+use crate::error::errors::*;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// The namespace used to derive CIDs from names when an application doesn't have a namespace of its own.
+///
+/// Different applications deriving CIDs from the same names within this namespace get the same CIDs, applications which may run on
+/// the same network with overlapping names should use their own namespace.
+pub const SACN_CID_NAMESPACE: Uuid = Uuid::from_u128(0xeee9c2c3_77fc_457c_9c80_fa18afd89da7);
+
+/// Derives a CID from the given namespace and name, the same namespace and name always give the same CID.
+///
+/// The CID is a version 5 (SHA-1 name based) UUID as described in RFC 9562 Section 5.5.
+///
+/// # Arguments
+/// namespace: The namespace the name is within, for example `SACN_CID_NAMESPACE` or a UUID specific to the application.
+///
+/// name: A name identifying the device or instance within the namespace, for example a hostname or serial number.
+pub fn derive_cid(namespace: &Uuid, name: &str) -> Uuid {
+    Uuid::new_v5(namespace, name.as_bytes())
+}
+
+/// Keeps the CID of a source in a file so that the same CID is used each time the application starts.
+///
+/// The file holds the CID as a single hyphenated UUID, for example `eee9c2c3-77fc-457c-9c80-fa18afd89da7`.
+///
+/// ```no_run
+/// use sacn::cid::CidStore;
+/// use sacn::source::SacnSourceBuilder;
+///
+/// let src = SacnSourceBuilder::new("Source")
+///     .cid_store(CidStore::new("/var/lib/my-app/sacn-cid"))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CidStore {
+    /// The path of the file the CID is kept in.
+    path: PathBuf,
+}
+
+impl CidStore {
+    /// Creates a `CidStore` using the file at the given path, the file isn't accessed until the CID is loaded or saved.
+    pub fn new<P: AsRef<Path>>(path: P) -> CidStore {
+        CidStore {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the path of the file the CID is kept in.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the CID from the file, None if the file doesn't exist.
+    ///
+    /// # Errors
+    /// Io: Returned if the file exists but can't be read.
+    ///
+    /// Uuid: Returned if the file doesn't contain a valid UUID.
+    pub fn load(&self) -> Result<Option<Uuid>> {
+        match fs::read_to_string(&self.path) {
+            Ok(contents) => Ok(Some(Uuid::parse_str(contents.trim())?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Saves the given CID to the file, replacing any CID already saved.
+    ///
+    /// The CID is written to a temporary file alongside which then replaces the file so that a partially written CID is never loaded.
+    ///
+    /// # Errors
+    /// Io: Returned if the file can't be written.
+    pub fn save(&self, cid: &Uuid) -> Result<()> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+
+        fs::write(&tmp, format!("{}\n", cid.hyphenated()))?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// Loads the CID from the file, if there isn't one the CID given by `f` is saved and returned.
+    ///
+    /// # Errors
+    /// See (`load`)[`CidStore::load`] and (`save`)[`CidStore::save`].
+    pub fn load_or_insert_with<F: FnOnce() -> Uuid>(&self, f: F) -> Result<Uuid> {
+        if let Some(cid) = self.load()? {
+            return Ok(cid);
+        }
+
+        let cid = f();
+        self.save(&cid)?;
+        Ok(cid)
+    }
+
+    /// Loads the CID from the file, if there isn't one a random CID is generated, saved and returned.
+    ///
+    /// # Errors
+    /// See (`load`)[`CidStore::load`] and (`save`)[`CidStore::save`].
+    pub fn load_or_generate(&self) -> Result<Uuid> {
+        self.load_or_insert_with(Uuid::new_v4)
+    }
+}
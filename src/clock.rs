@@ -0,0 +1,154 @@
+#![warn(missing_docs)]
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Sources of the current time used by `SacnSource` and `SacnReceiver` for timeouts.
+//!
+//! By default the system monotonic clock is used. A `MockClock` can be used instead so that timeout behaviour such as the
+//! `E131_NETWORK_DATA_LOSS_TIMEOUT` or the `E131_UNIVERSE_DISCOVERY_INTERVAL` can be tested without waiting in real time.
+//!
+//! ```
+//! use sacn::clock::{Clock, MockClock};
+//! use std::time::Duration;
+//!
+//! let clock = MockClock::new();
+//! let start = clock.now();
+//!
+//! clock.advance(Duration::from_secs(3));
+//! assert_eq!(clock.now() - start, Duration::from_secs(3));
+//! ```
+//!
+//! An `Instant` can't be compared between processes or serialised so `to_system_time` converts one into the wall-clock time it
+//! corresponds to, with the `serde` feature `serde_system_time` uses this to serialise the timestamps of received data.
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+/// A source of the current time.
+///
+/// Only the time used for protocol timeouts and shutdown deadlines is taken from the clock, blocking operations such as waiting for
+/// data on a transport still wait in real time.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Returns the current time according to this clock.
+    fn now(&self) -> Instant;
+}
+
+/// The system monotonic clock, as given by `Instant::now()`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock which only moves when it is manually advanced.
+///
+/// Clones share the same time so a clone can be given to a source or receiver while the original is kept to advance it.
+#[derive(Clone, Debug)]
+pub struct MockClock {
+    /// The current time of the clock, shared between clones.
+    now: Arc<Mutex<Instant>>,
+}
+
+impl MockClock {
+    /// Creates a new `MockClock` starting at the current system time.
+    pub fn new() -> MockClock {
+        MockClock::starting_at(Instant::now())
+    }
+
+    /// Creates a new `MockClock` starting at the given time.
+    pub fn starting_at(start: Instant) -> MockClock {
+        MockClock {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    /// Moves the clock forward by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    /// Sets the clock to the given time.
+    ///
+    /// The time may be earlier than the current time of the clock, however a source or receiver using the clock assumes that
+    /// time doesn't go backwards so timeouts may then be delayed.
+    pub fn set(&self, now: Instant) {
+        *self.lock() = now;
+    }
+
+    /// Locks the current time, a poisoned lock is still used as an `Instant` can't be left partially updated.
+    fn lock(&self) -> MutexGuard<'_, Instant> {
+        self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> MockClock {
+        MockClock::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.lock()
+    }
+}
+
+/// Converts the given `Instant` into the wall-clock time it corresponds to, None if it can't be represented as a `SystemTime`.
+///
+/// The conversion is relative to the current time of both clocks so the result moves if the system clock is changed. An `Instant`
+/// from a `MockClock` is converted as if it came from the `SystemClock`.
+pub fn to_system_time(instant: Instant) -> Option<SystemTime> {
+    let now = Instant::now();
+    let system_now = SystemTime::now();
+    match now.checked_duration_since(instant) {
+        Some(elapsed) => system_now.checked_sub(elapsed),
+        None => system_now.checked_add(instant - now),
+    }
+}
+
+/// Converts the given wall-clock time into an `Instant`, the reverse of `to_system_time`.
+///
+/// Returns None if the time can't be represented as an `Instant`, for example if it is before the system started.
+pub fn from_system_time(time: SystemTime) -> Option<Instant> {
+    let now = Instant::now();
+    match SystemTime::now().duration_since(time) {
+        Ok(elapsed) => now.checked_sub(elapsed),
+        Err(e) => now.checked_add(e.duration()),
+    }
+}
+
+/// Serialises an `Instant` as the `SystemTime` given by `to_system_time`, for use with `#[serde(with = "sacn::clock::serde_system_time")]`.
+#[cfg(feature = "serde")]
+pub mod serde_system_time {
+    use super::{from_system_time, to_system_time};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+    use std::time::{Instant, SystemTime};
+
+    /// Serialises the instant as a `SystemTime`.
+    ///
+    /// # Errors
+    /// Returned if the instant can't be represented as a `SystemTime`.
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        to_system_time(*instant)
+            .ok_or_else(|| ser::Error::custom("instant can't be represented as a system time"))?
+            .serialize(serializer)
+    }
+
+    /// Deserialises a `SystemTime` into an `Instant`.
+    ///
+    /// # Errors
+    /// Returned if the time can't be represented as an `Instant`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        from_system_time(SystemTime::deserialize(deserializer)?)
+            .ok_or_else(|| de::Error::custom("system time can't be represented as an instant"))
+    }
+}
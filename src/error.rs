@@ -11,7 +11,7 @@
 //!
 //! Errors from external sources are wrapped within thiserror.
 //!
-//! Io errors from std::io::Error are wrapped within Io(::std::io::Error)
+//! Io errors from std::io::Error are wrapped within Io(::std::io::Error), this variant only exists with the `std` feature.
 //!
 //! String errors from std::str::Utf8Error are wrapped within Str(::std::str::Utf8Error)
 //!
@@ -20,9 +20,31 @@
 //! ParsePack related errors come within their own family wrapped inside this error to allow easy matching (can just match for SacnParsePackError rather than a specific).
 //!
 //! SacnParsePackError(sacn_parse_pack_error::Error, sacn_parse_pack_error::ErrorKind)
+//!
+//! Each error belongs to a `SacnErrorKind` returned by `SacnError::kind`, and `SacnError::is_transient` says whether the operation which
+//! returned it may succeed if tried again. This allows, for example, a receive loop to keep going after a timeout or a bad packet
+//! without matching on individual variants or `io::ErrorKind`s.
+//!
+//! ```
+//! use sacn::error::errors::{SacnError, SacnErrorKind};
+//! use std::io;
+//!
+//! let timeout: SacnError = io::Error::new(io::ErrorKind::WouldBlock, "No data available in given timeout").into();
+//! assert_eq!(timeout.kind(), SacnErrorKind::Io);
+//! assert!(timeout.is_timeout());
+//! assert!(timeout.is_transient());
+//!
+//! let illegal = SacnError::IllegalUniverse(0);
+//! assert_eq!(illegal.kind(), SacnErrorKind::Configuration);
+//! assert!(!illegal.is_transient());
+//! ```
 
 pub mod errors {
     use crate::sacn_parse_pack_error::ParsePacketError;
+    use alloc::string::String;
+    use core::net::SocketAddr;
+    #[cfg(feature = "std")]
+    use std::io;
     use thiserror::Error;
     use uuid::Uuid;
 
@@ -30,19 +52,47 @@ pub mod errors {
     ///
     /// This type is used throughout the sACN crate for any operation which
     /// can produce an error.
-    pub type Result<T> = std::result::Result<T, SacnError>;
+    pub type Result<T> = core::result::Result<T, SacnError>;
+
+    /// The broad category of a `SacnError`, see `SacnError::kind`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub enum SacnErrorKind {
+        /// An error from the underlying network or file system, including receive timeouts.
+        Io,
+
+        /// A packet received from the network broke ANSI E1.31-2018 or couldn't be accepted, for example because it was malformed or
+        /// out of sequence. The packet is discarded and the receiver can carry on receiving.
+        Protocol,
+
+        /// The source, receiver or a value was configured or used incorrectly by the caller, for example an illegal universe. Trying
+        /// again with the same arguments gives the same error.
+        Configuration,
 
+        /// Not a failure, a receiver uses these to announce something happening on the network such as a source being discovered
+        /// or a universe terminating.
+        Event,
+
+        /// The source or receiver has been left in an unusable state and should be recreated.
+        Internal,
+    }
+
+    /// The errors returned by sources and receivers, see `kind` for how they are grouped.
+    ///
+    /// New variants may be added in future releases so matches should include a wildcard arm.
     #[derive(Debug, Error)]
+    #[non_exhaustive]
     pub enum SacnError {
-        // Allow IO errors to be used with the error system.
+        // Allow IO errors to be used with the error system, sockets and files are only available with the std feature.
+        #[cfg(feature = "std")]
         #[error("Io error occurred: {0}")]
         Io(#[from] std::io::Error),
         // Allow standard string library errors to be used with the error system.
         #[error("String error occurred: {0}")]
-        Str(#[from] std::str::Utf8Error),
-        // Allow UUID library to be used with error system.
+        Str(#[from] core::str::Utf8Error),
+        // Allow UUID library to be used with error system, uuid only implements Error with its std feature.
         #[error("Uuid error occurred: {0}")]
-        Uuid(#[from] uuid::Error),
+        Uuid(#[cfg_attr(feature = "std", from)] uuid::Error),
 
         /// Returned to indicate that too many bytes were read to fit into supplied buffer.
         ///
@@ -80,16 +130,22 @@ pub mod errors {
         /// as per ANSI E1.31-2018 Section 6.2.3.3.
         ///
         /// # Arguments
-        /// Number of sources
-        #[error("Limit for the number of supported sources has been reached: {0}")]
-        SourcesExceededError(usize),
+        /// Uuid: The CID of the new source which was rejected.
+        ///
+        /// usize: Number of sources
+        #[error(
+            "Limit for the number of supported sources has been reached: {1}, source cid: {0} rejected"
+        )]
+        SourcesExceededError(Uuid, usize),
 
         /// A source was discovered by a receiver with the `announce_discovery_flag` set to true.
         ///
         /// # Arguments
-        /// The name of the source discovered.
-        #[error("Source discovered with announce_discovery_flag set to true: {0}")]
-        SourceDiscovered(String),
+        /// Uuid: The CID of the source discovered.
+        ///
+        /// String: The name of the source discovered.
+        #[error("Source discovered with announce_discovery_flag set to true: {1}, source cid: {0}")]
+        SourceDiscovered(Uuid, String),
 
         /// Attempted to exceed the capacity of a single universe (`packet::UNIVERSE_CHANNEL_CAPACITY`).
         ///
@@ -149,15 +205,28 @@ pub mod errors {
         /// Packet was received out of sequence and so should be discarded.
         ///
         /// # Arguments
+        /// Uuid: The CID of the source which sent the packet.
+        ///
+        /// u16: The universe of the packet, the synchronisation address for synchronisation packets.
+        ///
         /// u8: The sequence number of the packet received.
         ///
         /// u8: The last sequence number received.
         ///
         /// isize: The difference between the last and current sequence numbers.
         #[error(
-            "Packet received with sequence number {0} is out of sequence, last {1}, seq-diff {2}"
+            "Packet received with sequence number {2} is out of sequence, last {3}, seq-diff {4}, source cid: {0}, universe: {1}"
         )]
-        OutOfSequence(u8, u8, isize),
+        OutOfSequence(Uuid, u16, u8, u8, isize),
+
+        /// A receiver received a packet which couldn't be parsed.
+        ///
+        /// # Arguments
+        /// `SocketAddr`: The address the packet was received from.
+        ///
+        /// `ParsePacketError`: Why the packet couldn't be parsed.
+        #[error("Malformed packet received from {0}: {1}")]
+        MalformedPacket(SocketAddr, #[source] ParsePacketError),
 
         /// A source terminated a universe and this was detected when trying to receive data.
         /// This is only returned if the `announce_stream_termination` flag is set to true (default false).
@@ -235,5 +304,129 @@ pub mod errors {
             "Attempting to receive data with no data universes registered, an infinite timeout and no discovery announcements"
         )]
         NoDataUniversesRegistered(),
+
+        /// Returned if an impairment profile used to simulate a poor network is invalid.
+        ///
+        /// # Arguments
+        /// String: A message describing which part of the profile is invalid.
+        #[error("Invalid impairment profile: {0}")]
+        InvalidImpairmentProfile(String),
+
+        /// Returned if a timing profile given to a source or receiver is invalid.
+        ///
+        /// # Arguments
+        /// String: A message describing which part of the profile is invalid.
+        #[error("Invalid timing profile: {0}")]
+        InvalidTimingProfile(String),
+
+        /// Returned by a builder if the combination of options it was given can't be used together.
+        ///
+        /// # Arguments
+        /// String: A message describing which options conflict.
+        #[error("Invalid configuration: {0}")]
+        InvalidConfiguration(String),
+
+        /// Returned if DMX data isn't valid for its start code, see `start_code::StartCodeRegistry::decode`.
+        ///
+        /// # Arguments
+        /// u8: The start code.
+        ///
+        /// String: A message describing why the data is invalid.
+        #[error("Invalid data for start code {0:#04x}: {1}")]
+        InvalidStartCodePayload(u8, String),
+
+        /// Returned within a `ShutdownReport` for a universe which wasn't terminated or left before the shutdown timeout passed.
+        ///
+        /// # Arguments
+        /// u16: The universe which wasn't shut down.
+        #[error("Shutdown timed out before universe {0} was shut down")]
+        ShutdownTimeout(u16),
+    }
+
+    #[cfg(not(feature = "std"))]
+    impl From<uuid::Error> for SacnError {
+        fn from(e: uuid::Error) -> SacnError {
+            SacnError::Uuid(e)
+        }
+    }
+
+    impl SacnError {
+        /// Returns the category of the error.
+        pub fn kind(&self) -> SacnErrorKind {
+            match self {
+                #[cfg(feature = "std")]
+                SacnError::Io(_) => SacnErrorKind::Io,
+
+                SacnError::ShutdownTimeout(_) => SacnErrorKind::Io,
+
+                SacnError::Str(_)
+                | SacnError::TooManyBytesRead(..)
+                | SacnError::SacnParsePackError(_)
+                | SacnError::SourcesExceededError(..)
+                | SacnError::OutOfSequence(..)
+                | SacnError::MalformedPacket(..)
+                | SacnError::InvalidStartCodePayload(..) => SacnErrorKind::Protocol,
+
+                SacnError::SourceDiscovered(..)
+                | SacnError::UniverseTerminated(..)
+                | SacnError::UniverseTimeout(..) => SacnErrorKind::Event,
+
+                SacnError::SourceCorrupt(_) => SacnErrorKind::Internal,
+
+                SacnError::Uuid(_)
+                | SacnError::MalformedSourceName(_)
+                | SacnError::InvalidPriority(_)
+                | SacnError::ExceedUniverseCapacity(_)
+                | SacnError::IllegalUniverse(_)
+                | SacnError::IllegalSyncUniverse(_)
+                | SacnError::UniverseNotRegistered(_)
+                | SacnError::IpVersionError()
+                | SacnError::UnsupportedIpVersion(_)
+                | SacnError::SenderAlreadyTerminated(_)
+                | SacnError::DmxMergeError()
+                | SacnError::UniverseNotFound(_)
+                | SacnError::SourceNotFound(_)
+                | SacnError::OsOperationUnsupported(_)
+                | SacnError::DataArrayEmpty()
+                | SacnError::UniverseListEmpty()
+                | SacnError::SourceLimitZero()
+                | SacnError::NoDataUniversesRegistered()
+                | SacnError::InvalidImpairmentProfile(_)
+                | SacnError::InvalidTimingProfile(_)
+                | SacnError::InvalidConfiguration(_) => SacnErrorKind::Configuration,
+            }
+        }
+
+        /// Returns true if the operation which returned the error may succeed if it is tried again unchanged.
+        ///
+        /// This is the case for timeouts and interrupted IO, for `Protocol` errors as they only affect the packet received and for
+        /// `Event`s. A receiver can therefore keep calling `recv` while this returns true.
+        pub fn is_transient(&self) -> bool {
+            match self.kind() {
+                SacnErrorKind::Io => match self {
+                    #[cfg(feature = "std")]
+                    SacnError::Io(e) => self.is_timeout() || e.kind() == io::ErrorKind::Interrupted,
+                    _ => false,
+                },
+                SacnErrorKind::Protocol | SacnErrorKind::Event => true,
+                SacnErrorKind::Configuration | SacnErrorKind::Internal => false,
+            }
+        }
+
+        /// Returns true if the error is the `Io` error returned when no data was received within the timeout.
+        ///
+        /// Unix and Windows use `WouldBlock` and `TimedOut` respectively for this so both are treated as a timeout.
+        pub fn is_timeout(&self) -> bool {
+            match self {
+                #[cfg(feature = "std")]
+                SacnError::Io(e) => {
+                    matches!(
+                        e.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    )
+                }
+                _ => false,
+            }
+        }
     }
 }
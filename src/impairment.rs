@@ -0,0 +1,543 @@
+#![warn(missing_docs)]
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Network impairment simulation used for testing how sources and receivers cope with a poor network.
+//!
+//! An `ImpairedTransport` wraps another `Transport` and applies an `ImpairmentProfile` to the datagrams sent and / or received through it.
+//! A profile can drop (loss), duplicate, reorder, delay and jitter datagrams. All random decisions are made using a pseudo random generator
+//! seeded from the profile so a test run with the same profile and the same traffic always sees the same impairment.
+//!
+//! This allows checking the receiver behaviour described in ANSI E1.31-2018 Section 6.7.2 Sequence Numbering: packets that arrive after
+//! a lost packet are accepted while duplicated and late (reordered) packets are discarded.
+//!
+//! # Examples
+//!
+//! ```
+//! use sacn::impairment::{ImpairedTransport, ImpairmentProfile};
+//! use sacn::packet::ACN_SDT_MULTICAST_PORT;
+//! use sacn::receive::SacnReceiver;
+//! use sacn::transport::MemoryNetwork;
+//!
+//! use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+//!
+//! let network = MemoryNetwork::new();
+//! let rcv_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), ACN_SDT_MULTICAST_PORT);
+//!
+//! // Lose roughly 1 in 10 received datagrams.
+//! let profile = ImpairmentProfile {
+//!     loss: 0.1,
+//!     seed: 42,
+//!     ..ImpairmentProfile::default()
+//! };
+//!
+//! let transport = ImpairedTransport::on_recv(network.bind(rcv_addr).unwrap(), profile).unwrap();
+//! let dmx_rcv = SacnReceiver::with_transport(transport, None).unwrap();
+//! ```
+
+use crate::error::errors::*;
+use crate::transport::Transport;
+
+use std::cmp::{Reverse, max, min};
+use std::collections::BinaryHeap;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Socket2 is only used to expose the socket of the wrapped transport.
+use socket2::SockRef;
+
+/// The smallest timeout used when waiting on the wrapped transport, some transports (e.g. UDP sockets) reject a zero timeout.
+const MIN_INNER_TIMEOUT: Duration = Duration::from_millis(1);
+
+/// Describes the impairment applied to datagrams passing through one direction of an `ImpairedTransport`.
+///
+/// Each datagram is first checked for loss, a datagram which isn't lost may be duplicated and each copy is then given a delivery time of
+/// `delay` plus a random jitter of up to `jitter`. Each copy may also be reordered, meaning it is held back and delivered after the next
+/// datagram.
+///
+/// The default profile applies no impairment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ImpairmentProfile {
+    /// The probability in the range [0, 1] that a datagram is lost.
+    pub loss: f64,
+
+    /// The probability in the range [0, 1] that a datagram is delivered twice.
+    pub duplication: f64,
+
+    /// The probability in the range [0, 1] that a datagram is held back and delivered after the datagram that follows it.
+    pub reorder: f64,
+
+    /// The fixed delay added to every datagram.
+    pub delay: Duration,
+
+    /// The maximum random delay added to each datagram on top of `delay`. Jitter larger than the gap between datagrams reorders them.
+    pub jitter: Duration,
+
+    /// The seed for the pseudo random generator used to make impairment decisions.
+    pub seed: u64,
+}
+
+impl Default for ImpairmentProfile {
+    fn default() -> Self {
+        ImpairmentProfile {
+            loss: 0.0,
+            duplication: 0.0,
+            reorder: 0.0,
+            delay: Duration::ZERO,
+            jitter: Duration::ZERO,
+            seed: 0,
+        }
+    }
+}
+
+impl ImpairmentProfile {
+    /// Checks that the probabilities of this profile are within the range [0, 1].
+    ///
+    /// # Errors
+    /// `InvalidImpairmentProfile`: Returned if any of the loss, duplication or reorder probabilities are outwith the range [0, 1].
+    pub fn validate(&self) -> Result<()> {
+        for (name, p) in [
+            ("loss", self.loss),
+            ("duplication", self.duplication),
+            ("reorder", self.reorder),
+        ] {
+            if !(0.0..=1.0).contains(&p) {
+                return Err(SacnError::InvalidImpairmentProfile(format!(
+                    "{name} probability {p} is outwith the range [0, 1]"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Counts of the impairment applied to one direction of an `ImpairedTransport`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImpairmentStats {
+    /// The number of datagrams which entered the impairment.
+    pub submitted: usize,
+
+    /// The number of datagrams which were lost.
+    pub lost: usize,
+
+    /// The number of datagrams which were duplicated.
+    pub duplicated: usize,
+
+    /// The number of datagram copies which were held back to be delivered after the following datagram.
+    pub reordered: usize,
+
+    /// The number of datagram copies which have been delivered so far.
+    pub delivered: usize,
+}
+
+/// A `Transport` which applies an `ImpairmentProfile` to the datagrams sent and received over the transport it wraps.
+///
+/// Received datagrams are held until their delivery time and returned by `recv_from` in delivery time order. A datagram held back for
+/// reordering with no datagram following it is delivered once the read timeout of `recv_from` expires. Delivery times of received
+/// datagrams are relative to when they are read from the wrapped transport, so datagrams queued while nothing was receiving are treated
+/// as arriving together.
+///
+/// Sent datagrams are passed to the wrapped transport by `send_to` once their delivery time has been reached, this is checked each time
+/// `send_to` is called so delayed datagrams are sent by a later call. `flush` sends all held datagrams immediately.
+#[derive(Debug)]
+pub struct ImpairedTransport<T: Transport> {
+    /// The wrapped transport.
+    inner: T,
+
+    /// The impairment applied to sent datagrams.
+    send: Mutex<Impairer>,
+
+    /// The impairment applied to received datagrams.
+    recv: Mutex<Impairer>,
+
+    /// The timeout used by `recv_from`, None means block until a datagram is delivered.
+    read_timeout: Mutex<Option<Duration>>,
+}
+
+impl<T: Transport> ImpairedTransport<T> {
+    /// Wraps the given transport applying the given profiles to sent and received datagrams.
+    ///
+    /// # Errors
+    /// `InvalidImpairmentProfile`: Returned if either profile is invalid, see `ImpairmentProfile::validate`.
+    pub fn new(
+        inner: T,
+        send_profile: ImpairmentProfile,
+        recv_profile: ImpairmentProfile,
+    ) -> Result<ImpairedTransport<T>> {
+        send_profile.validate()?;
+        recv_profile.validate()?;
+        Ok(ImpairedTransport {
+            inner,
+            send: Mutex::new(Impairer::new(send_profile)),
+            recv: Mutex::new(Impairer::new(recv_profile)),
+            read_timeout: Mutex::new(None),
+        })
+    }
+
+    /// Wraps the given transport applying the given profile to sent datagrams only.
+    ///
+    /// # Errors
+    /// `InvalidImpairmentProfile`: Returned if the profile is invalid, see `ImpairmentProfile::validate`.
+    pub fn on_send(inner: T, profile: ImpairmentProfile) -> Result<ImpairedTransport<T>> {
+        ImpairedTransport::new(inner, profile, ImpairmentProfile::default())
+    }
+
+    /// Wraps the given transport applying the given profile to received datagrams only.
+    ///
+    /// # Errors
+    /// `InvalidImpairmentProfile`: Returned if the profile is invalid, see `ImpairmentProfile::validate`.
+    pub fn on_recv(inner: T, profile: ImpairmentProfile) -> Result<ImpairedTransport<T>> {
+        ImpairedTransport::new(inner, ImpairmentProfile::default(), profile)
+    }
+
+    /// Replaces the profile applied to sent datagrams, the pseudo random generator is reseeded from the new profile.
+    ///
+    /// Datagrams already held by the previous profile are kept and delivered as normal.
+    ///
+    /// # Errors
+    /// `InvalidImpairmentProfile`: Returned if the profile is invalid, see `ImpairmentProfile::validate`.
+    ///
+    /// Io: Returned if the lock was poisoned by a panic in another thread.
+    pub fn set_send_profile(&self, profile: ImpairmentProfile) -> Result<()> {
+        profile.validate()?;
+        lock_impairment(&self.send)?.set_profile(profile);
+        Ok(())
+    }
+
+    /// Replaces the profile applied to received datagrams, the pseudo random generator is reseeded from the new profile.
+    ///
+    /// Datagrams already held by the previous profile are kept and delivered as normal.
+    ///
+    /// # Errors
+    /// `InvalidImpairmentProfile`: Returned if the profile is invalid, see `ImpairmentProfile::validate`.
+    ///
+    /// Io: Returned if the lock was poisoned by a panic in another thread.
+    pub fn set_recv_profile(&self, profile: ImpairmentProfile) -> Result<()> {
+        profile.validate()?;
+        lock_impairment(&self.recv)?.set_profile(profile);
+        Ok(())
+    }
+
+    /// Returns the counts of the impairment applied to sent datagrams.
+    ///
+    /// # Errors
+    /// Io: Returned if the lock was poisoned by a panic in another thread.
+    pub fn send_stats(&self) -> Result<ImpairmentStats> {
+        Ok(lock_impairment(&self.send)?.stats)
+    }
+
+    /// Returns the counts of the impairment applied to received datagrams.
+    ///
+    /// # Errors
+    /// Io: Returned if the lock was poisoned by a panic in another thread.
+    pub fn recv_stats(&self) -> Result<ImpairmentStats> {
+        Ok(lock_impairment(&self.recv)?.stats)
+    }
+
+    /// Immediately sends all sent datagrams that are still held because of delay, jitter or reordering, in delivery order.
+    ///
+    /// # Errors
+    /// Returns any error from sending on the wrapped transport, see `Transport::send_to`.
+    ///
+    /// Io: Returned if the lock was poisoned by a panic in another thread.
+    pub fn flush(&self) -> Result<()> {
+        let mut send = lock_impairment(&self.send)?;
+        while let Some(p) = send.pop_any() {
+            self.inner.send_to(&p.datagram, p.addr)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a reference to the wrapped transport.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: Transport> Transport for ImpairedTransport<T> {
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> Result<usize> {
+        let now = Instant::now();
+        let mut send = lock_impairment(&self.send)?;
+        send.submit(now, buf.to_vec(), addr);
+        while let Some(p) = send.pop_due(now) {
+            self.inner.send_to(&p.datagram, p.addr)?;
+        }
+
+        // As with UDP a lost datagram still counts as sent.
+        Ok(buf.len())
+    }
+
+    fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddr)> {
+        let timeout = *lock_impairment(&self.read_timeout)?;
+        let deadline = timeout.and_then(|t| Instant::now().checked_add(t));
+
+        let mut recv = lock_impairment(&self.recv)?;
+        let mut inner_buf = vec![0; buf.len()];
+        loop {
+            let now = Instant::now();
+            if let Some(p) = recv.pop_due(now) {
+                let n = p.datagram.len().min(buf.len());
+                buf[..n].copy_from_slice(&p.datagram[..n]);
+                return Ok((n, p.addr));
+            }
+
+            // Wait for the wrapped transport until either the read timeout expires or the next held datagram is due.
+            let mut wait = deadline.map(|d| d.saturating_duration_since(now));
+            if let Some(release) = recv.next_release() {
+                let until_release = release.saturating_duration_since(now);
+                wait = Some(wait.map_or(until_release, |w| min(w, until_release)));
+            }
+
+            if deadline.is_some_and(|d| now >= d) {
+                // A datagram held back for reordering with nothing following it is delivered rather than lost.
+                if recv.release_held() {
+                    continue;
+                }
+                return Err(io::Error::new(
+                    io::ErrorKind::WouldBlock,
+                    "No datagram available in given timeout",
+                )
+                .into());
+            }
+
+            self.inner
+                .set_read_timeout(wait.map(|w| max(w, MIN_INNER_TIMEOUT)))?;
+            match self.inner.recv_from(&mut inner_buf) {
+                Ok((n, from)) => recv.submit(Instant::now(), inner_buf[..n].to_vec(), from),
+                Err(ref e) if e.is_timeout() => {
+                    // Loop to deliver any datagram now due or to check the deadline.
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn join_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()> {
+        self.inner.join_multicast(group, interface)
+    }
+
+    fn leave_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()> {
+        self.inner.leave_multicast(group, interface)
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        *lock_impairment(&self.read_timeout)? = timeout;
+        Ok(())
+    }
+
+    fn local_addr(&self) -> Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn socket(&self) -> Option<SockRef<'_>> {
+        self.inner.socket()
+    }
+}
+
+/// A datagram held by an `Impairer` until its delivery time.
+///
+/// Ordered by delivery time and then by the order in which it was held so datagrams with the same delivery time keep their order.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct HeldDatagram {
+    /// The time at which the datagram should be delivered.
+    release: Instant,
+
+    /// The position of this datagram in the order datagrams were held.
+    order: u64,
+
+    /// The contents of the datagram.
+    datagram: Vec<u8>,
+
+    /// The address the datagram is sent to or received from.
+    addr: SocketAddr,
+}
+
+/// Applies an `ImpairmentProfile` to a stream of datagrams.
+#[derive(Debug)]
+struct Impairer {
+    /// The profile being applied.
+    profile: ImpairmentProfile,
+
+    /// Generator used for all random decisions, seeded from the profile.
+    rng: SplitMix64,
+
+    /// Datagrams waiting for their delivery time, earliest first.
+    pending: BinaryHeap<Reverse<HeldDatagram>>,
+
+    /// A datagram held back for reordering, delivered after the next datagram.
+    held: Option<HeldDatagram>,
+
+    /// The order given to the next datagram added to `pending`.
+    next_order: u64,
+
+    /// Counts of the impairment applied so far.
+    stats: ImpairmentStats,
+}
+
+impl Impairer {
+    /// Creates a new `Impairer` applying the given profile.
+    fn new(profile: ImpairmentProfile) -> Impairer {
+        Impairer {
+            profile,
+            rng: SplitMix64::new(profile.seed),
+            pending: BinaryHeap::new(),
+            held: None,
+            next_order: 0,
+            stats: ImpairmentStats::default(),
+        }
+    }
+
+    /// Replaces the profile being applied and reseeds the generator.
+    fn set_profile(&mut self, profile: ImpairmentProfile) {
+        self.profile = profile;
+        self.rng = SplitMix64::new(profile.seed);
+    }
+
+    /// Applies the profile to the given datagram which arrived at the given time.
+    fn submit(&mut self, now: Instant, datagram: Vec<u8>, addr: SocketAddr) {
+        self.stats.submitted += 1;
+        if self.rng.chance(self.profile.loss) {
+            self.stats.lost += 1;
+            return;
+        }
+
+        let copies = if self.rng.chance(self.profile.duplication) {
+            self.stats.duplicated += 1;
+            2
+        } else {
+            1
+        };
+
+        for _ in 0..copies {
+            let release = now + self.profile.delay + self.rng.duration_up_to(self.profile.jitter);
+            let copy = HeldDatagram {
+                release,
+                order: 0,
+                datagram: datagram.clone(),
+                addr,
+            };
+
+            if self.held.is_none() && self.rng.chance(self.profile.reorder) {
+                self.stats.reordered += 1;
+                self.held = Some(copy);
+                continue;
+            }
+
+            self.push(copy);
+            if let Some(mut held) = self.held.take() {
+                // Delivered after the datagram that overtook it.
+                held.release = max(held.release, release);
+                self.push(held);
+            }
+        }
+    }
+
+    /// Adds the given datagram to the pending datagrams after all those already pending with the same delivery time.
+    fn push(&mut self, mut datagram: HeldDatagram) {
+        datagram.order = self.next_order;
+        self.next_order += 1;
+        self.pending.push(Reverse(datagram));
+    }
+
+    /// Returns the next datagram if its delivery time is at or before the given time.
+    fn pop_due(&mut self, now: Instant) -> Option<HeldDatagram> {
+        match self.pending.peek() {
+            Some(Reverse(p)) if p.release <= now => self.pop_any(),
+            _ => None,
+        }
+    }
+
+    /// Returns the next datagram in delivery order regardless of its delivery time, including a datagram held back for reordering.
+    fn pop_any(&mut self) -> Option<HeldDatagram> {
+        if self.pending.is_empty() {
+            self.release_held();
+        }
+        let p = self.pending.pop().map(|Reverse(p)| p);
+        if p.is_some() {
+            self.stats.delivered += 1;
+        }
+        p
+    }
+
+    /// Moves a datagram held back for reordering to the pending datagrams, returns true if there was one.
+    fn release_held(&mut self) -> bool {
+        match self.held.take() {
+            Some(held) => {
+                self.push(held);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the delivery time of the next pending datagram.
+    fn next_release(&self) -> Option<Instant> {
+        self.pending.peek().map(|Reverse(p)| p.release)
+    }
+}
+
+/// The `SplitMix64` pseudo random generator, used as it is small, fast and fully determined by its seed.
+///
+/// See <https://prng.di.unimi.it/splitmix64.c>.
+#[derive(Debug)]
+struct SplitMix64 {
+    /// The generator state.
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a generator with the given seed.
+    fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    /// Returns the next pseudo random value.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns true with the given probability, no value is drawn for a probability of 0 or 1.
+    fn chance(&mut self, probability: f64) -> bool {
+        if probability <= 0.0 {
+            false
+        } else if probability >= 1.0 {
+            true
+        } else {
+            // The top 53 bits give a uniform value in [0, 1) at the full precision of an f64.
+            ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+        }
+    }
+
+    /// Returns a uniformly distributed duration in the range [0, max].
+    fn duration_up_to(&mut self, max: Duration) -> Duration {
+        if max.is_zero() {
+            return Duration::ZERO;
+        }
+        let max_nanos = u64::try_from(max.as_nanos()).unwrap_or(u64::MAX);
+        Duration::from_nanos(self.next_u64() % max_nanos.saturating_add(1))
+    }
+}
+
+/// Locks a mutex used by an `ImpairedTransport`.
+///
+/// # Errors
+/// Io: Returned if the lock was poisoned by a panic in another thread.
+fn lock_impairment<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>> {
+    match mutex.lock() {
+        // The PoisonError is not returned directly as it only gives access to the potentially inconsistent protected state.
+        Err(_) => Err(SacnError::Io(io::Error::other(
+            "Impaired transport mutex poisoned",
+        ))),
+        Ok(lock) => Ok(lock),
+    }
+}
@@ -15,6 +15,9 @@
 //!
 //! Installation instructions are detailed within the README file.
 //!
+//! Without the default `std` feature the crate is `no_std` and only the `packet`, `types` and `error` modules are available, these
+//! need an allocator. This allows the same packet parsing and packing to be used on embedded devices as by sources and receivers.
+//!
 //!
 //!
 //! This file was modified as part of a University of St Andrews Computer Science BSC Senior Honours Dissertation Project.
@@ -26,11 +29,12 @@
 //! ```
 //! use sacn::receive::SacnReceiver;
 //! use sacn::packet::ACN_SDT_MULTICAST_PORT;
+//! use sacn::types::Universe;
 //!
 //! use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 //! use std::time::Duration;
 //!
-//! const UNIVERSE1: u16 = 1;
+//! const UNIVERSE1: Universe = Universe::new(1).unwrap();
 //! const TIMEOUT: Option<Duration> = Some(Duration::from_secs(1)); // A timeout of None means blocking behaviour, some indicates the actual timeout.
 //!
 //! let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
@@ -78,7 +82,7 @@
 //! match dmx_rcv.recv(TIMEOUT) {
 //!     Err(e) => {
 //!         match e {
-//!             sacn::error::errors::SacnError::SourceDiscovered(source_name) => {
+//!             sacn::error::errors::SacnError::SourceDiscovered(_cid, source_name) => {
 //!                 println!("Source name: {} discovered!", source_name);
 //!             }
 //!             other => {
@@ -99,15 +103,16 @@
 //! ```no_run
 //! use sacn::source::SacnSource;
 //! use sacn::packet::ACN_SDT_MULTICAST_PORT;
+//! use sacn::types::{Priority, SyncAddress, Universe};
 //! use std::net::{IpAddr, SocketAddr};
 //!
 //! let local_addr: SocketAddr = SocketAddr::new(IpAddr::V4("0.0.0.0".parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
 //!
-//! let mut src = SacnSource::with_ip("Source", local_addr).unwrap();
+//! let src = SacnSource::with_ip("Source", local_addr).unwrap();
 //!
-//! let universe: u16 = 1;                        // Universe the data is to be sent on.
-//! let sync_uni: Option<u16> = None;             // Don't want the packet to be delayed on the receiver awaiting synchronisation.
-//! let priority: u8 = 100;                       // The priority for the sending data, must be 1-200 inclusive,  None means use default.
+//! let universe = Universe::try_from(1).unwrap();       // Universe the data is to be sent on.
+//! let sync_uni: Option<SyncAddress> = None;            // Don't want the packet to be delayed on the receiver awaiting synchronisation.
+//! let priority = Priority::try_from(100).unwrap();     // The priority for the sending data, must be 0-200 inclusive,  None means use default.
 //! let dst_ip: Option<SocketAddr> = None;        // Sending the data using IP multicast so don't have a destination IP.
 //!
 //! src.register_universe(universe).unwrap(); // Register with the source that will be sending on the given universe.
@@ -122,6 +127,7 @@
 //! ```no_run
 //! use sacn::source::SacnSource;
 //! use sacn::packet::ACN_SDT_MULTICAST_PORT;
+//! use sacn::types::{Priority, SyncAddress, Universe};
 //!
 //! use std::net::{IpAddr, SocketAddr};
 //! use std::thread::sleep;
@@ -129,11 +135,11 @@
 //!
 //! let local_addr: SocketAddr = SocketAddr::new(IpAddr::V4("0.0.0.0".parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
 //!
-//! let mut src = SacnSource::with_ip("Source", local_addr).unwrap();
+//! let src = SacnSource::with_ip("Source", local_addr).unwrap();
 //!
-//! let universe: u16 = 1;                        // Universe the data is to be sent on.
-//! let sync_uni: Option<u16> = Some(1);          // Data packets use a synchronisation address of 1.
-//! let priority: u8 = 100;                       // The priority for the sending data, must be 1-200 inclusive,  None means use default.
+//! let universe = Universe::try_from(1).unwrap();       // Universe the data is to be sent on.
+//! let sync_uni = Some(SyncAddress::try_from(1).unwrap()); // Data packets use a synchronisation address of 1.
+//! let priority = Priority::try_from(100).unwrap();     // The priority for the sending data, must be 0-200 inclusive,  None means use default.
 //! let dst_ip: Option<SocketAddr> = None;        // Sending the data using IP multicast so don't have a destination IP.
 //!
 //! src.register_universe(universe).unwrap(); // Register with the source that will be sending on the given universe.
@@ -155,6 +161,7 @@
 //! ```no_run
 //! use sacn::source::SacnSource;
 //! use sacn::packet::ACN_SDT_MULTICAST_PORT;
+//! use sacn::types::{Priority, SyncAddress, Universe};
 //!
 //! use std::net::{IpAddr, SocketAddr};
 //! use std::thread::sleep;
@@ -162,11 +169,11 @@
 //!
 //! let local_addr: SocketAddr = SocketAddr::new(IpAddr::V4("0.0.0.0".parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
 //!
-//! let mut src = SacnSource::with_ip("Source", local_addr).unwrap();
+//! let src = SacnSource::with_ip("Source", local_addr).unwrap();
 //!
-//! let universe: u16 = 1;                        // Universe the data is to be sent on.
-//! let sync_uni: Option<u16> = None;             // Data packets are unsynchronised in this example but unicast transmission supports synchronised and unsynchronised sending.
-//! let priority: Option<u8> = Some(100);                       // The priority for the sending data, must be 1-200 inclusive,  None means use default.
+//! let universe = Universe::try_from(1).unwrap();       // Universe the data is to be sent on.
+//! let sync_uni: Option<SyncAddress> = None;            // Data packets are unsynchronised in this example but unicast transmission supports synchronised and unsynchronised sending.
+//! let priority = Some(Priority::try_from(100).unwrap()); // The priority for the sending data, must be 0-200 inclusive,  None means use default.
 //!
 //! // To send using unicast the dst_ip argument is set to a Some() value with the address to send the data to. By default the port should be the
 //! // ACN_SDT_MULTICAST_PORT but this can be configured differently if required in a specific situation. Change this address to the correct address for your
@@ -186,6 +193,9 @@
 // #![warn(missing_docs)]
 // Recursion limit for error_chain.
 #![recursion_limit = "1024"]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 /// The errors within the sACN crate related to parse/pack errors.
 /// Error-chain is used for errors within the library to allow chaining errors together to provide more informative backtraces.
@@ -199,7 +209,39 @@ pub mod error;
 pub mod packet;
 
 /// The source module handles generation of sACN on the network.
+#[cfg(feature = "std")]
 pub mod source;
 
 /// The receive module handles the receiving of sACN on the network.
+#[cfg(feature = "std")]
 pub mod receive;
+
+/// The transport module abstracts the datagram layer that sources and receivers send and receive sACN over.
+#[cfg(feature = "std")]
+pub mod transport;
+
+/// The impairment module simulates packet loss, duplication, reordering, delay and jitter for testing.
+#[cfg(feature = "std")]
+pub mod impairment;
+
+/// The clock module provides the source of time used for timeouts, allowing a manually advanced clock to be used in tests.
+#[cfg(feature = "std")]
+pub mod clock;
+
+/// The timing module holds the timeouts and intervals used by sources and receivers, with the E1.31 values as the default.
+#[cfg(feature = "std")]
+pub mod timing;
+
+/// The `start_code` module decodes DMX data according to its start code, such as levels, per-address priorities and text.
+pub mod start_code;
+
+/// The types module holds validated universes, synchronisation addresses, priorities and start codes used by the public API.
+pub mod types;
+
+/// The cid module derives and persists the Component Identifiers (CIDs) that identify sources.
+#[cfg(feature = "std")]
+pub mod cid;
+
+/// The shutdown module reports which universes were cleaned up when a source or receiver is explicitly shut down.
+#[cfg(feature = "std")]
+pub mod shutdown;
@@ -13,6 +13,15 @@
 //!
 //! The packets live within the scope of the ACN protocol suite.
 //!
+//! Packets can be built and parsed layer by layer using `AcnRootLayerProtocol` or as an `E131Packet` which owns its data and can be
+//! created using the builders returned by `E131Packet::data`, `E131Packet::sync` and `E131Packet::discovery`.
+//!
+//! This module only needs `alloc` so it is available without the `std` feature, for example on microcontrollers, apart from the
+//! functions returning multicast socket addresses. `AcnRootLayerProtocol::parse_fixed` parses a packet without allocating.
+//!
+//! `AcnRootLayerProtocol` holds a single PDU as sent by ANSI E1.31-2018 sources. Packets holding a block of several PDUs, which may
+//! inherit their vector, header or data from the previous PDU as allowed by ANSI E1.17, are handled by `AcnRootLayerBlock`.
+//!
 //! # Examples
 //!
 //! ```
@@ -54,18 +63,28 @@
 
 /// Uses the sACN errors.
 use crate::error::errors::{Result, SacnError};
-use crate::sacn_parse_pack_error::ParsePacketError;
+use crate::sacn_parse_pack_error::{PacketLayer, ParseErrorContext, ParsePacketError};
+use crate::types::{Priority, SyncAddress, Universe};
 
 /// The core crate is used for string processing during packet parsing/packing as well as to provide access to the Hash trait.
+use core::fmt::Display;
+use core::fmt::{self, Write};
 use core::hash::{self, Hash};
+use core::ops::Range;
 use core::str;
 
-use std::borrow::Cow;
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
-use std::vec::Vec;
-use std::{time, time::Duration};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::time::Duration;
 
+#[cfg(feature = "std")]
 use socket2::SockAddr;
+#[cfg(feature = "std")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 
 /// The byteorder crate is used for marshalling data on/off the network in Network Byte Order.
 use byteorder::{ByteOrder, NetworkEndian};
@@ -102,7 +121,7 @@ pub const E131_MIN_MULTICAST_UNIVERSE: u16 = 1;
 pub const E131_NO_SYNC_ADDR: u16 = 0;
 
 /// The interval between universe discovery packets (adverts) as defined by ANSI E1.31-2018 Appendix A.
-pub const E131_UNIVERSE_DISCOVERY_INTERVAL: Duration = time::Duration::from_secs(10);
+pub const E131_UNIVERSE_DISCOVERY_INTERVAL: Duration = Duration::from_secs(10);
 
 /// The exclusive lower bound on the different between the received and expected sequence numbers within which a
 /// packet will be discarded. Outside of the range specified by (`E131_SEQ_DIFF_DISCARD_LOWER_BOUND`, `E131_SEQ_DIFF_DISCARD_UPPER_BOUND`]
@@ -162,6 +181,25 @@ pub const E131_PDU_LENGTH_FLAGS_LENGTH: usize = 2;
 /// The pdu flags expected for an ANSI E1.31-2018 packet as per ANSI E1.31-2018 Section 4 Table 4-1, 4-2, 4-3.
 pub const E131_PDU_FLAGS: u8 = 0x70;
 
+/// The pdu flag set when the Length field is 20 bits long rather than 12, making the flags and length field 3 bytes long, ANSI E1.17.
+pub const ACN_PDU_LENGTH_FLAG: u8 = 0x80;
+
+/// The pdu flag set when the pdu has its own Vector field, if not set the vector is inherited from the previous pdu of the block,
+/// ANSI E1.17.
+pub const ACN_PDU_VECTOR_FLAG: u8 = 0x40;
+
+/// The pdu flag set when the pdu has its own header, if not set the header is inherited from the previous pdu of the block, ANSI E1.17.
+pub const ACN_PDU_HEADER_FLAG: u8 = 0x20;
+
+/// The pdu flag set when the pdu has its own data, if not set the data is inherited from the previous pdu of the block, ANSI E1.17.
+pub const ACN_PDU_DATA_FLAG: u8 = 0x10;
+
+/// The largest value of a 12 bit pdu Length field.
+const E131_PDU_MAX_LENGTH: usize = 0x0fff;
+
+/// The largest value of a 20 bit pdu Length field, used when `ACN_PDU_LENGTH_FLAG` is set.
+const ACN_PDU_MAX_EXTENDED_LENGTH: usize = 0x000f_ffff;
+
 /// The length in bytes of the root layer vector field as per ANSI E1.31-2018 Section 4 Table 4-1, 4-2, 4-3.
 pub const E131_ROOT_LAYER_VECTOR_LENGTH: usize = 4;
 
@@ -248,6 +286,9 @@ const E131_CID_END_INDEX: usize =
 /// The length of the Source Name field in bytes in an ANSI E1.31-2018 packet as per ANSI E1.31-2018 Section 4, Table 4-1, 4-2, 4-3.
 pub const E131_SOURCE_NAME_FIELD_LENGTH: usize = 64;
 
+/// The length in bytes of the Source Name field of a draft data packet, see `VECTOR_ROOT_E131_DRAFT_DATA`.
+pub const E131_DRAFT_SOURCE_NAME_FIELD_LENGTH: usize = 32;
+
 /// The length of the Synchronisation Address field in bytes in an ANSI E1.31-2018 packet as per ANSI E1.31-2018 Section 4, Table 4-1, 4-2, 4-3.
 pub const E131_SYNC_ADDR_FIELD_LENGTH: usize = 2;
 
@@ -276,6 +317,18 @@ pub const VECTOR_ROOT_E131_DATA: u32 = 0x0000_0004;
 /// Value as defined in ANSI E1.31-2018 Appendix A: Defined Parameters (Normative).
 pub const VECTOR_ROOT_E131_EXTENDED: u32 = 0x0000_0008;
 
+/// The vector field value used to identify the ACN packet as a data packet in the layout of the ANSI E1.31 draft (revision 0.2)
+/// which came before ANSI E1.31-2009, as still sent by some older gear. See `parse_draft_data_packet`.
+/// This is used at the ACN packet layer not the E1.31 layer.
+pub const VECTOR_ROOT_E131_DRAFT_DATA: u32 = 0x0000_0003;
+
+/// The root layer vectors and their names, used to describe the Vector field when inspecting a packet.
+const ROOT_VECTORS: &[(u32, &str)] = &[
+    (VECTOR_ROOT_E131_DATA, "VECTOR_ROOT_E131_DATA"),
+    (VECTOR_ROOT_E131_EXTENDED, "VECTOR_ROOT_E131_EXTENDED"),
+    (VECTOR_ROOT_E131_DRAFT_DATA, "VECTOR_ROOT_E131_DRAFT_DATA"),
+];
+
 /// The E1.31 packet vector field value used to identify the E1.31 packet as a synchronisation packet.
 /// This is used at the E1.31 layer and shouldn't be confused with the VECTOR values used for the ACN layer (i.e. `VECTOR_ROOT_E131_DATA` and `VECTOR_ROOT_E131_EXTENDED`).
 /// Value as defined in ANSI E1.31-2018 Appendix A: Defined Parameters (Normative).
@@ -316,8 +369,11 @@ pub const NO_SYNC_UNIVERSE: u16 = 0;
 /// The timeout before data loss is assumed for an E131 source, as defined in Appendix A of ANSI E1.31-2018.
 pub const E131_NETWORK_DATA_LOSS_TIMEOUT: Duration = Duration::from_millis(2500);
 
-/// The timeout before a discovered source is assumed to be lost as defined in section 12.2 of ANSI E1.31-2018.
-pub const UNIVERSE_DISCOVERY_SOURCE_TIMEOUT: Duration = E131_NETWORK_DATA_LOSS_TIMEOUT;
+/// The timeout before a discovered source is assumed to be lost if no further universe discovery packets are received.
+///
+/// Sources advertise every `E131_UNIVERSE_DISCOVERY_INTERVAL` so this is twice that interval, allowing a single advert to be lost
+/// without the source disappearing.
+pub const UNIVERSE_DISCOVERY_SOURCE_TIMEOUT: Duration = Duration::from_secs(20);
 
 /// Converts the given ANSI E1.31-2018 universe into an Ipv4 multicast address with the port set to the acn multicast port as defined
 /// in `packet::ACN_SDT_MULTICAST_PORT`.
@@ -329,6 +385,7 @@ pub const UNIVERSE_DISCOVERY_SOURCE_TIMEOUT: Duration = E131_NETWORK_DATA_LOSS_T
 /// # Errors
 /// `IllegalUniverse`: Returned if the given universe is outwith the allowed range of universes,
 ///     see (`is_universe_in_range`)[`fn.is_universe_in_range.packet`].
+#[cfg(feature = "std")]
 pub fn universe_to_ipv4_multicast_addr(universe: u16) -> Result<SockAddr> {
     is_universe_in_range(universe)?;
 
@@ -353,6 +410,7 @@ pub fn universe_to_ipv4_multicast_addr(universe: u16) -> Result<SockAddr> {
 /// # Errors
 /// `IllegalUniverse`: Returned if the given universe is outwith the allowed range of universes,
 ///     see (`is_universe_in_range`)[`fn.is_universe_in_range.packet`].
+#[cfg(feature = "std")]
 pub fn universe_to_ipv6_multicast_addr(universe: u16) -> Result<SockAddr> {
     is_universe_in_range(universe)?;
 
@@ -386,15 +444,374 @@ fn zeros(buf: &mut [u8], n: usize) {
     }
 }
 
+/// Which deviations from ANSI E1.31-2018 are accepted when parsing a packet.
+///
+/// By default packets are parsed strictly and any deviation is an error. Some sources send packets which deviate from the standard in
+/// ways which don't stop the packet being understood, `ParseOptions::lenient` accepts these and reports each deviation as a warning
+/// on the `ParsedPacket` instead.
+///
+/// ```
+/// use sacn::packet::*;
+/// use sacn::sacn_parse_pack_error::ParsePacketError;
+/// use uuid::Uuid;
+///
+/// let packet = AcnRootLayerProtocol {
+///     pdu: E131RootLayer {
+///         cid: Uuid::new_v4(),
+///         data: E131RootLayerData::UniverseDiscoveryPacket(UniverseDiscoveryPacketFramingLayer {
+///             source_name: "Source_A".into(),
+///             data: UniverseDiscoveryPacketUniverseDiscoveryLayer {
+///                 page: 0,
+///                 last_page: 0,
+///                 universes: vec![1, 2].into(),
+///             },
+///         }),
+///     },
+/// };
+/// let mut buf = packet.pack_alloc().unwrap();
+///
+/// // Fill the whole Source Name field leaving no null terminator.
+/// buf[44..108].fill(b'A');
+/// assert!(AcnRootLayerProtocol::parse(&buf).is_err());
+///
+/// let options = ParseOptions {
+///     allow_invalid_utf8_source_name: false,
+///     ..ParseOptions::lenient()
+/// };
+/// let parsed = AcnRootLayerProtocol::parse_with_options(&buf, &options).unwrap();
+///
+/// assert!(matches!(parsed.warnings[..], [ParsePacketError::SourceNameNotNullTerminated(_)]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseOptions {
+    /// Accept a source name which fills the whole Source Name field without a null terminator, ANSI E1.31-2018 Section 6.2.2.
+    /// The whole field is used as the name.
+    pub allow_unterminated_source_name: bool,
+
+    /// Accept a source name which isn't valid UTF-8, invalid bytes are replaced with U+FFFD.
+    pub allow_invalid_utf8_source_name: bool,
+
+    /// Accept a DMP layer Address Increment other than 1, ANSI E1.31-2018 Section 7.5. The property values are still treated as
+    /// consecutive slots.
+    pub allow_any_address_increment: bool,
+
+    /// Accept a universe discovery layer whose Length field doesn't match the length given by the framing layer, the shorter of the
+    /// two is used.
+    pub allow_discovery_length_mismatch: bool,
+
+    /// Accept a root layer vector other than `VECTOR_ROOT_E131_DATA` and `VECTOR_ROOT_E131_EXTENDED`, or an extended packet with a
+    /// framing layer vector other than synchronization and universe discovery, ANSI E1.31-2018 Section 5.5 and 6.2.1. The packet is
+    /// parsed as `E131RootLayerData::Unknown` or `E131RootLayerData::UnknownExtended`, see `VectorParsers`.
+    pub allow_unknown_vectors: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::strict()
+    }
+}
+
+impl ParseOptions {
+    /// Rejects any packet which deviates from ANSI E1.31-2018, this is the default.
+    pub const fn strict() -> ParseOptions {
+        ParseOptions {
+            allow_unterminated_source_name: false,
+            allow_invalid_utf8_source_name: false,
+            allow_any_address_increment: false,
+            allow_discovery_length_mismatch: false,
+            allow_unknown_vectors: false,
+        }
+    }
+
+    /// Accepts all the recoverable deviations, reporting each as a warning.
+    pub const fn lenient() -> ParseOptions {
+        ParseOptions {
+            allow_unterminated_source_name: true,
+            allow_invalid_utf8_source_name: true,
+            allow_any_address_increment: true,
+            allow_discovery_length_mismatch: true,
+            allow_unknown_vectors: true,
+        }
+    }
+}
+
+/// A packet parsed using `AcnRootLayerProtocol::parse_with_options` along with any deviations that were accepted.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ParsedPacket<'a> {
+    /// The packet.
+    pub packet: AcnRootLayerProtocol<'a>,
+
+    /// The deviations from ANSI E1.31-2018 which were accepted, each is the error which would have been returned if parsing strictly.
+    /// Always empty when parsing strictly.
+    pub warnings: Vec<ParsePacketError>,
+}
+
+/// Fixed capacity storage which a packet can be parsed into without allocating, see `AcnRootLayerProtocol::parse_fixed`.
+///
+/// The source name and property values of a parsed packet borrow from the buffer parsed, the universe list of a universe discovery
+/// packet is held here as it needs converting from network byte order. A `FixedParseBuffer` can be reused for each packet parsed, for
+/// example as a `static` on a device without an allocator.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedParseBuffer {
+    /// The universe list of the last universe discovery packet parsed.
+    universes: [u16; DISCOVERY_UNI_PER_PAGE],
+}
+
+impl FixedParseBuffer {
+    /// Creates an empty buffer.
+    pub const fn new() -> FixedParseBuffer {
+        FixedParseBuffer {
+            universes: [0; DISCOVERY_UNI_PER_PAGE],
+        }
+    }
+}
+
+impl Default for FixedParseBuffer {
+    fn default() -> Self {
+        FixedParseBuffer::new()
+    }
+}
+
+/// The state kept while parsing a packet into layers borrowing from a buffer with lifetime 'b.
+struct ParseContext<'o, 'b> {
+    /// The deviations accepted.
+    options: &'o ParseOptions,
+
+    /// The storage the universe list of a universe discovery packet is parsed into, if None the list is allocated.
+    universes: Option<&'b mut [u16; DISCOVERY_UNI_PER_PAGE]>,
+
+    /// The offset in bytes of the layer being parsed from the start of the packet.
+    offset: usize,
+
+    /// The deviations which have been accepted so far, offsets are from the start of the packet.
+    warnings: Vec<ParsePacketError>,
+
+    /// The layers and fields found so far when inspecting a packet, None when only parsing it.
+    dissection: Option<Dissection>,
+}
+
+/// The layers and fields found while parsing a packet for `inspect`.
+#[derive(Default)]
+struct Dissection {
+    /// The layers being parsed, outermost first, each holding the fields and layers found within it so far.
+    open: Vec<InspectedLayer>,
+
+    /// The outermost layers which have been parsed.
+    layers: Vec<InspectedLayer>,
+}
+
+impl<'o, 'b> ParseContext<'o, 'b> {
+    fn new(options: &'o ParseOptions) -> ParseContext<'o, 'b> {
+        ParseContext {
+            options,
+            universes: None,
+            offset: 0,
+            warnings: Vec::new(),
+            dissection: None,
+        }
+    }
+
+    /// Returns the given error if the deviation isn't allowed, otherwise records it as a warning and returns Ok.
+    ///
+    /// # Arguments
+    /// allowed: Whether the deviation is accepted by the options used.
+    ///
+    /// error: The error describing the deviation, with an offset from the start of the layer being parsed.
+    fn deviation(&mut self, allowed: bool, error: SacnError) -> Result<()> {
+        match error {
+            SacnError::SacnParsePackError(e) if allowed => {
+                self.warnings.push(e.offset_by(self.offset));
+                Ok(())
+            }
+            e => Err(e),
+        }
+    }
+
+    /// Parses a layer or field which starts offset bytes into the layer being parsed, the offsets of any errors or warnings are moved
+    /// to be from the start of the packet.
+    fn at<T>(&mut self, offset: usize, parse: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        self.offset += offset;
+        let result = parse(self);
+        self.offset -= offset;
+        at_offset(result, offset)
+    }
+
+    /// Parses a layer which starts at the current offset and is at most len bytes long, recording it when inspecting a packet.
+    ///
+    /// The layer is recorded even if it fails to parse, it then ends after the last field or layer found within it.
+    fn layer<T>(
+        &mut self,
+        name: &'static str,
+        len: usize,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        let Some(dissection) = &mut self.dissection else {
+            return parse(self);
+        };
+        dissection.open.push(InspectedLayer {
+            name: name.to_string(),
+            range: self.offset..self.offset + len,
+            fields: Vec::new(),
+            layers: Vec::new(),
+            violations: Vec::new(),
+        });
+
+        let result = parse(self);
+
+        if let Some(dissection) = &mut self.dissection
+            && let Some(mut layer) = dissection.open.pop()
+        {
+            let fields = layer.fields.iter().map(|field| field.range.end);
+            if let Some(end) = fields.chain(layer.layers.iter().map(|l| l.range.end)).max() {
+                layer.range.end = end;
+            }
+            match dissection.open.last_mut() {
+                Some(parent) => parent.layers.push(layer),
+                None => dissection.layers.push(layer),
+            }
+        }
+        result
+    }
+
+    /// Records a field of the layer being parsed when inspecting a packet.
+    ///
+    /// # Arguments
+    /// name: The name of the field as used in ANSI E1.31-2018 Section 4.
+    ///
+    /// buf: The buffer holding the field, starting at the current offset.
+    ///
+    /// range: The location of the field within buf.
+    ///
+    /// value: Describes the value of the field, only called when inspecting and buf holds the whole field.
+    fn field(
+        &mut self,
+        name: &'static str,
+        buf: &[u8],
+        range: Range<usize>,
+        value: impl FnOnce() -> String,
+    ) {
+        if let Some(layer) = self.dissection.as_mut().and_then(|d| d.open.last_mut())
+            && let Some(raw) = buf.get(range.clone())
+        {
+            layer.fields.push(InspectedField {
+                name: name.to_string(),
+                range: self.offset + range.start..self.offset + range.end,
+                raw: raw.to_vec(),
+                value: value(),
+                violations: Vec::new(),
+            });
+        }
+    }
+
+    /// Reads the Flags and Length and Vector fields at the start of a layer, see `pdu_info`, recording them when inspecting a packet.
+    ///
+    /// vectors: The vectors the layer may have along with their names, used to describe the Vector field.
+    fn pdu_info(
+        &mut self,
+        buf: &[u8],
+        vector_length: usize,
+        layer: PacketLayer,
+        vectors: &[(u32, &str)],
+    ) -> Result<PduInfo> {
+        if buf.len() >= E131_PDU_LENGTH_FLAGS_LENGTH {
+            self.field(
+                "Flags and Length",
+                buf,
+                0..E131_PDU_LENGTH_FLAGS_LENGTH,
+                || {
+                    let flags_and_length = NetworkEndian::read_u16(buf);
+                    format!(
+                        "Flags {:#x}, Length {}",
+                        flags_and_length >> 12,
+                        flags_and_length & 0x0fff
+                    )
+                },
+            );
+        }
+        let vector_end = E131_PDU_LENGTH_FLAGS_LENGTH + vector_length;
+        if buf.len() >= vector_end {
+            self.field(
+                "Vector",
+                buf,
+                E131_PDU_LENGTH_FLAGS_LENGTH..vector_end,
+                || {
+                    let vector = NetworkEndian::read_uint(
+                        &buf[E131_PDU_LENGTH_FLAGS_LENGTH..],
+                        vector_length,
+                    );
+                    let name = vectors
+                        .iter()
+                        .find(|(v, _)| u64::from(*v) == vector)
+                        .map_or("unknown", |(_, name)| name);
+                    format!("{vector:#0width$x} ({name})", width = 2 + 2 * vector_length)
+                },
+            );
+        }
+        pdu_info(buf, vector_length, layer)
+    }
+}
+
+/// Creates the error returned when a field of a packet couldn't be parsed.
+///
+/// # Arguments
+/// kind: The variant of `ParsePacketError` to return, e.g. `ParsePacketError::ParseInvalidData`.
+///
+/// layer: The layer of the packet containing the field.
+///
+/// field: The name of the field as used in ANSI E1.31-2018 Section 4.
+///
+/// offset: The offset of the field in bytes from the start of the buffer being parsed.
+///
+/// expected: The value expected.
+///
+/// actual: The value found.
+fn parse_error(
+    kind: fn(ParseErrorContext) -> ParsePacketError,
+    layer: PacketLayer,
+    field: &'static str,
+    offset: usize,
+    expected: impl Display,
+    actual: impl Display,
+) -> SacnError {
+    SacnError::SacnParsePackError(kind(ParseErrorContext::new(
+        layer, field, offset, expected, actual,
+    )))
+}
+
+/// Moves the location of any parse error in the given result by offset bytes.
+///
+/// Layers are parsed from a buffer starting at the start of that layer, this is used so that errors from a layer report an offset
+/// from the start of the packet rather than the start of the layer.
+#[inline]
+fn at_offset<T>(result: Result<T>, offset: usize) -> Result<T> {
+    match result {
+        Err(SacnError::SacnParsePackError(e)) => {
+            Err(SacnError::SacnParsePackError(e.offset_by(offset)))
+        }
+        result => result,
+    }
+}
+
 /// Takes the given byte buffer (e.g. a c char array) and parses it into a rust &str.
 ///
 /// # Arguments
 /// buf: The byte buffer to parse into a str.
 ///
+/// layer: The layer of the packet containing the source name, used if the source name is invalid.
+///
+/// ctx: The parse context, a source name which isn't null terminated or valid UTF-8 is accepted if allowed by its options.
+///
 /// # Errors
 /// `SourceNameNotNullTerminated`: Returned if the source name is not null terminated as required by ANSI E1.31-2018 Section 6.2.2
+///
+/// `Utf8Error`: Returned if the source name isn't valid UTF-8, the offset is that of the first invalid byte.
 #[inline]
-fn parse_source_name_str(buf: &[u8]) -> Result<&str> {
+fn parse_source_name_str<'b>(
+    buf: &'b [u8],
+    layer: PacketLayer,
+    ctx: &mut ParseContext<'_, '_>,
+) -> Result<Cow<'b, str>> {
     let mut source_name_length = buf.len();
     for (i, b) in buf.iter().enumerate() {
         if *b == 0 {
@@ -403,59 +820,190 @@ fn parse_source_name_str(buf: &[u8]) -> Result<&str> {
         }
     }
 
+    ctx.field("Source Name", buf, 0..buf.len(), || {
+        format!("{:?}", String::from_utf8_lossy(&buf[..source_name_length]))
+    });
+
     if source_name_length == buf.len() && buf[buf.len() - 1] != 0 {
-        return Err(SacnError::SacnParsePackError(
-            ParsePacketError::SourceNameNotNullTerminated(),
+        ctx.deviation(
+            ctx.options.allow_unterminated_source_name,
+            parse_error(
+                ParsePacketError::SourceNameNotNullTerminated,
+                layer,
+                "Source Name",
+                0,
+                "a null terminated string",
+                format!("no null byte within {} bytes", buf.len()),
+            ),
+        )?;
+    }
+
+    let buf = &buf[..source_name_length];
+    match str::from_utf8(buf) {
+        Ok(source_name) => Ok(source_name.into()),
+        Err(e) => {
+            ctx.deviation(
+                ctx.options.allow_invalid_utf8_source_name,
+                parse_error(
+                    ParsePacketError::Utf8Error,
+                    layer,
+                    "Source Name",
+                    e.valid_up_to(),
+                    "UTF-8",
+                    format!("invalid byte {:#04x}", buf[e.valid_up_to()]),
+                ),
+            )?;
+            Ok(String::from_utf8_lossy(buf))
+        }
+    }
+}
+
+/// Checks the preamble of a packet which comes before the root layer PDU block, ANSI E1.31-2018 Section 5.1 - 5.3.
+///
+/// # Errors
+/// `ParseInsufficientData`: Returned if the buffer is shorter than the preamble.
+///
+/// `ParseInvalidData`: Returned if the Preamble Size, Post-amble Size or ACN Packet Identifier are incorrect.
+fn parse_preamble(buf: &[u8], ctx: &mut ParseContext<'_, '_>) -> Result<()> {
+    if buf.len() < (E131_PREAMBLE_SIZE as usize) {
+        return Err(parse_error(
+            ParsePacketError::ParseInsufficientData,
+            PacketLayer::Root,
+            "Preamble Size",
+            0,
+            format!("at least {} bytes", E131_PREAMBLE_SIZE),
+            format!("{} bytes", buf.len()),
+        ));
+    }
+
+    // Preamble Size
+    let preamble_size = NetworkEndian::read_u16(&buf[0..2]);
+    ctx.field("Preamble Size", buf, 0..2, || preamble_size.to_string());
+    if preamble_size != E131_PREAMBLE_SIZE {
+        return Err(parse_error(
+            ParsePacketError::ParseInvalidData,
+            PacketLayer::Root,
+            "Preamble Size",
+            0,
+            format!("{:#06x}", E131_PREAMBLE_SIZE),
+            format!("{:#06x}", preamble_size),
         ));
     }
 
-    Ok(str::from_utf8(&buf[..source_name_length])?)
+    // Post-amble Size
+    let postamble_size = NetworkEndian::read_u16(&buf[2..4]);
+    ctx.field("Post-amble Size", buf, 2..4, || postamble_size.to_string());
+    if postamble_size != E131_POSTAMBLE_SIZE {
+        return Err(parse_error(
+            ParsePacketError::ParseInvalidData,
+            PacketLayer::Root,
+            "Post-amble Size",
+            2,
+            format!("{:#06x}", E131_POSTAMBLE_SIZE),
+            format!("{:#06x}", postamble_size),
+        ));
+    }
+
+    // ACN Packet Identifier
+    ctx.field(
+        "ACN Packet Identifier",
+        buf,
+        4..(E131_PREAMBLE_SIZE as usize),
+        || {
+            format!(
+                "\"{}\"",
+                buf[4..(E131_PREAMBLE_SIZE as usize)].escape_ascii()
+            )
+        },
+    );
+    if buf[4..(E131_PREAMBLE_SIZE as usize)] != E131_ACN_PACKET_IDENTIFIER {
+        return Err(parse_error(
+            ParsePacketError::ParseInvalidData,
+            PacketLayer::Root,
+            "ACN Packet Identifier",
+            4,
+            format!("{:02x?}", E131_ACN_PACKET_IDENTIFIER),
+            format!("{:02x?}", &buf[4..(E131_PREAMBLE_SIZE as usize)]),
+        ));
+    }
+
+    Ok(())
 }
 
 macro_rules! impl_acn_root_layer_protocol {
     ( $( $lt:tt )* ) => {
         /// Root layer protocol of the Architecture for Control Networks (ACN) protocol.
         #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct AcnRootLayerProtocol$( $lt )* {
             /// The PDU this packet carries.
             pub pdu: E131RootLayer$( $lt )*,
         }
 
         impl$( $lt )* AcnRootLayerProtocol$( $lt )* {
-            /// Parse the packet from the given buffer.
+            /// Parse the packet from the given buffer, rejecting any deviation from ANSI E1.31-2018.
+            ///
+            /// Only the first PDU of the root layer PDU block is parsed, see `AcnRootLayerBlock` for packets holding several PDUs.
             pub fn parse(buf: &[u8]) -> Result<AcnRootLayerProtocol<'_>> {
-                if buf.len() <  (E131_PREAMBLE_SIZE as usize) {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInsufficientData(
-                            "Insufficient data for ACN root layer preamble".to_string(),
-                        ),
-                    ));
-                }
+                Ok(AcnRootLayerProtocol::parse_with_options(buf, &ParseOptions::strict())?.packet)
+            }
 
-                // Preamble Size
-                if NetworkEndian::read_u16(&buf[0..2]) != E131_PREAMBLE_SIZE {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInvalidData("invalid Preamble Size".to_string()),
-                    ));
-                }
+            /// Parse the packet from the given buffer accepting the deviations from ANSI E1.31-2018 allowed by the given options.
+            ///
+            /// # Errors
+            /// The same errors as `parse` for any deviations which aren't allowed.
+            pub fn parse_with_options<'b>(buf: &'b [u8], options: &ParseOptions) -> Result<ParsedPacket<'b>> {
+                let mut ctx = ParseContext::new(options);
+                let packet = AcnRootLayerProtocol::parse_packet(buf, &mut ctx)?;
+                Ok(ParsedPacket {
+                    packet,
+                    warnings: ctx.warnings,
+                })
+            }
 
-                // Post-amble Size
-                if NetworkEndian::read_u16(&buf[2..4]) != E131_POSTAMBLE_SIZE {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInvalidData("invalid Post-amble Size".to_string()),
-                    ));
-                }
+            /// Parse the packet from the given buffer without allocating, rejecting any deviation from ANSI E1.31-2018.
+            ///
+            /// The source name and property values borrow from buf and the universe list of a universe discovery packet is parsed into
+            /// storage. Memory is only allocated for the description of the problem if an error is returned.
+            ///
+            /// # Errors
+            /// The same errors as `parse`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use sacn::packet::{AcnRootLayerProtocol, E131Packet, FixedParseBuffer};
+            /// use sacn::types::Universe;
+            /// # use uuid::Uuid;
+            ///
+            /// let packet = E131Packet::data(Universe::try_from(1).unwrap())
+            ///     .cid(Uuid::from_bytes([1; 16]))
+            ///     .source_name("Source_A")
+            ///     .values(&[0, 255, 128])
+            ///     .build()
+            ///     .unwrap();
+            /// let buf = packet.pack_alloc().unwrap();
+            ///
+            /// let mut storage = FixedParseBuffer::new();
+            /// let parsed = AcnRootLayerProtocol::parse_fixed(&buf, &mut storage).unwrap();
+            ///
+            /// assert_eq!(parsed, packet.as_protocol());
+            /// ```
+            pub fn parse_fixed<'b>(buf: &'b [u8], storage: &'b mut FixedParseBuffer) -> Result<AcnRootLayerProtocol<'b>> {
+                let options = ParseOptions::strict();
+                let mut ctx = ParseContext::new(&options);
+                ctx.universes = Some(&mut storage.universes);
+                AcnRootLayerProtocol::parse_packet(buf, &mut ctx)
+            }
 
-                // ACN Packet Identifier
-                if &buf[4 .. (E131_PREAMBLE_SIZE as usize)] != E131_ACN_PACKET_IDENTIFIER {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInvalidData("invalid ACN packet identifier".to_string()),
-                    ));
-                }
+            fn parse_packet<'b>(buf: &'b [u8], ctx: &mut ParseContext<'_, 'b>) -> Result<AcnRootLayerProtocol<'b>> {
+                ctx.layer("Root Layer", buf.len(), |ctx| {
+                    parse_preamble(buf, ctx)?;
 
-                // PDU block
-                Ok(AcnRootLayerProtocol {
-                    pdu: E131RootLayer::parse(&buf[(E131_PREAMBLE_SIZE as usize) ..])?,
+                    // PDU block
+                    Ok(AcnRootLayerProtocol {
+                        pdu: ctx.at(E131_PREAMBLE_SIZE as usize, |ctx| E131RootLayer::parse(&buf[(E131_PREAMBLE_SIZE as usize) ..], ctx))?,
+                    })
                 })
             }
 
@@ -479,7 +1027,7 @@ macro_rules! impl_acn_root_layer_protocol {
             pub fn pack(&self, buf: &mut [u8]) -> Result<()> {
                 if buf.len() < self.len() {
                     return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInvalidData("Invalid ACN packet identifier".to_string())
+                        ParsePacketError::PackBufferInsufficient("AcnRootLayerProtocol pack buffer length insufficient".to_string())
                     ));
                 }
 
@@ -508,12 +1056,1114 @@ macro_rules! impl_acn_root_layer_protocol {
                 // PDU block
                 self.pdu.len()
             }
+
+            /// Converts the packet into one which owns its data so that it can outlive the buffer it was parsed from.
+            pub fn into_owned(self) -> AcnRootLayerProtocol<'static> {
+                AcnRootLayerProtocol {
+                    pdu: self.pdu.into_owned(),
+                }
+            }
         }
     };
 }
 
 impl_acn_root_layer_protocol!(<'a>);
 
+/// A packet holding a block of root layer PDUs, each of which can hold a block of framing layer PDUs, ANSI E1.17.
+///
+/// Within a block each PDU may inherit its vector, header or data from the previous PDU by clearing the corresponding flag. ANSI
+/// E1.31-2018 sources always send a single PDU with all the flags set, which `AcnRootLayerProtocol` handles, but other ACN devices may
+/// pack several framing layer PDUs together.
+///
+/// Each framing layer PDU is held as its own `E131RootLayer` along with the CID of the root layer PDU carrying it. When packing,
+/// consecutive PDUs with the same CID and root layer vector share a root layer PDU and any vector, header or data which is the same as
+/// that of the previous PDU is inherited rather than repeated.
+///
+/// ```
+/// use sacn::packet::*;
+/// use uuid::Uuid;
+///
+/// let cid = Uuid::new_v4();
+/// let pdu = |universe| E131RootLayer {
+///     cid,
+///     data: E131RootLayerData::DataPacket(DataPacketFramingLayer {
+///         source_name: "Source_A".into(),
+///         priority: 100,
+///         synchronization_address: 0,
+///         sequence_number: 0,
+///         preview_data: false,
+///         stream_terminated: false,
+///         force_synchronization: false,
+///         universe,
+///         data: DataPacketDmpLayer {
+///             property_values: vec![0, 255].into(),
+///         },
+///     }),
+/// };
+/// let block = AcnRootLayerBlock {
+///     pdus: vec![pdu(1), pdu(2)],
+/// };
+///
+/// let buf = block.pack_alloc().unwrap();
+/// assert_eq!(AcnRootLayerBlock::parse(&buf).unwrap(), block);
+///
+/// // Only the first PDU is parsed as an E1.31 packet.
+/// assert_eq!(AcnRootLayerProtocol::parse(&buf).unwrap().pdu, block.pdus[0]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcnRootLayerBlock<'a> {
+    /// The framing layer PDUs of the packet in order, each with the CID of the root layer PDU carrying it.
+    pub pdus: Vec<E131RootLayer<'a>>,
+}
+
+/// A PDU block parsed using `AcnRootLayerBlock::parse_with_options` along with any deviations that were accepted.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ParsedBlock<'a> {
+    /// The PDU block.
+    pub block: AcnRootLayerBlock<'a>,
+
+    /// The deviations from ANSI E1.31-2018 which were accepted, see `ParsedPacket::warnings`.
+    pub warnings: Vec<ParsePacketError>,
+}
+
+impl<'a> AcnRootLayerBlock<'a> {
+    /// Parse all the PDUs of the packet in the given buffer, rejecting any deviation from ANSI E1.31-2018 within each PDU.
+    ///
+    /// The whole buffer must be taken up by the preamble and the root layer PDU block.
+    ///
+    /// # Errors
+    /// The same errors as `AcnRootLayerProtocol::parse` for each PDU.
+    ///
+    /// `ParsePduInvalidFlags`: Returned if the first PDU of a block inherits from the previous PDU as there is none.
+    ///
+    /// `PduInvalidLength`: Returned if the Length field of a PDU is too short to hold the fields it has.
+    ///
+    /// A PDU which inherits from the previous PDU or has a 20 bit Length field is parsed as if it had been sent on its own, so the
+    /// offsets of errors within such a PDU are from the start of the PDU as if it held all its fields.
+    pub fn parse(buf: &[u8]) -> Result<AcnRootLayerBlock<'_>> {
+        Ok(AcnRootLayerBlock::parse_with_options(buf, &ParseOptions::strict())?.block)
+    }
+
+    /// Parse all the PDUs of the packet in the given buffer accepting the deviations from ANSI E1.31-2018 allowed by the given options.
+    ///
+    /// # Errors
+    /// The same errors as `parse` for any deviations which aren't allowed.
+    pub fn parse_with_options<'b>(
+        buf: &'b [u8],
+        options: &ParseOptions,
+    ) -> Result<ParsedBlock<'b>> {
+        let mut ctx = ParseContext::new(options);
+        parse_preamble(buf, &mut ctx)?;
+
+        let pdus = ctx.at(E131_PREAMBLE_SIZE as usize, |ctx| {
+            parse_root_layer_block(&buf[(E131_PREAMBLE_SIZE as usize)..], ctx)
+        })?;
+        Ok(ParsedBlock {
+            block: AcnRootLayerBlock { pdus },
+            warnings: ctx.warnings,
+        })
+    }
+
+    /// Packs the packet into heap allocated memory.
+    ///
+    /// # Errors
+    /// `PackInvalidData`: Returned if there are no PDUs or a PDU is too long for the 20 bit Length field.
+    pub fn pack_alloc(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        self.pack_vec(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Packs the packet into the given vector, replacing its contents.
+    ///
+    /// # Errors
+    /// The same errors as `pack_alloc`.
+    pub fn pack_vec(&self, buf: &mut Vec<u8>) -> Result<()> {
+        if self.pdus.is_empty() {
+            return Err(SacnError::SacnParsePackError(
+                ParsePacketError::PackInvalidData(
+                    "A PDU block must hold at least one PDU".to_string(),
+                ),
+            ));
+        }
+
+        buf.clear();
+        buf.resize(E131_PREAMBLE_SIZE as usize, 0);
+
+        // Preamble Size
+        NetworkEndian::write_u16(&mut buf[0..2], E131_PREAMBLE_SIZE);
+
+        // Post-amble Size
+        NetworkEndian::write_u16(&mut buf[2..4], E131_POSTAMBLE_SIZE);
+
+        // ACN Packet Identifier
+        buf[4..(E131_PREAMBLE_SIZE as usize)].copy_from_slice(&E131_ACN_PACKET_IDENTIFIER);
+
+        // PDU block
+        let mut previous_root: Option<PduParts> = None;
+        // The data of an unknown packet may not be a block of framing layer PDUs so it is always packed in its own root layer PDU.
+        for group in self.pdus.chunk_by(|a, b| {
+            a.cid == b.cid
+                && root_vector(&a.data) == root_vector(&b.data)
+                && !is_unknown(&a.data)
+                && !is_unknown(&b.data)
+        }) {
+            let vector = root_vector(&group[0].data);
+
+            let mut framing_block = Vec::new();
+            let mut previous_framing: Option<PduParts> = None;
+            for pdu in group {
+                if let E131RootLayerData::Unknown { raw, .. }
+                | E131RootLayerData::UnknownExtended { raw, .. } = &pdu.data
+                {
+                    framing_block.extend_from_slice(raw);
+                    continue;
+                }
+
+                let packed = pack_framing_layer(&pdu.data)?;
+                let header_start = E131_PDU_LENGTH_FLAGS_LENGTH + E131_FRAMING_LAYER_VECTOR_LENGTH;
+                let framing_vector =
+                    NetworkEndian::read_u32(&packed[E131_PDU_LENGTH_FLAGS_LENGTH..header_start]);
+                let data_start = header_start + framing_header_length(vector, framing_vector)?;
+                let parts = PduParts {
+                    vector: packed[E131_PDU_LENGTH_FLAGS_LENGTH..header_start].to_vec(),
+                    header: packed[header_start..data_start].to_vec(),
+                    data: packed[data_start..].to_vec(),
+                };
+                pack_block_pdu(&mut framing_block, &parts, previous_framing.as_ref())?;
+                previous_framing = Some(parts);
+            }
+
+            let parts = PduParts {
+                vector: vector.to_be_bytes().to_vec(),
+                header: group[0].cid.as_bytes().to_vec(),
+                data: framing_block,
+            };
+            pack_block_pdu(buf, &parts, previous_root.as_ref())?;
+            previous_root = Some(parts);
+        }
+
+        Ok(())
+    }
+
+    /// Converts the packet into one which owns its data, see `AcnRootLayerProtocol::into_owned`.
+    pub fn into_owned(self) -> AcnRootLayerBlock<'static> {
+        AcnRootLayerBlock {
+            pdus: self
+                .pdus
+                .into_iter()
+                .map(E131RootLayer::into_owned)
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<AcnRootLayerProtocol<'a>> for AcnRootLayerBlock<'a> {
+    fn from(packet: AcnRootLayerProtocol<'a>) -> AcnRootLayerBlock<'a> {
+        AcnRootLayerBlock {
+            pdus: vec![packet.pdu],
+        }
+    }
+}
+
+/// Parses the data of a packet with a vector which isn't part of ANSI E1.31-2018, see `VectorParsers`.
+///
+/// Called with the vector and data of an `E131RootLayerData::Unknown` or `E131RootLayerData::UnknownExtended`. Returns the data
+/// parsed, which may be one of the ANSI E1.31-2018 packets if the vector is an alternative encoding of one, or the unknown data as it
+/// was if the data is only checked.
+///
+/// # Errors
+/// Any error describing why the data isn't valid, for example a `ParsePacketError`.
+pub type VectorParser = for<'a> fn(u32, &'a [u8]) -> Result<E131RootLayerData<'a>>;
+
+/// Parsers for packets with vectors which aren't part of ANSI E1.31-2018, such as vendor extensions, by root layer vector or, for
+/// extended packets, by framing layer vector.
+///
+/// Packets with unknown vectors are parsed as `E131RootLayerData::Unknown` when `ParseOptions::allow_unknown_vectors` is set,
+/// `VectorParsers::parse` then passes their data to the parser registered for their vector using `register`. An extended packet with
+/// an unknown framing layer vector is parsed as `E131RootLayerData::UnknownExtended` and passed to the parser registered for its
+/// framing layer vector using `register_extended`.
+///
+/// ```
+/// use sacn::error::errors::Result;
+/// use sacn::packet::*;
+/// use uuid::Uuid;
+///
+/// const VENDOR_VECTOR: u32 = 0x7f00_0001;
+///
+/// fn parse_vendor(vector: u32, raw: &[u8]) -> Result<E131RootLayerData<'_>> {
+///     // Check the data and keep it as it is.
+///     Ok(E131RootLayerData::Unknown { vector, raw: raw.into() })
+/// }
+///
+/// let packet = AcnRootLayerProtocol {
+///     pdu: E131RootLayer {
+///         cid: Uuid::new_v4(),
+///         data: E131RootLayerData::Unknown { vector: VENDOR_VECTOR, raw: vec![1, 2, 3].into() },
+///     },
+/// };
+/// let buf = packet.pack_alloc().unwrap();
+/// assert!(AcnRootLayerProtocol::parse(&buf).is_err());
+///
+/// let options = ParseOptions {
+///     allow_unknown_vectors: true,
+///     ..ParseOptions::strict()
+/// };
+/// let parsed = AcnRootLayerProtocol::parse_with_options(&buf, &options).unwrap().packet;
+///
+/// let mut parsers = VectorParsers::new();
+/// parsers.register(VENDOR_VECTOR, parse_vendor);
+/// assert_eq!(parsers.parse(parsed.pdu.data).unwrap(), packet.pdu.data);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct VectorParsers {
+    /// The parsers by root layer vector.
+    parsers: BTreeMap<u32, VectorParser>,
+
+    /// The parsers for extended packets by framing layer vector.
+    extended_parsers: BTreeMap<u32, VectorParser>,
+}
+
+impl VectorParsers {
+    /// Creates an empty set of parsers.
+    pub fn new() -> VectorParsers {
+        VectorParsers {
+            parsers: BTreeMap::new(),
+            extended_parsers: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the parser for the given root layer vector, replacing and returning any previous parser.
+    pub fn register(&mut self, vector: u32, parser: VectorParser) -> Option<VectorParser> {
+        self.parsers.insert(vector, parser)
+    }
+
+    /// Removes and returns the parser for the given root layer vector, if there is one.
+    pub fn unregister(&mut self, vector: u32) -> Option<VectorParser> {
+        self.parsers.remove(&vector)
+    }
+
+    /// Returns the parser for the given root layer vector, None if there isn't one.
+    pub fn get(&self, vector: u32) -> Option<VectorParser> {
+        self.parsers.get(&vector).copied()
+    }
+
+    /// Registers the parser for extended packets with the given framing layer vector, replacing and returning any previous parser.
+    pub fn register_extended(&mut self, vector: u32, parser: VectorParser) -> Option<VectorParser> {
+        self.extended_parsers.insert(vector, parser)
+    }
+
+    /// Removes and returns the parser for extended packets with the given framing layer vector, if there is one.
+    pub fn unregister_extended(&mut self, vector: u32) -> Option<VectorParser> {
+        self.extended_parsers.remove(&vector)
+    }
+
+    /// Returns the parser for extended packets with the given framing layer vector, None if there isn't one.
+    pub fn get_extended(&self, vector: u32) -> Option<VectorParser> {
+        self.extended_parsers.get(&vector).copied()
+    }
+
+    /// Returns true if there are no parsers.
+    pub fn is_empty(&self) -> bool {
+        self.parsers.is_empty() && self.extended_parsers.is_empty()
+    }
+
+    /// Parses the given data with the parser registered for its vector. Data which isn't `E131RootLayerData::Unknown` or
+    /// `E131RootLayerData::UnknownExtended` or has no parser is returned as it is.
+    ///
+    /// # Errors
+    /// Any error returned by the parser.
+    pub fn parse<'a>(&self, data: E131RootLayerData<'a>) -> Result<E131RootLayerData<'a>> {
+        match data {
+            E131RootLayerData::Unknown { vector, raw } => match self.get(vector) {
+                Some(parser) => apply_vector_parser(parser, vector, raw),
+                None => Ok(E131RootLayerData::Unknown { vector, raw }),
+            },
+            E131RootLayerData::UnknownExtended { vector, raw } => match self.get_extended(vector) {
+                Some(parser) => apply_vector_parser(parser, vector, raw),
+                None => Ok(E131RootLayerData::UnknownExtended { vector, raw }),
+            },
+            data => Ok(data),
+        }
+    }
+}
+
+/// Parses the given data of a packet with the given unknown vector using the given parser, data owned by the packet is parsed into
+/// owned data.
+///
+/// # Errors
+/// Any error returned by the parser.
+fn apply_vector_parser<'a>(
+    parser: VectorParser,
+    vector: u32,
+    raw: Cow<'a, [u8]>,
+) -> Result<E131RootLayerData<'a>> {
+    match raw {
+        Cow::Borrowed(raw) => parser(vector, raw),
+        Cow::Owned(raw) => Ok(parser(vector, &raw)?.into_owned()),
+    }
+}
+
+// Calculate the indexes of the fields within the buffer based on the size of the fields previous.
+// Theses indexes are only valid within the scope of the framing layer of a draft data packet.
+const DRAFT_SOURCE_NAME_INDEX: usize =
+    E131_PDU_LENGTH_FLAGS_LENGTH + E131_FRAMING_LAYER_VECTOR_LENGTH;
+const DRAFT_PRIORITY_INDEX: usize = DRAFT_SOURCE_NAME_INDEX + E131_DRAFT_SOURCE_NAME_FIELD_LENGTH;
+const DRAFT_SEQ_NUM_INDEX: usize = DRAFT_PRIORITY_INDEX + E131_PRIORITY_FIELD_LENGTH;
+const DRAFT_UNIVERSE_INDEX: usize = DRAFT_SEQ_NUM_INDEX + E131_SEQ_NUM_FIELD_LENGTH;
+const DRAFT_DATA_INDEX: usize = DRAFT_UNIVERSE_INDEX + E131_UNIVERSE_FIELD_LENGTH;
+
+/// The deviations accepted when parsing a draft data packet, older gear fills the whole Source Name field without a null terminator.
+const DRAFT_PARSE_OPTIONS: ParseOptions = ParseOptions {
+    allow_unterminated_source_name: true,
+    ..ParseOptions::strict()
+};
+
+/// Parses the data of a packet in the layout of the ANSI E1.31 draft (revision 0.2) which came before ANSI E1.31-2009, a
+/// `VectorParser` for `VECTOR_ROOT_E131_DRAFT_DATA`.
+///
+/// The framing layer of the draft has a 32 byte Source Name field followed by the Priority, Sequence Number and Universe fields,
+/// there are no Synchronization Address or Options fields. The DMP layer is the same as in ANSI E1.31-2018. The packet is returned as
+/// an `E131RootLayerData::DataPacket` without a synchronization address and with all options unset so that it can be processed like
+/// any other data packet.
+///
+/// ```
+/// use sacn::packet::*;
+/// use uuid::Uuid;
+///
+/// let packet = E131Packet::data(1.try_into().unwrap())
+///     .cid(Uuid::new_v4())
+///     .source_name("Old Desk")
+///     .values(&[0, 255])
+///     .build()
+///     .unwrap();
+/// let E131RootLayerData::DataPacket(data) = packet.as_protocol().pdu.data else {
+///     unreachable!()
+/// };
+/// let draft = pack_draft_data_packet(&data).unwrap();
+///
+/// let mut parsers = VectorParsers::new();
+/// parsers.register(VECTOR_ROOT_E131_DRAFT_DATA, parse_draft_data_packet);
+/// assert_eq!(parsers.parse(draft).unwrap(), E131RootLayerData::DataPacket(data));
+/// ```
+///
+/// # Errors
+/// `ParseInsufficientData`: Returned if the data is shorter than the length of the framing layer PDU.
+///
+/// `PduInvalidLength`: Returned if the length of the framing layer PDU is too short to hold its header.
+///
+/// `PduInvalidVector`: Returned if the framing layer vector isn't `VECTOR_E131_DATA_PACKET`.
+///
+/// Any error from parsing the source name, priority, universe or DMP layer as for an ANSI E1.31-2018 data packet. Offsets are from the
+/// start of the given data, the framing layer.
+pub fn parse_draft_data_packet(_vector: u32, raw: &[u8]) -> Result<E131RootLayerData<'_>> {
+    let mut ctx = ParseContext::new(&DRAFT_PARSE_OPTIONS);
+
+    // Length and Vector
+    let PduInfo { length, vector } =
+        pdu_info(raw, E131_FRAMING_LAYER_VECTOR_LENGTH, PacketLayer::Framing)?;
+    if length < DRAFT_DATA_INDEX {
+        return Err(parse_error(
+            ParsePacketError::PduInvalidLength,
+            PacketLayer::Framing,
+            "Length",
+            0,
+            format!("at least {DRAFT_DATA_INDEX}"),
+            length,
+        ));
+    }
+    if raw.len() < length {
+        return Err(insufficient_length_error(
+            PacketLayer::Framing,
+            length,
+            raw.len(),
+        ));
+    }
+
+    if vector != VECTOR_E131_DATA_PACKET {
+        return Err(invalid_vector_error(
+            PacketLayer::Framing,
+            format!("{VECTOR_E131_DATA_PACKET:#x}"),
+            vector,
+        ));
+    }
+
+    // Source Name
+    let source_name = ctx.at(DRAFT_SOURCE_NAME_INDEX, |ctx| {
+        parse_source_name_str(
+            &raw[DRAFT_SOURCE_NAME_INDEX..DRAFT_PRIORITY_INDEX],
+            PacketLayer::Framing,
+            ctx,
+        )
+    })?;
+
+    // Priority
+    let priority = raw[DRAFT_PRIORITY_INDEX];
+    if priority > E131_MAX_PRIORITY {
+        return Err(parse_error(
+            ParsePacketError::ParseInvalidPriority,
+            PacketLayer::Framing,
+            "Priority",
+            DRAFT_PRIORITY_INDEX,
+            format!("[0, {E131_MAX_PRIORITY}]"),
+            priority,
+        ));
+    }
+
+    // Sequence Number
+    let sequence_number = raw[DRAFT_SEQ_NUM_INDEX];
+
+    // Universe
+    let universe = NetworkEndian::read_u16(&raw[DRAFT_UNIVERSE_INDEX..DRAFT_DATA_INDEX]);
+    if !(E131_MIN_MULTICAST_UNIVERSE..=E131_MAX_MULTICAST_UNIVERSE).contains(&universe) {
+        return Err(parse_error(
+            ParsePacketError::ParseInvalidUniverse,
+            PacketLayer::Framing,
+            "Universe",
+            DRAFT_UNIVERSE_INDEX,
+            format!("[{E131_MIN_MULTICAST_UNIVERSE}, {E131_MAX_MULTICAST_UNIVERSE}]"),
+            universe,
+        ));
+    }
+
+    // Data layer.
+    let data = ctx.at(DRAFT_DATA_INDEX, |ctx| {
+        DataPacketDmpLayer::parse(&raw[DRAFT_DATA_INDEX..length], ctx)
+    })?;
+
+    Ok(E131RootLayerData::DataPacket(DataPacketFramingLayer {
+        source_name,
+        priority,
+        synchronization_address: E131_NO_SYNC_ADDR,
+        sequence_number,
+        preview_data: false,
+        stream_terminated: false,
+        force_synchronization: false,
+        universe,
+        data,
+    }))
+}
+
+/// Packs the given data packet into the layout of the ANSI E1.31 draft (revision 0.2) which came before ANSI E1.31-2009, for older
+/// gear which only understands the draft. See `parse_draft_data_packet`.
+///
+/// The result is an `E131RootLayerData::Unknown` with the vector `VECTOR_ROOT_E131_DRAFT_DATA` which is sent in a root layer PDU like
+/// any other packet. The draft has no Synchronization Address or Options fields so these are dropped, and the source name is cut
+/// short on a character boundary to leave room for a null terminator within the 32 byte field.
+///
+/// # Errors
+/// `PackInvalidData`: Returned if there are more than 513 property values.
+pub fn pack_draft_data_packet(
+    data: &DataPacketFramingLayer<'_>,
+) -> Result<E131RootLayerData<'static>> {
+    let mut source_name_length = data
+        .source_name
+        .len()
+        .min(E131_DRAFT_SOURCE_NAME_FIELD_LENGTH - 1);
+    while !data.source_name.is_char_boundary(source_name_length) {
+        source_name_length -= 1;
+    }
+
+    let length = DRAFT_DATA_INDEX + data.data.len();
+    let mut buf = vec![0; length];
+
+    // Flags and Length
+    let flags_and_length =
+        NetworkEndian::read_u16(&[E131_PDU_FLAGS, 0x0]) | (length as u16) & 0x0fff;
+    NetworkEndian::write_u16(&mut buf[0..E131_PDU_LENGTH_FLAGS_LENGTH], flags_and_length);
+
+    // Vector
+    NetworkEndian::write_u32(
+        &mut buf[E131_PDU_LENGTH_FLAGS_LENGTH..DRAFT_SOURCE_NAME_INDEX],
+        VECTOR_E131_DATA_PACKET,
+    );
+
+    // Source Name, the rest of the field is left as 0's.
+    buf[DRAFT_SOURCE_NAME_INDEX..DRAFT_SOURCE_NAME_INDEX + source_name_length]
+        .copy_from_slice(&data.source_name.as_bytes()[..source_name_length]);
+
+    // Priority
+    buf[DRAFT_PRIORITY_INDEX] = data.priority;
+
+    // Sequence Number
+    buf[DRAFT_SEQ_NUM_INDEX] = data.sequence_number;
+
+    // Universe
+    NetworkEndian::write_u16(
+        &mut buf[DRAFT_UNIVERSE_INDEX..DRAFT_DATA_INDEX],
+        data.universe,
+    );
+
+    // Data
+    data.data.pack(&mut buf[DRAFT_DATA_INDEX..])?;
+
+    Ok(E131RootLayerData::Unknown {
+        vector: VECTOR_ROOT_E131_DRAFT_DATA,
+        raw: buf.into(),
+    })
+}
+
+/// A field of a packet found by `inspect`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InspectedField {
+    /// The name of the field as used in ANSI E1.31-2018 Section 4.
+    pub name: String,
+
+    /// The location of the field in bytes from the start of the packet.
+    pub range: Range<usize>,
+
+    /// The bytes of the field as they appear in the packet.
+    pub raw: Vec<u8>,
+
+    /// A description of the value of the field, e.g. the universe number or the name of the vector.
+    pub value: String,
+
+    /// The ways in which the field deviates from ANSI E1.31-2018.
+    pub violations: Vec<ParsePacketError>,
+}
+
+/// A layer of a packet found by `inspect`, holding the fields and layers within it in the order they appear.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InspectedLayer {
+    /// The name of the layer, e.g. "Data Packet Framing Layer".
+    pub name: String,
+
+    /// The location of the layer in bytes from the start of the packet. A layer which couldn't be parsed ends after the last field
+    /// found within it.
+    pub range: Range<usize>,
+
+    /// The fields of the layer.
+    pub fields: Vec<InspectedField>,
+
+    /// The layers held by this layer.
+    pub layers: Vec<InspectedLayer>,
+
+    /// The deviations from ANSI E1.31-2018 within the layer which don't belong to one of its fields, e.g. a missing field.
+    pub violations: Vec<ParsePacketError>,
+}
+
+/// A field by field description of a packet as produced by `inspect`.
+///
+/// Displays as an indented tree of layers and fields in the style of Wireshark, use `to_json` for a form that tools can read.
+#[derive(Debug)]
+pub struct PacketInspection {
+    /// The outermost layers of the packet.
+    pub layers: Vec<InspectedLayer>,
+
+    /// Every deviation from ANSI E1.31-2018 found, in the order found, including the one which stopped parsing if any. Each is also
+    /// held by the field or layer it was found in.
+    pub violations: Vec<ParsePacketError>,
+
+    /// The error which stopped the packet from being parsed, None if the whole packet was parsed.
+    pub error: Option<SacnError>,
+}
+
+/// Describes each field of the given packet along with any deviations from ANSI E1.31-2018, for example to include in a bug report
+/// instead of a hex dump.
+///
+/// The packet is parsed by the same code as `AcnRootLayerProtocol::parse`, leniently so that as much of the packet as possible is
+/// described. Parsing stops at the first deviation which can't be recovered from, the fields found up to then are still returned.
+///
+/// ```
+/// use sacn::packet::*;
+/// use sacn::types::Universe;
+/// use uuid::Uuid;
+///
+/// let packet = E131Packet::data(Universe::try_from(1).unwrap())
+///     .cid(Uuid::new_v4())
+///     .source_name("Source_A")
+///     .values(&[0, 255, 128])
+///     .build()
+///     .unwrap();
+/// let mut buf = packet.pack_alloc().unwrap();
+///
+/// let inspection = inspect(&buf);
+/// assert!(inspection.is_valid());
+/// assert!(inspection.to_string().contains("Universe: 1"));
+///
+/// // Universe 0 isn't allowed.
+/// buf[113..115].fill(0);
+/// let inspection = inspect(&buf);
+/// assert!(!inspection.is_valid());
+/// assert!(inspection.to_string().contains("[Violation:"));
+/// ```
+pub fn inspect(buf: &[u8]) -> PacketInspection {
+    let options = ParseOptions::lenient();
+    let mut ctx = ParseContext::new(&options);
+    ctx.dissection = Some(Dissection::default());
+    let error = AcnRootLayerProtocol::parse_packet(buf, &mut ctx).err();
+
+    let mut violations = core::mem::take(&mut ctx.warnings);
+    if let Some(SacnError::SacnParsePackError(e)) = &error {
+        violations.push(e.clone());
+    }
+
+    let mut layers = ctx.dissection.map(|d| d.layers).unwrap_or_default();
+    for violation in &violations {
+        let offset = violation.context().map_or(0, |c| c.offset);
+        attach_violation(&mut layers, offset, violation);
+    }
+
+    PacketInspection {
+        layers,
+        violations,
+        error,
+    }
+}
+
+/// Attaches the violation to the innermost field containing the offset, otherwise to the innermost layer. Returns false if no layer
+/// contains the offset.
+fn attach_violation(
+    layers: &mut [InspectedLayer],
+    offset: usize,
+    violation: &ParsePacketError,
+) -> bool {
+    let Some(layer) = layers
+        .iter_mut()
+        .rev()
+        .find(|l| l.range.start <= offset && offset <= l.range.end)
+    else {
+        return false;
+    };
+    if attach_violation(&mut layer.layers, offset, violation) {
+        return true;
+    }
+    match layer.fields.iter_mut().find(|f| f.range.contains(&offset)) {
+        Some(field) => field.violations.push(violation.clone()),
+        None => layer.violations.push(violation.clone()),
+    }
+    true
+}
+
+impl PacketInspection {
+    /// Returns true if the whole packet was parsed without any deviations from ANSI E1.31-2018.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none() && self.violations.is_empty()
+    }
+
+    /// Returns the inspection as a JSON object.
+    ///
+    /// The object has the members `valid`, `error` (a string or null), `violations` and `layers`. Each layer has a `name`, `start`,
+    /// `end`, `fields`, `layers` and `violations`, each field a `name`, `start`, `end`, `raw` (as a hex string), `value` and
+    /// `violations`. Violations are strings.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\"valid\":");
+        json.push_str(if self.is_valid() { "true" } else { "false" });
+        json.push_str(",\"error\":");
+        match &self.error {
+            Some(e) => json_string(&mut json, &e.to_string()),
+            None => json.push_str("null"),
+        }
+        json.push_str(",\"violations\":");
+        json_violations(&mut json, &self.violations);
+        json.push_str(",\"layers\":");
+        json_layers(&mut json, &self.layers);
+        json.push('}');
+        json
+    }
+}
+
+impl fmt::Display for PacketInspection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for layer in &self.layers {
+            fmt_layer(f, layer, 0)?;
+        }
+        if let Some(e) = &self.error {
+            writeln!(f, "Parsing stopped: {e}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of bytes of a field shown when displaying an inspection, the rest are elided.
+const INSPECT_DISPLAY_BYTES: usize = 16;
+
+/// Writes the layer indented by the given depth, followed by its fields and layers.
+fn fmt_layer(f: &mut fmt::Formatter<'_>, layer: &InspectedLayer, depth: usize) -> fmt::Result {
+    let indent = depth * 4;
+    writeln!(
+        f,
+        "{:indent$}{} (bytes {}..{})",
+        "", layer.name, layer.range.start, layer.range.end
+    )?;
+    for field in &layer.fields {
+        write!(
+            f,
+            "{:indent$}    {}: {}  [{}:",
+            "", field.name, field.value, field.range.start
+        )?;
+        for byte in field.raw.iter().take(INSPECT_DISPLAY_BYTES) {
+            write!(f, " {byte:02x}")?;
+        }
+        if field.raw.len() > INSPECT_DISPLAY_BYTES {
+            f.write_str(" ...")?;
+        }
+        f.write_str("]\n")?;
+        for violation in &field.violations {
+            writeln!(f, "{:indent$}        [Violation: {violation}]", "")?;
+        }
+    }
+    for violation in &layer.violations {
+        writeln!(f, "{:indent$}    [Violation: {violation}]", "")?;
+    }
+    for child in &layer.layers {
+        fmt_layer(f, child, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Appends the layers to the JSON as an array.
+fn json_layers(json: &mut String, layers: &[InspectedLayer]) {
+    json.push('[');
+    for (i, layer) in layers.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str("{\"name\":");
+        json_string(json, &layer.name);
+        let _ = write!(
+            json,
+            ",\"start\":{},\"end\":{},\"fields\":[",
+            layer.range.start, layer.range.end
+        );
+        for (j, field) in layer.fields.iter().enumerate() {
+            if j > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"name\":");
+            json_string(json, &field.name);
+            let _ = write!(
+                json,
+                ",\"start\":{},\"end\":{},\"raw\":\"",
+                field.range.start, field.range.end
+            );
+            for byte in &field.raw {
+                let _ = write!(json, "{byte:02x}");
+            }
+            json.push_str("\",\"value\":");
+            json_string(json, &field.value);
+            json.push_str(",\"violations\":");
+            json_violations(json, &field.violations);
+            json.push('}');
+        }
+        json.push_str("],\"layers\":");
+        json_layers(json, &layer.layers);
+        json.push_str(",\"violations\":");
+        json_violations(json, &layer.violations);
+        json.push('}');
+    }
+    json.push(']');
+}
+
+/// Appends the violations to the JSON as an array of strings.
+fn json_violations(json: &mut String, violations: &[ParsePacketError]) {
+    json.push('[');
+    for (i, violation) in violations.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json_string(json, &violation.to_string());
+    }
+    json.push(']');
+}
+
+/// Appends the string to the JSON as a quoted and escaped JSON string.
+fn json_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(json, "\\u{:04x}", u32::from(c));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Describes the Options field of a data packet framing layer, ANSI E1.31-2018 Section 6.2.6.
+fn describe_options(options: u8) -> String {
+    let names: Vec<&str> = [
+        (E131_PREVIEW_DATA_OPTION_BIT_MASK, "Preview_Data"),
+        (E131_STREAM_TERMINATION_OPTION_BIT_MASK, "Stream_Terminated"),
+        (
+            E131_FORCE_SYNCHRONISATION_OPTION_BIT_MASK,
+            "Force_Synchronization",
+        ),
+    ]
+    .into_iter()
+    .filter(|(mask, _)| options & mask != 0)
+    .map(|(_, name)| name)
+    .collect();
+    if names.is_empty() {
+        format!("{options:#04x} (none)")
+    } else {
+        format!("{options:#04x} ({})", names.join(", "))
+    }
+}
+
+/// Describes the Property values field of a DMP layer, the START Code followed by the slots.
+fn describe_property_values(values: &[u8]) -> String {
+    match values.split_first() {
+        Some((start_code, slots)) => format!("start code {start_code:#04x}, {} slots", slots.len()),
+        None => "empty".to_string(),
+    }
+}
+
+/// The number of universes listed when describing the List of Universes field of a universe discovery layer.
+const INSPECT_DISPLAY_UNIVERSES: usize = 16;
+
+/// Describes the List of Universes field of a universe discovery layer.
+fn describe_universes(list: &[u8]) -> String {
+    let universes: Vec<String> = list
+        .chunks_exact(E131_UNIVERSE_FIELD_LENGTH)
+        .take(INSPECT_DISPLAY_UNIVERSES)
+        .map(|u| NetworkEndian::read_u16(u).to_string())
+        .collect();
+    let count = list.len() / E131_UNIVERSE_FIELD_LENGTH;
+    if count == 0 {
+        "none".to_string()
+    } else if count > INSPECT_DISPLAY_UNIVERSES {
+        format!("{}, ... ({count} universes)", universes.join(", "))
+    } else {
+        format!("{} ({count} universes)", universes.join(", "))
+    }
+}
+
+/// Parses a root layer PDU block into a PDU for each framing layer PDU it holds.
+fn parse_root_layer_block<'b>(
+    buf: &'b [u8],
+    ctx: &mut ParseContext<'_, 'b>,
+) -> Result<Vec<E131RootLayer<'b>>> {
+    let allow_unknown_vectors = ctx.options.allow_unknown_vectors;
+    let unknown_root_vector_error = |vector| {
+        invalid_vector_error(
+            PacketLayer::Root,
+            format!("{VECTOR_ROOT_E131_DATA:#x} or {VECTOR_ROOT_E131_EXTENDED:#x}"),
+            vector,
+        )
+    };
+    let root_pdus = parse_pdu_block(
+        buf,
+        E131_ROOT_LAYER_VECTOR_LENGTH,
+        |vector| {
+            if vector == VECTOR_ROOT_E131_DATA
+                || vector == VECTOR_ROOT_E131_EXTENDED
+                || allow_unknown_vectors
+            {
+                Ok(E131_CID_FIELD_LENGTH)
+            } else {
+                Err(unknown_root_vector_error(vector))
+            }
+        },
+        PacketLayer::Root,
+    )?;
+
+    let mut pdus = Vec::new();
+    for root in root_pdus {
+        let cid = Uuid::from_slice(root.header)?;
+
+        // The data of a root layer PDU with an unknown vector is kept as it is.
+        if root.vector != VECTOR_ROOT_E131_DATA && root.vector != VECTOR_ROOT_E131_EXTENDED {
+            ctx.at(root.offset, |ctx| {
+                ctx.deviation(
+                    allow_unknown_vectors,
+                    unknown_root_vector_error(root.vector),
+                )
+            })?;
+            pdus.push(E131RootLayer {
+                cid,
+                data: E131RootLayerData::Unknown {
+                    vector: root.vector,
+                    raw: Cow::Borrowed(root.data),
+                },
+            });
+            continue;
+        }
+
+        ctx.at(root.data_offset, |ctx| {
+            let framing_pdus = parse_pdu_block(
+                root.data,
+                E131_FRAMING_LAYER_VECTOR_LENGTH,
+                |vector| match framing_header_length(root.vector, vector) {
+                    // The whole of an extended PDU with an unknown vector is kept as its data.
+                    Err(_) if root.vector == VECTOR_ROOT_E131_EXTENDED && allow_unknown_vectors => {
+                        Ok(0)
+                    }
+                    result => result,
+                },
+                PacketLayer::Framing,
+            )?;
+            for framing in framing_pdus {
+                pdus.push(E131RootLayer {
+                    cid,
+                    data: parse_framing_pdu(root.vector, &framing, ctx)?,
+                });
+            }
+            Ok(())
+        })?;
+    }
+    Ok(pdus)
+}
+
+/// Parses a framing layer PDU of a PDU block carried by a root layer PDU with the given vector.
+///
+/// A PDU which inherits from the previous PDU or has a 20 bit Length field is rebuilt as a standalone PDU to be parsed, the parsed
+/// layer then owns its data.
+fn parse_framing_pdu<'b>(
+    root_vector: u32,
+    pdu: &BlockPdu<'b>,
+    ctx: &mut ParseContext<'_, 'b>,
+) -> Result<E131RootLayerData<'b>> {
+    if pdu.flags == E131_PDU_FLAGS {
+        return ctx.at(pdu.offset, |ctx| {
+            parse_framing_layer(root_vector, pdu.raw, ctx)
+        });
+    }
+
+    let rebuilt = at_offset(
+        rebuild_pdu(pdu, E131_FRAMING_LAYER_VECTOR_LENGTH, PacketLayer::Framing),
+        pdu.offset,
+    )?;
+    let mut rebuilt_ctx = ParseContext::new(ctx.options);
+    rebuilt_ctx.offset = ctx.offset + pdu.offset;
+    let data = at_offset(
+        parse_framing_layer(root_vector, &rebuilt, &mut rebuilt_ctx),
+        pdu.offset,
+    )?
+    .into_owned();
+    ctx.warnings.append(&mut rebuilt_ctx.warnings);
+    Ok(data)
+}
+
+/// Parses a framing layer PDU carried by a root layer PDU with the given vector.
+fn parse_framing_layer<'b>(
+    root_vector: u32,
+    buf: &'b [u8],
+    ctx: &mut ParseContext<'_, 'b>,
+) -> Result<E131RootLayerData<'b>> {
+    if root_vector == VECTOR_ROOT_E131_DATA {
+        return Ok(E131RootLayerData::DataPacket(
+            DataPacketFramingLayer::parse(buf, ctx)?,
+        ));
+    }
+
+    let PduInfo { vector, .. } =
+        pdu_info(buf, E131_FRAMING_LAYER_VECTOR_LENGTH, PacketLayer::Framing)?;
+    if vector != VECTOR_E131_EXTENDED_SYNCHRONIZATION && vector != VECTOR_E131_EXTENDED_DISCOVERY {
+        ctx.deviation(
+            ctx.options.allow_unknown_vectors,
+            invalid_vector_error(
+                PacketLayer::Framing,
+                format!("{VECTOR_E131_EXTENDED_SYNCHRONIZATION:#x} or {VECTOR_E131_EXTENDED_DISCOVERY:#x}"),
+                vector,
+            ),
+        )?;
+        return Ok(E131RootLayerData::UnknownExtended {
+            vector,
+            raw: Cow::Borrowed(buf),
+        });
+    }
+
+    match vector {
+        VECTOR_E131_EXTENDED_SYNCHRONIZATION => Ok(E131RootLayerData::SynchronizationPacket(
+            SynchronizationPacketFramingLayer::parse(buf, ctx)?,
+        )),
+        _ => Ok(E131RootLayerData::UniverseDiscoveryPacket(
+            UniverseDiscoveryPacketFramingLayer::parse(buf, ctx)?,
+        )),
+    }
+}
+
+/// Returns the length in bytes of the header of a framing layer PDU, the fields between the Vector field and the next layer.
+///
+/// # Arguments
+/// `root_vector`: The vector of the root layer PDU carrying the framing layer PDU.
+///
+/// vector: The vector of the framing layer PDU.
+///
+/// # Errors
+/// `PduInvalidVector`: Returned if the vector isn't valid for the root layer vector.
+fn framing_header_length(root_vector: u32, vector: u32) -> Result<usize> {
+    match (root_vector, vector) {
+        (VECTOR_ROOT_E131_DATA, VECTOR_E131_DATA_PACKET) => Ok(E131_SOURCE_NAME_FIELD_LENGTH
+            + E131_PRIORITY_FIELD_LENGTH
+            + E131_SYNC_ADDR_FIELD_LENGTH
+            + E131_SEQ_NUM_FIELD_LENGTH
+            + E131_OPTIONS_FIELD_LENGTH
+            + E131_UNIVERSE_FIELD_LENGTH),
+        (VECTOR_ROOT_E131_DATA, vector) => Err(invalid_vector_error(
+            PacketLayer::Framing,
+            format!("{VECTOR_E131_DATA_PACKET:#x}"),
+            vector,
+        )),
+        (_, VECTOR_E131_EXTENDED_SYNCHRONIZATION) => {
+            Ok(E131_SYNC_FRAMING_LAYER_SEQ_NUM_FIELD_LENGTH
+                + E131_SYNC_ADDR_FIELD_LENGTH
+                + E131_SYNC_FRAMING_LAYER_RESERVE_FIELD_LENGTH)
+        }
+        (_, VECTOR_E131_EXTENDED_DISCOVERY) => {
+            Ok(E131_SOURCE_NAME_FIELD_LENGTH + E131_DISCOVERY_FRAMING_LAYER_RESERVE_FIELD_LENGTH)
+        }
+        (_, vector) => Err(invalid_vector_error(
+            PacketLayer::Framing,
+            format!(
+                "{VECTOR_E131_EXTENDED_SYNCHRONIZATION:#x} or {VECTOR_E131_EXTENDED_DISCOVERY:#x}"
+            ),
+            vector,
+        )),
+    }
+}
+
+/// Returns the vector of the root layer PDU carrying the given data.
+fn root_vector(data: &E131RootLayerData<'_>) -> u32 {
+    match data {
+        E131RootLayerData::DataPacket(_) => VECTOR_ROOT_E131_DATA,
+        E131RootLayerData::SynchronizationPacket(_)
+        | E131RootLayerData::UniverseDiscoveryPacket(_)
+        | E131RootLayerData::UnknownExtended { .. } => VECTOR_ROOT_E131_EXTENDED,
+        E131RootLayerData::Unknown { vector, .. } => *vector,
+    }
+}
+
+/// Returns true if the given data is of a packet with an unknown vector, whose data may not be a block of framing layer PDUs.
+fn is_unknown(data: &E131RootLayerData<'_>) -> bool {
+    matches!(
+        data,
+        E131RootLayerData::Unknown { .. } | E131RootLayerData::UnknownExtended { .. }
+    )
+}
+
+/// Packs the framing layer PDU of the given data on its own.
+fn pack_framing_layer(data: &E131RootLayerData<'_>) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    match data {
+        E131RootLayerData::DataPacket(data) => {
+            buf.resize(data.len(), 0);
+            data.pack(&mut buf)?;
+        }
+        E131RootLayerData::SynchronizationPacket(data) => {
+            buf.resize(data.len(), 0);
+            data.pack(&mut buf)?;
+        }
+        E131RootLayerData::UniverseDiscoveryPacket(data) => {
+            buf.resize(data.len(), 0);
+            data.pack(&mut buf)?;
+        }
+        E131RootLayerData::Unknown { raw, .. } | E131RootLayerData::UnknownExtended { raw, .. } => {
+            buf.extend_from_slice(raw);
+        }
+    }
+    Ok(buf)
+}
+
 /// Represents the data contained with the `PduInfo` section that appears at the start of a layer in an sACN packet.
 struct PduInfo {
     /// The length in bytes of this layer inclusive of the `PduInfo`.
@@ -529,38 +2179,391 @@ struct PduInfo {
 ///
 /// `vector_length`: The length of the vectorfield in bytes.
 ///
+/// layer: The layer being parsed, used if the flags, length or vector can't be parsed.
+///
 /// # Errors
 /// `ParseInsufficientData`: If the length of the buffer is less than the flag, length and vector fields (`E131_PDU_LENGTH_FLAGS_LENGTH` + `vector_length`).
 ///
 /// `ParsePduInvalidFlags`: If the flags parsed don't match the flags expected for an ANSI E1.31-2018 packet as per ANSI E1.31-2018 Section 4 Table 4-1, 4-2, 4-3.
-fn pdu_info(buf: &[u8], vector_length: usize) -> Result<PduInfo> {
+fn pdu_info(buf: &[u8], vector_length: usize, layer: PacketLayer) -> Result<PduInfo> {
     if buf.len() < E131_PDU_LENGTH_FLAGS_LENGTH + vector_length {
-        return Err(SacnError::SacnParsePackError(
-            ParsePacketError::ParseInsufficientData(
-                "Insufficient data when parsing pdu_info, no flags or length field".to_string(),
+        return Err(parse_error(
+            ParsePacketError::ParseInsufficientData,
+            layer,
+            "Flags and Length",
+            0,
+            format!(
+                "at least {} bytes",
+                E131_PDU_LENGTH_FLAGS_LENGTH + vector_length
             ),
+            format!("{} bytes", buf.len()),
         ));
     }
 
-    // Flags
-    let flags = buf[0] & 0xf0; // Flags are stored in the top 4 bits.
-    if flags != E131_PDU_FLAGS {
-        return Err(SacnError::SacnParsePackError(
-            ParsePacketError::ParsePduInvalidFlags(flags),
-        ));
+    // Flags
+    let flags = buf[0] & 0xf0; // Flags are stored in the top 4 bits.
+    if flags != E131_PDU_FLAGS {
+        return Err(parse_error(
+            ParsePacketError::ParsePduInvalidFlags,
+            layer,
+            "Flags",
+            0,
+            format!("{:#04x}", E131_PDU_FLAGS),
+            format!("{:#04x}", flags),
+        ));
+    }
+    // Length
+    let length = (NetworkEndian::read_u16(&buf[0..E131_PDU_LENGTH_FLAGS_LENGTH]) & 0x0fff) as usize;
+
+    // Vector
+    let vector =
+        NetworkEndian::read_uint(&buf[E131_PDU_LENGTH_FLAGS_LENGTH..], vector_length) as u32;
+
+    Ok(PduInfo { length, vector })
+}
+
+/// Creates the error returned when the buffer holding a layer is shorter than the length given by the Length field of the layer.
+///
+/// # Arguments
+/// layer: The layer being parsed.
+///
+/// length: The value of the Length field.
+///
+/// `buf_len`: The length of the buffer holding the layer.
+fn insufficient_length_error(layer: PacketLayer, length: usize, buf_len: usize) -> SacnError {
+    parse_error(
+        ParsePacketError::ParseInsufficientData,
+        layer,
+        "Length",
+        0,
+        format!("at least {length} bytes"),
+        format!("{buf_len} bytes"),
+    )
+}
+
+/// Creates the error returned when the Length field of a layer is too short to hold the fields which come before its data.
+///
+/// # Arguments
+/// layer: The layer being parsed.
+///
+/// minimum: The length of the fields which come before the data of the layer.
+///
+/// length: The value of the Length field.
+fn pdu_too_short_error(layer: PacketLayer, minimum: usize, length: usize) -> SacnError {
+    parse_error(
+        ParsePacketError::PduInvalidLength,
+        layer,
+        "Length",
+        0,
+        format!("at least {minimum}"),
+        length,
+    )
+}
+
+/// Creates the error returned when the Vector field of a layer has an unexpected value.
+///
+/// # Arguments
+/// layer: The layer being parsed.
+///
+/// expected: A description of the vector(s) expected.
+///
+/// vector: The value of the Vector field.
+fn invalid_vector_error(layer: PacketLayer, expected: impl Display, vector: u32) -> SacnError {
+    parse_error(
+        ParsePacketError::PduInvalidVector,
+        layer,
+        "Vector",
+        E131_PDU_LENGTH_FLAGS_LENGTH,
+        expected,
+        format!("{vector:#x}"),
+    )
+}
+
+/// A PDU of a PDU block with the vector, header and data it holds or inherits from the previous PDU of the block, see
+/// `parse_pdu_block`.
+struct BlockPdu<'b> {
+    /// The flags of the PDU, stored in the top 4 bits.
+    flags: u8,
+
+    /// The offset of the PDU in bytes from the start of the block.
+    offset: usize,
+
+    /// The whole PDU, as long as its Length field.
+    raw: &'b [u8],
+
+    /// The vector of the PDU.
+    vector: u32,
+
+    /// The header of the PDU, the fields between the Vector field and the data.
+    header: &'b [u8],
+
+    /// The data of the PDU, the block of PDUs of the next layer.
+    data: &'b [u8],
+
+    /// The offset of the data in bytes from the start of the block, within an earlier PDU if the data is inherited.
+    data_offset: usize,
+}
+
+/// Splits the given buffer into the PDUs of a PDU block, resolving the vector, header and data each PDU inherits, ANSI E1.17.
+///
+/// # Arguments
+/// buf: The PDU block, which must be entirely taken up by PDUs.
+///
+/// `vector_length`: The length of the Vector field of each PDU in bytes.
+///
+/// `header_length`: Returns the length of the header of a PDU with the given vector, or an error if the vector isn't valid.
+///
+/// layer: The layer of the PDUs, used if the block can't be parsed.
+///
+/// # Errors
+/// `ParseInsufficientData`: Returned if the block is empty or shorter than the Length field of a PDU.
+///
+/// `PduInvalidLength`: Returned if the Length field of a PDU is too short to hold the fields the PDU has.
+///
+/// `ParsePduInvalidFlags`: Returned if the first PDU of the block inherits as there is no PDU to inherit from.
+fn parse_pdu_block<'b>(
+    buf: &'b [u8],
+    vector_length: usize,
+    header_length: impl Fn(u32) -> Result<usize>,
+    layer: PacketLayer,
+) -> Result<Vec<BlockPdu<'b>>> {
+    if buf.is_empty() {
+        return Err(parse_error(
+            ParsePacketError::ParseInsufficientData,
+            layer,
+            "Flags and Length",
+            0,
+            "at least one PDU",
+            "0 bytes",
+        ));
+    }
+
+    let mut pdus: Vec<BlockPdu<'b>> = Vec::new();
+    let mut offset = 0;
+    while offset < buf.len() {
+        let pdu = at_offset(
+            parse_block_pdu(
+                &buf[offset..],
+                offset,
+                pdus.last(),
+                vector_length,
+                &header_length,
+                layer,
+            ),
+            offset,
+        )?;
+        offset += pdu.raw.len();
+        pdus.push(pdu);
+    }
+    Ok(pdus)
+}
+
+/// Parses the PDU at the start of the given buffer which is offset bytes into a PDU block, see `parse_pdu_block`.
+fn parse_block_pdu<'b>(
+    buf: &'b [u8],
+    offset: usize,
+    previous: Option<&BlockPdu<'b>>,
+    vector_length: usize,
+    header_length: &impl Fn(u32) -> Result<usize>,
+    layer: PacketLayer,
+) -> Result<BlockPdu<'b>> {
+    // Flags, stored in the top 4 bits.
+    let flags = buf[0] & 0xf0;
+    let inherited = |field: &str| {
+        previous.ok_or_else(|| {
+            parse_error(
+                ParsePacketError::ParsePduInvalidFlags,
+                layer,
+                "Flags",
+                0,
+                format!("the {field} flag set on the first PDU of a block"),
+                format!("{flags:#04x}"),
+            )
+        })
+    };
+
+    // Length
+    let length_length = if flags & ACN_PDU_LENGTH_FLAG == 0 {
+        E131_PDU_LENGTH_FLAGS_LENGTH
+    } else {
+        E131_PDU_LENGTH_FLAGS_LENGTH + 1
+    };
+    if buf.len() < length_length {
+        return Err(parse_error(
+            ParsePacketError::ParseInsufficientData,
+            layer,
+            "Flags and Length",
+            0,
+            format!("at least {length_length} bytes"),
+            format!("{} bytes", buf.len()),
+        ));
+    }
+    let length = if flags & ACN_PDU_LENGTH_FLAG == 0 {
+        (NetworkEndian::read_u16(&buf[0..E131_PDU_LENGTH_FLAGS_LENGTH]) & 0x0fff) as usize
+    } else {
+        NetworkEndian::read_u24(&buf[0..length_length]) as usize & ACN_PDU_MAX_EXTENDED_LENGTH
+    };
+    if buf.len() < length {
+        return Err(insufficient_length_error(layer, length, buf.len()));
+    }
+    let raw = &buf[..length];
+    let too_short = |required: usize| {
+        parse_error(
+            ParsePacketError::PduInvalidLength,
+            layer,
+            "Length",
+            0,
+            format!("at least {required}"),
+            length,
+        )
+    };
+    if length < length_length {
+        return Err(too_short(length_length));
+    }
+    let mut pos = length_length;
+
+    // Vector
+    let vector = if flags & ACN_PDU_VECTOR_FLAG != 0 {
+        if length < pos + vector_length {
+            return Err(too_short(pos + vector_length));
+        }
+        let vector = NetworkEndian::read_uint(&raw[pos..], vector_length) as u32;
+        pos += vector_length;
+        vector
+    } else {
+        inherited("vector")?.vector
+    };
+
+    // Header
+    let header = if flags & ACN_PDU_HEADER_FLAG != 0 {
+        let header_length = header_length(vector)?;
+        if length < pos + header_length {
+            return Err(too_short(pos + header_length));
+        }
+        pos += header_length;
+        &raw[pos - header_length..pos]
+    } else {
+        inherited("header")?.header
+    };
+
+    // Data
+    let (data, data_offset) = if flags & ACN_PDU_DATA_FLAG != 0 {
+        (&raw[pos..], offset + pos)
+    } else {
+        let previous = inherited("data")?;
+        (previous.data, previous.data_offset)
+    };
+
+    Ok(BlockPdu {
+        flags,
+        offset,
+        raw,
+        vector,
+        header,
+        data,
+        data_offset,
+    })
+}
+
+/// Rebuilds a PDU of a PDU block which inherits from the previous PDU or has a 20 bit Length field as a standalone PDU with the
+/// flags required by ANSI E1.31-2018, so that it can be parsed by the parser for that layer.
+///
+/// # Errors
+/// `PduInvalidLength`: Returned if the rebuilt PDU is too long for a 12 bit Length field.
+fn rebuild_pdu(pdu: &BlockPdu<'_>, vector_length: usize, layer: PacketLayer) -> Result<Vec<u8>> {
+    let length = E131_PDU_LENGTH_FLAGS_LENGTH + vector_length + pdu.header.len() + pdu.data.len();
+    if length > E131_PDU_MAX_LENGTH {
+        return Err(parse_error(
+            ParsePacketError::PduInvalidLength,
+            layer,
+            "Length",
+            0,
+            format!("at most {E131_PDU_MAX_LENGTH} including inherited fields"),
+            length,
+        ));
+    }
+
+    let mut buf = vec![0; E131_PDU_LENGTH_FLAGS_LENGTH + vector_length];
+    NetworkEndian::write_u16(
+        &mut buf[0..E131_PDU_LENGTH_FLAGS_LENGTH],
+        (u16::from(E131_PDU_FLAGS) << 8) | length as u16,
+    );
+    NetworkEndian::write_uint(
+        &mut buf[E131_PDU_LENGTH_FLAGS_LENGTH..],
+        pdu.vector.into(),
+        vector_length,
+    );
+    buf.extend_from_slice(pdu.header);
+    buf.extend_from_slice(pdu.data);
+    Ok(buf)
+}
+
+/// The vector, header and data of a PDU to be packed into a PDU block, see `pack_block_pdu`.
+struct PduParts {
+    /// The Vector field.
+    vector: Vec<u8>,
+
+    /// The fields between the Vector field and the data.
+    header: Vec<u8>,
+
+    /// The data, the block of PDUs of the next layer.
+    data: Vec<u8>,
+}
+
+/// Appends the given PDU to a PDU block, inheriting the vector, header and data from the previous PDU of the block where they are
+/// the same. A 20 bit Length field is used if the PDU is too long for a 12 bit one.
+///
+/// # Errors
+/// `PackInvalidData`: Returned if the PDU is too long for a 20 bit Length field.
+fn pack_block_pdu(buf: &mut Vec<u8>, pdu: &PduParts, previous: Option<&PduParts>) -> Result<()> {
+    let has_vector = previous.is_none_or(|p| p.vector != pdu.vector);
+    let has_header = previous.is_none_or(|p| p.header != pdu.header);
+    let has_data = previous.is_none_or(|p| p.data != pdu.data);
+
+    let mut flags = 0;
+    let mut length = 0;
+    for (has, flag, field) in [
+        (has_vector, ACN_PDU_VECTOR_FLAG, &pdu.vector),
+        (has_header, ACN_PDU_HEADER_FLAG, &pdu.header),
+        (has_data, ACN_PDU_DATA_FLAG, &pdu.data),
+    ] {
+        if has {
+            flags |= flag;
+            length += field.len();
+        }
     }
-    // Length
-    let length = (NetworkEndian::read_u16(&buf[0..E131_PDU_LENGTH_FLAGS_LENGTH]) & 0x0fff) as usize;
 
-    // Vector
-    let vector =
-        NetworkEndian::read_uint(&buf[E131_PDU_LENGTH_FLAGS_LENGTH..], vector_length) as u32;
+    // Flags and Length
+    if length + E131_PDU_LENGTH_FLAGS_LENGTH <= E131_PDU_MAX_LENGTH {
+        length += E131_PDU_LENGTH_FLAGS_LENGTH;
+        buf.extend_from_slice(&((u16::from(flags) << 8) | length as u16).to_be_bytes());
+    } else {
+        length += E131_PDU_LENGTH_FLAGS_LENGTH + 1;
+        if length > ACN_PDU_MAX_EXTENDED_LENGTH {
+            return Err(SacnError::SacnParsePackError(
+                ParsePacketError::PackInvalidData(format!(
+                    "A PDU of {length} bytes is too long for a 20 bit Length field"
+                )),
+            ));
+        }
+        let flags_and_length = (u32::from(flags | ACN_PDU_LENGTH_FLAG) << 16) | length as u32;
+        buf.extend_from_slice(&flags_and_length.to_be_bytes()[1..]);
+    }
 
-    Ok(PduInfo { length, vector })
+    if has_vector {
+        buf.extend_from_slice(&pdu.vector);
+    }
+    if has_header {
+        buf.extend_from_slice(&pdu.header);
+    }
+    if has_data {
+        buf.extend_from_slice(&pdu.data);
+    }
+    Ok(())
 }
 
-trait Pdu: Sized {
-    fn parse(buf: &[u8]) -> Result<Self>;
+trait Pdu<'a>: Sized {
+    fn parse(buf: &'a [u8], ctx: &mut ParseContext<'_, 'a>) -> Result<Self>;
 
     fn pack(&self, buf: &mut [u8]) -> Result<()>;
 
@@ -571,6 +2574,7 @@ macro_rules! impl_e131_root_layer {
     ( $( $lt:tt )* ) => {
         /// Payload of the Root Layer PDU.
         #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum E131RootLayerData$( $lt )* {
             /// DMX data packet.
             DataPacket(DataPacketFramingLayer$( $lt )*),
@@ -580,10 +2584,32 @@ macro_rules! impl_e131_root_layer {
 
             /// Universe discovery packet.
             UniverseDiscoveryPacket(UniverseDiscoveryPacketFramingLayer$( $lt )*),
+
+            /// A packet with a vector which isn't part of ANSI E1.31-2018, such as a vendor extension, accepted when
+            /// `ParseOptions::allow_unknown_vectors` is set. See `VectorParsers`.
+            Unknown {
+                /// The vector of the root layer PDU.
+                vector: u32,
+
+                /// The data of the root layer PDU following the CID.
+                raw: Cow<'a, [u8]>,
+            },
+
+            /// An extended packet with a framing layer vector which isn't part of ANSI E1.31-2018, accepted when
+            /// `ParseOptions::allow_unknown_vectors` is set. See `VectorParsers::register_extended`.
+            UnknownExtended {
+                /// The vector of the framing layer PDU.
+                vector: u32,
+
+                /// The framing layer PDU, this is packed as it is following the CID of a `VECTOR_ROOT_E131_EXTENDED` root layer PDU so
+                /// its Vector field should match `vector`.
+                raw: Cow<'a, [u8]>,
+            },
         }
 
         /// Root layer protocol data unit (PDU).
         #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct E131RootLayer$( $lt )* {
             /// Sender UUID.
             pub cid: Uuid,
@@ -591,62 +2617,80 @@ macro_rules! impl_e131_root_layer {
             pub data: E131RootLayerData$( $lt )*,
         }
 
-        impl$( $lt )* Pdu for E131RootLayer$( $lt )* {
-            fn parse(buf: &[u8]) -> Result<E131RootLayer$( $lt )*> {
+        impl$( $lt )* Pdu$( $lt )* for E131RootLayer$( $lt )* {
+            fn parse(buf: &'a [u8], ctx: &mut ParseContext<'_, 'a>) -> Result<E131RootLayer$( $lt )*> {
                 // Length and Vector
-                let PduInfo { length, vector } = pdu_info(&buf, E131_ROOT_LAYER_VECTOR_LENGTH)?;
+                let PduInfo { length, vector } = ctx.pdu_info(&buf, E131_ROOT_LAYER_VECTOR_LENGTH, PacketLayer::Root, ROOT_VECTORS)?;
                 if buf.len() < length {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInsufficientData(
-                            "Buffer contains insufficient data based on ACN root layer pdu length field".to_string(),
-                        ),
-                    ));
+                    return Err(insufficient_length_error(PacketLayer::Root, length, buf.len()));
+                }
+                if length < E131_CID_END_INDEX {
+                    return Err(pdu_too_short_error(PacketLayer::Root, E131_CID_END_INDEX, length));
                 }
 
                 if vector != VECTOR_ROOT_E131_DATA && vector != VECTOR_ROOT_E131_EXTENDED {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::PduInvalidVector(vector),
-                    ));
+                    ctx.deviation(ctx.options.allow_unknown_vectors, invalid_vector_error(
+                        PacketLayer::Root,
+                        format!("{VECTOR_ROOT_E131_DATA:#x} or {VECTOR_ROOT_E131_EXTENDED:#x}"),
+                        vector,
+                    ))?;
                 }
 
                 // CID
                 let cid = Uuid::from_slice(&buf[E131_PDU_LENGTH_FLAGS_LENGTH + E131_ROOT_LAYER_VECTOR_LENGTH .. E131_CID_END_INDEX])?;
+                ctx.field("CID", buf, E131_PDU_LENGTH_FLAGS_LENGTH + E131_ROOT_LAYER_VECTOR_LENGTH .. E131_CID_END_INDEX, || cid.to_string());
 
                 // Data
                 let data = match vector {
                     VECTOR_ROOT_E131_DATA => {
-                        E131RootLayerData::DataPacket(DataPacketFramingLayer::parse(&buf[E131_CID_END_INDEX .. length])?)
+                        E131RootLayerData::DataPacket(ctx.at(E131_CID_END_INDEX, |ctx| {
+                            ctx.layer("Data Packet Framing Layer", length - E131_CID_END_INDEX, |ctx| {
+                                DataPacketFramingLayer::parse(&buf[E131_CID_END_INDEX .. length], ctx)
+                            })
+                        })?)
                     }
                     VECTOR_ROOT_E131_EXTENDED => {
                         let data_buf = &buf[E131_CID_END_INDEX .. length];
-                        let PduInfo { length, vector} = pdu_info(data_buf, E131_FRAMING_LAYER_VECTOR_LENGTH)?;
+                        let PduInfo { length, vector} = at_offset(pdu_info(data_buf, E131_FRAMING_LAYER_VECTOR_LENGTH, PacketLayer::Framing), E131_CID_END_INDEX)?;
                         if data_buf.len() < length {
-                            return Err(SacnError::SacnParsePackError(
-                                ParsePacketError::ParseInsufficientData(
-                                    "Buffer contains insufficient data based on E131 framing layer pdu length field".to_string(),
-                                ),
-                            ));
+                            return at_offset(Err(insufficient_length_error(PacketLayer::Framing, length, data_buf.len())), E131_CID_END_INDEX);
                         }
 
                         match vector {
                             VECTOR_E131_EXTENDED_SYNCHRONIZATION => {
                                 E131RootLayerData::SynchronizationPacket(
-                                    SynchronizationPacketFramingLayer::parse(data_buf)?,
+                                    ctx.at(E131_CID_END_INDEX, |ctx| {
+                                        ctx.layer("Synchronization Packet Framing Layer", data_buf.len(), |ctx| {
+                                            SynchronizationPacketFramingLayer::parse(data_buf, ctx)
+                                        })
+                                    })?,
                                 )
                             }
                             VECTOR_E131_EXTENDED_DISCOVERY => {
                                 E131RootLayerData::UniverseDiscoveryPacket(
-                                    UniverseDiscoveryPacketFramingLayer::parse(data_buf)?,
+                                    ctx.at(E131_CID_END_INDEX, |ctx| {
+                                        ctx.layer("Universe Discovery Packet Framing Layer", data_buf.len(), |ctx| {
+                                            UniverseDiscoveryPacketFramingLayer::parse(data_buf, ctx)
+                                        })
+                                    })?,
                                 )
                             }
-                            vector => return Err(SacnError::SacnParsePackError(
-                                ParsePacketError::PduInvalidVector(vector),
-                            )),
+                            framing_vector => {
+                                ctx.at(E131_CID_END_INDEX, |ctx| ctx.deviation(ctx.options.allow_unknown_vectors, invalid_vector_error(
+                                    PacketLayer::Framing,
+                                    format!("{VECTOR_E131_EXTENDED_SYNCHRONIZATION:#x} or {VECTOR_E131_EXTENDED_DISCOVERY:#x}"),
+                                    framing_vector,
+                                )))?;
+                                ctx.field("Data", buf, E131_CID_END_INDEX .. length, || format!("{} bytes", data_buf.len()));
+                                E131RootLayerData::UnknownExtended { vector: framing_vector, raw: Cow::Borrowed(data_buf) }
+                            }
                         }
                     }
-                    vector => return Err(SacnError::SacnParsePackError(
-                                ParsePacketError::PduInvalidVector(vector),
-                            )),
+                    // Only reached if unknown vectors are allowed.
+                    vector => {
+                        ctx.field("Data", buf, E131_CID_END_INDEX .. length, || format!("{} bytes", length - E131_CID_END_INDEX));
+                        E131RootLayerData::Unknown { vector, raw: Cow::Borrowed(&buf[E131_CID_END_INDEX .. length]) }
+                    }
                 };
 
                 Ok(E131RootLayer {
@@ -672,9 +2716,13 @@ macro_rules! impl_e131_root_layer {
                         NetworkEndian::write_u32(&mut buf[E131_PDU_LENGTH_FLAGS_LENGTH .. E131_PDU_LENGTH_FLAGS_LENGTH + E131_ROOT_LAYER_VECTOR_LENGTH], VECTOR_ROOT_E131_DATA)
                     }
                     E131RootLayerData::SynchronizationPacket(_)
-                    | E131RootLayerData::UniverseDiscoveryPacket(_) => {
+                    | E131RootLayerData::UniverseDiscoveryPacket(_)
+                    | E131RootLayerData::UnknownExtended { .. } => {
                         NetworkEndian::write_u32(&mut buf[E131_PDU_LENGTH_FLAGS_LENGTH .. E131_PDU_LENGTH_FLAGS_LENGTH + E131_ROOT_LAYER_VECTOR_LENGTH], VECTOR_ROOT_E131_EXTENDED)
                     }
+                    E131RootLayerData::Unknown { vector, .. } => {
+                        NetworkEndian::write_u32(&mut buf[E131_PDU_LENGTH_FLAGS_LENGTH .. E131_PDU_LENGTH_FLAGS_LENGTH + E131_ROOT_LAYER_VECTOR_LENGTH], vector)
+                    }
                 }
 
                 // CID
@@ -685,6 +2733,10 @@ macro_rules! impl_e131_root_layer {
                     E131RootLayerData::DataPacket(ref data) => Ok(data.pack(&mut buf[E131_CID_END_INDEX .. ])?),
                     E131RootLayerData::SynchronizationPacket(ref data) => Ok(data.pack(&mut buf[E131_CID_END_INDEX .. ])?),
                     E131RootLayerData::UniverseDiscoveryPacket(ref data) => Ok(data.pack(&mut buf[E131_CID_END_INDEX .. ])?),
+                    E131RootLayerData::Unknown { ref raw, .. } | E131RootLayerData::UnknownExtended { ref raw, .. } => {
+                        buf[E131_CID_END_INDEX .. E131_CID_END_INDEX + raw.len()].copy_from_slice(raw);
+                        Ok(())
+                    }
                 }
             }
 
@@ -700,6 +2752,32 @@ macro_rules! impl_e131_root_layer {
                     E131RootLayerData::DataPacket(ref data) => data.len(),
                     E131RootLayerData::SynchronizationPacket(ref data) => data.len(),
                     E131RootLayerData::UniverseDiscoveryPacket(ref data) => data.len(),
+                    E131RootLayerData::Unknown { ref raw, .. } | E131RootLayerData::UnknownExtended { ref raw, .. } => raw.len(),
+                }
+            }
+        }
+
+        impl$( $lt )* E131RootLayer$( $lt )* {
+            /// Converts the PDU into one which owns its data, see `AcnRootLayerProtocol::into_owned`.
+            pub fn into_owned(self) -> E131RootLayer<'static> {
+                E131RootLayer {
+                    cid: self.cid,
+                    data: self.data.into_owned(),
+                }
+            }
+        }
+
+        impl$( $lt )* E131RootLayerData$( $lt )* {
+            /// Converts the payload into one which owns its data, see `AcnRootLayerProtocol::into_owned`.
+            pub fn into_owned(self) -> E131RootLayerData<'static> {
+                match self {
+                    E131RootLayerData::DataPacket(data) => E131RootLayerData::DataPacket(data.into_owned()),
+                    E131RootLayerData::SynchronizationPacket(data) => E131RootLayerData::SynchronizationPacket(data),
+                    E131RootLayerData::UniverseDiscoveryPacket(data) => E131RootLayerData::UniverseDiscoveryPacket(data.into_owned()),
+                    E131RootLayerData::Unknown { vector, raw } => E131RootLayerData::Unknown { vector, raw: Cow::Owned(raw.into_owned()) },
+                    E131RootLayerData::UnknownExtended { vector, raw } => {
+                        E131RootLayerData::UnknownExtended { vector, raw: Cow::Owned(raw.into_owned()) }
+                    }
                 }
             }
         }
@@ -712,6 +2790,7 @@ macro_rules! impl_data_packet_framing_layer {
     ( $( $lt:tt )* ) => {
         /// Framing layer PDU for sACN data packets.
         #[derive(Eq, PartialEq, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct DataPacketFramingLayer$( $lt )* {
             /// The name of the source.
             pub source_name: Cow<'a, str>,
@@ -752,60 +2831,85 @@ macro_rules! impl_data_packet_framing_layer {
         const UNIVERSE_INDEX: usize = OPTIONS_FIELD_INDEX + E131_OPTIONS_FIELD_LENGTH;
         const DATA_INDEX: usize = UNIVERSE_INDEX + E131_UNIVERSE_FIELD_LENGTH;
 
-        impl$( $lt )* Pdu for DataPacketFramingLayer$( $lt )* {
-            fn parse(buf: &[u8]) -> Result<DataPacketFramingLayer$( $lt )*> {
+        impl$( $lt )* Pdu$( $lt )* for DataPacketFramingLayer$( $lt )* {
+            fn parse(buf: &'a [u8], ctx: &mut ParseContext<'_, 'a>) -> Result<DataPacketFramingLayer$( $lt )*> {
                 // Length and Vector
-                let PduInfo { length, vector } = pdu_info(&buf, E131_FRAMING_LAYER_VECTOR_LENGTH)?;
+                let PduInfo { length, vector } = ctx.pdu_info(&buf, E131_FRAMING_LAYER_VECTOR_LENGTH, PacketLayer::Framing, &[
+                    (VECTOR_E131_DATA_PACKET, "VECTOR_E131_DATA_PACKET"),
+                ])?;
                 if buf.len() < length {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInsufficientData("Buffer contains insufficient data based on data packet framing layer pdu length field".to_string())));
+                    return Err(insufficient_length_error(PacketLayer::Framing, length, buf.len()));
+                }
+                if length < DATA_INDEX {
+                    return Err(pdu_too_short_error(PacketLayer::Framing, DATA_INDEX, length));
                 }
 
                 if vector != VECTOR_E131_DATA_PACKET {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::PduInvalidVector(vector)));
+                    return Err(invalid_vector_error(PacketLayer::Framing, format!("{VECTOR_E131_DATA_PACKET:#x}"), vector));
                 }
 
                 // Source Name
-                let source_name = String::from(
-                    parse_source_name_str(
-                        &buf[SOURCE_NAME_INDEX .. PRIORITY_INDEX]
-                    )?
-                );
+                let source_name = ctx.at(SOURCE_NAME_INDEX, |ctx| {
+                    parse_source_name_str(&buf[SOURCE_NAME_INDEX .. PRIORITY_INDEX], PacketLayer::Framing, ctx)
+                })?;
 
                 // Priority
                 let priority = buf[PRIORITY_INDEX];
+                ctx.field("Priority", buf, PRIORITY_INDEX .. SYNC_ADDR_INDEX, || priority.to_string());
                 if priority > E131_MAX_PRIORITY {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInvalidPriority(priority),
+                    return Err(parse_error(
+                        ParsePacketError::ParseInvalidPriority,
+                        PacketLayer::Framing,
+                        "Priority",
+                        PRIORITY_INDEX,
+                        format!("[0, {E131_MAX_PRIORITY}]"),
+                        priority,
                     ));
                 }
 
                 // Synchronization Address
                 let synchronization_address = NetworkEndian::read_u16(&buf[SYNC_ADDR_INDEX .. SEQ_NUM_INDEX]);
+                ctx.field("Synchronization Address", buf, SYNC_ADDR_INDEX .. SEQ_NUM_INDEX, || synchronization_address.to_string());
                 if synchronization_address > E131_MAX_MULTICAST_UNIVERSE {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInvalidSyncAddr(synchronization_address),
+                    return Err(parse_error(
+                        ParsePacketError::ParseInvalidSyncAddr,
+                        PacketLayer::Framing,
+                        "Synchronization Address",
+                        SYNC_ADDR_INDEX,
+                        format!("[0, {E131_MAX_MULTICAST_UNIVERSE}]"),
+                        synchronization_address,
                     ));
                 }
 
                 // Sequence Number
                 let sequence_number = buf[SEQ_NUM_INDEX];
+                ctx.field("Sequence Number", buf, SEQ_NUM_INDEX .. OPTIONS_FIELD_INDEX, || sequence_number.to_string());
 
                 // Options, Stored as bit flag.
+                ctx.field("Options", buf, OPTIONS_FIELD_INDEX .. UNIVERSE_INDEX, || describe_options(buf[OPTIONS_FIELD_INDEX]));
                 let preview_data = buf[OPTIONS_FIELD_INDEX] & E131_PREVIEW_DATA_OPTION_BIT_MASK != 0;
                 let stream_terminated = buf[OPTIONS_FIELD_INDEX] & E131_STREAM_TERMINATION_OPTION_BIT_MASK != 0;
                 let force_synchronization = buf[OPTIONS_FIELD_INDEX] & E131_FORCE_SYNCHRONISATION_OPTION_BIT_MASK != 0;
 
                 // Universe
                 let universe = NetworkEndian::read_u16(&buf[UNIVERSE_INDEX .. DATA_INDEX]);
+                ctx.field("Universe", buf, UNIVERSE_INDEX .. DATA_INDEX, || universe.to_string());
 
                 if !(E131_MIN_MULTICAST_UNIVERSE..=E131_MAX_MULTICAST_UNIVERSE).contains(&universe) {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInvalidUniverse(universe),
+                    return Err(parse_error(
+                        ParsePacketError::ParseInvalidUniverse,
+                        PacketLayer::Framing,
+                        "Universe",
+                        UNIVERSE_INDEX,
+                        format!("[{E131_MIN_MULTICAST_UNIVERSE}, {E131_MAX_MULTICAST_UNIVERSE}]"),
+                        universe,
                     ));
                 }
 
                 // Data layer.
-                let data = DataPacketDmpLayer::parse(&buf[DATA_INDEX .. length])?;
+                let data = ctx.at(DATA_INDEX, |ctx| {
+                    ctx.layer("DMP Layer", length - DATA_INDEX, |ctx| DataPacketDmpLayer::parse(&buf[DATA_INDEX .. length], ctx))
+                })?;
 
                 Ok(DataPacketFramingLayer {
                     source_name: source_name.into(),
@@ -892,6 +2996,23 @@ macro_rules! impl_data_packet_framing_layer {
             }
         }
 
+        impl$( $lt )* DataPacketFramingLayer$( $lt )* {
+            /// Converts the PDU into one which owns its data, see `AcnRootLayerProtocol::into_owned`.
+            pub fn into_owned(self) -> DataPacketFramingLayer<'static> {
+                DataPacketFramingLayer {
+                    source_name: Cow::Owned(self.source_name.into_owned()),
+                    priority: self.priority,
+                    synchronization_address: self.synchronization_address,
+                    sequence_number: self.sequence_number,
+                    preview_data: self.preview_data,
+                    stream_terminated: self.stream_terminated,
+                    force_synchronization: self.force_synchronization,
+                    universe: self.universe,
+                    data: self.data.into_owned(),
+                }
+            }
+        }
+
         impl$( $lt )* Clone for DataPacketFramingLayer$( $lt )* {
             fn clone(&self) -> Self {
                 DataPacketFramingLayer {
@@ -933,6 +3054,7 @@ macro_rules! impl_data_packet_dmp_layer {
         ///
         /// Used for sACN data packets.
         #[derive(Eq, PartialEq, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct DataPacketDmpLayer$( $lt )* {
             /// DMX data property values (DMX start coder + 512 slots).
             pub property_values: Cow<'a, [u8]>,
@@ -948,45 +3070,89 @@ macro_rules! impl_data_packet_dmp_layer {
         const PROPERTY_VALUE_COUNT_FIELD_INDEX: usize = ADDRESS_INCREMENT_FIELD_INDEX + E131_DATA_PACKET_DMP_LAYER_ADDRESS_INCREMENT_FIELD_LENGTH;
         const PROPERTY_VALUES_FIELD_INDEX: usize = PROPERTY_VALUE_COUNT_FIELD_INDEX + E131_DATA_PACKET_DMP_LAYER_PROPERTY_VALUE_COUNT_FIELD_LENGTH;
 
-        impl$( $lt )* Pdu for DataPacketDmpLayer$( $lt )* {
+        impl$( $lt )* Pdu$( $lt )* for DataPacketDmpLayer$( $lt )* {
 
-            fn parse(buf: &[u8]) -> Result<DataPacketDmpLayer$( $lt )*> {
+            fn parse(buf: &'a [u8], ctx: &mut ParseContext<'_, 'a>) -> Result<DataPacketDmpLayer$( $lt )*> {
                 // Length and Vector
-                let PduInfo { length, vector } = pdu_info(&buf, E131_DATA_PACKET_DMP_LAYER_VECTOR_FIELD_LENGTH)?;
+                let PduInfo { length, vector } = ctx.pdu_info(&buf, E131_DATA_PACKET_DMP_LAYER_VECTOR_FIELD_LENGTH, PacketLayer::Dmp, &[
+                    (u32::from(VECTOR_DMP_SET_PROPERTY), "VECTOR_DMP_SET_PROPERTY"),
+                ])?;
                 if buf.len() < length {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInsufficientData("Buffer contains insufficient data based on data packet dmp layer pdu length field".to_string())));
+                    return Err(insufficient_length_error(PacketLayer::Dmp, length, buf.len()));
+                }
+                if buf.len() < PROPERTY_VALUES_FIELD_INDEX {
+                    return Err(insufficient_length_error(PacketLayer::Dmp, PROPERTY_VALUES_FIELD_INDEX, buf.len()));
                 }
 
                 if vector != u32::from(VECTOR_DMP_SET_PROPERTY) {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::PduInvalidVector(vector)));
+                    return Err(invalid_vector_error(PacketLayer::Dmp, format!("{VECTOR_DMP_SET_PROPERTY:#x}"), vector));
                 }
 
                 // Address and Data Type
+                ctx.field("Address Type & Data Type", buf, ADDRESS_DATA_FIELD_INDEX .. FIRST_PRIORITY_FIELD_INDEX, || {
+                    format!("{:#04x}", buf[ADDRESS_DATA_FIELD_INDEX])
+                });
                 if buf[ADDRESS_DATA_FIELD_INDEX] != E131_DMP_LAYER_ADDRESS_DATA_FIELD {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInvalidData("invalid Address and Data Type".to_string())));
+                    return Err(parse_error(
+                        ParsePacketError::ParseInvalidData,
+                        PacketLayer::Dmp,
+                        "Address Type & Data Type",
+                        ADDRESS_DATA_FIELD_INDEX,
+                        format!("{E131_DMP_LAYER_ADDRESS_DATA_FIELD:#04x}"),
+                        format!("{:#04x}", buf[ADDRESS_DATA_FIELD_INDEX]),
+                    ));
                 }
 
                 // First Property Address
-                if NetworkEndian::read_u16(&buf[FIRST_PRIORITY_FIELD_INDEX .. ADDRESS_INCREMENT_FIELD_INDEX]) != E131_DATA_PACKET_DMP_LAYER_FIRST_PROPERTY_FIELD {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInvalidData("invalid First Property Address".to_string())));
+                let first_property_address = NetworkEndian::read_u16(&buf[FIRST_PRIORITY_FIELD_INDEX .. ADDRESS_INCREMENT_FIELD_INDEX]);
+                ctx.field("First Property Address", buf, FIRST_PRIORITY_FIELD_INDEX .. ADDRESS_INCREMENT_FIELD_INDEX, || {
+                    format!("{first_property_address:#06x}")
+                });
+                if first_property_address != E131_DATA_PACKET_DMP_LAYER_FIRST_PROPERTY_FIELD {
+                    return Err(parse_error(
+                        ParsePacketError::ParseInvalidData,
+                        PacketLayer::Dmp,
+                        "First Property Address",
+                        FIRST_PRIORITY_FIELD_INDEX,
+                        format!("{E131_DATA_PACKET_DMP_LAYER_FIRST_PROPERTY_FIELD:#06x}"),
+                        format!("{first_property_address:#06x}"),
+                    ));
                 }
 
                 // Address Increment
-                if NetworkEndian::read_u16(&buf[ADDRESS_INCREMENT_FIELD_INDEX .. PROPERTY_VALUE_COUNT_FIELD_INDEX]) != E131_DATA_PACKET_DMP_LAYER_ADDRESS_INCREMENT {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInvalidData("invalid Address Increment".to_string())));
+                let address_increment = NetworkEndian::read_u16(&buf[ADDRESS_INCREMENT_FIELD_INDEX .. PROPERTY_VALUE_COUNT_FIELD_INDEX]);
+                ctx.field("Address Increment", buf, ADDRESS_INCREMENT_FIELD_INDEX .. PROPERTY_VALUE_COUNT_FIELD_INDEX, || {
+                    format!("{address_increment:#06x}")
+                });
+                if address_increment != E131_DATA_PACKET_DMP_LAYER_ADDRESS_INCREMENT {
+                    ctx.deviation(ctx.options.allow_any_address_increment, parse_error(
+                        ParsePacketError::ParseInvalidData,
+                        PacketLayer::Dmp,
+                        "Address Increment",
+                        ADDRESS_INCREMENT_FIELD_INDEX,
+                        format!("{E131_DATA_PACKET_DMP_LAYER_ADDRESS_INCREMENT:#06x}"),
+                        format!("{address_increment:#06x}"),
+                    ))?;
                 }
 
                 // Property value count
                 let property_value_count = NetworkEndian::read_u16(&buf[PROPERTY_VALUE_COUNT_FIELD_INDEX .. PROPERTY_VALUES_FIELD_INDEX]);
+                ctx.field("Property value count", buf, PROPERTY_VALUE_COUNT_FIELD_INDEX .. PROPERTY_VALUES_FIELD_INDEX, || {
+                    property_value_count.to_string()
+                });
+                ctx.field("Property values", buf, PROPERTY_VALUES_FIELD_INDEX .. length, || {
+                    describe_property_values(&buf[PROPERTY_VALUES_FIELD_INDEX .. length])
+                });
 
                 // Check that the property value count matches the expected count based on the pdu length given previously.
                 if property_value_count as usize + PROPERTY_VALUES_FIELD_INDEX != length {
-                    return Err(SacnError::SacnParsePackError(
-                        ParsePacketError::ParseInsufficientData(
-                            format!("Invalid data packet dmp layer property value count, pdu length indicates {} property values, property value count field indicates {} property values",
-                                length , property_value_count)
-                            .to_string()
-                        )
+                    return Err(parse_error(
+                        ParsePacketError::ParseInsufficientData,
+                        PacketLayer::Dmp,
+                        "Property value count",
+                        PROPERTY_VALUE_COUNT_FIELD_INDEX,
+                        format!("{} property values as given by the Length field", length.saturating_sub(PROPERTY_VALUES_FIELD_INDEX)),
+                        format!("{property_value_count} property values"),
                     ));
                 }
 
@@ -994,15 +3160,18 @@ macro_rules! impl_data_packet_dmp_layer {
                 // The property value length is only of the property values and not the headers so start counting at the index that the property values start.
                 let property_values_length = length - PROPERTY_VALUES_FIELD_INDEX;
                 if property_values_length > UNIVERSE_CHANNEL_CAPACITY {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInvalidData("only 512 DMX slots allowed".to_string())));
+                    return Err(parse_error(
+                        ParsePacketError::ParseInvalidData,
+                        PacketLayer::Dmp,
+                        "Property values",
+                        PROPERTY_VALUES_FIELD_INDEX,
+                        format!("at most {UNIVERSE_CHANNEL_CAPACITY} property values (start code + 512 DMX slots)"),
+                        format!("{property_values_length} property values"),
+                    ));
                 }
 
-                let mut property_values = Vec::with_capacity(property_values_length);
-
-                property_values.extend_from_slice(&buf[PROPERTY_VALUES_FIELD_INDEX .. length]);
-
                 Ok(DataPacketDmpLayer {
-                    property_values: property_values.into(),
+                    property_values: Cow::Borrowed(&buf[PROPERTY_VALUES_FIELD_INDEX .. length]),
                 })
             }
 
@@ -1058,6 +3227,15 @@ macro_rules! impl_data_packet_dmp_layer {
             }
         }
 
+        impl$( $lt )* DataPacketDmpLayer$( $lt )* {
+            /// Converts the PDU into one which owns its data, see `AcnRootLayerProtocol::into_owned`.
+            pub fn into_owned(self) -> DataPacketDmpLayer<'static> {
+                DataPacketDmpLayer {
+                    property_values: Cow::Owned(self.property_values.into_owned()),
+                }
+            }
+        }
+
         impl$( $lt )* Clone for DataPacketDmpLayer$( $lt )* {
             fn clone(&self) -> Self {
                 DataPacketDmpLayer {
@@ -1079,6 +3257,7 @@ impl_data_packet_dmp_layer!(<'a>);
 
 /// sACN synchronization packet PDU.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SynchronizationPacketFramingLayer {
     /// The sequence number of the packet.
     pub sequence_number: u8,
@@ -1100,45 +3279,98 @@ const E131_SYNC_FRAMING_LAYER_RESERVE_FIELD_INDEX: usize =
 const E131_SYNC_FRAMING_LAYER_END_INDEX: usize =
     E131_SYNC_FRAMING_LAYER_RESERVE_FIELD_INDEX + E131_SYNC_FRAMING_LAYER_RESERVE_FIELD_LENGTH;
 
-impl Pdu for SynchronizationPacketFramingLayer {
-    fn parse(buf: &[u8]) -> Result<SynchronizationPacketFramingLayer> {
+impl<'a> Pdu<'a> for SynchronizationPacketFramingLayer {
+    fn parse(
+        buf: &'a [u8],
+        ctx: &mut ParseContext<'_, 'a>,
+    ) -> Result<SynchronizationPacketFramingLayer> {
         // Length and Vector
-        let PduInfo { length, vector } = pdu_info(buf, E131_FRAMING_LAYER_VECTOR_LENGTH)?;
+        let PduInfo { length, vector } = ctx.pdu_info(
+            buf,
+            E131_FRAMING_LAYER_VECTOR_LENGTH,
+            PacketLayer::Framing,
+            &[(
+                VECTOR_E131_EXTENDED_SYNCHRONIZATION,
+                "VECTOR_E131_EXTENDED_SYNCHRONIZATION",
+            )],
+        )?;
         if buf.len() < length {
-            return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInsufficientData("Buffer contains insufficient data based on synchronisation packet framing layer pdu length field".to_string())));
+            return Err(insufficient_length_error(
+                PacketLayer::Framing,
+                length,
+                buf.len(),
+            ));
         }
 
         if vector != VECTOR_E131_EXTENDED_SYNCHRONIZATION {
-            return Err(SacnError::SacnParsePackError(
-                ParsePacketError::PduInvalidVector(vector),
+            return Err(invalid_vector_error(
+                PacketLayer::Framing,
+                format!("{VECTOR_E131_EXTENDED_SYNCHRONIZATION:#x}"),
+                vector,
             ));
         }
 
         if length != E131_UNIVERSE_SYNC_PACKET_FRAMING_LAYER_LENGTH {
-            return Err(SacnError::SacnParsePackError(
-                ParsePacketError::PduInvalidLength(length),
+            return Err(parse_error(
+                ParsePacketError::PduInvalidLength,
+                PacketLayer::Framing,
+                "Length",
+                0,
+                E131_UNIVERSE_SYNC_PACKET_FRAMING_LAYER_LENGTH,
+                length,
             ));
         }
 
         // Sequence Number
         let sequence_number = buf[E131_SYNC_FRAMING_LAYER_SEQ_NUM_FIELD_INDEX];
+        ctx.field(
+            "Sequence Number",
+            buf,
+            E131_SYNC_FRAMING_LAYER_SEQ_NUM_FIELD_INDEX
+                ..E131_SYNC_FRAMING_LAYER_SYNC_ADDRESS_FIELD_INDEX,
+            || sequence_number.to_string(),
+        );
 
         // Synchronization Address
         let synchronization_address = NetworkEndian::read_u16(
             &buf[E131_SYNC_FRAMING_LAYER_SYNC_ADDRESS_FIELD_INDEX
                 ..E131_SYNC_FRAMING_LAYER_RESERVE_FIELD_INDEX],
         );
+        ctx.field(
+            "Synchronization Address",
+            buf,
+            E131_SYNC_FRAMING_LAYER_SYNC_ADDRESS_FIELD_INDEX
+                ..E131_SYNC_FRAMING_LAYER_RESERVE_FIELD_INDEX,
+            || synchronization_address.to_string(),
+        );
 
         if !(E131_MIN_MULTICAST_UNIVERSE..=E131_MAX_MULTICAST_UNIVERSE)
             .contains(&synchronization_address)
         {
-            return Err(SacnError::SacnParsePackError(
-                ParsePacketError::ParseInvalidSyncAddr(synchronization_address),
+            return Err(parse_error(
+                ParsePacketError::ParseInvalidSyncAddr,
+                PacketLayer::Framing,
+                "Synchronization Address",
+                E131_SYNC_FRAMING_LAYER_SYNC_ADDRESS_FIELD_INDEX,
+                format!("[{E131_MIN_MULTICAST_UNIVERSE}, {E131_MAX_MULTICAST_UNIVERSE}]"),
+                synchronization_address,
             ));
         }
 
         // Reserved fields (2 bytes right immediately after the synchronisation address) should be ignored by receivers as per
         // ANSI E1.31-2018 Section 6.3.4.
+        ctx.field(
+            "Reserved",
+            buf,
+            E131_SYNC_FRAMING_LAYER_RESERVE_FIELD_INDEX..E131_SYNC_FRAMING_LAYER_END_INDEX,
+            || {
+                format!(
+                    "{:02x?}",
+                    &buf[E131_SYNC_FRAMING_LAYER_RESERVE_FIELD_INDEX
+                        ..E131_SYNC_FRAMING_LAYER_END_INDEX]
+                )
+            },
+        );
 
         Ok(SynchronizationPacketFramingLayer {
             sequence_number,
@@ -1205,6 +3437,7 @@ macro_rules! impl_universe_discovery_packet_framing_layer {
     ( $( $lt:tt )* ) => {
         /// Framing layer PDU for sACN universe discovery packets.
         #[derive(Eq, PartialEq, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct UniverseDiscoveryPacketFramingLayer$( $lt )* {
             /// Name of the source.
             pub source_name: Cow<'a, str>,
@@ -1221,29 +3454,47 @@ macro_rules! impl_universe_discovery_packet_framing_layer {
         const E131_DISCOVERY_FRAMING_LAYER_RESERVE_FIELD_INDEX: usize = E131_DISCOVERY_FRAMING_LAYER_SOURCE_NAME_FIELD_INDEX + E131_SOURCE_NAME_FIELD_LENGTH;
         const E131_DISCOVERY_FRAMING_LAYER_DATA_INDEX: usize = E131_DISCOVERY_FRAMING_LAYER_RESERVE_FIELD_INDEX + E131_DISCOVERY_FRAMING_LAYER_RESERVE_FIELD_LENGTH;
 
-        impl$( $lt )* Pdu for UniverseDiscoveryPacketFramingLayer$( $lt )* {
-            fn parse(buf: &[u8]) -> Result<UniverseDiscoveryPacketFramingLayer$( $lt )*> {
+        impl$( $lt )* Pdu$( $lt )* for UniverseDiscoveryPacketFramingLayer$( $lt )* {
+            fn parse(buf: &'a [u8], ctx: &mut ParseContext<'_, 'a>) -> Result<UniverseDiscoveryPacketFramingLayer$( $lt )*> {
                 // Length and Vector
-                let PduInfo { length, vector } = pdu_info(&buf, E131_FRAMING_LAYER_VECTOR_LENGTH)?;
+                let PduInfo { length, vector } = ctx.pdu_info(&buf, E131_FRAMING_LAYER_VECTOR_LENGTH, PacketLayer::Framing, &[
+                    (VECTOR_E131_EXTENDED_DISCOVERY, "VECTOR_E131_EXTENDED_DISCOVERY"),
+                ])?;
                 if buf.len() < length {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInsufficientData("Buffer contains insufficient data based on universe discovery packet framing layer pdu length field".to_string())));
+                    return Err(insufficient_length_error(PacketLayer::Framing, length, buf.len()));
                 }
 
                 if vector != VECTOR_E131_EXTENDED_DISCOVERY {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::PduInvalidVector(vector)));
+                    return Err(invalid_vector_error(PacketLayer::Framing, format!("{VECTOR_E131_EXTENDED_DISCOVERY:#x}"), vector));
                 }
 
                 if length < E131_UNIVERSE_DISCOVERY_FRAMING_LAYER_MIN_LENGTH {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::PduInvalidLength(length)));
+                    return Err(parse_error(
+                        ParsePacketError::PduInvalidLength,
+                        PacketLayer::Framing,
+                        "Length",
+                        0,
+                        format!("at least {E131_UNIVERSE_DISCOVERY_FRAMING_LAYER_MIN_LENGTH}"),
+                        length,
+                    ));
                 }
 
                 // Source Name
-                let source_name = String::from(parse_source_name_str(&buf[E131_DISCOVERY_FRAMING_LAYER_SOURCE_NAME_FIELD_INDEX .. E131_DISCOVERY_FRAMING_LAYER_RESERVE_FIELD_INDEX])?);
+                let source_name = ctx.at(E131_DISCOVERY_FRAMING_LAYER_SOURCE_NAME_FIELD_INDEX, |ctx| {
+                    parse_source_name_str(&buf[E131_DISCOVERY_FRAMING_LAYER_SOURCE_NAME_FIELD_INDEX .. E131_DISCOVERY_FRAMING_LAYER_RESERVE_FIELD_INDEX], PacketLayer::Framing, ctx)
+                })?;
 
                 // Reserved data (immediately after source_name) ignored as per ANSI E1.31-2018 Section 6.4.3.
+                ctx.field("Reserved", buf, E131_DISCOVERY_FRAMING_LAYER_RESERVE_FIELD_INDEX .. E131_DISCOVERY_FRAMING_LAYER_DATA_INDEX, || {
+                    format!("{:02x?}", &buf[E131_DISCOVERY_FRAMING_LAYER_RESERVE_FIELD_INDEX .. E131_DISCOVERY_FRAMING_LAYER_DATA_INDEX])
+                });
 
                 // The universe discovery data.
-                let data = UniverseDiscoveryPacketUniverseDiscoveryLayer::parse(&buf[E131_DISCOVERY_FRAMING_LAYER_DATA_INDEX .. length])?;
+                let data = ctx.at(E131_DISCOVERY_FRAMING_LAYER_DATA_INDEX, |ctx| {
+                    ctx.layer("Universe Discovery Layer", length - E131_DISCOVERY_FRAMING_LAYER_DATA_INDEX, |ctx| {
+                        UniverseDiscoveryPacketUniverseDiscoveryLayer::parse(&buf[E131_DISCOVERY_FRAMING_LAYER_DATA_INDEX .. length], ctx)
+                    })
+                })?;
 
                 Ok(UniverseDiscoveryPacketFramingLayer {
                     source_name: source_name.into(),
@@ -1288,6 +3539,16 @@ macro_rules! impl_universe_discovery_packet_framing_layer {
             }
         }
 
+        impl$( $lt )* UniverseDiscoveryPacketFramingLayer$( $lt )* {
+            /// Converts the PDU into one which owns its data, see `AcnRootLayerProtocol::into_owned`.
+            pub fn into_owned(self) -> UniverseDiscoveryPacketFramingLayer<'static> {
+                UniverseDiscoveryPacketFramingLayer {
+                    source_name: Cow::Owned(self.source_name.into_owned()),
+                    data: self.data.into_owned(),
+                }
+            }
+        }
+
         impl$( $lt )* Clone for UniverseDiscoveryPacketFramingLayer$( $lt )* {
             fn clone(&self) -> Self {
                 UniverseDiscoveryPacketFramingLayer {
@@ -1313,6 +3574,7 @@ macro_rules! impl_universe_discovery_packet_universe_discovery_layer {
     ( $( $lt:tt )* ) => {
         /// Universe discovery layer PDU.
         #[derive(Eq, PartialEq, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct UniverseDiscoveryPacketUniverseDiscoveryLayer$( $lt )* {
             /// Current page of the discovery packet.
             pub page: u8,
@@ -1332,37 +3594,70 @@ macro_rules! impl_universe_discovery_packet_universe_discovery_layer {
         const E131_DISCOVERY_LAYER_LAST_PAGE_FIELD_INDEX: usize = E131_DISCOVERY_LAYER_PAGE_FIELD_INDEX + E131_DISCOVERY_LAYER_PAGE_FIELD_LENGTH;
         const E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX: usize = E131_DISCOVERY_LAYER_LAST_PAGE_FIELD_INDEX + E131_DISCOVERY_LAYER_LAST_PAGE_FIELD_LENGTH;
 
-        impl$( $lt )* Pdu for UniverseDiscoveryPacketUniverseDiscoveryLayer$( $lt )* {
-            fn parse(buf: &[u8]) -> Result<UniverseDiscoveryPacketUniverseDiscoveryLayer$( $lt )*> {
+        impl$( $lt )* Pdu$( $lt )* for UniverseDiscoveryPacketUniverseDiscoveryLayer$( $lt )* {
+            fn parse(buf: &'a [u8], ctx: &mut ParseContext<'_, 'a>) -> Result<UniverseDiscoveryPacketUniverseDiscoveryLayer$( $lt )*> {
                 // Length and Vector
-                let PduInfo { length, vector } = pdu_info(&buf, E131_DISCOVERY_LAYER_VECTOR_FIELD_LENGTH)?;
+                let PduInfo { length, vector } = ctx.pdu_info(&buf, E131_DISCOVERY_LAYER_VECTOR_FIELD_LENGTH, PacketLayer::UniverseDiscovery, &[
+                    (VECTOR_UNIVERSE_DISCOVERY_UNIVERSE_LIST, "VECTOR_UNIVERSE_DISCOVERY_UNIVERSE_LIST"),
+                ])?;
                 if buf.len() != length {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInsufficientData(
-                        format!("Buffer contains incorrect amount of data ({} bytes) based on universe discovery packet universe discovery layer pdu length field ({} bytes)"
-                        , buf.len() ,length).to_string())));
+                    ctx.deviation(ctx.options.allow_discovery_length_mismatch, parse_error(
+                        ParsePacketError::ParseInsufficientData,
+                        PacketLayer::UniverseDiscovery,
+                        "Length",
+                        0,
+                        format!("{length} bytes"),
+                        format!("{} bytes", buf.len()),
+                    ))?;
                 }
+                let length = length.min(buf.len());
 
                 if vector != VECTOR_UNIVERSE_DISCOVERY_UNIVERSE_LIST {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::PduInvalidVector(vector)));
+                    return Err(invalid_vector_error(PacketLayer::UniverseDiscovery, format!("{VECTOR_UNIVERSE_DISCOVERY_UNIVERSE_LIST:#x}"), vector));
                 }
 
                 if !(E131_UNIVERSE_DISCOVERY_LAYER_MIN_LENGTH..=E131_UNIVERSE_DISCOVERY_LAYER_MAX_LENGTH).contains(&length) {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::PduInvalidLength(length)));
+                    return Err(parse_error(
+                        ParsePacketError::PduInvalidLength,
+                        PacketLayer::UniverseDiscovery,
+                        "Length",
+                        0,
+                        format!("[{E131_UNIVERSE_DISCOVERY_LAYER_MIN_LENGTH}, {E131_UNIVERSE_DISCOVERY_LAYER_MAX_LENGTH}]"),
+                        length,
+                    ));
                 }
 
                 // Page
                 let page = buf[E131_DISCOVERY_LAYER_PAGE_FIELD_INDEX];
+                ctx.field("Page", buf, E131_DISCOVERY_LAYER_PAGE_FIELD_INDEX .. E131_DISCOVERY_LAYER_LAST_PAGE_FIELD_INDEX, || page.to_string());
 
                 // Last Page
                 let last_page = buf[E131_DISCOVERY_LAYER_LAST_PAGE_FIELD_INDEX];
+                ctx.field("Last Page", buf, E131_DISCOVERY_LAYER_LAST_PAGE_FIELD_INDEX .. E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX, || {
+                    last_page.to_string()
+                });
 
                 if page > last_page {
-                    return Err(SacnError::SacnParsePackError(ParsePacketError::ParseInvalidPage("Page value higher than last_page".to_string())));
+                    return Err(parse_error(
+                        ParsePacketError::ParseInvalidPage,
+                        PacketLayer::UniverseDiscovery,
+                        "Page",
+                        E131_DISCOVERY_LAYER_PAGE_FIELD_INDEX,
+                        format!("at most the Last Page ({last_page})"),
+                        page,
+                    ));
                 }
 
                 // The number of universes, calculated by dividing the remaining space in the packet by the size of a single universe.
                 let universes_length = (length - E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX) / E131_UNIVERSE_FIELD_LENGTH;
-                let universes: Cow<'a, [u16]> = parse_universe_list(&buf[E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX ..], universes_length)?;
+                let universes_end = E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX + universes_length * E131_UNIVERSE_FIELD_LENGTH;
+                ctx.field("List of Universes", buf, E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX .. universes_end, || {
+                    describe_universes(&buf[E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX .. universes_end])
+                });
+                let universes: Cow<'a, [u16]> = at_offset(
+                    parse_universe_list(&buf[E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX ..], universes_length, ctx.universes.take()),
+                    E131_DISCOVERY_LAYER_UNIVERSE_LIST_FIELD_INDEX,
+                )?;
 
                 Ok(UniverseDiscoveryPacketUniverseDiscoveryLayer {
                     page,
@@ -1427,6 +3722,17 @@ macro_rules! impl_universe_discovery_packet_universe_discovery_layer {
             }
         }
 
+        impl$( $lt )* UniverseDiscoveryPacketUniverseDiscoveryLayer$( $lt )* {
+            /// Converts the PDU into one which owns its data, see `AcnRootLayerProtocol::into_owned`.
+            pub fn into_owned(self) -> UniverseDiscoveryPacketUniverseDiscoveryLayer<'static> {
+                UniverseDiscoveryPacketUniverseDiscoveryLayer {
+                    page: self.page,
+                    last_page: self.last_page,
+                    universes: Cow::Owned(self.universes.into_owned()),
+                }
+            }
+        }
+
         impl$( $lt )* Clone for UniverseDiscoveryPacketUniverseDiscoveryLayer$( $lt )* {
             fn clone(&self) -> Self {
                 UniverseDiscoveryPacketUniverseDiscoveryLayer {
@@ -1456,12 +3762,18 @@ macro_rules! impl_universe_discovery_packet_universe_discovery_layer {
 /// buf: The byte buffer to parse into the universe.
 /// length: The number of universes to attempt to parse from the buffer.
 ///
+/// storage: The storage to parse the universes into, if None they are allocated. The length must be at most `DISCOVERY_UNI_PER_PAGE`
+/// if given.
+///
 /// # Errors
 /// `ParseInvalidUniverseOrder`: If the universes are not sorted in ascending order with no duplicates.
 ///
 /// `ParseInsufficientData`: If the buffer doesn't contain sufficient bytes and so cannot be parsed into the specified number of u16 universes.
-fn parse_universe_list<'a>(buf: &[u8], length: usize) -> Result<Cow<'a, [u16]>> {
-    let mut universes: Vec<u16> = Vec::with_capacity(length);
+fn parse_universe_list<'a>(
+    buf: &[u8],
+    length: usize,
+    storage: Option<&'a mut [u16; DISCOVERY_UNI_PER_PAGE]>,
+) -> Result<Cow<'a, [u16]>> {
     let mut i = 0;
 
     // Last_universe starts as a placeholder value that is guaranteed to be less than the lowest possible advertised universe.
@@ -1470,12 +3782,13 @@ fn parse_universe_list<'a>(buf: &[u8], length: usize) -> Result<Cow<'a, [u16]>>
     let mut last_universe: i32 = -1;
 
     if buf.len() < length * E131_UNIVERSE_FIELD_LENGTH {
-        return Err(SacnError::SacnParsePackError(
-            ParsePacketError::ParseInsufficientData(format!(
-                "The given buffer of length {} bytes cannot be parsed into the given number of universes {}",
-                buf.len(),
-                length
-            )),
+        return Err(parse_error(
+            ParsePacketError::ParseInsufficientData,
+            PacketLayer::UniverseDiscovery,
+            "List of Universes",
+            0,
+            format!("{} bytes", length * E131_UNIVERSE_FIELD_LENGTH),
+            format!("{} bytes", buf.len()),
         ));
     }
 
@@ -1484,23 +3797,575 @@ fn parse_universe_list<'a>(buf: &[u8], length: usize) -> Result<Cow<'a, [u16]>>
 
         if (u as i32) > last_universe {
             // Enforce assending ordering of universes as per ANSI E1.31-2018 Section 8.5.
-            universes.push(u);
             last_universe = u as i32;
             i += E131_UNIVERSE_FIELD_LENGTH; // Jump to the next universe.
         } else {
-            return Err(SacnError::SacnParsePackError(
-                ParsePacketError::ParseInvalidUniverseOrder(format!(
-                    "Universe {u} is out of order, discovery packet universe list must be in accending order!"
-                )),
+            return Err(parse_error(
+                ParsePacketError::ParseInvalidUniverseOrder,
+                PacketLayer::UniverseDiscovery,
+                "List of Universes",
+                i,
+                format!("a universe greater than {last_universe}"),
+                u,
             ));
         }
     }
 
-    Ok(universes.into())
+    let universes = buf[..length * E131_UNIVERSE_FIELD_LENGTH]
+        .chunks_exact(E131_UNIVERSE_FIELD_LENGTH)
+        .map(NetworkEndian::read_u16);
+
+    match storage {
+        Some(storage) => {
+            let storage = &mut storage[..length];
+            for (s, u) in storage.iter_mut().zip(universes) {
+                *s = u;
+            }
+            Ok(Cow::Borrowed(storage))
+        }
+        None => Ok(universes.collect()),
+    }
 }
 
 impl_universe_discovery_packet_universe_discovery_layer!(<'a>);
 
+/// An sACN packet which owns its data, a simpler alternative to an `AcnRootLayerProtocol` which can be built using
+/// `E131Packet::data`, `E131Packet::sync` and `E131Packet::discovery`.
+///
+/// ```
+/// use sacn::packet::E131Packet;
+/// use sacn::types::{Priority, Universe};
+/// use uuid::Uuid;
+///
+/// let packet = E131Packet::data(Universe::try_from(1).unwrap())
+///     .cid(Uuid::new_v4())
+///     .source_name("Source_A")
+///     .priority(Priority::try_from(150).unwrap())
+///     .values(&[0, 255, 128])
+///     .build()
+///     .unwrap();
+///
+/// let buf = packet.pack_alloc().unwrap();
+/// assert_eq!(E131Packet::parse(&buf).unwrap(), packet);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum E131Packet {
+    /// DMX data packet.
+    Data {
+        /// The CID of the source.
+        cid: Uuid,
+        /// The framing layer of the packet.
+        data: DataPacketFramingLayer<'static>,
+    },
+
+    /// Synchronization packet.
+    Sync {
+        /// The CID of the source.
+        cid: Uuid,
+        /// The framing layer of the packet.
+        data: SynchronizationPacketFramingLayer,
+    },
+
+    /// Universe discovery packet.
+    Discovery {
+        /// The CID of the source.
+        cid: Uuid,
+        /// The framing layer of the packet.
+        data: UniverseDiscoveryPacketFramingLayer<'static>,
+    },
+
+    /// A packet with a vector which isn't part of ANSI E1.31-2018, see `E131RootLayerData::Unknown`.
+    Unknown {
+        /// The CID of the source.
+        cid: Uuid,
+        /// The vector of the root layer PDU.
+        vector: u32,
+        /// The data of the root layer PDU following the CID.
+        raw: Vec<u8>,
+    },
+
+    /// An extended packet with a framing layer vector which isn't part of ANSI E1.31-2018, see
+    /// `E131RootLayerData::UnknownExtended`.
+    UnknownExtended {
+        /// The CID of the source.
+        cid: Uuid,
+        /// The vector of the framing layer PDU.
+        vector: u32,
+        /// The framing layer PDU.
+        raw: Vec<u8>,
+    },
+}
+
+impl E131Packet {
+    /// Returns a builder for a data packet sent to the given universe.
+    pub fn data(universe: Universe) -> DataPacketBuilder {
+        DataPacketBuilder::new(universe)
+    }
+
+    /// Returns a builder for a synchronization packet for the given synchronization address.
+    pub fn sync(synchronization_address: SyncAddress) -> SyncPacketBuilder {
+        SyncPacketBuilder::new(synchronization_address)
+    }
+
+    /// Returns a builder for a universe discovery packet.
+    pub fn discovery() -> DiscoveryPacketBuilder {
+        DiscoveryPacketBuilder::new()
+    }
+
+    /// Parse the packet from the given buffer, see `AcnRootLayerProtocol::parse`.
+    pub fn parse(buf: &[u8]) -> Result<E131Packet> {
+        Ok(AcnRootLayerProtocol::parse(buf)?.into())
+    }
+
+    /// Returns the CID of the source of the packet.
+    pub fn cid(&self) -> Uuid {
+        match self {
+            E131Packet::Data { cid, .. }
+            | E131Packet::Sync { cid, .. }
+            | E131Packet::Discovery { cid, .. }
+            | E131Packet::Unknown { cid, .. }
+            | E131Packet::UnknownExtended { cid, .. } => *cid,
+        }
+    }
+
+    /// Returns the packet as an `AcnRootLayerProtocol` which borrows the data of this packet.
+    pub fn as_protocol(&self) -> AcnRootLayerProtocol<'_> {
+        let (cid, data) = match self {
+            E131Packet::Data { cid, data } => (
+                *cid,
+                E131RootLayerData::DataPacket(DataPacketFramingLayer {
+                    source_name: Cow::Borrowed(&data.source_name),
+                    data: DataPacketDmpLayer {
+                        property_values: Cow::Borrowed(&data.data.property_values),
+                    },
+                    ..*data
+                }),
+            ),
+            E131Packet::Sync { cid, data } => {
+                (*cid, E131RootLayerData::SynchronizationPacket(*data))
+            }
+            E131Packet::Discovery { cid, data } => (
+                *cid,
+                E131RootLayerData::UniverseDiscoveryPacket(UniverseDiscoveryPacketFramingLayer {
+                    source_name: Cow::Borrowed(&data.source_name),
+                    data: UniverseDiscoveryPacketUniverseDiscoveryLayer {
+                        universes: Cow::Borrowed(&data.data.universes),
+                        ..data.data
+                    },
+                }),
+            ),
+            E131Packet::Unknown { cid, vector, raw } => (
+                *cid,
+                E131RootLayerData::Unknown {
+                    vector: *vector,
+                    raw: Cow::Borrowed(raw),
+                },
+            ),
+            E131Packet::UnknownExtended { cid, vector, raw } => (
+                *cid,
+                E131RootLayerData::UnknownExtended {
+                    vector: *vector,
+                    raw: Cow::Borrowed(raw),
+                },
+            ),
+        };
+
+        AcnRootLayerProtocol {
+            pdu: E131RootLayer { cid, data },
+        }
+    }
+
+    /// Packs the packet into heap allocated memory, see `AcnRootLayerProtocol::pack_alloc`.
+    pub fn pack_alloc(&self) -> Result<Vec<u8>> {
+        self.as_protocol().pack_alloc()
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    /// The length of the packet when packed.
+    pub fn len(&self) -> usize {
+        self.as_protocol().len()
+    }
+}
+
+impl From<AcnRootLayerProtocol<'_>> for E131Packet {
+    fn from(packet: AcnRootLayerProtocol<'_>) -> E131Packet {
+        let cid = packet.pdu.cid;
+        match packet.pdu.data {
+            E131RootLayerData::DataPacket(data) => E131Packet::Data {
+                cid,
+                data: data.into_owned(),
+            },
+            E131RootLayerData::SynchronizationPacket(data) => E131Packet::Sync { cid, data },
+            E131RootLayerData::UniverseDiscoveryPacket(data) => E131Packet::Discovery {
+                cid,
+                data: data.into_owned(),
+            },
+            E131RootLayerData::Unknown { vector, raw } => E131Packet::Unknown {
+                cid,
+                vector,
+                raw: raw.into_owned(),
+            },
+            E131RootLayerData::UnknownExtended { vector, raw } => E131Packet::UnknownExtended {
+                cid,
+                vector,
+                raw: raw.into_owned(),
+            },
+        }
+    }
+}
+
+impl From<E131Packet> for AcnRootLayerProtocol<'static> {
+    fn from(packet: E131Packet) -> AcnRootLayerProtocol<'static> {
+        let (cid, data) = match packet {
+            E131Packet::Data { cid, data } => (cid, E131RootLayerData::DataPacket(data)),
+            E131Packet::Sync { cid, data } => (cid, E131RootLayerData::SynchronizationPacket(data)),
+            E131Packet::Discovery { cid, data } => {
+                (cid, E131RootLayerData::UniverseDiscoveryPacket(data))
+            }
+            E131Packet::Unknown { cid, vector, raw } => (
+                cid,
+                E131RootLayerData::Unknown {
+                    vector,
+                    raw: Cow::Owned(raw),
+                },
+            ),
+            E131Packet::UnknownExtended { cid, vector, raw } => (
+                cid,
+                E131RootLayerData::UnknownExtended {
+                    vector,
+                    raw: Cow::Owned(raw),
+                },
+            ),
+        };
+
+        AcnRootLayerProtocol {
+            pdu: E131RootLayer { cid, data },
+        }
+    }
+}
+
+/// Returns the CID given to a packet builder.
+///
+/// # Errors
+/// `InvalidConfiguration`: Returned if no CID was given.
+fn check_cid(cid: Option<Uuid>) -> Result<Uuid> {
+    cid.ok_or_else(|| SacnError::InvalidConfiguration("A CID must be given".to_string()))
+}
+
+/// Checks that the source name given to a packet builder fits within the Source Name field.
+///
+/// # Errors
+/// `MalformedSourceName`: Returned if the source name doesn't fit within `E131_SOURCE_NAME_FIELD_LENGTH` bytes including the null
+/// terminator required by ANSI E1.31-2018 Section 6.2.2.
+fn check_source_name(source_name: &str) -> Result<()> {
+    if source_name.len() >= E131_SOURCE_NAME_FIELD_LENGTH {
+        return Err(SacnError::MalformedSourceName(format!(
+            "Source name must be shorter than {E131_SOURCE_NAME_FIELD_LENGTH} bytes to leave room for the null terminator"
+        )));
+    }
+    Ok(())
+}
+
+/// Builds a data packet, created using `E131Packet::data`.
+///
+/// By default the packet has the `E131_DEFAULT_PRIORITY`, no synchronization address, sequence number 0, no options set and no
+/// property values. A CID must be given.
+#[derive(Clone, Debug)]
+pub struct DataPacketBuilder {
+    /// The CID of the source, must be given before building.
+    cid: Option<Uuid>,
+
+    /// The name of the source.
+    source_name: String,
+
+    /// The priority of the data.
+    priority: Priority,
+
+    /// The synchronization address, None if the data isn't synchronised.
+    synchronization_address: Option<SyncAddress>,
+
+    /// The sequence number of the packet.
+    sequence_number: u8,
+
+    /// The value of the Preview Data option.
+    preview_data: bool,
+
+    /// The value of the Stream Terminated option.
+    stream_terminated: bool,
+
+    /// The value of the Force Synchronization option.
+    force_synchronization: bool,
+
+    /// The universe the data is for.
+    universe: Universe,
+
+    /// The property values, the start code followed by the slots of data.
+    values: Vec<u8>,
+}
+
+impl DataPacketBuilder {
+    fn new(universe: Universe) -> DataPacketBuilder {
+        DataPacketBuilder {
+            cid: None,
+            source_name: String::new(),
+            priority: Priority::DEFAULT,
+            synchronization_address: None,
+            sequence_number: STARTING_SEQUENCE_NUMBER,
+            preview_data: false,
+            stream_terminated: false,
+            force_synchronization: false,
+            universe,
+            values: Vec::new(),
+        }
+    }
+
+    /// Sets the CID of the source of the packet.
+    pub fn cid(mut self, cid: Uuid) -> Self {
+        self.cid = Some(cid);
+        self
+    }
+
+    /// Sets the name of the source of the packet.
+    pub fn source_name(mut self, source_name: impl Into<String>) -> Self {
+        self.source_name = source_name.into();
+        self
+    }
+
+    /// Sets the priority of the data.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the synchronization address the data is synchronised to, None (the default) means the data isn't synchronised.
+    pub fn synchronization_address(mut self, synchronization_address: Option<SyncAddress>) -> Self {
+        self.synchronization_address = synchronization_address;
+        self
+    }
+
+    /// Sets the sequence number of the packet.
+    pub fn sequence_number(mut self, sequence_number: u8) -> Self {
+        self.sequence_number = sequence_number;
+        self
+    }
+
+    /// Sets whether the data is preview data.
+    pub fn preview_data(mut self, preview_data: bool) -> Self {
+        self.preview_data = preview_data;
+        self
+    }
+
+    /// Sets whether the packet terminates the stream of data on the universe.
+    pub fn stream_terminated(mut self, stream_terminated: bool) -> Self {
+        self.stream_terminated = stream_terminated;
+        self
+    }
+
+    /// Sets whether receivers should act on the data if synchronization packets stop being received.
+    pub fn force_synchronization(mut self, force_synchronization: bool) -> Self {
+        self.force_synchronization = force_synchronization;
+        self
+    }
+
+    /// Sets the property values of the packet, the start code followed by up to 512 slots of data.
+    pub fn values(mut self, values: &[u8]) -> Self {
+        self.values = values.to_vec();
+        self
+    }
+
+    /// Builds the packet.
+    ///
+    /// # Errors
+    /// `InvalidConfiguration`: Returned if no CID was given.
+    ///
+    /// `MalformedSourceName`: Returned if the source name is `E131_SOURCE_NAME_FIELD_LENGTH` bytes or longer.
+    ///
+    /// `DataArrayEmpty`: Returned if no property values were given, a data packet must contain at least the start code.
+    ///
+    /// `ExceedUniverseCapacity`: Returned if more than `UNIVERSE_CHANNEL_CAPACITY` property values were given.
+    pub fn build(self) -> Result<E131Packet> {
+        let cid = check_cid(self.cid)?;
+        check_source_name(&self.source_name)?;
+        if self.values.is_empty() {
+            return Err(SacnError::DataArrayEmpty());
+        }
+        if self.values.len() > UNIVERSE_CHANNEL_CAPACITY {
+            return Err(SacnError::ExceedUniverseCapacity(self.values.len()));
+        }
+
+        Ok(E131Packet::Data {
+            cid,
+            data: DataPacketFramingLayer {
+                source_name: self.source_name.into(),
+                priority: self.priority.get(),
+                synchronization_address: self
+                    .synchronization_address
+                    .map_or(E131_NO_SYNC_ADDR, SyncAddress::get),
+                sequence_number: self.sequence_number,
+                preview_data: self.preview_data,
+                stream_terminated: self.stream_terminated,
+                force_synchronization: self.force_synchronization,
+                universe: self.universe.get(),
+                data: DataPacketDmpLayer {
+                    property_values: self.values.into(),
+                },
+            },
+        })
+    }
+}
+
+/// Builds a synchronization packet, created using `E131Packet::sync`.
+///
+/// By default the packet has sequence number 0. A CID must be given.
+#[derive(Clone, Debug)]
+pub struct SyncPacketBuilder {
+    /// The CID of the source, must be given before building.
+    cid: Option<Uuid>,
+
+    /// The sequence number of the packet.
+    sequence_number: u8,
+
+    /// The synchronization address being synchronised.
+    synchronization_address: SyncAddress,
+}
+
+impl SyncPacketBuilder {
+    fn new(synchronization_address: SyncAddress) -> SyncPacketBuilder {
+        SyncPacketBuilder {
+            cid: None,
+            sequence_number: STARTING_SEQUENCE_NUMBER,
+            synchronization_address,
+        }
+    }
+
+    /// Sets the CID of the source of the packet.
+    pub fn cid(mut self, cid: Uuid) -> Self {
+        self.cid = Some(cid);
+        self
+    }
+
+    /// Sets the sequence number of the packet.
+    pub fn sequence_number(mut self, sequence_number: u8) -> Self {
+        self.sequence_number = sequence_number;
+        self
+    }
+
+    /// Builds the packet.
+    ///
+    /// # Errors
+    /// `InvalidConfiguration`: Returned if no CID was given.
+    pub fn build(self) -> Result<E131Packet> {
+        let cid = check_cid(self.cid)?;
+
+        Ok(E131Packet::Sync {
+            cid,
+            data: SynchronizationPacketFramingLayer {
+                sequence_number: self.sequence_number,
+                synchronization_address: self.synchronization_address.get(),
+            },
+        })
+    }
+}
+
+/// Builds a universe discovery packet, created using `E131Packet::discovery`.
+///
+/// By default the packet is page 0 of 0 with no universes. A CID must be given.
+#[derive(Clone, Debug)]
+pub struct DiscoveryPacketBuilder {
+    /// The CID of the source, must be given before building.
+    cid: Option<Uuid>,
+
+    /// The name of the source.
+    source_name: String,
+
+    /// The page of the packet.
+    page: u8,
+
+    /// The number of the final page.
+    last_page: u8,
+
+    /// The universes listed on this page.
+    universes: Vec<Universe>,
+}
+
+impl DiscoveryPacketBuilder {
+    fn new() -> DiscoveryPacketBuilder {
+        DiscoveryPacketBuilder {
+            cid: None,
+            source_name: String::new(),
+            page: 0,
+            last_page: 0,
+            universes: Vec::new(),
+        }
+    }
+
+    /// Sets the CID of the source of the packet.
+    pub fn cid(mut self, cid: Uuid) -> Self {
+        self.cid = Some(cid);
+        self
+    }
+
+    /// Sets the name of the source of the packet.
+    pub fn source_name(mut self, source_name: impl Into<String>) -> Self {
+        self.source_name = source_name.into();
+        self
+    }
+
+    /// Sets the page of the packet and the number of the final page.
+    pub fn page(mut self, page: u8, last_page: u8) -> Self {
+        self.page = page;
+        self.last_page = last_page;
+        self
+    }
+
+    /// Sets the universes listed on this page, these must be sorted in ascending order as per ANSI E1.31-2018 Section 8.5.
+    pub fn universes(mut self, universes: &[Universe]) -> Self {
+        self.universes = universes.to_vec();
+        self
+    }
+
+    /// Builds the packet.
+    ///
+    /// # Errors
+    /// `InvalidConfiguration`: Returned if no CID was given, if the page is higher than the last page, if more than
+    /// `DISCOVERY_UNI_PER_PAGE` universes were given or if the universes aren't sorted in ascending order without duplicates.
+    ///
+    /// `MalformedSourceName`: Returned if the source name is `E131_SOURCE_NAME_FIELD_LENGTH` bytes or longer.
+    pub fn build(self) -> Result<E131Packet> {
+        let cid = check_cid(self.cid)?;
+        check_source_name(&self.source_name)?;
+        if self.page > self.last_page {
+            return Err(SacnError::InvalidConfiguration(format!(
+                "Page {} is higher than the last page {}",
+                self.page, self.last_page
+            )));
+        }
+        if self.universes.len() > DISCOVERY_UNI_PER_PAGE {
+            return Err(SacnError::InvalidConfiguration(format!(
+                "Maximum {DISCOVERY_UNI_PER_PAGE} universes allowed per discovery page"
+            )));
+        }
+        if self.universes.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(SacnError::InvalidConfiguration(
+                "Universes must be sorted in ascending order without duplicates".to_string(),
+            ));
+        }
+
+        Ok(E131Packet::Discovery {
+            cid,
+            data: UniverseDiscoveryPacketFramingLayer {
+                source_name: self.source_name.into(),
+                data: UniverseDiscoveryPacketUniverseDiscoveryLayer {
+                    page: self.page,
+                    last_page: self.last_page,
+                    universes: self.universes.into_iter().map(Universe::get).collect(),
+                },
+            },
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -17,12 +17,26 @@
 // received, if a discovery packet is received but there are more pages the source won't be discovered until all the pages are received.
 // If a page is lost this therefore means the source update / discovery in its entirety will be lost - implementation detail.
 
-/// Socket 2 used for the underlying UDP socket that sACN is sent over.
-use socket2::{Domain, Protocol, SockAddr, Socket, Type};
+/// The transport that sACN is received over, by default a UDP socket.
+use crate::transport::{Transport, UdpTransport};
+
+/// The clock used for timeouts, by default the system clock.
+use crate::clock::{Clock, SystemClock};
+
+/// The timeouts used by the receiver, by default the values from ANSI E1.31-2018.
+use crate::timing::TimingProfile;
+
+/// The shutdown report returned when a receiver is explicitly shut down.
+use crate::shutdown::ShutdownReport;
+
+/// Validated universes used by the public API.
+use crate::types::{StartCode, Universe};
 
 /// Mass import as a very large amount of packet is used here (upwards of 20 items) and this is much cleaner.
 use crate::packet::{
-    E131RootLayerData::{DataPacket, SynchronizationPacket, UniverseDiscoveryPacket},
+    E131RootLayerData::{
+        DataPacket, SynchronizationPacket, UniverseDiscoveryPacket, Unknown, UnknownExtended,
+    },
     *,
 };
 
@@ -33,33 +47,13 @@ use crate::error::errors::*;
 /// This is used for uniquely identifying sources when counting sequence numbers.
 use uuid::Uuid;
 
-use std::cmp::{Ordering, max};
-use std::collections::HashMap;
-use std::io::Read;
-use std::net::{Ipv4Addr, SocketAddr};
+use std::cmp::{Ordering, Reverse, max};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::mpsc;
 use std::time::{Duration, Instant};
 use std::{fmt, io};
 
-/// Extra net imports required for the IPv6 handling on the linux side.
-#[cfg(not(target_os = "windows"))]
-use std::net::{IpAddr, Ipv6Addr};
-
-/// Constants required to detect if an IP is IPv4 or IPv6.
-#[cfg(not(target_os = "windows"))]
-use libc::{AF_INET, AF_INET6};
-
-/// The libc constants required are not available on many windows environments and therefore are hard-coded.
-/// Defined as per <https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-socket>
-#[cfg(target_os = "windows")]
-const AF_INET: i32 = 2;
-
-/// Defined as per <https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-socket>
-#[cfg(target_os = "windows")]
-const AF_INET6: i32 = 23;
-
-#[cfg(target_os = "windows")]
-use std::net::IpAddr;
-
 /// The default size of the buffer used to receive E1.31 packets.
 /// 1143 bytes is biggest packet required as per Section 8 of ANSI E1.31-2018, aligned to 64 bit that is 1144 bytes.
 pub const RCV_BUF_DEFAULT_SIZE: usize = 1144;
@@ -92,8 +86,17 @@ const ANNOUNCE_TIMEOUT_DEFAULT: bool = false;
 const DEFAULT_MERGE_FUNC: fn(&DMXData, &DMXData) -> Result<DMXData> =
     discard_lowest_priority_then_previous;
 
+/// A filter applied to every packet received before it is processed, given the CID of the source and the root layer data of the packet.
+///
+/// A packet is only processed if all the filters of the receiver return true, otherwise it is silently dropped as if it was never received.
+/// As filtered packets are never processed they don't affect sequence numbering, synchronisation or universe discovery.
+pub type PacketFilter = fn(&Uuid, &E131RootLayerData<'_>) -> bool;
+
 /// Holds a universes worth of DMX data.
+///
+/// With the `serde` feature the `recv_timestamp` is serialised as wall-clock time, see `clock::serde_system_time`.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DMXData {
     /// The universe that the data was sent to.
     pub universe: u16,
@@ -117,7 +120,8 @@ pub struct DMXData {
     /// Indicates if the data is marked as 'preview' data indicating it is for use by visualisers etc. as per ANSI E1.31-2018 Section 6.2.6.
     pub preview: bool,
 
-    /// The timestamp that the data was received.
+    /// The timestamp that the data was received, as given by the clock of the receiver.
+    #[cfg_attr(feature = "serde", serde(with = "crate::clock::serde_system_time"))]
     pub recv_timestamp: Instant,
 }
 
@@ -129,11 +133,12 @@ pub struct DMXData {
 /// // Example showing creation of a receiver and receiving some data, as there is no sender this receiver then handles the timeout.
 /// use sacn::receive::SacnReceiver;
 /// use sacn::packet::ACN_SDT_MULTICAST_PORT;
+/// use sacn::types::Universe;
 ///
 /// use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 /// use std::time::Duration;
 ///
-/// const UNIVERSE1: u16 = 1;
+/// const UNIVERSE1: Universe = Universe::new(1).unwrap();
 /// const TIMEOUT: Option<Duration> = Some(Duration::from_secs(1)); // A timeout of None means blocking behaviour, some indicates the actual timeout.
 ///
 /// let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
@@ -158,18 +163,25 @@ pub struct SacnReceiver {
     receiver: SacnNetworkReceiver,
 
     /// Data that hasn't been passed up yet as it is waiting e.g. due to universe synchronisation.
-    /// Key is the universe. A receiver may not have more than one packet waiting per `data_universe`.
+    /// Key is the universe and start code. A receiver may not have more than one packet waiting per `data_universe` and start code, so
+    /// for example per-address priority data can wait alongside the levels it applies to.
     /// `Data_universe` used as key as oppose to sync universe because multiple packets might be waiting on the same sync universe
     /// and adding data by data universe is at least as common as retrieving data by sync address because in a normal setup
     /// 1 or more bits of data wait for 1 sync.
-    waiting_data: HashMap<u16, DMXData>,
+    waiting_data: HashMap<WaitingDataKey, DMXData>,
+
+    /// The deadlines at which data in `waiting_data` is discarded if the synchronisation packet it is waiting for hasn't arrived.
+    waiting_data_deadlines: DeadlineQueue<WaitingDataKey>,
 
     /// Universes that this receiver is currently listening for.
-    universes: Vec<u16>,
+    universes: UniverseSet,
 
     /// Sacn sources that have been discovered by this receiver through universe discovery packets.
     discovered_sources: Vec<DiscoveredSacnSource>,
 
+    /// The deadlines at which discovered and partially discovered sources expire if no further discovery packets are received.
+    discovery_deadlines: DeadlineQueue<Uuid>,
+
     /// The merge function used by this receiver if `DMXData` for the same universe and synchronisation universe is received while there
     /// is already `DMXData` waiting for that universe and synchronisation address.
     merge_func: fn(&DMXData, &DMXData) -> Result<DMXData>,
@@ -197,10 +209,57 @@ pub struct SacnReceiver {
 
     /// Flag which indicates if an `UniverseTimeout` error should be thrown if it is detected that a source has timed out.
     announce_timeout: bool,
+
+    /// The clock used to timestamp received data and to check timeouts, by default the system clock.
+    clock: Box<dyn Clock>,
+
+    /// The timeouts used by this receiver, by default the values from ANSI E1.31-2018.
+    timing: TimingProfile,
+
+    /// The filters that a received packet must pass to be processed, by default there are no filters.
+    packet_filters: Vec<PacketFilter>,
+
+    /// Where data is delivered instead of being returned by `recv`, by start code. By default there are no routes.
+    start_code_routes: HashMap<u8, StartCodeRoute>,
+
+    /// The parsers applied to packets with vectors which aren't part of ANSI E1.31-2018, by default there are none.
+    vector_parsers: VectorParsers,
+
+    /// Passed packets with vectors which aren't part of ANSI E1.31-2018 that aren't parsed by `vector_parsers`, if None they are
+    /// discarded.
+    unknown_packet_handler: Option<UnknownPacketHandler>,
+
+    /// Whether packets with unknown vectors are allowed other than to accept draft packets, either by the parse options given to the
+    /// builder or by registering a vector parser or unknown packet handler. `ParseOptions::allow_unknown_vectors` is restored to this
+    /// when draft packets stop being accepted.
+    allow_unknown_vectors: bool,
+
+    /// Errors, including `SourceDiscovered` announcements, from the PDUs of packets already received which are still to be returned by
+    /// `recv`, oldest first. A packet can hold several PDUs so these are kept rather than losing the data from the other PDUs.
+    pending_errors: VecDeque<SacnError>,
+}
+
+/// Called by a `SacnReceiver` with the CID of the source, the root layer vector and the data of a packet with a vector which isn't
+/// part of ANSI E1.31-2018, see `SacnReceiver::set_unknown_packet_handler`.
+type UnknownPacketHandler = Box<dyn FnMut(Uuid, u32, &[u8]) + Send>;
+
+/// The key of data waiting in a `SacnReceiver`, the universe and start code of the data.
+type WaitingDataKey = (u16, u8);
+
+/// Where a `SacnReceiver` delivers data with a particular start code instead of returning it from `recv`.
+enum StartCodeRoute {
+    /// The data is passed to the handler, see `SacnReceiver::set_start_code_handler`.
+    Handler(Box<dyn FnMut(DMXData) + Send>),
+
+    /// The data is sent on the channel, see `SacnReceiver::subscribe_start_code`.
+    Channel(mpsc::Sender<DMXData>),
 }
 
 /// Represents an sACN source/sender on the network that has been discovered by this sACN receiver by receiving universe discovery packets.
+///
+/// With the `serde` feature the `last_updated` time is serialised as wall-clock time, see `clock::serde_system_time`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiscoveredSacnSource {
     /// The name of the source, no protocol guarantee this will be unique but if it isn't then universe discovery may not work correctly.
     pub name: String,
@@ -209,6 +268,7 @@ pub struct DiscoveredSacnSource {
     pub cid: Uuid,
 
     /// The time at which the discovered source was last updated / a discovery packet was received by the source.
+    #[cfg_attr(feature = "serde", serde(with = "crate::clock::serde_system_time"))]
     pub last_updated: Instant,
 
     /// The pages that have been sent so far by this source when enumerating the universes it is currently sending on.
@@ -221,8 +281,8 @@ pub struct DiscoveredSacnSource {
 /// Used for receiving dmx or other data on a particular universe using multicast.
 #[derive(Debug)]
 struct SacnNetworkReceiver {
-    /// The underlying UDP network socket used.
-    socket: Socket,
+    /// The underlying transport used, by default a UDP socket.
+    transport: Box<dyn Transport>,
 
     /// The address that this `SacnNetworkReceiver` is bound to.
     addr: SocketAddr,
@@ -231,6 +291,12 @@ struct SacnNetworkReceiver {
     /// This flag is set when the receiver is created as not all environments currently support IP multicast.
     /// E.g. IPv6 Windows IP Multicast is currently unsupported.
     is_multicast_enabled: bool,
+
+    /// If true then `SO_BROADCAST` has been enabled so that datagrams sent to a broadcast address are accepted.
+    accept_broadcast: bool,
+
+    /// The deviations from ANSI E1.31-2018 accepted in received packets.
+    parse_options: ParseOptions,
 }
 
 /// Universe discovery packets are broken down into pages to allow sending a large list of universes, each page contains a list of universes and
@@ -239,6 +305,7 @@ struct SacnNetworkReceiver {
 /// The concept of pages is intentionally hidden from the end-user of the library as they are a way of fragmenting large discovery
 /// universe lists so that they can work over the network and don't play any part out-side of the protocol.
 #[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct UniversePage {
     /// The page number of this page.
     page: u8,
@@ -260,6 +327,11 @@ impl fmt::Debug for SacnReceiver {
 }
 
 impl SacnReceiver {
+    /// Returns a `SacnReceiverBuilder` for creating a `SacnReceiver` with a configuration other than the default.
+    pub fn builder() -> SacnReceiverBuilder {
+        SacnReceiverBuilder::new()
+    }
+
     /// Creates a new `SacnReceiver`.
     ///
     /// `SacnReceiverInternal` is used for actually receiving the sACN data but is wrapped in `SacnReceiver` to allow the update thread to handle
@@ -293,22 +365,132 @@ impl SacnReceiver {
         {
             return Err(SacnError::SourceLimitZero());
         };
+        SacnReceiver::with_network_receiver(
+            SacnNetworkReceiver::new(ip)?,
+            source_limit,
+            Box::new(SystemClock),
+            TimingProfile::default(),
+        )
+    }
+
+    /// Creates a new `SacnReceiver` which receives over the given transport instead of a UDP socket.
+    ///
+    /// The local address of the transport is used as the address of the interface to join multicast groups on. This allows a receiver to be
+    /// used with an in-process `MemoryTransport` or any other user provided `Transport`.
+    ///
+    /// Arguments:
+    ///     transport: The transport to receive sACN over.
+    ///     `source_limit`: The limit to the number of sources, past this limit a new source will cause a `SourcesExceededError` as per ANSI E1.31-2018 Section 6.2.3.3.
+    ///                     A source limit of None means no limit to the number of sources.
+    ///
+    /// # Errors
+    /// Will return a `SourceLimitZero` error if the `source_limit` has a value of Some(0).
+    ///
+    /// Will return an error if the local address of the transport cannot be retrieved, see `Transport::local_addr`.
+    ///
+    /// Will return an error if the created `SacnReceiver` fails to listen to the `E1.31_DISCOVERY_UNIVERSE`.
+    /// For more details see `SacnReceiver::listen_universes()`.
+    pub fn with_transport<T: Transport + 'static>(
+        transport: T,
+        source_limit: Option<usize>,
+    ) -> Result<SacnReceiver> {
+        SacnReceiver::with_clock(transport, source_limit, SystemClock)
+    }
+
+    /// Creates a new `SacnReceiver` which receives over the given transport and takes the time used for timeouts from the given clock.
+    ///
+    /// The clock is used for the `recv_timestamp` of received data, the data loss timeout of sources and waiting data and the timeout of
+    /// discovered sources. The timeout given to `recv` is still waited for in real time.
+    ///
+    /// Arguments:
+    ///     transport: The transport to receive sACN over.
+    ///     `source_limit`: The limit to the number of sources, past this limit a new source will cause a `SourcesExceededError` as per ANSI E1.31-2018 Section 6.2.3.3.
+    ///                     A source limit of None means no limit to the number of sources.
+    ///     clock: The clock to use for timeouts, e.g. a `MockClock` so that timeouts can be tested without waiting.
+    ///
+    /// # Errors
+    /// See (`with_transport`)[`with_transport`]
+    pub fn with_clock<T: Transport + 'static, C: Clock + 'static>(
+        transport: T,
+        source_limit: Option<usize>,
+        clock: C,
+    ) -> Result<SacnReceiver> {
+        SacnReceiver::with_timing(transport, source_limit, clock, TimingProfile::default())
+    }
+
+    /// Creates a new `SacnReceiver` which receives over the given transport using the given clock and timing profile.
+    ///
+    /// The timing profile sets the data loss timeout, the discovered source timeout and the sequence number discard window used by
+    /// the receiver in place of the values from ANSI E1.31-2018.
+    ///
+    /// Arguments:
+    ///     transport: The transport to receive sACN over.
+    ///     `source_limit`: The limit to the number of sources, past this limit a new source will cause a `SourcesExceededError` as per ANSI E1.31-2018 Section 6.2.3.3.
+    ///                     A source limit of None means no limit to the number of sources.
+    ///     clock: The clock to use for timeouts, usually `SystemClock`.
+    ///     timing: The timeouts to use, see `TimingProfile`.
+    ///
+    /// # Errors
+    /// Will return an `InvalidTimingProfile` error if the timing profile is invalid, see `TimingProfile::validate`.
+    ///
+    /// Otherwise see (`with_transport`)[`with_transport`]
+    pub fn with_timing<T: Transport + 'static, C: Clock + 'static>(
+        transport: T,
+        source_limit: Option<usize>,
+        clock: C,
+        timing: TimingProfile,
+    ) -> Result<SacnReceiver> {
+        timing.validate()?;
+        if let Some(x) = source_limit
+            && x == 0
+        {
+            return Err(SacnError::SourceLimitZero());
+        };
+        SacnReceiver::with_network_receiver(
+            SacnNetworkReceiver::with_transport(Box::new(transport))?,
+            source_limit,
+            Box::new(clock),
+            timing,
+        )
+    }
+
+    /// Creates a new `SacnReceiver` which receives using the given `SacnNetworkReceiver`, takes the time used for timeouts from the
+    /// given clock, uses the given timing profile and listens to the `E1.31_DISCOVERY_UNIVERSE`.
+    ///
+    /// # Errors
+    /// Will return an error if the created `SacnReceiver` fails to listen to the `E1.31_DISCOVERY_UNIVERSE`.
+    fn with_network_receiver(
+        receiver: SacnNetworkReceiver,
+        source_limit: Option<usize>,
+        clock: Box<dyn Clock>,
+        timing: TimingProfile,
+    ) -> Result<SacnReceiver> {
         let mut sri = SacnReceiver {
-            receiver: SacnNetworkReceiver::new(ip)?,
+            allow_unknown_vectors: receiver.parse_options.allow_unknown_vectors,
+            receiver,
             waiting_data: HashMap::new(),
-            universes: Vec::new(),
+            waiting_data_deadlines: DeadlineQueue::new(),
+            universes: UniverseSet::new(),
             discovered_sources: Vec::new(),
+            discovery_deadlines: DeadlineQueue::new(),
             merge_func: DEFAULT_MERGE_FUNC,
             partially_discovered_sources: Vec::new(),
             process_preview_data: PROCESS_PREVIEW_DATA_DEFAULT,
             source_limit,
-            sequences: SequenceNumbering::new(),
+            sequences: SequenceNumbering::new(&timing),
             announce_source_discovery: ANNOUNCE_SOURCE_DISCOVERY_DEFAULT,
             announce_stream_termination: ANNOUNCE_STREAM_TERMINATION_DEFAULT,
             announce_timeout: ANNOUNCE_TIMEOUT_DEFAULT,
+            clock,
+            timing,
+            packet_filters: Vec::new(),
+            start_code_routes: HashMap::new(),
+            vector_parsers: VectorParsers::new(),
+            unknown_packet_handler: None,
+            pending_errors: VecDeque::new(),
         };
 
-        sri.listen_universes(&[E131_DISCOVERY_UNIVERSE])?;
+        sri.listen_universes(&[Universe::DISCOVERY])?;
 
         Ok(sri)
     }
@@ -336,6 +518,29 @@ impl SacnReceiver {
         self.receiver.is_multicast_enabled()
     }
 
+    /// Sets whether this receiver accepts data sent to an IPv4 broadcast address by a source in broadcast mode,
+    /// see `SacnSource::set_broadcast_addr`.
+    ///
+    /// This enables `SO_BROADCAST` on the underlying socket, the receiver must also be bound to the unspecified address (0.0.0.0) to
+    /// receive datagrams sent to a directed broadcast address. A receiver which only receives broadcast can disable multicast using
+    /// `set_is_multicast_enabled` so that no multicast groups are joined when listening to universes.
+    ///
+    /// # Arguments
+    /// val: If true then broadcast datagrams are accepted, if false they are not.
+    ///
+    /// # Errors
+    /// Will return an `IpVersionError` if attempting to accept broadcast on an IPv6 receiver, IPv6 has no broadcast.
+    ///
+    /// Will return an Io error if `SO_BROADCAST` fails to be set on the socket.
+    pub fn set_accept_broadcast(&mut self, val: bool) -> Result<()> {
+        self.receiver.set_accept_broadcast(val)
+    }
+
+    /// Returns true if this receiver accepts data sent to a broadcast address and false if not, by default false.
+    pub fn accept_broadcast(&self) -> bool {
+        self.receiver.accept_broadcast()
+    }
+
     /// Wipes the record of discovered and sequence number tracked sources.
     /// This is one way to handle a sources exceeded condition.
     ///
@@ -344,6 +549,7 @@ impl SacnReceiver {
         self.sequences.clear();
         self.partially_discovered_sources.clear();
         self.discovered_sources.clear();
+        self.discovery_deadlines.clear();
     }
 
     /// Deletes all data currently waiting to be passed up - e.g. waiting for a synchronisation packet.
@@ -354,16 +560,19 @@ impl SacnReceiver {
     /// To clear only a specific universe of waiting data see (`clear_waiting_data`)[`clear_waiting_data`].
     pub fn clear_all_waiting_data(&mut self) {
         self.waiting_data.clear();
+        self.waiting_data_deadlines.clear();
     }
 
-    /// Clears data (if any) waiting to be passed up for the specific universe.
+    /// Clears data (if any) waiting to be passed up for the specific universe, whatever its start code.
     ///
     /// Returns true if data was removed and false if there wasn't any data to remove for this universe.
     ///
     /// # Arguments
     /// universe: The universe that the data that is waiting was sent to.
-    pub fn clear_waiting_data(&mut self, universe: u16) -> bool {
-        self.waiting_data.remove(&universe).is_some()
+    pub fn clear_waiting_data(&mut self, universe: Universe) -> bool {
+        let waiting = self.waiting_data.len();
+        self.waiting_data.retain(|(u, _), _| *u != universe.get());
+        self.waiting_data.len() != waiting
     }
 
     /// Sets the merge function to be used by this receiver.
@@ -394,26 +603,26 @@ impl SacnReceiver {
     /// If 1 or more universes in the list are already being listened to this method will have no effect for those universes only.
     ///
     /// # Errors
-    /// Returns an `SacnError::IllegalUniverse` error if the given universe is outwith the allowed range of universes,
-    /// see (`is_universe_in_range`)[`fn.is_universe_in_range.packet`].
-    ///
-    ///
-    pub fn listen_universes(&mut self, universes: &[u16]) -> Result<()> {
+    /// Io: Returned if the receiver fails to join the multicast group of a universe.
+    pub fn listen_universes(&mut self, universes: &[Universe]) -> Result<()> {
         for u in universes {
-            is_universe_in_range(*u)?;
+            self.listen_universe(u.get())?;
         }
 
-        for u in universes {
-            if let Err(i) = self.universes.binary_search(u) {
-                // Value not found, i is the position it should be inserted
-                self.universes.insert(i, *u);
+        Ok(())
+    }
 
-                if self.is_multicast_enabled() {
-                    self.receiver.listen_multicast_universe(*u)?;
-                }
-            } else {
-                // If value found then don't insert to avoid duplicates.
-            }
+    /// Allows receiving from the given universe which may have come from a received packet so isn't yet known to be valid.
+    ///
+    /// # Errors
+    /// Returns an `SacnError::IllegalUniverse` error if the given universe is outwith the allowed range of universes,
+    /// see (`is_universe_in_range`)[`fn.is_universe_in_range.packet`].
+    fn listen_universe(&mut self, universe: u16) -> Result<()> {
+        is_universe_in_range(universe)?;
+
+        // If already present then the universe isn't inserted again to avoid duplicates.
+        if self.universes.insert(universe) && self.is_multicast_enabled() {
+            self.receiver.listen_multicast_universe(universe)?;
         }
 
         Ok(())
@@ -422,23 +631,15 @@ impl SacnReceiver {
     /// Stops listening to the given universe.
     ///
     /// # Errors
-    /// Returns an `SacnError::IllegalUniverse` error if the given universe is outwith the allowed range of universes,
-    /// see (`is_universe_in_range`)[`fn.is_universe_in_range.packet`].
-    ///
     /// Returns `UniverseNotFound` if the given universe wasn't already being listened to.
-    pub fn mute_universe(&mut self, universe: u16) -> Result<()> {
-        is_universe_in_range(universe)?;
+    pub fn mute_universe(&mut self, universe: Universe) -> Result<()> {
+        let universe = universe.get();
 
-        match self.universes.binary_search(&universe) {
-            Err(_) => {
-                // Universe isn't found.
-                Err(SacnError::UniverseNotFound(universe))
-            }
-            Ok(i) => {
-                // If value found then don't insert to avoid duplicates.
-                self.universes.remove(i);
-                self.receiver.mute_multicast_universe(universe)
-            }
+        if self.universes.remove(universe) {
+            self.receiver.mute_multicast_universe(universe)
+        } else {
+            // Universe isn't found.
+            Err(SacnError::UniverseNotFound(universe))
         }
     }
 
@@ -452,6 +653,201 @@ impl SacnReceiver {
         self.process_preview_data = val;
     }
 
+    /// Adds a filter which received packets must pass to be processed, see `PacketFilter`.
+    ///
+    /// Argument:
+    /// filter: The filter to add in addition to any existing filters.
+    pub fn add_packet_filter(&mut self, filter: PacketFilter) {
+        self.packet_filters.push(filter);
+    }
+
+    /// Removes all packet filters so that all received packets are processed.
+    pub fn clear_packet_filters(&mut self) {
+        self.packet_filters.clear();
+    }
+
+    /// Passes all data with the given start code to the handler instead of returning it from `recv`, replacing any existing handler or
+    /// subscription for the start code.
+    ///
+    /// The handler is called from within `recv`, which keeps waiting for other data afterwards. Data is routed once it is ready to act
+    /// upon so synchronised data is passed to the handler when its synchronisation packet arrives.
+    ///
+    /// # Arguments
+    /// `start_code`: The start code of the data to pass to the handler, see `start_code::StartCodeRegistry` for decoding the data.
+    ///
+    /// handler: Called with each `DMXData` received with the start code.
+    pub fn set_start_code_handler(
+        &mut self,
+        start_code: StartCode,
+        handler: impl FnMut(DMXData) + Send + 'static,
+    ) {
+        self.start_code_routes
+            .insert(start_code.get(), StartCodeRoute::Handler(Box::new(handler)));
+    }
+
+    /// Sends all data with the given start code on the returned channel instead of returning it from `recv`, replacing any existing
+    /// handler or subscription for the start code.
+    ///
+    /// Data is only sent while `recv` is being called. Once the returned `Receiver` is dropped data with the start code is returned by
+    /// `recv` again.
+    ///
+    /// # Arguments
+    /// `start_code`: The start code of the data to send on the channel.
+    pub fn subscribe_start_code(&mut self, start_code: StartCode) -> mpsc::Receiver<DMXData> {
+        let (sender, receiver) = mpsc::channel();
+        self.start_code_routes
+            .insert(start_code.get(), StartCodeRoute::Channel(sender));
+        receiver
+    }
+
+    /// Removes the handler or subscription for the given start code so that its data is returned by `recv`.
+    ///
+    /// Returns true if there was a handler or subscription to remove.
+    pub fn remove_start_code_route(&mut self, start_code: StartCode) -> bool {
+        self.start_code_routes.remove(&start_code.get()).is_some()
+    }
+
+    /// Registers a parser for packets with the given root layer vector which isn't part of ANSI E1.31-2018, such as a vendor
+    /// extension, replacing and returning any previous parser. See `VectorParsers`.
+    ///
+    /// Packets parsed into one of the ANSI E1.31-2018 packets are then processed as normal, those left as
+    /// `E131RootLayerData::Unknown` are passed to the unknown packet handler, see `set_unknown_packet_handler`. Any error returned by
+    /// the parser is returned by `recv`.
+    ///
+    /// Enables `ParseOptions::allow_unknown_vectors` for the receiver so that these packets aren't rejected.
+    pub fn register_vector_parser(
+        &mut self,
+        vector: u32,
+        parser: VectorParser,
+    ) -> Option<VectorParser> {
+        self.allow_unknown_vectors = true;
+        self.receiver.parse_options.allow_unknown_vectors = true;
+        self.vector_parsers.register(vector, parser)
+    }
+
+    /// Registers a parser for extended packets with the given framing layer vector which isn't part of ANSI E1.31-2018, replacing and
+    /// returning any previous parser. See `VectorParsers::register_extended` and `register_vector_parser`.
+    ///
+    /// Enables `ParseOptions::allow_unknown_vectors` for the receiver so that these packets aren't rejected.
+    pub fn register_extended_vector_parser(
+        &mut self,
+        vector: u32,
+        parser: VectorParser,
+    ) -> Option<VectorParser> {
+        self.allow_unknown_vectors = true;
+        self.receiver.parse_options.allow_unknown_vectors = true;
+        self.vector_parsers.register_extended(vector, parser)
+    }
+
+    /// Passes packets with vectors which aren't part of ANSI E1.31-2018, and aren't parsed by a parser registered using
+    /// `register_vector_parser` or `register_extended_vector_parser`, to the given handler, replacing any existing handler.
+    ///
+    /// The handler is called from within `recv` with the CID of the source, the root layer vector and the data of the root layer PDU
+    /// following the CID, see `E131RootLayerData::Unknown`. An extended packet with an unknown framing layer vector is passed with
+    /// `VECTOR_ROOT_E131_EXTENDED` and its framing layer PDU, see `E131RootLayerData::UnknownExtended`. Unknown packets are discarded
+    /// if there is no handler.
+    ///
+    /// Enables `ParseOptions::allow_unknown_vectors` for the receiver so that these packets aren't rejected.
+    pub fn set_unknown_packet_handler(
+        &mut self,
+        handler: impl FnMut(Uuid, u32, &[u8]) + Send + 'static,
+    ) {
+        self.allow_unknown_vectors = true;
+        self.receiver.parse_options.allow_unknown_vectors = true;
+        self.unknown_packet_handler = Some(Box::new(handler));
+    }
+
+    /// Sets whether data packets in the layout of the ANSI E1.31 draft (revision 0.2) which came before ANSI E1.31-2009 are accepted,
+    /// as sent by some older gear and by a source in draft mode, see `SacnSource::set_draft_mode`. By default they are rejected.
+    ///
+    /// Draft packets are converted into data packets without a synchronization address or options and returned as `DMXData` like any
+    /// other, see `parse_draft_data_packet`. Accepting them registers that parser for `VECTOR_ROOT_E131_DRAFT_DATA` and enables
+    /// `ParseOptions::allow_unknown_vectors`. When they stop being accepted `ParseOptions::allow_unknown_vectors` is restored to what it
+    /// was otherwise set to, by the builder or by `register_vector_parser` or `set_unknown_packet_handler`.
+    ///
+    /// # Arguments
+    /// val: If true then draft data packets are accepted, if false their parser is removed.
+    pub fn set_accept_draft_packets(&mut self, val: bool) {
+        if val {
+            self.vector_parsers
+                .register(VECTOR_ROOT_E131_DRAFT_DATA, parse_draft_data_packet);
+        } else {
+            self.vector_parsers.unregister(VECTOR_ROOT_E131_DRAFT_DATA);
+        }
+        self.receiver.parse_options.allow_unknown_vectors = val || self.allow_unknown_vectors;
+    }
+
+    /// Returns true if this receiver accepts data packets in the layout of the ANSI E1.31 draft, see `set_accept_draft_packets`.
+    pub fn accept_draft_packets(&self) -> bool {
+        self.vector_parsers
+            .get(VECTOR_ROOT_E131_DRAFT_DATA)
+            .is_some()
+    }
+
+    /// Removes the unknown packet handler so that packets with unknown vectors are discarded.
+    ///
+    /// Returns true if there was a handler to remove.
+    pub fn remove_unknown_packet_handler(&mut self) -> bool {
+        self.unknown_packet_handler.take().is_some()
+    }
+
+    /// Delivers each of the given data with a start code route to its handler or channel, returning the data without one.
+    fn route_start_codes(&mut self, data: Vec<DMXData>) -> Vec<DMXData> {
+        if self.start_code_routes.is_empty() {
+            return data;
+        }
+
+        let mut unrouted = Vec::with_capacity(data.len());
+        for d in data {
+            let start_code = d.start_code().get();
+            match self.start_code_routes.get_mut(&start_code) {
+                Some(StartCodeRoute::Handler(handler)) => handler(d),
+                Some(StartCodeRoute::Channel(sender)) => {
+                    if let Err(mpsc::SendError(d)) = sender.send(d) {
+                        // The subscription has been dropped so the start code is returned by recv again.
+                        self.start_code_routes.remove(&start_code);
+                        unrouted.push(d);
+                    }
+                }
+                None => unrouted.push(d),
+            }
+        }
+        unrouted
+    }
+
+    /// Shuts down this receiver, leaving the multicast groups of all the universes it is listening to.
+    ///
+    /// This does the same as dropping the receiver however reports which universes were left and which failed rather than ignoring
+    /// errors.
+    ///
+    /// # Arguments
+    /// timeout: The time after which no further universes are left, any remaining universes are reported as failed with a
+    ///     `ShutdownTimeout` error. It is measured on the clock of the receiver, see `SacnReceiverBuilder::clock`. None means all
+    ///     universes are left.
+    ///
+    /// # Errors
+    /// Errors leaving a particular universe are returned within the `ShutdownReport` rather than as an error.
+    pub fn shutdown(mut self, timeout: Option<Duration>) -> Result<ShutdownReport> {
+        let deadline = timeout.map(|t| self.clock.now() + t);
+
+        let mut report = ShutdownReport::default();
+        // Universes are removed as they are muted so drop doesn't try to leave them again.
+        for u in self.listened_universes() {
+            if deadline.is_some_and(|d| self.clock.now() >= d) {
+                // Forgotten without leaving the group so that drop doesn't wait past the timeout leaving it either.
+                self.universes.remove(u.get());
+                report.failed.push((u, SacnError::ShutdownTimeout(u.get())));
+                continue;
+            }
+
+            match self.mute_universe(u) {
+                Ok(()) => report.completed.push(u),
+                Err(e) => report.failed.push((u, e)),
+            }
+        }
+        Ok(report)
+    }
+
     /// Checks if this receiver is currently listening to the given universe.
     ///
     /// A receiver is 'listening' to a universe if it allows that universe to be received without filtering it out.
@@ -462,8 +858,18 @@ impl SacnReceiver {
     ///
     /// Returns:
     /// True if the universe is being listened to by this receiver, false if not.
-    pub fn is_listening(&self, universe: &u16) -> bool {
-        self.universes.contains(universe)
+    pub fn is_listening(&self, universe: Universe) -> bool {
+        self.universes.contains(universe.get())
+    }
+
+    /// Returns the universes this receiver is listening to, including the `E131_DISCOVERY_UNIVERSE`.
+    fn listened_universes(&self) -> Vec<Universe> {
+        // Only universes within range are listened to so none are filtered out.
+        self.universes
+            .to_vec()
+            .into_iter()
+            .filter_map(Universe::new)
+            .collect()
     }
 
     /// Attempt to receive data from any of the registered universes.
@@ -485,17 +891,28 @@ impl SacnReceiver {
     /// The method may also return an error if there is an issue setting a timeout on the receiver. See
     /// `SacnNetworkReceiver::set_timeout` for details.
     ///
+    /// Will return a `MalformedPacket` error holding the address of the sender if a packet is received which can't be parsed, the
+    /// receiver can continue to be used as `is_transient` is true for this error.
+    ///
     /// The method may also return an error if there is an issue handling the data as either a Data, Synchronisation or Discovery packet.
     /// See the `SacnReceiver::handle_data_packet`, `SacnReceiver::handle_sync_packet` and `SacnReceiver::handle_universe_discovery_packet` methods
     /// for details.
     ///
+    /// A packet may hold several PDUs, see `AcnRootLayerBlock`. Every PDU is handled and any data ready is returned, errors from the
+    /// PDUs (including `SourceDiscovered`) are returned in order by this and following calls before any more data is received.
+    ///
     /// If the `announce_timeout` flag is set then the recv will return a `UniverseTimeout` error if a source fails to send on a universe within the timeout
-    /// specified by `E131_NETWORK_DATA_LOSS_TIMEOUT` (ANSI E1.31-2018 Appendix A).  This may not be detected immediately unless data is received for the timed-out
-    /// universe from the source. If it isn't detected immediately it will be detected within an interval of `E131_NETWORK_DATA_LOSS_TIMEOUT` (assuming code
-    /// executes in zero time).
+    /// specified by the data loss timeout of the timing profile, by default `E131_NETWORK_DATA_LOSS_TIMEOUT` (ANSI E1.31-2018 Appendix A). This may not be
+    /// detected immediately unless data is received for the timed-out universe from the source. If it isn't detected immediately it will be detected within
+    /// an interval of the data loss timeout (assuming code executes in zero time).
     pub fn recv(&mut self, timeout: Option<Duration>) -> Result<Vec<DMXData>> {
+        // Errors from the PDUs of an earlier packet which was returned as data.
+        if let Some(e) = self.pending_errors.pop_front() {
+            return Err(e);
+        }
+
         if self.universes.len() == 1
-            && self.universes[0] == E131_DISCOVERY_UNIVERSE
+            && self.universes.contains(E131_DISCOVERY_UNIVERSE)
             && timeout.is_none()
             && !self.announce_source_discovery
         {
@@ -508,7 +925,8 @@ impl SacnReceiver {
         // if timeout is 0, then it's time to return
         if timeout == Some(Duration::from_secs(0)) {
             // always check timeouts
-            self.sequences.check_timeouts(self.announce_timeout)?;
+            self.sequences
+                .check_timeouts(self.clock.now(), self.announce_timeout)?;
             self.check_waiting_data_timeouts();
             return Err(io::Error::new(
                 // Use the right expected error for the operating system.
@@ -529,12 +947,13 @@ impl SacnReceiver {
         let mut buf: [u8; RCV_BUF_DEFAULT_SIZE] = [0; RCV_BUF_DEFAULT_SIZE];
 
         loop {
-            self.sequences.check_timeouts(self.announce_timeout)?;
+            self.sequences
+                .check_timeouts(self.clock.now(), self.announce_timeout)?;
             self.check_waiting_data_timeouts();
 
-            // In the case of `timeout` being longer than `E131_NETWORK_DATA_LOSS_TIMEOUT`:
-            // Forces the actual timeout used for receiving from the underlying network to never exceed E131_NETWORK_DATA_LOSS_TIMEOUT.
-            // This means that the timeouts for the sequence numbers will be checked at least every E131_NETWORK_DATA_LOSS_TIMEOUT even if
+            // In the case of `timeout` being longer than the data loss timeout (by default `E131_NETWORK_DATA_LOSS_TIMEOUT`):
+            // Forces the actual timeout used for receiving from the underlying network to never exceed the data loss timeout.
+            // This means that the timeouts for the sequence numbers will be checked at least every data loss timeout even if
             // recv is called with a longer timeout.
             let remaining = match deadline {
                 None => None, // set to data loss timeout below so timeouts are checked again.
@@ -557,9 +976,9 @@ impl SacnReceiver {
             };
 
             let actual_timeout = if let Some(rem) = remaining {
-                rem.min(E131_NETWORK_DATA_LOSS_TIMEOUT)
+                rem.min(self.timing.data_loss_timeout)
             } else {
-                E131_NETWORK_DATA_LOSS_TIMEOUT
+                self.timing.data_loss_timeout
             };
 
             self.receiver.set_timeout(Some(actual_timeout))?; // "Failed to set a timeout value for the receiver"
@@ -568,27 +987,30 @@ impl SacnReceiver {
             buf.fill(0);
 
             match self.receiver.recv(&mut buf) {
-                Ok(pkt) => {
-                    let pdu = pkt.pdu;
-                    let data = pdu.data;
-                    let res = match data {
-                        DataPacket(d) => self.handle_data_packet(pdu.cid, d)?,
-                        SynchronizationPacket(s) => self.handle_sync_packet(pdu.cid, s)?,
-                        UniverseDiscoveryPacket(u) => {
-                            let discovered_src: Option<String> =
-                                self.handle_universe_discovery_packet(pdu.cid, u);
-                            if let Some(src) = discovered_src
-                                && self.announce_source_discovery
-                            {
-                                return Err(SacnError::SourceDiscovered(src));
-                            }
-                            None
+                Ok(pdus) => {
+                    // A packet may hold several PDUs, every PDU is handled and the data from all of them which is ready is
+                    // returned together. Errors and discovered sources are queued in the order of the PDUs and returned once no
+                    // data is ready.
+                    let mut res: Option<Vec<DMXData>> = None;
+                    for pdu in pdus {
+                        match self.handle_pdu(pdu) {
+                            Ok(Some(r)) => res.get_or_insert_with(Vec::new).extend(r),
+                            Ok(None) => {}
+                            Err(e) => self.pending_errors.push_back(e),
                         }
-                    };
+                    }
 
-                    // return the data, otherwise continue if no data is ready
+                    // return the data, otherwise continue if no data is ready or it was all delivered by start code routes
                     if let Some(r) = res {
-                        return Ok(r);
+                        let received = r.len();
+                        let r = self.route_start_codes(r);
+                        if received == 0 || !r.is_empty() {
+                            return Ok(r);
+                        }
+                    }
+
+                    if let Some(e) = self.pending_errors.pop_front() {
+                        return Err(e);
                     }
 
                     // end of loop
@@ -599,12 +1021,7 @@ impl SacnReceiver {
                 {
                     match err {
                         // Windows and Unix use different error types (WouldBlock/TimedOut) for the same error.
-                        SacnError::Io(ref s)
-                            if matches!(
-                                s.kind(),
-                                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
-                            ) =>
-                        {
+                        ref e if e.is_timeout() => {
                             // socket read timedout.
                             // start new loop to compute new remaining which will return if deadline has passed
                         }
@@ -618,6 +1035,48 @@ impl SacnReceiver {
         }
     }
 
+    /// Handles a single PDU of a received packet.
+    ///
+    /// Returns the data which is ready to be passed up as a result of the PDU, if any.
+    ///
+    /// # Errors
+    /// Will return a `SourceDiscovered` error if the PDU completes the discovery of a source and the `announce_source_discovery` flag
+    /// is set.
+    ///
+    /// Will return an error if the PDU can't be handled, see `recv`.
+    fn handle_pdu(&mut self, pdu: E131RootLayer<'_>) -> Result<Option<Vec<DMXData>>> {
+        let data = self.vector_parsers.parse(pdu.data)?;
+        if !self.packet_filters.iter().all(|f| f(&pdu.cid, &data)) {
+            return Ok(None); // Filtered out, the PDU is treated as never received.
+        }
+        match data {
+            DataPacket(d) => self.handle_data_packet(pdu.cid, d),
+            SynchronizationPacket(s) => self.handle_sync_packet(pdu.cid, s),
+            UniverseDiscoveryPacket(u) => {
+                let discovered_src: Option<String> =
+                    self.handle_universe_discovery_packet(pdu.cid, u);
+                match discovered_src {
+                    Some(src) if self.announce_source_discovery => {
+                        Err(SacnError::SourceDiscovered(pdu.cid, src))
+                    }
+                    _ => Ok(None),
+                }
+            }
+            Unknown { vector, raw } => {
+                if let Some(handler) = &mut self.unknown_packet_handler {
+                    handler(pdu.cid, vector, &raw);
+                }
+                Ok(None)
+            }
+            UnknownExtended { raw, .. } => {
+                if let Some(handler) = &mut self.unknown_packet_handler {
+                    handler(pdu.cid, VECTOR_ROOT_E131_EXTENDED, &raw);
+                }
+                Ok(None)
+            }
+        }
+    }
+
     /// Returns the current value of the `announce_source_discovery` flag.
     /// See (`set_announce_source_discovery`)[`receive::set_announce_source_discovery`] for an explanation of the flag.
     pub fn get_announce_source_discovery(&self) -> bool {
@@ -720,7 +1179,7 @@ impl SacnReceiver {
             return Ok(None);
         }
 
-        if !self.is_listening(&data_pkt.universe) {
+        if !self.universes.contains(data_pkt.universe) {
             return Ok(None); // If not listening for this universe then ignore the packet.
         }
 
@@ -728,7 +1187,9 @@ impl SacnReceiver {
         // This is as per ANSI E1.31-2018 Section 6.2.6, Stream_Terminated: Bit 6, 'Any property values
         // in an E1.31 Data Packet containing this bit shall be ignored'
 
+        let now = self.clock.now();
         self.sequences.check_data_seq_number(
+            now,
             self.source_limit,
             cid,
             data_pkt.sequence_number,
@@ -737,7 +1198,10 @@ impl SacnReceiver {
         )?;
 
         if data_pkt.synchronization_address == E131_NO_SYNC_ADDR {
-            self.clear_waiting_data(data_pkt.universe);
+            // As per ANSI E1.31-2018 Section 6.2.4.1 data without a synchronisation address replaces any data waiting for the universe,
+            // whatever its start code.
+            self.waiting_data
+                .retain(|(universe, _), _| *universe != data_pkt.universe);
 
             let vals: Vec<u8> = data_pkt.data.property_values.into_owned();
             let dmx_data: DMXData = DMXData {
@@ -747,14 +1211,14 @@ impl SacnReceiver {
                 priority: data_pkt.priority,
                 src_cid: Some(cid),
                 preview: data_pkt.preview_data,
-                recv_timestamp: Instant::now(),
+                recv_timestamp: now,
             };
 
             Ok(Some(vec![dmx_data]))
         } else {
             // As per ANSI E1.31-2018 Appendix B.2 the receiver should listen at the synchronisation address when a data packet is received with a non-zero
             // synchronisation address.
-            self.listen_universes(&[data_pkt.synchronization_address])?;
+            self.listen_universe(data_pkt.synchronization_address)?;
 
             let vals: Vec<u8> = data_pkt.data.property_values.into_owned();
             let dmx_data: DMXData = DMXData {
@@ -764,7 +1228,7 @@ impl SacnReceiver {
                 priority: data_pkt.priority,
                 src_cid: Some(cid),
                 preview: data_pkt.preview_data,
-                recv_timestamp: Instant::now(),
+                recv_timestamp: now,
             };
 
             self.store_waiting_data(dmx_data)?;
@@ -801,7 +1265,7 @@ impl SacnReceiver {
 
     /// Takes the given data and tries to add it to the waiting data.
     ///
-    /// Note that a receiver will only store a single packet of data per `data_universe` at once.
+    /// Note that a receiver will only store a single packet of data per `data_universe` and start code at once.
     ///
     /// If there is waiting data for the same universe as the data then it will be merged as per the
     /// `merge_func` which by default keeps the highest priority data, if the data has the same priority
@@ -810,15 +1274,14 @@ impl SacnReceiver {
     /// # Errors
     /// Will return an `DmxMergeError` if there is an issue merging or replacing new and existing waiting data.
     fn store_waiting_data(&mut self, data: DMXData) -> Result<()> {
-        match self.waiting_data.remove(&data.universe) {
-            Some(existing) => {
-                self.waiting_data
-                    .insert(data.universe, ((self.merge_func)(&existing, &data))?);
-            }
-            None => {
-                self.waiting_data.insert(data.universe, data);
-            }
-        }
+        let key = (data.universe, data.start_code().get());
+        let data = match self.waiting_data.remove(&key) {
+            Some(existing) => (self.merge_func)(&existing, &data)?,
+            None => data,
+        };
+        self.waiting_data_deadlines
+            .schedule(data.recv_timestamp + self.timing.data_loss_timeout, key);
+        self.waiting_data.insert(key, data);
         Ok(())
     }
 
@@ -847,11 +1310,12 @@ impl SacnReceiver {
         cid: Uuid,
         sync_pkt: SynchronizationPacketFramingLayer,
     ) -> Result<Option<Vec<DMXData>>> {
-        if !self.is_listening(&sync_pkt.synchronization_address) {
+        if !self.universes.contains(sync_pkt.synchronization_address) {
             return Ok(None); // If not listening for this universe then ignore the packet.
         }
 
         self.sequences.check_sync_seq_number(
+            self.clock.now(),
             self.source_limit,
             cid,
             sync_pkt.sequence_number,
@@ -873,20 +1337,21 @@ impl SacnReceiver {
     /// Arguments:
     /// `sync_uni`: The synchronisation universe of the data that should be retrieved.
     fn rtrv_waiting_data(&mut self, sync_uni: u16) -> Vec<DMXData> {
-        // Get the universes (used as keys) to remove and then move the corresponding data out of the waiting data and into the result.
-        // This prevents having to copy DMXData.
+        // Get the universes and start codes (used as keys) to remove and then move the corresponding data out of the waiting data and
+        // into the result. This prevents having to copy DMXData.
         // Cannot do both actions at once as cannot modify a data structure while iterating over it.
-        let mut keys: Vec<u16> = Vec::new();
-        for (uni, data) in self.waiting_data.iter() {
+        let mut keys: Vec<WaitingDataKey> = Vec::new();
+        for (key, data) in self.waiting_data.iter() {
             if data.sync_uni == sync_uni {
-                keys.push(*uni);
+                keys.push(*key);
             }
         }
 
+        let now = self.clock.now();
         let mut res: Vec<DMXData> = Vec::new();
         for k in keys {
             let data = self.waiting_data.remove(&k).unwrap();
-            if data.recv_timestamp.elapsed() < E131_NETWORK_DATA_LOSS_TIMEOUT {
+            if now.duration_since(data.recv_timestamp) < self.timing.data_loss_timeout {
                 res.push(data);
             }
         }
@@ -942,7 +1407,7 @@ impl SacnReceiver {
             self.partially_discovered_sources[index]
                 .pages
                 .push(uni_page);
-            self.partially_discovered_sources[index].last_updated = Instant::now();
+            self.partially_discovered_sources[index].last_updated = self.clock.now();
             if self.partially_discovered_sources[index].has_all_pages() {
                 let discovered_src: DiscoveredSacnSource =
                     self.partially_discovered_sources.remove(index);
@@ -956,15 +1421,17 @@ impl SacnReceiver {
                 cid,
                 last_page,
                 pages: vec![uni_page],
-                last_updated: Instant::now(),
+                last_updated: self.clock.now(),
             };
 
             if page == 0 && page == last_page {
                 // Indicates that this is a single page universe discovery packet.
+                self.schedule_discovery_expiry(cid, discovered_src.last_updated);
                 self.update_discovered_srcs(discovered_src);
                 return Some(discovery_pkt.source_name.to_string());
             } else {
                 // Indicates that this is a page in a set of pages as part of a sources universe discovery.
+                self.schedule_discovery_expiry(cid, discovered_src.last_updated);
                 self.partially_discovered_sources.push(discovered_src);
             }
         }
@@ -972,19 +1439,65 @@ impl SacnReceiver {
         None // No source fully discovered.
     }
 
-    /// Goes through all the waiting data and removes any which has timed out as a sync-packet for it hasn't been received within the `E131_NETWORK_DATA_LOSS_TIMEOUT`
-    /// period as specified by ANSI E1.31-2018 Section 11.1.2.
+    /// Goes through all the waiting data and removes any which has timed out as a sync-packet for it hasn't been received within the data loss timeout
+    /// (by default `E131_NETWORK_DATA_LOSS_TIMEOUT`) as specified by ANSI E1.31-2018 Section 11.1.2.
+    ///
+    /// Only universes with a deadline that has passed are checked, data which has since been replaced by newer data is left in place.
     fn check_waiting_data_timeouts(&mut self) {
-        self.waiting_data
-            .retain(|_uni, data| data.recv_timestamp.elapsed() < E131_NETWORK_DATA_LOSS_TIMEOUT);
+        let now = self.clock.now();
+        while let Some((_, key)) = self.waiting_data_deadlines.pop_expired(now) {
+            if self.waiting_data.get(&key).is_some_and(|data| {
+                now.duration_since(data.recv_timestamp) >= self.timing.data_loss_timeout
+            }) {
+                self.waiting_data.remove(&key);
+            }
+        }
     }
 
-    /// Goes through all discovered sources and removes any that have timed out
+    /// Removes any discovered or partially discovered sources that have timed out.
+    ///
+    /// Only sources with a deadline that has passed are checked, sources which have sent a discovery packet since are left in place.
     fn remove_expired_sources(&mut self) {
-        self.partially_discovered_sources
-            .retain(|s| s.last_updated.elapsed() < UNIVERSE_DISCOVERY_SOURCE_TIMEOUT);
-        self.discovered_sources
-            .retain(|s| s.last_updated.elapsed() < UNIVERSE_DISCOVERY_SOURCE_TIMEOUT);
+        let now = self.clock.now();
+        let timeout = self.timing.discovery_source_timeout;
+        while let Some((_, cid)) = self.discovery_deadlines.pop_expired(now) {
+            let expired = |s: &DiscoveredSacnSource| {
+                s.cid == cid && now.duration_since(s.last_updated) >= timeout
+            };
+            self.partially_discovered_sources.retain(|s| !expired(s));
+            self.discovered_sources.retain(|s| !expired(s));
+
+            // If the source has been updated since the deadline was scheduled then schedule it again for its new expiry.
+            let remaining = self
+                .partially_discovered_sources
+                .iter()
+                .chain(self.discovered_sources.iter())
+                .filter(|s| s.cid == cid)
+                .map(|s| s.last_updated)
+                .min();
+            if let Some(last_updated) = remaining {
+                self.discovery_deadlines
+                    .schedule(last_updated + timeout, cid);
+            }
+        }
+    }
+
+    /// Schedules the expiry of the source with the given CID if it isn't already known to this receiver.
+    ///
+    /// Each known source has exactly one scheduled deadline, which is pushed back when reached if the source has been updated since,
+    /// so this must be called before the source is added to the discovered or partially discovered sources.
+    ///
+    /// Arguments:
+    /// cid: The CID of the source.
+    ///
+    /// `last_updated`: The time at which the source was last updated.
+    fn schedule_discovery_expiry(&mut self, cid: Uuid, last_updated: Instant) {
+        let known = find_discovered_src(&self.discovered_sources, &cid).is_some()
+            || find_discovered_src(&self.partially_discovered_sources, &cid).is_some();
+        if !known {
+            self.discovery_deadlines
+                .schedule(last_updated + self.timing.discovery_source_timeout, cid);
+        }
     }
 }
 
@@ -992,8 +1505,7 @@ impl SacnReceiver {
 /// and if it goes out of reference it will clean itself up.
 impl Drop for SacnReceiver {
     fn drop(&mut self) {
-        let universes = self.universes.clone();
-        for u in universes {
+        for u in self.listened_universes() {
             // Cannot return an error or pass it onto the user because drop might be called during a panic.
             // Therefore if there is an error cleaning up the only options are ignore, notify or panic.
             // Notify using stdout might pollute the application using the library so would require a flag to enable/disable but the function of this
@@ -1009,163 +1521,356 @@ impl Drop for SacnReceiver {
     }
 }
 
-/// Searches for the discovered source with the given name in the given vector of discovered sources and
-/// returns the index of the src in the Vec or None if not found.
+/// Where a `SacnReceiverBuilder` receives from, either a UDP socket bound to an address or a user provided transport.
+#[derive(Debug)]
+enum ReceiverBinding {
+    /// Bind a UDP socket to the interface with the given address.
+    Addr(SocketAddr),
+
+    /// Receive over the given transport.
+    Transport(Box<dyn Transport>),
+}
+
+/// Builds a `SacnReceiver` with the given configuration, checking that the configuration is valid once when `build` is called.
 ///
-/// Arguments:
+/// Options which aren't set use the same defaults as `SacnReceiver::with_ip`. By default the receiver binds to the unspecified IPv4 address
+/// on the `ACN_SDT_MULTICAST_PORT`.
 ///
-/// srcs: The Vec of `DiscoveredSacnSources` to search.
+/// # Examples
 ///
-/// cid: The CID (uuid) of the source to find.
-fn find_discovered_src(srcs: &[DiscoveredSacnSource], cid: &Uuid) -> Option<usize> {
-    (0..srcs.len()).find(|&i| srcs[i].cid == *cid)
-}
+/// ```
+/// use sacn::receive::SacnReceiverBuilder;
+/// use sacn::packet::ACN_SDT_MULTICAST_PORT;
+/// use sacn::timing::TimingProfile;
+/// use sacn::types::Universe;
+///
+/// use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+///
+/// const UNIVERSE1: Universe = Universe::new(1).unwrap();
+/// const UNIVERSE2: Universe = Universe::new(2).unwrap();
+///
+/// let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+///
+/// let dmx_rcv = SacnReceiverBuilder::new()
+///     .bind(addr)
+///     .source_limit(Some(4))
+///     .announce_timeout(true)
+///     .timing(TimingProfile::lenient())
+///     .universes(&[UNIVERSE1, UNIVERSE2])
+///     .build()
+///     .unwrap();
+///
+/// assert!(dmx_rcv.is_listening(UNIVERSE2));
+/// ```
+#[derive(Debug)]
+pub struct SacnReceiverBuilder {
+    /// Where the receiver receives from.
+    binding: ReceiverBinding,
 
-/// In general the lower level transport layer is handled by `SacnNetworkReceiver` (which itself wraps a Socket).
-/// Windows and linux handle multicast sockets differently.
-/// This is built for / tested with Windows 10 1909.
-#[cfg(target_os = "windows")]
-impl SacnNetworkReceiver {
-    /// Creates a new DMX receiver on the interface specified by the given address.
-    ///
-    /// If the given address is an IPv4 address then communication will only work between IPv4 devices, if the given address is IPv6 then communication
-    /// will only work between IPv6 devices by default but IPv4 receiving can be enabled using `set_ipv6_only(false)`.
-    ///
-    /// # Errors
-    /// Will return an error if the `SacnReceiver` fails to bind to a socket with the given ip.
-    /// For more details see `socket2::Socket::new()`.
-    fn new(ip: SocketAddr) -> Result<SacnNetworkReceiver> {
-        Ok(SacnNetworkReceiver {
-            socket: create_win_socket(ip)?,
-            addr: ip,
-            is_multicast_enabled: !(ip.is_ipv6()), // IPv6 Windows IP Multicast is currently unsupported.
-        })
+    /// The limit to the number of sources, None means no limit.
+    source_limit: Option<usize>,
+
+    /// The value of the `process_preview_data` flag.
+    process_preview_data: bool,
+
+    /// The value of the `announce_source_discovery` flag.
+    announce_source_discovery: bool,
+
+    /// The value of the `announce_stream_termination` flag.
+    announce_stream_termination: bool,
+
+    /// The value of the `announce_timeout` flag.
+    announce_timeout: bool,
+
+    /// The merge function used for waiting data.
+    merge_func: fn(&DMXData, &DMXData) -> Result<DMXData>,
+
+    /// Whether an IPv6 receiver only receives IPv6, None leaves the platform default.
+    ipv6_only: Option<bool>,
+
+    /// Whether multicast is enabled, None uses the default for the platform and IP version.
+    multicast_enabled: Option<bool>,
+
+    /// Whether datagrams sent to a broadcast address are accepted.
+    accept_broadcast: bool,
+
+    /// Whether data packets in the layout of the ANSI E1.31 draft are accepted.
+    accept_draft_packets: bool,
+
+    /// The size of the socket receive buffer in bytes, None leaves the platform default.
+    recv_buffer_size: Option<usize>,
+
+    /// The filters that received packets must pass to be processed.
+    packet_filters: Vec<PacketFilter>,
+
+    /// The timeouts used by the receiver.
+    timing: TimingProfile,
+
+    /// The deviations from ANSI E1.31-2018 accepted in received packets.
+    parse_options: ParseOptions,
+
+    /// The clock used for timeouts.
+    clock: Box<dyn Clock>,
+
+    /// The universes to listen to once the receiver is created.
+    universes: Vec<Universe>,
+}
+
+impl Default for SacnReceiverBuilder {
+    fn default() -> Self {
+        SacnReceiverBuilder::new()
     }
+}
 
-    /// Connects this `SacnNetworkReceiver` to the multicast address which corresponds to the given universe to allow receiving packets for that universe.
-    ///
-    /// # Errors
-    /// Will return an Error if the given universe cannot be converted to an Ipv4 or Ipv6 `multicast_addr` depending on if the Receiver is bound to an
-    /// IPv4 or IPv6 address. See `packet::universe_to_ipv4_multicast_addr` and `packet::universe_to_ipv6_multicast_addr`.
-    ///
-    /// Will return an Io error if cannot join the universes corresponding multicast group address.
-    fn listen_multicast_universe(&self, universe: u16) -> Result<()> {
-        let multicast_addr = if self.addr.is_ipv4() {
-            universe_to_ipv4_multicast_addr(universe)? // "Failed to convert universe to IPv4 multicast addr"
-        } else {
-            universe_to_ipv6_multicast_addr(universe)? // "Failed to convert universe to IPv6 multicast addr"
-        };
+impl SacnReceiverBuilder {
+    /// Creates a new `SacnReceiverBuilder` with the default configuration.
+    pub fn new() -> SacnReceiverBuilder {
+        SacnReceiverBuilder {
+            binding: ReceiverBinding::Addr(SocketAddr::new(
+                IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                ACN_SDT_MULTICAST_PORT,
+            )),
+            source_limit: None,
+            process_preview_data: PROCESS_PREVIEW_DATA_DEFAULT,
+            announce_source_discovery: ANNOUNCE_SOURCE_DISCOVERY_DEFAULT,
+            announce_stream_termination: ANNOUNCE_STREAM_TERMINATION_DEFAULT,
+            announce_timeout: ANNOUNCE_TIMEOUT_DEFAULT,
+            merge_func: DEFAULT_MERGE_FUNC,
+            ipv6_only: None,
+            multicast_enabled: None,
+            accept_broadcast: false,
+            accept_draft_packets: false,
+            recv_buffer_size: None,
+            packet_filters: Vec::new(),
+            timing: TimingProfile::default(),
+            parse_options: ParseOptions::default(),
+            clock: Box::new(SystemClock),
+            universes: Vec::new(),
+        }
+    }
 
-        join_win_multicast(&self.socket, multicast_addr, self.addr.ip())
+    /// Receive using a UDP socket on the interface with the given address, replacing any previously given address or transport.
+    /// See `SacnReceiver::with_ip`.
+    pub fn bind(mut self, addr: SocketAddr) -> Self {
+        self.binding = ReceiverBinding::Addr(addr);
+        self
     }
 
-    /// Removes this `SacnNetworkReceiver` from the multicast group which corresponds to the given universe.
-    ///
-    /// # Errors
-    /// Will return an Error if the given universe cannot be converted to an Ipv4 or Ipv6 `multicast_addr` depending on if the Receiver is bound to an
-    /// IPv4 or IPv6 address. See `packet::universe_to_ipv4_multicast_addr` and `packet::universe_to_ipv6_multicast_addr`.
-    fn mute_multicast_universe(&mut self, universe: u16) -> Result<()> {
-        let multicast_addr = if self.addr.is_ipv4() {
-            universe_to_ipv4_multicast_addr(universe)? // "Failed to convert universe to IPv4 multicast addr"
-        } else {
-            universe_to_ipv6_multicast_addr(universe)? // "Failed to convert universe to IPv6 multicast addr"
-        };
+    /// Receive over the given transport, replacing any previously given address or transport. See `SacnReceiver::with_transport`.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.binding = ReceiverBinding::Transport(Box::new(transport));
+        self
+    }
 
-        leave_win_multicast(&self.socket, multicast_addr)
+    /// Sets the limit to the number of sources, None (the default) means no limit.
+    pub fn source_limit(mut self, source_limit: Option<usize>) -> Self {
+        self.source_limit = source_limit;
+        self
     }
 
-    /// Sets the value of the `is_multicast_enabled` flag to the given value.
-    ///
-    /// If set to false then the receiver won't attempt to join any more multicast groups.
-    ///
-    /// This method does not attempt to leave multicast groups already joined through previous `listen_universe` calls.
-    ///
-    /// # Arguments
-    /// val: The new value for the `is_multicast_enabled` flag.
+    /// Sets whether packets marked as preview data are processed, see `SacnReceiver::set_process_preview_data`.
+    pub fn process_preview_data(mut self, val: bool) -> Self {
+        self.process_preview_data = val;
+        self
+    }
+
+    /// Sets whether `recv` returns a `SourceDiscovered` error when a source is discovered, see `SacnReceiver::set_announce_source_discovery`.
+    pub fn announce_source_discovery(mut self, val: bool) -> Self {
+        self.announce_source_discovery = val;
+        self
+    }
+
+    /// Sets whether `recv` returns a `UniverseTerminated` error when a stream is terminated, see `SacnReceiver::set_announce_stream_termination`.
+    pub fn announce_stream_termination(mut self, val: bool) -> Self {
+        self.announce_stream_termination = val;
+        self
+    }
+
+    /// Sets whether `recv` returns a `UniverseTimeout` error when a source universe times out, see `SacnReceiver::set_announce_timeout`.
+    pub fn announce_timeout(mut self, val: bool) -> Self {
+        self.announce_timeout = val;
+        self
+    }
+
+    /// Sets the merge function used when data for a universe is received while data is already waiting, see `SacnReceiver::set_merge_fn`.
+    pub fn merge_fn(mut self, func: fn(&DMXData, &DMXData) -> Result<DMXData>) -> Self {
+        self.merge_func = func;
+        self
+    }
+
+    /// Sets whether a receiver bound to an IPv6 address only receives IPv6. This is set before the socket is bound so it can only be
+    /// used with `bind`.
+    pub fn ipv6_only(mut self, val: bool) -> Self {
+        self.ipv6_only = Some(val);
+        self
+    }
+
+    /// Sets whether the receiver joins multicast groups, see `SacnReceiver::set_is_multicast_enabled`.
     ///
-    /// # Errors
-    /// Will return an `OsOperationUnsupported` error if attempting to set the flag to true in an environment that multicast
-    /// isn't supported i.e. Ipv6 on Windows.
-    fn set_is_multicast_enabled(&mut self, val: bool) -> Result<()> {
-        if val && self.is_ipv6() {
-            return Err(SacnError::OsOperationUnsupported(
-                "IPv6 multicast is currently unsupported on Windows".to_string(),
-            ));
-        }
-        self.is_multicast_enabled = val;
-        Ok(())
+    /// By default multicast is enabled unless it is unsupported, i.e. IPv6 on Windows.
+    pub fn multicast_enabled(mut self, val: bool) -> Self {
+        self.multicast_enabled = Some(val);
+        self
     }
 
-    /// Returns true if multicast is enabled on this receiver and false if not.
-    /// This flag is set when the receiver is created as not all environments currently support IP multicast.
-    /// E.g. IPv6 Windows IP Multicast is currently unsupported.
-    fn is_multicast_enabled(&self) -> bool {
-        self.is_multicast_enabled
+    /// Sets whether data sent to a broadcast address is accepted, see `SacnReceiver::set_accept_broadcast`.
+    pub fn accept_broadcast(mut self, val: bool) -> Self {
+        self.accept_broadcast = val;
+        self
     }
 
-    /// If set to true then only receive over IPv6. If false then receiving will be over both IPv4 and IPv6.
-    /// This will return an error if the `SacnReceiver` wasn't created using an IPv6 address to bind to.
-    fn set_only_v6(&mut self, val: bool) -> Result<()> {
-        if self.addr.is_ipv4() {
-            Err(SacnError::IpVersionError())
-        } else {
-            Ok(self.socket.set_only_v6(val)?)
-        }
+    /// Sets whether data packets in the layout of the ANSI E1.31 draft are accepted, see `SacnReceiver::set_accept_draft_packets`.
+    pub fn accept_draft_packets(mut self, val: bool) -> Self {
+        self.accept_draft_packets = val;
+        self
+    }
+
+    /// Sets the size of the socket receive buffer in bytes, a larger buffer reduces loss when many universes arrive in bursts.
+    /// This requires a transport backed by a socket.
+    pub fn recv_buffer_size(mut self, size: usize) -> Self {
+        self.recv_buffer_size = Some(size);
+        self
+    }
+
+    /// Adds a filter which received packets must pass to be processed, see `PacketFilter`.
+    pub fn packet_filter(mut self, filter: PacketFilter) -> Self {
+        self.packet_filters.push(filter);
+        self
+    }
+
+    /// Sets the timeouts used by the receiver, see `TimingProfile`.
+    pub fn timing(mut self, timing: TimingProfile) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Sets the deviations from ANSI E1.31-2018 accepted in received packets, by default packets are parsed strictly. See
+    /// `ParseOptions`.
+    pub fn parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+
+    /// Sets the clock used for timeouts, see `Clock`.
+    pub fn clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Sets the universes that the receiver listens to once created, in addition to the `E131_DISCOVERY_UNIVERSE`.
+    pub fn universes(mut self, universes: &[Universe]) -> Self {
+        self.universes = universes.to_vec();
+        self
     }
 
-    /// Returns a packet if there is one available.
+    /// Checks the configuration and creates the `SacnReceiver`.
     ///
-    /// The packet may not be ready to transmit if it is awaiting synchronisation.
-    /// Will only block if `set_timeout` was called with a timeout of None so otherwise (and by default) it won't
-    /// block so may return a WouldBlock/TimedOut error to indicate that there was no data ready.
+    /// # Errors
+    /// `SourceLimitZero`: Returned if the source limit is Some(0).
     ///
-    /// IMPORTANT NOTE:
-    /// An explicit lifetime is given to the `AcnRootLayerProtocol` which comes from the lifetime of the given buffer.
-    /// The compiler will prevent usage of the returned `AcnRootLayerProtocol` after the buffer is dropped normally but may not in the case
-    /// of unsafe code .
+    /// `InvalidTimingProfile`: Returned if the timing profile is invalid, see `TimingProfile::validate`.
     ///
-    /// Arguments:
-    /// buf: The buffer to use for storing the received data into. This buffer shouldn't be accessed or used directly as the data
-    /// is returned formatted properly in the `AcnRootLayerProtocol`. This buffer is used as memory space for the returned `AcnRootLayerProtocol`.
+    /// `InvalidConfiguration`: Returned if `ipv6_only` is set with an IPv4 address or a transport, if the receive buffer size is zero or
+    /// if the receive buffer size is set with a transport that isn't backed by a socket.
     ///
-    /// # Errors
-    /// May return an error if there is an issue receiving data from the underlying socket, see (recv)[fn.recv.Socket].
+    /// `OsOperationUnsupported`: Returned if multicast is enabled in an environment that doesn't support it, i.e. IPv6 on Windows.
     ///
-    /// May return an error if there is an issue parsing the data from the underlying socket, see (parse)[`fn.AcnRootLayerProtocol::parse.packet`].
-    fn recv<'a>(
-        &mut self,
-        buf: &'a mut [u8; RCV_BUF_DEFAULT_SIZE],
-    ) -> Result<AcnRootLayerProtocol<'a>> {
-        // use read() for the windows impl, since windows does not like using read_exact()
-        let n = self.socket.read(buf)?;
-        if n > RCV_BUF_DEFAULT_SIZE {
-            return Err(SacnError::TooManyBytesRead(n, buf.len()));
+    /// `IpVersionError`: Returned if broadcast is accepted on an IPv6 receiver.
+    ///
+    /// Io: Returned if the socket cannot be created, bound or configured, or if the receiver fails to join the multicast groups of
+    /// its universes.
+    pub fn build(self) -> Result<SacnReceiver> {
+        if self.source_limit == Some(0) {
+            return Err(SacnError::SourceLimitZero());
+        }
+        self.timing.validate()?;
+        if self.recv_buffer_size == Some(0) {
+            return Err(SacnError::InvalidConfiguration(
+                "Receive buffer size must be greater than zero".to_string(),
+            ));
         }
-        AcnRootLayerProtocol::parse(buf)
-    }
 
-    /// Set the timeout for the recv operation.
-    ///
-    /// Arguments:
-    /// timeout: The new timeout for the receive operation, a value of None means the recv operation will become blocking.
-    ///
-    /// Errors:
-    /// A timeout with Duration 0 will cause an error. See (`set_read_timeout`)[`fn.set_read_timeout.Socket`].
-    fn set_timeout(&mut self, timeout: Option<Duration>) -> Result<()> {
-        Ok(self.socket.set_read_timeout(timeout)?)
-    }
+        let mut receiver = match (self.binding, self.ipv6_only) {
+            (ReceiverBinding::Addr(addr), None) => SacnNetworkReceiver::new(addr)?,
+            (ReceiverBinding::Addr(addr), Some(only_v6)) => {
+                if addr.is_ipv4() {
+                    return Err(SacnError::InvalidConfiguration(
+                        "IPv6 only can't be set when binding to an IPv4 address".to_string(),
+                    ));
+                }
+                SacnNetworkReceiver::with_udp_transport(
+                    addr,
+                    UdpTransport::bind_receiver_only_v6(addr, only_v6)?,
+                )
+            }
+            (ReceiverBinding::Transport(_), Some(_)) => {
+                return Err(SacnError::InvalidConfiguration(
+                    "IPv6 only must be set before binding so can't be used with a transport"
+                        .to_string(),
+                ));
+            }
+            (ReceiverBinding::Transport(transport), None) => {
+                SacnNetworkReceiver::with_transport(transport)?
+            }
+        };
+
+        if let Some(size) = self.recv_buffer_size {
+            match receiver.transport.socket() {
+                Some(socket) => socket.set_recv_buffer_size(size)?,
+                None => {
+                    return Err(SacnError::InvalidConfiguration(
+                        "Receive buffer size can't be set as the transport isn't backed by a socket"
+                            .to_string(),
+                    ));
+                }
+            }
+        }
 
-    /// Returns true if this `SacnNetworkReceiver` is bound to an Ipv6 address.
-    fn is_ipv6(&self) -> bool {
-        self.addr.is_ipv6()
+        if let Some(val) = self.multicast_enabled {
+            receiver.set_is_multicast_enabled(val)?;
+        }
+
+        if self.accept_broadcast {
+            receiver.set_accept_broadcast(true)?;
+        }
+
+        receiver.parse_options = self.parse_options;
+
+        let mut rcv = SacnReceiver::with_network_receiver(
+            receiver,
+            self.source_limit,
+            self.clock,
+            self.timing,
+        )?;
+        rcv.process_preview_data = self.process_preview_data;
+        rcv.announce_source_discovery = self.announce_source_discovery;
+        rcv.announce_stream_termination = self.announce_stream_termination;
+        rcv.announce_timeout = self.announce_timeout;
+        rcv.merge_func = self.merge_func;
+        rcv.packet_filters = self.packet_filters;
+        rcv.set_accept_draft_packets(self.accept_draft_packets);
+        rcv.listen_universes(&self.universes)?;
+
+        Ok(rcv)
     }
 }
 
-/// Windows and linux handle multicast sockets differently.
-/// This is built for / tested with Fedora 30/31.
-#[cfg(not(target_os = "windows"))]
+/// Searches for the discovered source with the given name in the given vector of discovered sources and
+/// returns the index of the src in the Vec or None if not found.
+///
+/// Arguments:
+///
+/// srcs: The Vec of `DiscoveredSacnSources` to search.
+///
+/// cid: The CID (uuid) of the source to find.
+fn find_discovered_src(srcs: &[DiscoveredSacnSource], cid: &Uuid) -> Option<usize> {
+    (0..srcs.len()).find(|&i| srcs[i].cid == *cid)
+}
+
+/// In general the lower level transport layer is handled by `SacnNetworkReceiver` which wraps a `Transport`.
+/// The differences between how Windows and linux handle multicast sockets are handled by `UdpTransport`.
 impl SacnNetworkReceiver {
-    /// Creates a new DMX receiver on the interface specified by the given address.
+    /// Creates a new DMX receiver on the interface specified by the given address using a `UdpTransport`.
     ///
     /// If the given address is an IPv4 address then communication will only work between IPv4 devices, if the given address is IPv6 then communication
     /// will only work between IPv6 devices by default but IPv4 receiving can be enabled using set_ipv6_only(false).
@@ -1174,43 +1879,79 @@ impl SacnNetworkReceiver {
     /// Will return an Io error if the SacnReceiver fails to bind to a socket with the given ip.
     /// For more details see socket2::Socket::new().
     fn new(ip: SocketAddr) -> Result<SacnNetworkReceiver> {
-        Ok(SacnNetworkReceiver {
-            socket: create_unix_socket(ip)?,
+        Ok(SacnNetworkReceiver::with_udp_transport(
+            ip,
+            UdpTransport::bind_receiver(ip)?,
+        ))
+    }
+
+    /// Creates a new DMX receiver on the interface specified by the given address which receives over the given `UdpTransport`.
+    ///
+    /// The transport is expected to have been created for the given address, see `UdpTransport::bind_receiver`.
+    fn with_udp_transport(ip: SocketAddr, transport: UdpTransport) -> SacnNetworkReceiver {
+        SacnNetworkReceiver {
+            transport: Box::new(transport),
             addr: ip,
-            is_multicast_enabled: true, // Linux IP Multicast is supported for Ipv4 and Ipv6.
-        })
+            // IPv6 Windows IP Multicast is currently unsupported, Linux IP Multicast is supported for Ipv4 and Ipv6.
+            is_multicast_enabled: !(cfg!(target_os = "windows") && ip.is_ipv6()),
+            accept_broadcast: false,
+            parse_options: ParseOptions::strict(),
+        }
     }
 
-    /// Connects this SacnNetworkReceiver to the multicast address which corresponds to the given universe to allow receiving packets for that universe.
+    /// Creates a new DMX receiver which receives over the given transport on the interface with the transport's local address.
     ///
     /// # Errors
-    /// Will return an Error if the given universe cannot be converted to an IPv4 or IPv6 multicast_addr depending on if the Receiver is bound to an
-    /// IPv4 or IPv6 address. See packet::universe_to_ipv4_multicast_addr and packet::universe_to_ipv6_multicast_addr.
+    /// Will return an error if the local address of the transport cannot be retrieved, see `Transport::local_addr`.
+    fn with_transport(transport: Box<dyn Transport>) -> Result<SacnNetworkReceiver> {
+        Ok(SacnNetworkReceiver {
+            addr: transport.local_addr()?,
+            transport,
+            is_multicast_enabled: true,
+            accept_broadcast: false,
+            parse_options: ParseOptions::strict(),
+        })
+    }
+
+    /// Returns the multicast group address which corresponds to the given universe for the IP version this receiver is bound to.
     ///
-    /// Will return an Io error if cannot join the universes corresponding multicast group address.
-    fn listen_multicast_universe(&self, universe: u16) -> Result<()> {
+    /// # Errors
+    /// Will return an Error if the given universe cannot be converted to an IPv4 or IPv6 `multicast_addr` depending on if the Receiver is bound to an
+    /// IPv4 or IPv6 address. See `packet::universe_to_ipv4_multicast_addr` and `packet::universe_to_ipv6_multicast_addr`.
+    fn multicast_group(&self, universe: u16) -> Result<IpAddr> {
         let multicast_addr = if self.addr.is_ipv4() {
             universe_to_ipv4_multicast_addr(universe)? // "Failed to convert universe to IPv4 multicast addr"
         } else {
             universe_to_ipv6_multicast_addr(universe)? // "Failed to convert universe to IPv6 multicast addr"
         };
 
-        join_unix_multicast(&self.socket, multicast_addr, self.addr.ip())
+        multicast_addr.as_socket().map(|a| a.ip()).ok_or_else(|| {
+            SacnError::UnsupportedIpVersion("Multicast address is not IPv4 or IPv6".to_string())
+        })
+    }
+
+    /// Connects this `SacnNetworkReceiver` to the multicast address which corresponds to the given universe to allow receiving packets for that universe.
+    ///
+    /// # Errors
+    /// Will return an Error if the given universe cannot be converted to an IPv4 or IPv6 `multicast_addr` depending on if the Receiver is bound to an
+    /// IPv4 or IPv6 address. See `packet::universe_to_ipv4_multicast_addr` and `packet::universe_to_ipv6_multicast_addr`.
+    ///
+    /// Will return an Io error if cannot join the universes corresponding multicast group address.
+    fn listen_multicast_universe(&self, universe: u16) -> Result<()> {
+        self.transport
+            .join_multicast(self.multicast_group(universe)?, self.addr.ip())
     }
 
     /// Removes this SacnNetworkReceiver from the multicast group which corresponds to the given universe.
     ///
     /// # Errors
-    /// Will return an Error if the given universe cannot be converted to an Ipv4 or Ipv6 multicast_addr depending on if the Receiver is bound to an
-    /// IPv4 or IPv6 address. See packet::universe_to_ipv4_multicast_addr and packet::universe_to_ipv6_multicast_addr.
+    /// Will return an Error if the given universe cannot be converted to an Ipv4 or Ipv6 `multicast_addr` depending on if the Receiver is bound to an
+    /// IPv4 or IPv6 address. See `packet::universe_to_ipv4_multicast_addr` and `packet::universe_to_ipv6_multicast_addr`.
+    ///
+    /// Will return an `OsOperationUnsupported` error if attempting to leave an Ipv6 multicast group on Windows.
     fn mute_multicast_universe(&mut self, universe: u16) -> Result<()> {
-        let multicast_addr = if self.addr.is_ipv4() {
-            universe_to_ipv4_multicast_addr(universe)?
-        } else {
-            universe_to_ipv6_multicast_addr(universe)?
-        };
-
-        leave_unix_multicast(&self.socket, multicast_addr, self.addr.ip())
+        self.transport
+            .leave_multicast(self.multicast_group(universe)?, self.addr.ip())
     }
 
     /// Sets the value of the is_multicast_enabled flag to the given value.
@@ -1223,9 +1964,14 @@ impl SacnNetworkReceiver {
     /// val: The new value for the is_multicast_enabled flag.
     ///
     /// # Errors
-    /// Will return an OsOperationUnsupported error if attempting to set the flag to true in an environment that multicast
-    /// isn't supported i.e. Ipv6 on Windows. Note that this is the UNIX implementation
+    /// Will return an `OsOperationUnsupported` error if attempting to set the flag to true in an environment that multicast
+    /// isn't supported i.e. Ipv6 on Windows.
     fn set_is_multicast_enabled(&mut self, val: bool) -> Result<()> {
+        if val && cfg!(target_os = "windows") && self.addr.is_ipv6() {
+            return Err(SacnError::OsOperationUnsupported(
+                "IPv6 multicast is currently unsupported on Windows".to_string(),
+            ));
+        }
         self.is_multicast_enabled = val;
         Ok(())
     }
@@ -1237,44 +1983,78 @@ impl SacnNetworkReceiver {
         self.is_multicast_enabled
     }
 
+    /// Sets whether datagrams sent to a broadcast address are accepted by enabling or disabling `SO_BROADCAST` on the socket.
+    /// A transport without a socket is assumed to already deliver broadcast datagrams.
+    ///
+    /// # Errors
+    /// Will return an `IpVersionError` if attempting to accept broadcast on an IPv6 receiver, IPv6 has no broadcast.
+    ///
+    /// Will return an Io error if `SO_BROADCAST` fails to be set on the socket.
+    fn set_accept_broadcast(&mut self, val: bool) -> Result<()> {
+        if val && self.addr.is_ipv6() {
+            return Err(SacnError::IpVersionError());
+        }
+        if let Some(socket) = self.transport.socket() {
+            socket.set_broadcast(val)?;
+        }
+        self.accept_broadcast = val;
+        Ok(())
+    }
+
+    /// Returns true if the receiver accepts datagrams sent to a broadcast address and false if not.
+    fn accept_broadcast(&self) -> bool {
+        self.accept_broadcast
+    }
+
     /// If set to true then only receive over IPv6. If false then receiving will be over both IPv4 and IPv6.
-    /// This will return an error if the SacnReceiver wasn't created using an IPv6 address to bind to.
+    /// This will return an error if the `SacnReceiver` wasn't created using an IPv6 address to bind to.
+    ///
+    /// Will return an `OsOperationUnsupported` error if the transport isn't backed by a socket.
     fn set_only_v6(&mut self, val: bool) -> Result<()> {
         if self.addr.is_ipv4() {
-            Err(SacnError::IpVersionError())
-        } else {
-            Ok(self.socket.set_only_v6(val)?)
+            return Err(SacnError::IpVersionError());
+        }
+        match self.transport.socket() {
+            Some(socket) => Ok(socket.set_only_v6(val)?),
+            None => Err(SacnError::OsOperationUnsupported(
+                "Transport does not support setting IPv6 only".to_string(),
+            )),
         }
     }
 
-    /// Returns a packet if there is one available.
+    /// Returns the PDUs of a packet if there is one available, see `AcnRootLayerBlock`.
     ///
     /// The packet may not be ready to transmit if it is awaiting synchronisation.
     /// Will only block if set_timeout was called with a timeout of None so otherwise (and by default) it won't
     /// block so may return a WouldBlock/TimedOut error to indicate that there was no data ready.
     ///
     /// IMPORTANT NOTE:
-    /// An explicit lifetime is given to the AcnRootLayerProtocol which comes from the lifetime of the given buffer.
-    /// The compiler will prevent usage of the returned AcnRootLayerProtocol after the buffer is dropped.
+    /// An explicit lifetime is given to the returned PDUs which comes from the lifetime of the given buffer.
+    /// The compiler will prevent usage of the returned PDUs after the buffer is dropped.
     ///
     /// Arguments:
     /// buf: The buffer to use for storing the received data into. This buffer shouldn't be accessed or used directly as the data
-    /// is returned formatted properly in the AcnRootLayerProtocol. This buffer is used as memory space for the returned AcnRootLayerProtocol.
+    /// is returned formatted properly in the returned PDUs. This buffer is used as memory space for the returned PDUs.
     ///
     /// # Errors
-    /// May return an error if there is an issue receiving data from the underlying socket, see (recv)[fn.recv.Socket].
+    /// May return an error if there is an issue receiving data from the underlying transport, see `Transport::recv_from`.
     ///
-    /// May return an error if there is an issue parsing the data from the underlying socket, see (parse)[fn.AcnRootLayerProtocol::parse.packet].
+    /// May return a `MalformedPacket` error holding the address of the sender if the data received can't be parsed using the
+    /// `ParseOptions` of the receiver, see (`parse_with_options`)[`fn.AcnRootLayerBlock::parse_with_options`]. Packets
+    /// accepted with warnings are processed as normal.
     fn recv<'a>(
         &mut self,
         buf: &'a mut [u8; RCV_BUF_DEFAULT_SIZE],
-    ) -> Result<AcnRootLayerProtocol<'a>> {
-        // use read() since read_exact() was not passing the tests.
-        let n = self.socket.read(buf)?;
+    ) -> Result<Vec<E131RootLayer<'a>>> {
+        let (n, peer) = self.transport.recv_from(buf)?;
         if n > RCV_BUF_DEFAULT_SIZE {
             return Err(SacnError::TooManyBytesRead(n, buf.len()));
         }
-        AcnRootLayerProtocol::parse(buf)
+        match AcnRootLayerBlock::parse_with_options(&buf[..n], &self.parse_options) {
+            Ok(parsed) => Ok(parsed.block.pdus),
+            Err(SacnError::SacnParsePackError(e)) => Err(SacnError::MalformedPacket(peer, e)),
+            Err(e) => Err(e),
+        }
     }
 
     /// Set the timeout for the recv operation.
@@ -1283,9 +2063,18 @@ impl SacnNetworkReceiver {
     /// timeout: The new timeout for the receive operation, a value of None means the recv operation will become blocking.
     ///
     /// Errors:
-    /// A timeout with Duration 0 will cause an error. See (set_read_timeout)[fn.set_read_timeout.Socket].
+    /// A timeout with Duration 0 will cause an error for a `UdpTransport`. See (`set_read_timeout`)[`fn.set_read_timeout.Socket`].
     fn set_timeout(&mut self, timeout: Option<Duration>) -> Result<()> {
-        Ok(self.socket.set_read_timeout(timeout)?)
+        self.transport.set_read_timeout(timeout)
+    }
+}
+
+impl DMXData {
+    /// Returns the start code of the data, the first value, or `StartCode::NULL` if there are no values.
+    pub fn start_code(&self) -> StartCode {
+        self.values
+            .first()
+            .map_or(StartCode::NULL, |s| StartCode::new(*s))
     }
 }
 
@@ -1370,304 +2159,129 @@ impl DiscoveredSacnSource {
     }
 }
 
-/// Creates a new Socket2 socket bound to the given address.
-///
-/// Returns the created socket.
-///
-/// Arguments:
-/// addr: The address that the newly created socket should bind to.
-///
-/// # Errors
-/// Will return an error if the socket cannot be created, see (Socket::new)[fn.new.Socket].
+/// The set of universes a receiver is listening to.
 ///
-/// Will return an error if the socket cannot be bound to the given address, see (bind)[fn.bind.Socket2].
-#[cfg(not(target_os = "windows"))]
-fn create_unix_socket(addr: SocketAddr) -> Result<Socket> {
-    if addr.is_ipv4() {
-        let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
-
-        // Multiple different processes might want to listen to the sACN stream so therefore need to allow re-using the ACN port.
-        // If the OS doesn't support SO_REUSEPORT then ignore the error and continue.
-        // FreeRTOS on ESP32 doesn't support SO_REUSEPORT and so this allows the library to be used on the platform without issue.
-        if let Err(e) = socket.set_reuse_port(true) {
-            if e.raw_os_error() != Some(libc::ENOPROTOOPT) {    
-                return Err(e.into());
-            }
-        }
-        socket.set_reuse_address(true)?;
+/// Stored as a fixed size bitset covering the whole `u16` universe space so that membership checks, which happen for every received packet,
+/// are constant time regardless of how many universes are registered.
+struct UniverseSet {
+    /// One bit per universe, universe `u` is stored at bit `u % 64` of word `u / 64`.
+    bits: Box<[u64; UNIVERSE_SET_WORDS]>,
+
+    /// The number of universes currently in the set.
+    len: usize,
+}
 
-        let socket_addr =
-            SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), ACN_SDT_MULTICAST_PORT);
-        socket.bind(&socket_addr.into())?;
-        Ok(socket)
-    } else {
-        let socket = Socket::new(Domain::IPV6, Type::DGRAM, Some(Protocol::UDP))?;
+/// The number of 64 bit words needed to hold a bit for every possible `u16` universe.
+const UNIVERSE_SET_WORDS: usize = (u16::MAX as usize + 1) / 64;
 
-        // Multiple different processes might want to listen to the sACN stream so therefore need to allow re-using the ACN port.
-        if let Err(e) = socket.set_reuse_port(true) {
-            if e.raw_os_error() != Some(libc::ENOPROTOOPT) {    
-                return Err(e.into());
-            }
+impl UniverseSet {
+    /// Creates a new empty `UniverseSet`.
+    fn new() -> UniverseSet {
+        UniverseSet {
+            bits: Box::new([0; UNIVERSE_SET_WORDS]),
+            len: 0,
         }
-        socket.set_reuse_address(true)?;
+    }
 
-        let socket_addr =
-            SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), ACN_SDT_MULTICAST_PORT);
-        socket.bind(&socket_addr.into())?;
-        Ok(socket)
+    /// Adds the given universe to the set.
+    ///
+    /// Returns true if the universe was added and false if it was already present.
+    fn insert(&mut self, universe: u16) -> bool {
+        let (word, mask) = UniverseSet::position(universe);
+        if self.bits[word] & mask != 0 {
+            return false;
+        }
+        self.bits[word] |= mask;
+        self.len += 1;
+        true
     }
-}
 
-/// Joins the multicast group with the given address using the given socket.
-///
-/// Arguments:
-/// socket: The socket to join to the multicast group.
-/// addr:   The address of the multicast group to join.
-///
-/// # Errors
-/// Will return an error if the given socket cannot be joined to the given multicast group address.
-///     See join_multicast_v4[fn.join_multicast_v4.Socket] and join_multicast_v6[fn.join_multicast_v6.Socket]
-///
-/// Will return an IpVersionError if addr and interface_addr are not the same IP version.
-#[cfg(not(target_os = "windows"))]
-fn join_unix_multicast(socket: &Socket, addr: SockAddr, interface_addr: IpAddr) -> Result<()> {
-    match addr.family() as i32 {
-        // Cast required because AF_INET is defined in libc in terms of a c_int (i32) but addr.family returns using u16.
-        AF_INET => match addr.as_socket_ipv4() {
-            Some(a) => match interface_addr {
-                IpAddr::V4(ref interface_v4) => {
-                    socket
-                        .join_multicast_v4(a.ip(), interface_v4)
-                        .map_err(|e| {
-                            SacnError::Io(std::io::Error::new(
-                                e.kind(),
-                                "Failed to join IPv4 multicast",
-                            ))
-                        })?;
-                }
-                IpAddr::V6(ref _interface_v6) => {
-                    return Err(SacnError::IpVersionError());
-                }
-            },
-            None => {
-                return Err(SacnError::UnsupportedIpVersion("IP version recognised as AF_INET but not actually usable as AF_INET so must be unknown type".to_string()));
-            }
-        },
-        AF_INET6 => match addr.as_socket_ipv6() {
-            Some(a) => {
-                socket.join_multicast_v6(a.ip(), 0).map_err(|e| {
-                    SacnError::Io(std::io::Error::new(
-                        e.kind(),
-                        "Failed to join IPv6 multicast",
-                    ))
-                })?;
-            }
-            None => {
-                return Err(SacnError::UnsupportedIpVersion("IP version recognised as AF_INET6 but not actually usable as AF_INET6 so must be unknown type".to_string()));
-            }
-        },
-        x => {
-            return Err(SacnError::UnsupportedIpVersion(format!("IP version not recognised as AF_INET (Ipv4) or AF_INET6 (Ipv6) - family value (as i32): {}", x).to_string()));
+    /// Removes the given universe from the set.
+    ///
+    /// Returns true if the universe was removed and false if it wasn't present.
+    fn remove(&mut self, universe: u16) -> bool {
+        let (word, mask) = UniverseSet::position(universe);
+        if self.bits[word] & mask == 0 {
+            return false;
         }
-    };
+        self.bits[word] &= !mask;
+        self.len -= 1;
+        true
+    }
 
-    Ok(())
-}
+    /// Returns true if the given universe is in the set.
+    fn contains(&self, universe: u16) -> bool {
+        let (word, mask) = UniverseSet::position(universe);
+        self.bits[word] & mask != 0
+    }
 
-/// Leaves the multicast group with the given address using the given socket.
-///
-/// Arguments:
-/// socket: The socket to leave the multicast group.
-/// addr:   The address of the multicast group to leave.
-///
-/// # Errors
-/// Will return an error if the given socket cannot leave the given multicast group address.
-///     See leave_multicast_v4[fn.leave_multicast_v4.Socket] and leave_multicast_v6[fn.leave_multicast_v6.Socket]
-///
-/// Will return an IpVersionError if addr and interface_addr are not the same IP version.
-#[cfg(not(target_os = "windows"))]
-fn leave_unix_multicast(socket: &Socket, addr: SockAddr, interface_addr: IpAddr) -> Result<()> {
-    match addr.family() as i32 {
-        // Cast required because AF_INET is defined in libc in terms of a c_int (i32) but addr.family returns using u16.
-        AF_INET => match addr.as_socket_ipv4() {
-            Some(a) => match interface_addr {
-                IpAddr::V4(ref interface_v4) => {
-                    socket
-                        .leave_multicast_v4(a.ip(), interface_v4)
-                        .map_err(|e| {
-                            SacnError::Io(std::io::Error::new(
-                                e.kind(),
-                                "Failed to leave IPv4 multicast",
-                            ))
-                        })?;
-                }
-                IpAddr::V6(ref _interface_v6) => {
-                    return Err(SacnError::IpVersionError());
-                }
-            },
-            None => {
-                return Err(SacnError::UnsupportedIpVersion("IP version recognised as AF_INET but not actually usable as AF_INET so must be unknown type".to_string()));
-            }
-        },
-        AF_INET6 => match addr.as_socket_ipv6() {
-            Some(a) => {
-                socket.leave_multicast_v6(a.ip(), 0).map_err(|e| {
-                    SacnError::Io(std::io::Error::new(
-                        e.kind(),
-                        "Failed to leave IPv6 multicast",
-                    ))
-                })?;
-            }
-            None => {
-                return Err(SacnError::UnsupportedIpVersion("IP version recognised as AF_INET6 but not actually usable as AF_INET6 so must be unknown type".to_string()));
+    /// Returns the number of universes in the set.
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the universes in the set in ascending order.
+    fn to_vec(&self) -> Vec<u16> {
+        let mut universes = Vec::with_capacity(self.len);
+        for (i, word) in self.bits.iter().enumerate() {
+            let mut w = *word;
+            while w != 0 {
+                let bit = w.trailing_zeros() as usize;
+                universes.push((i * 64 + bit) as u16);
+                w &= w - 1;
             }
-        },
-        x => {
-            return Err(SacnError::UnsupportedIpVersion(format!("IP version not recognised as AF_INET (Ipv4) or AF_INET6 (Ipv6) - family value (as i32): {}", x).to_string()));
         }
-    };
+        universes
+    }
 
-    Ok(())
+    /// Returns the word index and bit mask used to store the given universe.
+    fn position(universe: u16) -> (usize, u64) {
+        ((universe / 64) as usize, 1 << (universe % 64))
+    }
 }
 
-/// Creates a new Socket2 socket bound to the given address.
-///
-/// Returns the created socket.
-///
-/// Arguments:
-/// addr: The address that the newly created socket should bind to.
-///
-/// # Errors
-/// Will return an error if the socket cannot be created, see (`Socket::new`)[fn.new.Socket].
-///
-/// Will return an error if the socket cannot be bound to the given address, see (bind)[fn.bind.Socket].
-#[cfg(target_os = "windows")]
-fn create_win_socket(addr: SocketAddr) -> Result<Socket> {
-    if addr.is_ipv4() {
-        let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
-
-        socket.set_reuse_address(true)?;
-        socket.bind(&SockAddr::from(addr))?;
-        Ok(socket)
-    } else {
-        let socket = Socket::new(Domain::IPV6, Type::DGRAM, Some(Protocol::UDP))?;
-
-        socket.set_reuse_address(true)?;
-        socket.bind(&SockAddr::from(addr))?;
-        Ok(socket)
+impl fmt::Debug for UniverseSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.to_vec()).finish()
     }
 }
 
-/// Joins the multicast group with the given address using the given socket on the windows operating system.
-///
-/// Note that Ipv6 is currently unsupported.
-///
-/// Arguments:
-/// socket: The socket to join to the multicast group.
-/// addr:   The address of the multicast group to join.
+/// A min-queue of deadlines used to expire entries without scanning every entry on each check.
 ///
-/// # Errors
-/// Will return an error if the given socket cannot be joined to the given multicast group address.
-///     See `join_multicast_v4`[`fn.join_multicast_v4.Socket`] and `join_multicast_v6`[`fn.join_multicast_v6.Socket`]
-///
-/// Will return `OsOperationUnsupported` error if attempt to leave an Ipv6 multicast group as all Ipv6 multicast operations are currently unsupported in Rust on Windows.
-#[cfg(target_os = "windows")]
-fn join_win_multicast(socket: &Socket, addr: SockAddr, interface_addr: IpAddr) -> Result<()> {
-    match addr.family() as i32 {
-        // Cast required because AF_INET is defined in libc in terms of a c_int (i32) but addr.family returns using u16.
-        AF_INET => match addr.as_socket_ipv4() {
-            Some(a) => match interface_addr {
-                IpAddr::V4(ref interface_v4) => {
-                    socket
-                        .join_multicast_v4(a.ip(), interface_v4)
-                        .map_err(|e| {
-                            SacnError::Io(std::io::Error::new(
-                                e.kind(),
-                                "Failed to join IPv4 multicast",
-                            ))
-                        })?;
-                }
-                IpAddr::V6(ref _interface_v6) => {
-                    return Err(SacnError::IpVersionError());
-                }
-            },
-            None => {
-                return Err(SacnError::UnsupportedIpVersion("IP version recognised as AF_INET but not actually usable as AF_INET so must be unknown type".to_string()));
-            }
-        },
-        AF_INET6 => match addr.as_socket_ipv6() {
-            Some(a) => {
-                socket.join_multicast_v6(a.ip(), 0).map_err(|e| {
-                    SacnError::Io(std::io::Error::new(
-                        e.kind(),
-                        "Failed to join IPv6 multicast",
-                    ))
-                })?;
-            }
-            None => {
-                return Err(SacnError::UnsupportedIpVersion("IP version recognised as AF_INET6 but not actually usable as AF_INET6 so must be unknown type".to_string()));
-            }
-        },
-        x => {
-            return Err(SacnError::UnsupportedIpVersion(format!(
-                "IP version not recognised as AF_INET (Ipv4) or AF_INET6 (Ipv6) - family value (as i32): {x}"
-            )));
+/// Entries are never removed from the queue early, instead the owner of the queue records the deadline that is currently scheduled
+/// for each key and ignores any popped entry which no longer matches (lazy invalidation). This keeps scheduling, refreshing and
+/// cancelling O(1) with expiry checks costing O(log n) per expired entry.
+struct DeadlineQueue<K: Ord> {
+    heap: BinaryHeap<Reverse<(Instant, K)>>,
+}
+
+impl<K: Ord> DeadlineQueue<K> {
+    /// Creates a new empty `DeadlineQueue`.
+    fn new() -> DeadlineQueue<K> {
+        DeadlineQueue {
+            heap: BinaryHeap::new(),
         }
-    };
+    }
 
-    Ok(())
-}
+    /// Schedules the given key to expire at the given deadline.
+    fn schedule(&mut self, deadline: Instant, key: K) {
+        self.heap.push(Reverse((deadline, key)));
+    }
 
-/// Leaves the multicast group with the given address using the given socket.
-///
-/// Note that Ipv6 is currently unsupported.
-///
-/// Arguments:
-/// socket: The socket to leave the multicast group.
-/// addr:   The address of the multicast group to leave.
-///
-/// # Errors
-/// Will return an error if the given socket cannot leave the given multicast group address.
-///     See `leave_multicast_v4`[`fn.leave_multicast_v4.Socket`] and `leave_multicast_v6`[`fn.leave_multicast_v6.Socket`]
-///
-/// Will return `OsOperationUnsupported` error if attempt to leave an Ipv6 multicast group as all Ipv6 multicast operations are currently unsupported in Rust on Windows.
-#[cfg(target_os = "windows")]
-fn leave_win_multicast(socket: &Socket, addr: SockAddr) -> Result<()> {
-    match addr.family() as i32 {
-        // Cast required because AF_INET is defined in libc in terms of a c_int (i32) but addr.family returns using u16.
-        AF_INET => match addr.as_socket_ipv4() {
-            Some(a) => {
-                socket
-                    .leave_multicast_v4(a.ip(), &Ipv4Addr::new(0, 0, 0, 0))
-                    .map_err(|e| {
-                        SacnError::Io(std::io::Error::new(
-                            e.kind(),
-                            "Failed to leave IPv4 multicast",
-                        ))
-                    })?;
+    /// Removes and returns the earliest scheduled entry if its deadline is at or before the given time.
+    fn pop_expired(&mut self, now: Instant) -> Option<(Instant, K)> {
+        match self.heap.peek() {
+            Some(Reverse((deadline, _))) if *deadline <= now => {
+                self.heap.pop().map(|Reverse(entry)| entry)
             }
-            None => {
-                return Err(SacnError::UnsupportedIpVersion("IP version recognised as AF_INET but not actually usable as AF_INET so must be unknown type".to_string()));
-            }
-        },
-        AF_INET6 => match addr.as_socket_ipv6() {
-            Some(_) => {
-                return Err(SacnError::OsOperationUnsupported(
-                    "IPv6 multicast is currently unsupported on Windows".to_string(),
-                ));
-            }
-            None => {
-                return Err(SacnError::UnsupportedIpVersion("IP version recognised as AF_INET6 but not actually usable as AF_INET6 so must be unknown type".to_string()));
-            }
-        },
-        x => {
-            return Err(SacnError::UnsupportedIpVersion(format!(
-                "IP version not recognised as AF_INET (Ipv4) or AF_INET6 (Ipv6) - family value (as i32): {x}"
-            )));
+            _ => None,
         }
-    };
+    }
 
-    Ok(())
+    /// Removes all scheduled entries.
+    fn clear(&mut self) {
+        self.heap.clear();
+    }
 }
 
 /// Stores a sequence number and a timestamp.
@@ -1679,13 +2293,17 @@ fn leave_win_multicast(socket: &Socket, addr: SockAddr) -> Result<()> {
 struct TimedStampedSeqNo {
     sequence_number: u8,
     last_recv: Instant,
+
+    /// The deadline currently held in the `DeadlineQueue` for this entry, any other queued deadline for the same key is stale.
+    scheduled: Instant,
 }
 
 impl TimedStampedSeqNo {
-    fn new(sequence_number: u8, last_recv: Instant) -> TimedStampedSeqNo {
+    fn new(sequence_number: u8, last_recv: Instant, scheduled: Instant) -> TimedStampedSeqNo {
         TimedStampedSeqNo {
             sequence_number,
             last_recv,
+            scheduled,
         }
     }
 }
@@ -1697,26 +2315,31 @@ impl TimedStampedSeqNo {
 /// Abstracts over the internal data-structures/mechanisms used allowing them be changed.
 struct SequenceNumbering {
     /// The sequence numbers used for data packets, keeps a reference of the last sequence number received for each universe.
-    /// Sequence numbers are always in the range [0, 255] inclusive.
     /// Each type of packet is tracked differently with respect to sequence numbers as per ANSI E1.31-2018 Section 6.7.2 Sequence Numbering.
-    /// The uuid refers to the source that is sending the data.
-    data_sequences: HashMap<Uuid, HashMap<u16, TimedStampedSeqNo>>,
+    data_sequences: SourceSequences,
 
     /// The sequence numbers used for synchronisation packets, keeps a reference of the last sequence number received for each universe.
-    /// Sequence numbers are always in the range [0, 255] inclusive.
     /// Each type of packet is tracked differently with respect to sequence numbers as per ANSI E1.31-2018 Section 6.7.2 Sequence Numbering.
-    /// The uuid refers to the source that is sending the data.
-    sync_sequences: HashMap<Uuid, HashMap<u16, TimedStampedSeqNo>>,
+    sync_sequences: SourceSequences,
 }
 
 impl SequenceNumbering {
     /// Creates a new `SequenceNumbering` for tracking sequence numbers for the various types of packets.
     ///
-    /// This implementation uses `HashMaps` internally to allow O(1) checking and updating of sequence numbers.
-    fn new() -> SequenceNumbering {
+    /// This implementation uses `HashMaps` internally to allow O(1) checking and updating of sequence numbers and a `DeadlineQueue`
+    /// per packet type so that checking timeouts only touches the entries which have actually expired.
+    ///
+    /// The data loss timeout and sequence discard window are taken from the given timing profile.
+    fn new(timing: &TimingProfile) -> SequenceNumbering {
         SequenceNumbering {
-            data_sequences: HashMap::new(),
-            sync_sequences: HashMap::new(),
+            data_sequences: SourceSequences::new(
+                timing.data_loss_timeout,
+                timing.seq_discard_lower_bound,
+            ),
+            sync_sequences: SourceSequences::new(
+                timing.data_loss_timeout,
+                timing.seq_discard_lower_bound,
+            ),
         }
     }
 
@@ -1727,23 +2350,17 @@ impl SequenceNumbering {
     }
 
     /// Checks the timeouts for all packet types, sources and universes with sequence numbers registed.
-    /// Removes any universes for which the `last_recv` time was at least the given timeout amount of time ago.
+    /// Removes any universes for which the `last_recv` time was at least the network data loss timeout ago.
     /// Any sources which have no universes after this operation are also removed.
     ///
     /// #Arguments
     ///
+    /// now: The current time.
+    ///
     /// `announce_timeout`: A flag, if true it indicates than a `UniverseTimeout` error should be thrown if a universe times out on a source.
-    fn check_timeouts(&mut self, announce_timeout: bool) -> Result<()> {
-        check_timeouts(
-            &mut self.data_sequences,
-            E131_NETWORK_DATA_LOSS_TIMEOUT,
-            announce_timeout,
-        )?;
-        check_timeouts(
-            &mut self.sync_sequences,
-            E131_NETWORK_DATA_LOSS_TIMEOUT,
-            announce_timeout,
-        )
+    fn check_timeouts(&mut self, now: Instant, announce_timeout: bool) -> Result<()> {
+        self.data_sequences.check_timeouts(now, announce_timeout)?;
+        self.sync_sequences.check_timeouts(now, announce_timeout)
     }
 
     /// Checks the sequence number is correct for a data packet with the given `sequence_number` and universe from the given source with given cid.
@@ -1752,6 +2369,8 @@ impl SequenceNumbering {
     /// Returns Ok(()) if the packet is detected in-order.
     ///
     /// # Arguments
+    /// now: The time at which the packet was received.
+    ///
     /// `source_limit`: The limit on the number of sources which are allowed, None indicates no limit, if there is a limit then a `SourcesExceededError` may be returned.
     ///
     /// cid:    The Uuid of the source that send the packet.
@@ -1767,14 +2386,15 @@ impl SequenceNumbering {
     /// Return a `SourcesExceededError` if the cid of the source is new and would cause the number of sources to exceed the given `source_limit`.
     fn check_data_seq_number(
         &mut self,
+        now: Instant,
         source_limit: Option<usize>,
         cid: Uuid,
         sequence_number: u8,
         universe: u16,
         announce_timeout: bool,
     ) -> Result<()> {
-        check_seq_number(
-            &mut self.data_sequences,
+        self.data_sequences.check_seq_number(
+            now,
             source_limit,
             cid,
             sequence_number,
@@ -1789,6 +2409,8 @@ impl SequenceNumbering {
     /// Returns Ok(()) if the packet is detected in-order.
     ///
     /// # Arguments
+    /// now: The time at which the packet was received.
+    ///
     /// `source_limit`: The limit on the number of sources which are allowed, None indicates no limit, if there is a limit then a `SourcesExceededError` may be returned.
     ///
     /// cid:    The Uuid of the source that send the packet.
@@ -1804,14 +2426,15 @@ impl SequenceNumbering {
     /// Return a `SourcesExceededError` if the cid of the source is new and would cause the number of sources to exceed the given `source_limit`.
     fn check_sync_seq_number(
         &mut self,
+        now: Instant,
         source_limit: Option<usize>,
         cid: Uuid,
         sequence_number: u8,
         sync_uni: u16,
         announce_timeout: bool,
     ) -> Result<()> {
-        check_seq_number(
-            &mut self.sync_sequences,
+        self.sync_sequences.check_seq_number(
+            now,
             source_limit,
             cid,
             sequence_number,
@@ -1829,208 +2452,193 @@ impl SequenceNumbering {
     ///
     /// universe: The universe being sent by the source from which to remove the sequence numbers.
     fn remove_seq_numbers(&mut self, src_cid: Uuid, universe: u16) -> Result<()> {
-        remove_source_universe_seq(&mut self.data_sequences, src_cid, universe)?;
-        remove_source_universe_seq(&mut self.sync_sequences, src_cid, universe)
+        self.data_sequences.remove(src_cid, universe)?;
+        self.sync_sequences.remove(src_cid, universe)
     }
 }
 
-/// Checks the given sequence number for the given universe against the given expected sequence numbers.
-///
-/// Returns Ok(()) if the packet is detected in-order.
-///
-/// # Arguments
-/// `src_sequences`: A mutable hashmap which relates sources identified by Uuid to another hashmap which itself relates universes to sequence numbers. The given hashmap of
-///                 sequences should be for the specific packet-type being checked as different packet-types have their own sequence numbers even from the same source.
-/// `source_limit`: The limit on the number of sources which are allowed, None indicates no limit, if there is a limit then a `SourcesExceededError` may be returned.
-/// cid:    The Uuid of the source that send the packet.
-/// `sequence_number`: The sequence number of the packet to check.
-/// universe: The universe of the packet (this is the data universe for data packets and the sync universe for synchronisation packets).
-///
-/// # Errors
-/// Returns an `OutOfSequence` error if a packet is received out of order as detected by the different between
-/// the packets sequence number and the expected sequence number as specified in ANSI E1.31-2018 Section 6.7.2 Sequence Numbering.
-///
-/// Return a `SourcesExceededError` if the cid of the source is new and would cause the number of sources to exceed the given `source_limit`.
-fn check_seq_number(
-    src_sequences: &mut HashMap<Uuid, HashMap<u16, TimedStampedSeqNo>>,
-    source_limit: Option<usize>,
-    cid: Uuid,
-    sequence_number: u8,
-    universe: u16,
-    announce_timeout: bool,
-) -> Result<()> {
-    // Check all the timeouts at the start.
-    // This is done for all sources/universes rather than just the source that sent the packet because a completely dead (no packets being sent) universe
-    // would not be removed otherwise and would continue to take up space. This comes at the cost of increased processing time complexity as each
-    // source is checked every time.
-    check_timeouts(
-        src_sequences,
-        E131_NETWORK_DATA_LOSS_TIMEOUT,
-        announce_timeout,
-    )?;
-    if src_sequences.get(&cid).is_none() {
-        // New source not previously received from.
-        if source_limit.is_none() || src_sequences.len() < source_limit.unwrap() {
-            src_sequences.insert(cid, HashMap::new());
-        } else {
-            return Err(SacnError::SourcesExceededError(src_sequences.len()));
+/// The sequence numbers for a single packet type, keyed by source and then universe, along with the deadlines at which each
+/// source / universe combination will time out.
+struct SourceSequences {
+    /// Relates sources identified by Uuid to the last sequence number received on each of their universes.
+    /// Sequence numbers are always in the range [0, 255] inclusive.
+    sequences: HashMap<Uuid, HashMap<u16, TimedStampedSeqNo>>,
+
+    /// The deadlines at which source / universe combinations time out if no further packets are received.
+    deadlines: DeadlineQueue<(Uuid, u16)>,
+
+    /// The exclusive length of time permitted since a source last sent on a universe before it is said to have timed out.
+    timeout: Duration,
+
+    /// The exclusive lower bound on the sequence number difference within which a packet is discarded, by default
+    /// `E131_SEQ_DIFF_DISCARD_LOWER_BOUND`.
+    discard_lower_bound: isize,
+}
+
+impl SourceSequences {
+    /// Creates a new empty `SourceSequences` where source / universe combinations time out after the given duration and packets
+    /// are discarded if their sequence number difference is within (`discard_lower_bound`, `E131_SEQ_DIFF_DISCARD_UPPER_BOUND`].
+    fn new(timeout: Duration, discard_lower_bound: isize) -> SourceSequences {
+        SourceSequences {
+            sequences: HashMap::new(),
+            deadlines: DeadlineQueue::new(),
+            timeout,
+            discard_lower_bound,
         }
-    };
+    }
 
-    let expected_seq = match src_sequences.get(&cid) {
-        Some(src) => {
-            if let Some(s) = src.get(&universe) {
-                // Get the sequence number within the source for the specific universe.
-                // Indicates that the source / universe combination is known.
-                *s
-            } else {
-                // Indicates that this is the first time (or the first time since it timed out) the universe has been received from this source.
-                let initial_seq_num = sequence_number.wrapping_sub(1);
-                TimedStampedSeqNo::new(initial_seq_num, Instant::now())
+    /// Removes all sources, universes and scheduled deadlines.
+    fn clear(&mut self) {
+        self.sequences.clear();
+        self.deadlines.clear();
+    }
+
+    /// Checks the given sequence number for the given universe against the expected sequence numbers.
+    ///
+    /// Returns Ok(()) if the packet is detected in-order.
+    ///
+    /// # Arguments
+    /// now: The time at which the packet was received.
+    /// `source_limit`: The limit on the number of sources which are allowed, None indicates no limit, if there is a limit then a `SourcesExceededError` may be returned.
+    /// cid:    The Uuid of the source that send the packet.
+    /// `sequence_number`: The sequence number of the packet to check.
+    /// universe: The universe of the packet (this is the data universe for data packets and the sync universe for synchronisation packets).
+    ///
+    /// # Errors
+    /// Returns an `OutOfSequence` error if a packet is received out of order as detected by the different between
+    /// the packets sequence number and the expected sequence number as specified in ANSI E1.31-2018 Section 6.7.2 Sequence Numbering.
+    ///
+    /// Return a `SourcesExceededError` if the cid of the source is new and would cause the number of sources to exceed the given `source_limit`.
+    fn check_seq_number(
+        &mut self,
+        now: Instant,
+        source_limit: Option<usize>,
+        cid: Uuid,
+        sequence_number: u8,
+        universe: u16,
+        announce_timeout: bool,
+    ) -> Result<()> {
+        // Expire any timed out universes first so that a universe which stopped sending is treated as new and dead sources don't count
+        // towards the source limit. Only entries whose deadline has passed are visited so this doesn't grow with the number of streams.
+        self.check_timeouts(now, announce_timeout)?;
+
+        if !self.sequences.contains_key(&cid) {
+            // New source not previously received from.
+            if source_limit.is_some_and(|limit| self.sequences.len() >= limit) {
+                return Err(SacnError::SourcesExceededError(cid, self.sequences.len()));
             }
+            self.sequences.insert(cid, HashMap::new());
         }
-        None => {
-            // Previously checked that cid is present (and added if not), if None is returned now it indicates that between that check and this
-            // function the cid key value has been removed. This can only happen if there is a memory corruption/thread-interleaving or similar external
-            // event which the receiver cannot be expected to handle / doesn't support.
-            // The rust typing system forces this possibility to be acknowledged when in some languages this possibility would still exist but it would be hidden
-            // within the code.
-            // While a panic!() call here isn't ideal it shows the strength in the explictness of the rust system and points to an area of
-            // potential later improvement within the code by not hiding the problem. As normal if the panic must be caught then rust allows this later on by utilising
-            // a mechanism such as catch unwind https://doc.rust-lang.org/std/panic/fn.catch_unwind.html.
-            // Another possibility here could be to retry the method but this could end with an infinite loop.
-            // Returning an error could also be done but that could confuse error handling as this should not occur and the receiver would be in an inconsistent
-            // state.
-            panic!();
-        }
-    };
-
-    let seq_diff = sequence_number.wrapping_sub(expected_seq.sequence_number) as i8;
 
-    if seq_diff as isize <= E131_SEQ_DIFF_DISCARD_UPPER_BOUND
-        && seq_diff as isize > E131_SEQ_DIFF_DISCARD_LOWER_BOUND
-    {
-        // Reject the out of order packet as per ANSI E1.31-2018 Section 6.7.2 Sequence Numbering.
-        return Err(SacnError::OutOfSequence(
-            sequence_number,
-            expected_seq.sequence_number,
-            seq_diff as isize,
-        ));
-    }
+        let src = self.sequences.entry(cid).or_default();
+
+        if let Some(expected_seq) = src.get_mut(&universe) {
+            let seq_diff = sequence_number.wrapping_sub(expected_seq.sequence_number) as i8;
+
+            if seq_diff as isize <= E131_SEQ_DIFF_DISCARD_UPPER_BOUND
+                && seq_diff as isize > self.discard_lower_bound
+            {
+                // Reject the out of order packet as per ANSI E1.31-2018 Section 6.7.2 Sequence Numbering.
+                return Err(SacnError::OutOfSequence(
+                    cid,
+                    universe,
+                    sequence_number,
+                    expected_seq.sequence_number,
+                    seq_diff as isize,
+                ));
+            }
 
-    match src_sequences.get_mut(&cid) {
-        Some(src) => {
-            // Replace the old sequence number with the new and reset the timeout.
+            // Replace the old sequence number with the new and reset the timeout. The queued deadline is left as is and is
+            // pushed back lazily when it is reached.
+            expected_seq.sequence_number = sequence_number;
+            expected_seq.last_recv = now;
+        } else {
+            // Indicates that this is the first time (or the first time since it timed out) the universe has been received from this source.
+            let deadline = now + self.timeout;
             src.insert(
                 universe,
-                TimedStampedSeqNo::new(sequence_number, Instant::now()),
+                TimedStampedSeqNo::new(sequence_number, now, deadline),
             );
+            self.deadlines.schedule(deadline, (cid, universe));
         }
-        None => {
-            // See previous node regarding panic previously in this method.
-            panic!();
-        }
-    };
 
-    Ok(())
-}
+        Ok(())
+    }
 
-/// Checks the timeouts for all sources and universes for the given sequences.
-/// Removes any universes for which the `last_recv` time was at least the given timeout amount of time ago.
-/// Any sources which have no universes after this operation are also removed.
-///
-/// #Arguments
-///
-/// `src_sequences`: The source sequence numbers to check the timeout of.
-///
-/// timeout: The exclusive length of time permitted since a source last sent on a universe.
-///     If the time elapsed since the last received data that is equal to or great than the timeout then the source is said to have timed out.
-fn check_timeouts(
-    src_sequences: &mut HashMap<Uuid, HashMap<u16, TimedStampedSeqNo>>,
-    timeout: Duration,
-    announce_timeout: bool,
-) -> Result<()> {
-    if announce_timeout {
-        let mut timedout_src_id: Option<Uuid> = None;
-        let mut timedout_uni: Option<u16> = None;
-        for (src_id, universes) in src_sequences.iter_mut() {
-            for (uni, seq_num) in universes.iter() {
-                if seq_num.last_recv.elapsed() >= timeout {
-                    timedout_src_id = Some(*src_id);
-                    timedout_uni = Some(*uni);
-                    break;
-                }
+    /// Removes any universes for which the `last_recv` time was at least the timeout amount of time before the given time.
+    /// Any sources which have no universes after this operation are also removed.
+    ///
+    /// Only the entries whose scheduled deadline has passed are visited. An entry which has received data since it was scheduled is
+    /// rescheduled for its new deadline rather than removed.
+    ///
+    /// # Arguments
+    ///
+    /// now: The current time.
+    ///
+    /// `announce_timeout`: A flag, if true it indicates than a `UniverseTimeout` error should be returned for the first universe found to
+    ///     have timed out, any further timed out universes are left to be found by the next check.
+    fn check_timeouts(&mut self, now: Instant, announce_timeout: bool) -> Result<()> {
+        while let Some((deadline, (cid, universe))) = self.deadlines.pop_expired(now) {
+            let Some(universes) = self.sequences.get_mut(&cid) else {
+                continue; // Source already removed.
+            };
+            let Some(seq_num) = universes.get_mut(&universe) else {
+                continue; // Universe already removed.
+            };
+            if seq_num.scheduled != deadline {
+                continue; // Stale entry, the universe was removed and re-added since this was scheduled.
             }
-            if timedout_uni.is_none() {
-                break;
+
+            let expiry = seq_num.last_recv + self.timeout;
+            if expiry > now {
+                seq_num.scheduled = expiry;
+                self.deadlines.schedule(expiry, (cid, universe));
+                continue;
             }
-        }
-        if let Some(uni_to_remove) = timedout_uni {
-            // If None then it indicates nothing timed out.
-            let src_universes = src_sequences.get_mut(&timedout_src_id.unwrap());
 
-            if let Some(universes) = src_universes {
-                universes.remove(&uni_to_remove);
-                if universes.is_empty() {
-                    // Remove source if all its universes have timed out
-                    src_sequences.remove(&timedout_src_id.unwrap());
-                }
-                return Err(SacnError::UniverseTimeout(
-                    timedout_src_id.unwrap(),
-                    uni_to_remove,
-                ));
+            universes.remove(&universe);
+            if universes.is_empty() {
+                // Remove source if all its universes have timed out
+                self.sequences.remove(&cid);
             }
-        }
 
-        Ok(())
-    } else {
-        for (_src_id, universes) in src_sequences.iter_mut() {
-            universes.retain(|_uni, seq_num| seq_num.last_recv.elapsed() < timeout);
+            if announce_timeout {
+                return Err(SacnError::UniverseTimeout(cid, universe));
+            }
         }
-        // Remove all empty sources.
-        src_sequences.retain(|_src_id, universes| !universes.is_empty());
+
         Ok(())
     }
-}
 
-/// Removes the sequence number entry from the given sequences for the given source cid and universe.
-///
-/// This removes the source entirely if there are no universes left.
-///
-/// # Arguments
-/// `src_sequences`: The sequence numbers for each source and universe.
-///
-/// `src_cid`:       The CID for the source to remove the universe from.
-///
-/// universe:      The universe to remove from the source.
-///
-/// # Errors
-/// Returns a `SourceNotFound` error if the given `src_cid` isn't in the given collection of sources/sequence-numbers.
-///
-/// Returns a `UniverseNotFound` error if the given universe isn't registered to the given source and so cannot be removed.
-fn remove_source_universe_seq(
-    src_sequences: &mut HashMap<Uuid, HashMap<u16, TimedStampedSeqNo>>,
-    src_cid: Uuid,
-    universe: u16,
-) -> Result<()> {
-    match src_sequences.get_mut(&src_cid) {
-        Some(x) => {
-            match x.remove(&universe) {
-                Some(_) => {
-                    if x.is_empty() {
-                        // Remove the source if there are no universes registered to it.
-                        match src_sequences.remove(&src_cid) {
-                            Some(_x) => Ok(()),
-                            None => Err(SacnError::SourceNotFound(src_cid)),
-                        }
-                    } else {
-                        Ok(())
-                    }
-                }
-                None => Err(SacnError::UniverseNotFound(universe)),
-            }
+    /// Removes the sequence number entry for the given source cid and universe.
+    ///
+    /// This removes the source entirely if there are no universes left. Any deadline scheduled for the entry is discarded when reached.
+    ///
+    /// # Arguments
+    /// `src_cid`:       The CID for the source to remove the universe from.
+    ///
+    /// universe:      The universe to remove from the source.
+    ///
+    /// # Errors
+    /// Returns a `SourceNotFound` error if the given `src_cid` isn't in the given collection of sources/sequence-numbers.
+    ///
+    /// Returns a `UniverseNotFound` error if the given universe isn't registered to the given source and so cannot be removed.
+    fn remove(&mut self, src_cid: Uuid, universe: u16) -> Result<()> {
+        let universes = self
+            .sequences
+            .get_mut(&src_cid)
+            .ok_or(SacnError::SourceNotFound(src_cid))?;
+
+        if universes.remove(&universe).is_none() {
+            return Err(SacnError::UniverseNotFound(universe));
+        }
+
+        if universes.is_empty() {
+            // Remove the source if there are no universes registered to it.
+            self.sequences.remove(&src_cid);
         }
-        None => Err(SacnError::SourceNotFound(src_cid)),
+
+        Ok(())
     }
 }
 
@@ -2044,6 +2652,7 @@ fn remove_source_universe_seq(
 /// This function is only valid if both inputs have the same universe, sync addr, `start_code` and the data contains at least the first value (the start code).
 /// If this doesn't hold an error will be returned.
 /// Other merge functions may allow merging different start codes or not check for them.
+/// A `SacnReceiver` keeps waiting data separately per start code so only data with the same start code is passed to the merge function.
 pub fn discard_lowest_priority_then_previous(i: &DMXData, n: &DMXData) -> Result<DMXData> {
     if i.priority > n.priority {
         return Ok(i.clone());
@@ -2063,6 +2672,7 @@ pub fn discard_lowest_priority_then_previous(i: &DMXData, n: &DMXData) -> Result
 /// This function is only valid if both inputs have the same universe, sync addr, `start_code` and the data contains at least the first value (the start code).
 /// If this doesn't hold an error will be returned.
 /// Other merge functions may allow merging different start codes or not check for them.
+/// A `SacnReceiver` keeps waiting data separately per start code so only data with the same start code is passed to the merge function.
 pub fn htp_dmx_merge(i: &DMXData, n: &DMXData) -> Result<DMXData> {
     if i.values.is_empty()
         || n.values.is_empty()
@@ -2120,8 +2730,12 @@ pub fn htp_dmx_merge(i: &DMXData, n: &DMXData) -> Result<DMXData> {
 mod test {
     use super::*;
 
+    use crate::clock::MockClock;
+    use crate::transport::MemoryNetwork;
+    use crate::types::Priority;
+
     use std::borrow::Cow;
-    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
     use std::time::Instant;
 
     use uuid::Uuid;
@@ -2549,7 +3163,9 @@ mod test {
 
         let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
 
-        dmx_rcv.listen_universes(&[UNIVERSE1]).unwrap();
+        dmx_rcv
+            .listen_universes(&[Universe::try_from(UNIVERSE1).unwrap()])
+            .unwrap();
 
         let src_cid: Uuid = Uuid::from_bytes([
             0xef, 0x07, 0xc8, 0xdd, 0x00, 0x64, 0x44, 0x01, 0xa3, 0xa2, 0x45, 0x9e, 0xf8, 0xe6,
@@ -2632,7 +3248,9 @@ mod test {
         for i in SEQ_NUM_LOWER_BOUND..SEQ_NUM_UPPER_BOUND {
             // Create the receiver.
             let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
-            dmx_rcv.listen_universes(&[UNIVERSE1]).unwrap();
+            dmx_rcv
+                .listen_universes(&[Universe::try_from(UNIVERSE1).unwrap()])
+                .unwrap();
 
             // Generate the packets used to put the receiver in a known start state.
             let data_packet =
@@ -2743,7 +3361,9 @@ mod test {
         for i in SEQ_NUM_LOWER_BOUND..SEQ_NUM_UPPER_BOUND {
             // Create the receiver.
             let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
-            dmx_rcv.listen_universes(&[SYNC_ADDR]).unwrap();
+            dmx_rcv
+                .listen_universes(&[Universe::try_from(SYNC_ADDR).unwrap()])
+                .unwrap();
 
             // Generate the packets used to put the receiver in a known start state.
             let sync_packet =
@@ -2828,7 +3448,9 @@ mod test {
 
         let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
 
-        dmx_rcv.listen_universes(&[UNIVERSE1]).unwrap();
+        dmx_rcv
+            .listen_universes(&[Universe::try_from(UNIVERSE1).unwrap()])
+            .unwrap();
 
         let src_cid: Uuid = Uuid::from_bytes([
             0xef, 0x07, 0xc8, 0xdd, 0x00, 0x64, 0x44, 0x01, 0xa3, 0xa2, 0x45, 0x9e, 0xf8, 0xe6,
@@ -2888,7 +3510,9 @@ mod test {
 
         let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
 
-        dmx_rcv.listen_universes(&[UNIVERSE1]).unwrap();
+        dmx_rcv
+            .listen_universes(&[Universe::try_from(UNIVERSE1).unwrap()])
+            .unwrap();
 
         let src_cid: Uuid = Uuid::from_bytes([
             0xef, 0x07, 0xc8, 0xdd, 0x00, 0x64, 0x44, 0x01, 0xa3, 0xa2, 0x45, 0x9e, 0xf8, 0xe6,
@@ -2939,7 +3563,9 @@ mod test {
 
         let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
 
-        dmx_rcv.listen_universes(&[UNIVERSE1]).unwrap();
+        dmx_rcv
+            .listen_universes(&[Universe::try_from(UNIVERSE1).unwrap()])
+            .unwrap();
 
         let src_cid: Uuid = Uuid::from_bytes([
             0xef, 0x07, 0xc8, 0xdd, 0x00, 0x64, 0x44, 0x01, 0xa3, 0xa2, 0x45, 0x9e, 0xf8, 0xe6,
@@ -2991,7 +3617,9 @@ mod test {
 
         let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
 
-        dmx_rcv.listen_universes(&[UNIVERSE]).unwrap();
+        dmx_rcv
+            .listen_universes(&[Universe::try_from(UNIVERSE).unwrap()])
+            .unwrap();
 
         let src_cid: Uuid = Uuid::from_bytes([
             0xef, 0x07, 0xc8, 0xdd, 0x00, 0x64, 0x44, 0x01, 0xa3, 0xa2, 0x45, 0x9e, 0xf8, 0xe6,
@@ -3044,7 +3672,12 @@ mod test {
 
         let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
 
-        dmx_rcv.listen_universes(&[UNIVERSE1, UNIVERSE2]).unwrap();
+        dmx_rcv
+            .listen_universes(&[
+                Universe::try_from(UNIVERSE1).unwrap(),
+                Universe::try_from(UNIVERSE2).unwrap(),
+            ])
+            .unwrap();
 
         let src_cid: Uuid = Uuid::from_bytes([
             0xef, 0x07, 0xc8, 0xdd, 0x00, 0x64, 0x44, 0x01, 0xa3, 0xa2, 0x45, 0x9e, 0xf8, 0xe6,
@@ -3097,7 +3730,10 @@ mod test {
         let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
 
         dmx_rcv
-            .listen_universes(&[SYNC_ADDR_1, SYNC_ADDR_2])
+            .listen_universes(&[
+                Universe::try_from(SYNC_ADDR_1).unwrap(),
+                Universe::try_from(SYNC_ADDR_2).unwrap(),
+            ])
             .unwrap();
 
         let src_cid: Uuid = Uuid::from_bytes([
@@ -3183,6 +3819,35 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_set_accept_broadcast() {
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
+
+        assert!(!dmx_rcv.accept_broadcast(), "Broadcast accepted by default");
+
+        dmx_rcv.set_accept_broadcast(true).unwrap();
+
+        assert!(dmx_rcv.accept_broadcast(), "Broadcast not accepted");
+    }
+
+    #[test]
+    fn test_set_accept_broadcast_ipv6() {
+        let addr = SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        let mut dmx_rcv = SacnReceiver::builder()
+            .bind(addr)
+            .multicast_enabled(false)
+            .build()
+            .unwrap();
+
+        match dmx_rcv.set_accept_broadcast(true) {
+            Err(SacnError::IpVersionError()) => {
+                // Expected, IPv6 has no broadcast.
+            }
+            x => panic!("Unexpected result {x:?}"),
+        }
+    }
+
     #[test]
     fn test_clear_waiting_data() {
         let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
@@ -3323,7 +3988,8 @@ mod test {
 
         let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
         let mut rcv = SacnReceiver::with_ip(addr, None).unwrap();
-        rcv.listen_universes(&[UNIVERSE]).unwrap();
+        rcv.listen_universes(&[Universe::try_from(UNIVERSE).unwrap()])
+            .unwrap();
 
         // Initial sequence number of new universe is 255 so send a valid new sequnce number to start.
         let pkt = generate_data_packet_framing_layer_seq_num(UNIVERSE, 21u8);
@@ -3359,4 +4025,553 @@ mod test {
             res1
         );
     }
+
+    #[test]
+    fn test_universe_set_insert_remove() {
+        let mut set = UniverseSet::new();
+
+        assert!(set.insert(0));
+        assert!(set.insert(64));
+        assert!(set.insert(E131_MAX_MULTICAST_UNIVERSE));
+        assert!(set.insert(u16::MAX));
+        assert!(!set.insert(64), "Duplicate universe inserted");
+
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(64));
+        assert!(!set.contains(63));
+        assert!(!set.contains(65));
+        assert_eq!(
+            set.to_vec(),
+            vec![0, 64, E131_MAX_MULTICAST_UNIVERSE, u16::MAX]
+        );
+
+        assert!(set.remove(64));
+        assert!(!set.remove(64), "Universe removed twice");
+        assert!(!set.contains(64));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn test_listen_mute_universe_is_listening() {
+        const UNIVERSES: [Universe; 3] =
+            [Universe::MIN, Universe::new(200).unwrap(), Universe::MAX];
+
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        let mut dmx_rcv = SacnReceiver::with_ip(addr, None).unwrap();
+
+        dmx_rcv.listen_universes(&UNIVERSES).unwrap();
+        dmx_rcv.listen_universes(&UNIVERSES).unwrap();
+
+        for u in UNIVERSES {
+            assert!(dmx_rcv.is_listening(u));
+        }
+        assert!(dmx_rcv.is_listening(Universe::DISCOVERY));
+        assert_eq!(dmx_rcv.universes.len(), UNIVERSES.len() + 1);
+
+        dmx_rcv.mute_universe(UNIVERSES[1]).unwrap();
+        assert!(!dmx_rcv.is_listening(UNIVERSES[1]));
+        match dmx_rcv.mute_universe(UNIVERSES[1]) {
+            Err(SacnError::UniverseNotFound(200)) => {}
+            x => panic!("Unexpected result muting an unregistered universe: {x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_deadline_queue_pops_in_deadline_order() {
+        let start = Instant::now();
+        let mut queue: DeadlineQueue<u16> = DeadlineQueue::new();
+        queue.schedule(start + Duration::from_secs(3), 3);
+        queue.schedule(start + Duration::from_secs(1), 1);
+        queue.schedule(start + Duration::from_secs(2), 2);
+
+        assert!(queue.pop_expired(start).is_none());
+
+        let now = start + Duration::from_secs(2);
+        assert_eq!(queue.pop_expired(now).map(|(_, k)| k), Some(1));
+        assert_eq!(queue.pop_expired(now).map(|(_, k)| k), Some(2));
+        assert!(queue.pop_expired(now).is_none());
+        assert_eq!(
+            queue
+                .pop_expired(start + Duration::from_secs(3))
+                .map(|(_, k)| k),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_seq_timeout_only_expired_universe_removed() {
+        let src_cid = Uuid::new_v4();
+        let mut seqs = SourceSequences::new(
+            E131_NETWORK_DATA_LOSS_TIMEOUT,
+            E131_SEQ_DIFF_DISCARD_LOWER_BOUND,
+        );
+
+        let now = Instant::now();
+        seqs.check_seq_number(now, None, src_cid, 0, 1, false)
+            .unwrap();
+        seqs.check_seq_number(now, None, src_cid, 0, 2, false)
+            .unwrap();
+
+        // Universe 2 receives more data a second later so shouldn't time out with universe 1.
+        let base = seqs.sequences[&src_cid][&1].last_recv;
+        seqs.sequences
+            .get_mut(&src_cid)
+            .unwrap()
+            .get_mut(&2)
+            .unwrap()
+            .last_recv = base + Duration::from_secs(1);
+
+        match seqs.check_timeouts(base + E131_NETWORK_DATA_LOSS_TIMEOUT, true) {
+            Err(SacnError::UniverseTimeout(cid, 1)) => assert_eq!(cid, src_cid),
+            x => panic!("Expected universe 1 to time out, got {x:?}"),
+        }
+        seqs.check_timeouts(base + E131_NETWORK_DATA_LOSS_TIMEOUT, true)
+            .unwrap();
+        assert!(seqs.sequences[&src_cid].contains_key(&2));
+
+        // The refreshed universe is rescheduled and times out at its new deadline.
+        match seqs.check_timeouts(
+            base + Duration::from_secs(1) + E131_NETWORK_DATA_LOSS_TIMEOUT,
+            true,
+        ) {
+            Err(SacnError::UniverseTimeout(cid, 2)) => assert_eq!(cid, src_cid),
+            x => panic!("Expected universe 2 to time out, got {x:?}"),
+        }
+        assert!(seqs.sequences.is_empty());
+        assert!(seqs.deadlines.heap.is_empty());
+    }
+
+    #[test]
+    fn test_seq_timeout_removed_and_readded_universe() {
+        let src_cid = Uuid::new_v4();
+        let mut seqs = SourceSequences::new(
+            E131_NETWORK_DATA_LOSS_TIMEOUT,
+            E131_SEQ_DIFF_DISCARD_LOWER_BOUND,
+        );
+
+        let now = Instant::now();
+        seqs.check_seq_number(now, None, src_cid, 0, 1, false)
+            .unwrap();
+        seqs.remove(src_cid, 1).unwrap();
+        seqs.check_seq_number(now, None, src_cid, 10, 1, false)
+            .unwrap();
+
+        // The deadline from before the universe was removed must not remove the re-added universe early or announce it twice.
+        let last_recv = seqs.sequences[&src_cid][&1].last_recv;
+        let mut timeouts = 0;
+        while let Err(e) = seqs.check_timeouts(last_recv + E131_NETWORK_DATA_LOSS_TIMEOUT, true) {
+            match e {
+                SacnError::UniverseTimeout(_, 1) => timeouts += 1,
+                x => panic!("Unexpected error: {x:?}"),
+            }
+        }
+        assert_eq!(timeouts, 1);
+        assert!(seqs.sequences.is_empty());
+        assert!(seqs.deadlines.heap.is_empty());
+    }
+
+    #[test]
+    fn test_seq_timed_out_source_not_counted_towards_limit() {
+        let mut seqs =
+            SourceSequences::new(Duration::from_millis(1), E131_SEQ_DIFF_DISCARD_LOWER_BOUND);
+        let start = Instant::now();
+
+        seqs.check_seq_number(start, Some(1), Uuid::new_v4(), 0, 1, false)
+            .unwrap();
+
+        // The first source has timed out so the second is allowed.
+        seqs.check_seq_number(
+            start + Duration::from_millis(5),
+            Some(1),
+            Uuid::new_v4(),
+            0,
+            1,
+            false,
+        )
+        .unwrap();
+        assert_eq!(seqs.sequences.len(), 1);
+    }
+
+    /// Creates a receiver on a memory network which takes the time used for timeouts from the given clock.
+    fn mock_clock_receiver(clock: &MockClock) -> SacnReceiver {
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        let transport = MemoryNetwork::new().bind(addr).unwrap();
+        SacnReceiver::with_clock(transport, None, clock.clone()).unwrap()
+    }
+
+    #[test]
+    fn test_mock_clock_universe_data_loss_timeout() {
+        let clock = MockClock::new();
+        let mut dmx_rcv = mock_clock_receiver(&clock);
+        dmx_rcv.set_announce_timeout(true);
+        dmx_rcv
+            .listen_universes(&[Universe::try_from(1).unwrap()])
+            .unwrap();
+
+        let src_cid = Uuid::new_v4();
+        dmx_rcv
+            .handle_data_packet(src_cid, generate_data_packet_framing_layer_seq_num(1, 0))
+            .unwrap();
+
+        clock.advance(E131_NETWORK_DATA_LOSS_TIMEOUT - Duration::from_millis(1));
+        match dmx_rcv.recv(Some(Duration::from_secs(0))) {
+            Err(SacnError::Io(e)) if e.kind() == io::ErrorKind::WouldBlock => {
+                // Expected, the universe hasn't timed out yet.
+            }
+            x => panic!("Unexpected result {x:?}"),
+        }
+
+        clock.advance(Duration::from_millis(1));
+        match dmx_rcv.recv(Some(Duration::from_secs(0))) {
+            Err(SacnError::UniverseTimeout(cid, 1)) => assert_eq!(cid, src_cid),
+            x => panic!("Expected universe 1 to time out, got {x:?}"),
+        }
+
+        // The universe is treated as new after timing out so an earlier sequence number is accepted.
+        dmx_rcv
+            .handle_data_packet(src_cid, generate_data_packet_framing_layer_seq_num(1, 250))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_mock_clock_waiting_data_timeout() {
+        let clock = MockClock::new();
+        let mut dmx_rcv = mock_clock_receiver(&clock);
+        dmx_rcv
+            .listen_universes(&[
+                Universe::try_from(1).unwrap(),
+                Universe::try_from(2).unwrap(),
+            ])
+            .unwrap();
+
+        let mut data_pkt = generate_data_packet_framing_layer_seq_num(1, 0);
+        data_pkt.synchronization_address = 2;
+        let key = (1, data_pkt.data.property_values[0]);
+        assert!(
+            dmx_rcv
+                .handle_data_packet(Uuid::new_v4(), data_pkt)
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(dmx_rcv.waiting_data[&key].recv_timestamp, clock.now());
+
+        clock.advance(E131_NETWORK_DATA_LOSS_TIMEOUT - Duration::from_millis(1));
+        dmx_rcv.check_waiting_data_timeouts();
+        assert!(dmx_rcv.waiting_data.contains_key(&key));
+
+        clock.advance(Duration::from_millis(1));
+        dmx_rcv.check_waiting_data_timeouts();
+        assert!(dmx_rcv.waiting_data.is_empty());
+    }
+
+    #[test]
+    fn test_mock_clock_waiting_data_not_retrieved_after_timeout() {
+        let clock = MockClock::new();
+        let mut dmx_rcv = mock_clock_receiver(&clock);
+        dmx_rcv
+            .listen_universes(&[
+                Universe::try_from(1).unwrap(),
+                Universe::try_from(2).unwrap(),
+            ])
+            .unwrap();
+
+        let mut data_pkt = generate_data_packet_framing_layer_seq_num(1, 0);
+        data_pkt.synchronization_address = 2;
+        dmx_rcv
+            .handle_data_packet(Uuid::new_v4(), data_pkt)
+            .unwrap();
+
+        // The sync packet arrives after the data has timed out but before the timeouts have been checked.
+        clock.advance(E131_NETWORK_DATA_LOSS_TIMEOUT);
+        assert!(dmx_rcv.rtrv_waiting_data(2).is_empty());
+    }
+
+    #[test]
+    fn test_mock_clock_discovered_source_timeout() {
+        let clock = MockClock::new();
+        let mut dmx_rcv = mock_clock_receiver(&clock);
+
+        dmx_rcv.handle_universe_discovery_packet(
+            Uuid::new_v4(),
+            generate_discovery_packet_framing_layer("Test Src"),
+        );
+
+        clock.advance(UNIVERSE_DISCOVERY_SOURCE_TIMEOUT - Duration::from_millis(1));
+        assert_eq!(dmx_rcv.get_discovered_sources().len(), 1);
+
+        clock.advance(Duration::from_millis(1));
+        assert!(dmx_rcv.get_discovered_sources().is_empty());
+    }
+
+    /// Creates a discovery packet framing layer for a single page advert of universes 1 to 3 from a source with the given name.
+    fn generate_discovery_packet_framing_layer(
+        name: &str,
+    ) -> UniverseDiscoveryPacketFramingLayer<'_> {
+        UniverseDiscoveryPacketFramingLayer {
+            source_name: name.into(),
+            data: UniverseDiscoveryPacketUniverseDiscoveryLayer {
+                page: 0,
+                last_page: 0,
+                universes: vec![1, 2, 3].into(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_discovered_source_kept_between_adverts() {
+        let clock = MockClock::new();
+        let mut dmx_rcv = mock_clock_receiver(&clock);
+        let src_cid = Uuid::new_v4();
+
+        dmx_rcv.handle_universe_discovery_packet(
+            src_cid,
+            generate_discovery_packet_framing_layer("Test Src"),
+        );
+
+        // Sources advertise every E131_UNIVERSE_DISCOVERY_INTERVAL so the source must still be known just before the next advert.
+        clock.advance(E131_UNIVERSE_DISCOVERY_INTERVAL);
+        assert_eq!(dmx_rcv.get_discovered_sources().len(), 1);
+
+        dmx_rcv.handle_universe_discovery_packet(
+            src_cid,
+            generate_discovery_packet_framing_layer("Test Src"),
+        );
+        clock.advance(E131_UNIVERSE_DISCOVERY_INTERVAL);
+        assert_eq!(dmx_rcv.get_discovered_sources().len(), 1);
+    }
+
+    #[test]
+    fn test_timing_profile_data_loss_timeout() {
+        let clock = MockClock::new();
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        let transport = MemoryNetwork::new().bind(addr).unwrap();
+        let timing = TimingProfile::strict();
+        let mut dmx_rcv =
+            SacnReceiver::with_timing(transport, None, clock.clone(), timing).unwrap();
+        dmx_rcv.set_announce_timeout(true);
+        dmx_rcv
+            .listen_universes(&[Universe::try_from(1).unwrap()])
+            .unwrap();
+
+        let src_cid = Uuid::new_v4();
+        dmx_rcv
+            .handle_data_packet(src_cid, generate_data_packet_framing_layer_seq_num(1, 0))
+            .unwrap();
+
+        clock.advance(timing.data_loss_timeout);
+        match dmx_rcv.recv(Some(Duration::from_secs(0))) {
+            Err(SacnError::UniverseTimeout(cid, 1)) => assert_eq!(cid, src_cid),
+            x => panic!("Expected universe 1 to time out, got {x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_timing_profile_discovery_source_timeout() {
+        let clock = MockClock::new();
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        let transport = MemoryNetwork::new().bind(addr).unwrap();
+        let timing = TimingProfile::lenient();
+        let mut dmx_rcv =
+            SacnReceiver::with_timing(transport, None, clock.clone(), timing).unwrap();
+
+        dmx_rcv.handle_universe_discovery_packet(
+            Uuid::new_v4(),
+            generate_discovery_packet_framing_layer("Test Src"),
+        );
+
+        clock.advance(UNIVERSE_DISCOVERY_SOURCE_TIMEOUT);
+        assert_eq!(dmx_rcv.get_discovered_sources().len(), 1);
+
+        clock.advance(timing.discovery_source_timeout - UNIVERSE_DISCOVERY_SOURCE_TIMEOUT);
+        assert!(dmx_rcv.get_discovered_sources().is_empty());
+    }
+
+    #[test]
+    fn test_timing_profile_seq_discard_window() {
+        let src_cid = Uuid::new_v4();
+        let now = Instant::now();
+        let mut seqs = SourceSequences::new(E131_NETWORK_DATA_LOSS_TIMEOUT, -5);
+
+        seqs.check_seq_number(now, None, src_cid, 10, 1, false)
+            .unwrap();
+
+        // A difference of -4 is within the window so is discarded.
+        match seqs.check_seq_number(now, None, src_cid, 6, 1, false) {
+            Err(SacnError::OutOfSequence(cid, 1, 6, 10, -4)) => assert_eq!(cid, src_cid),
+            x => panic!("Expected packet to be discarded, got {x:?}"),
+        }
+
+        // A difference of -5 is outwith the window so is accepted.
+        seqs.check_seq_number(now, None, src_cid, 5, 1, false)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_invalid_timing_profile_rejected() {
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        let transport = MemoryNetwork::new().bind(addr).unwrap();
+        let timing = TimingProfile {
+            discovery_source_timeout: E131_UNIVERSE_DISCOVERY_INTERVAL,
+            ..TimingProfile::default()
+        };
+        match SacnReceiver::with_timing(transport, None, SystemClock, timing) {
+            Err(SacnError::InvalidTimingProfile(_)) => {
+                // Expected, sources would be lost between adverts.
+            }
+            x => panic!("Unexpected result {x:?}"),
+        }
+    }
+
+    /// Returns an address on a memory network with the given last byte.
+    fn memory_addr(last_byte: u8, port: u16) -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, last_byte)), port)
+    }
+
+    #[test]
+    fn test_builder_applies_configuration() {
+        let network = MemoryNetwork::new();
+        let dmx_rcv = SacnReceiver::builder()
+            .transport(
+                network
+                    .bind(memory_addr(1, ACN_SDT_MULTICAST_PORT))
+                    .unwrap(),
+            )
+            .source_limit(Some(2))
+            .announce_source_discovery(true)
+            .announce_stream_termination(true)
+            .announce_timeout(true)
+            .process_preview_data(true)
+            .multicast_enabled(false)
+            .timing(TimingProfile::strict())
+            .universes(&[
+                Universe::try_from(1).unwrap(),
+                Universe::try_from(5).unwrap(),
+            ])
+            .build()
+            .unwrap();
+
+        assert!(dmx_rcv.get_announce_source_discovery());
+        assert!(dmx_rcv.get_announce_stream_termination());
+        assert!(dmx_rcv.get_announce_timeout());
+        assert!(dmx_rcv.process_preview_data);
+        assert!(!dmx_rcv.is_multicast_enabled());
+        assert_eq!(dmx_rcv.source_limit, Some(2));
+        assert_eq!(dmx_rcv.timing, TimingProfile::strict());
+        assert!(dmx_rcv.is_listening(Universe::try_from(1).unwrap()));
+        assert!(dmx_rcv.is_listening(Universe::try_from(5).unwrap()));
+        assert!(dmx_rcv.is_listening(Universe::DISCOVERY));
+    }
+
+    #[test]
+    fn test_builder_source_limit_0() {
+        match SacnReceiver::builder().source_limit(Some(0)).build() {
+            Err(SacnError::SourceLimitZero()) => {}
+            x => panic!("Expected SourceLimitZero, got {x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_builder_ipv6_only_rejected_for_ipv4() {
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        match SacnReceiver::builder().bind(addr).ipv6_only(true).build() {
+            Err(SacnError::InvalidConfiguration(_)) => {}
+            x => panic!("Expected InvalidConfiguration, got {x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_builder_ipv6_only_rejected_for_transport() {
+        let transport = MemoryNetwork::new()
+            .bind(memory_addr(1, ACN_SDT_MULTICAST_PORT))
+            .unwrap();
+        match SacnReceiver::builder()
+            .transport(transport)
+            .ipv6_only(true)
+            .build()
+        {
+            Err(SacnError::InvalidConfiguration(_)) => {}
+            x => panic!("Expected InvalidConfiguration, got {x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_builder_recv_buffer_size() {
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
+        SacnReceiver::builder()
+            .bind(addr)
+            .recv_buffer_size(1 << 16)
+            .build()
+            .unwrap();
+
+        match SacnReceiver::builder()
+            .bind(addr)
+            .recv_buffer_size(0)
+            .build()
+        {
+            Err(SacnError::InvalidConfiguration(_)) => {}
+            x => panic!("Expected InvalidConfiguration, got {x:?}"),
+        }
+
+        let transport = MemoryNetwork::new()
+            .bind(memory_addr(1, ACN_SDT_MULTICAST_PORT))
+            .unwrap();
+        match SacnReceiver::builder()
+            .transport(transport)
+            .recv_buffer_size(1 << 16)
+            .build()
+        {
+            Err(SacnError::InvalidConfiguration(_)) => {}
+            x => panic!("Expected InvalidConfiguration, got {x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_builder_packet_filter() {
+        /// Only allows data packets with at least the default priority.
+        fn default_priority_or_above(_cid: &Uuid, data: &E131RootLayerData<'_>) -> bool {
+            !matches!(data, DataPacket(d) if d.priority < E131_DEFAULT_PRIORITY)
+        }
+
+        let network = MemoryNetwork::new();
+        let mut dmx_rcv = SacnReceiver::builder()
+            .transport(
+                network
+                    .bind(memory_addr(1, ACN_SDT_MULTICAST_PORT))
+                    .unwrap(),
+            )
+            .packet_filter(default_priority_or_above)
+            .universes(&[Universe::try_from(1).unwrap()])
+            .build()
+            .unwrap();
+
+        let src = crate::source::SacnSource::with_transport(
+            "Filtered Source",
+            network
+                .bind(memory_addr(2, ACN_SDT_MULTICAST_PORT + 1))
+                .unwrap(),
+        )
+        .unwrap();
+        src.register_universe(Universe::try_from(1).unwrap())
+            .unwrap();
+
+        src.send(
+            &[Universe::try_from(1).unwrap()],
+            &[0, 1],
+            Some(Priority::try_from(E131_DEFAULT_PRIORITY - 1).unwrap()),
+            None,
+            None,
+        )
+        .unwrap();
+        src.send(
+            &[Universe::try_from(1).unwrap()],
+            &[0, 2],
+            Some(Priority::DEFAULT),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let received = dmx_rcv.recv(Some(Duration::from_secs(2))).unwrap();
+        assert_eq!(received[0].values, vec![0, 2]);
+    }
 }
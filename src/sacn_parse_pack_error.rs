@@ -8,63 +8,178 @@
 // This file was created as part of a University of St Andrews Computer Science BSC Senior Honours Dissertation Project.
 
 //! The errors used within the SacnLibrary specifically those related to parsing and packeting packets received/sent on the network.
+//!
+//! Errors from parsing a packet carry a `ParseErrorContext` saying which layer and field of the packet was invalid, the offset of the
+//! field in bytes and the value expected compared to the value found so that a logged error is enough to diagnose a malformed packet.
+//!
+//! ```
+//! use sacn::error::errors::SacnError;
+//! use sacn::packet::AcnRootLayerProtocol;
+//! use sacn::sacn_parse_pack_error::PacketLayer;
+//!
+//! // A packet with an invalid Preamble Size.
+//! let buf = [0x00, 0x11, 0x00, 0x00, 0x41, 0x53, 0x43, 0x2d, 0x45, 0x31, 0x2e, 0x31, 0x37, 0x00, 0x00, 0x00];
+//!
+//! match AcnRootLayerProtocol::parse(&buf) {
+//!     Err(SacnError::SacnParsePackError(e)) => {
+//!         let context = e.context().unwrap();
+//!         assert_eq!(context.layer, PacketLayer::Root);
+//!         assert_eq!(context.field, "Preamble Size");
+//!         assert_eq!(context.offset, 0);
+//!         assert_eq!(context.expected, "0x0010");
+//!         assert_eq!(context.actual, "0x0011");
+//!     }
+//!     x => panic!("Expected a parse error, got {x:?}"),
+//! }
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt;
 
 use thiserror::Error;
 use uuid::Uuid;
 
-#[derive(Debug, Error)]
+/// The layers of an ANSI E1.31-2018 packet, as per ANSI E1.31-2018 Section 4.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PacketLayer {
+    /// The ACN root layer including the preamble, ANSI E1.31-2018 Section 5.
+    Root,
+
+    /// The framing layer of a data, synchronization or universe discovery packet, ANSI E1.31-2018 Section 6.
+    Framing,
+
+    /// The Device Management Protocol (DMP) layer of a data packet, ANSI E1.31-2018 Section 7.
+    Dmp,
+
+    /// The universe discovery layer of a universe discovery packet, ANSI E1.31-2018 Section 8.
+    UniverseDiscovery,
+}
+
+impl fmt::Display for PacketLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketLayer::Root => write!(f, "root"),
+            PacketLayer::Framing => write!(f, "framing"),
+            PacketLayer::Dmp => write!(f, "DMP"),
+            PacketLayer::UniverseDiscovery => write!(f, "universe discovery"),
+        }
+    }
+}
+
+/// Where a packet failed to parse and why.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseErrorContext {
+    /// The layer of the packet containing the invalid field.
+    pub layer: PacketLayer,
+
+    /// The name of the invalid field as used in ANSI E1.31-2018 Section 4, e.g. "Preamble Size".
+    pub field: &'static str,
+
+    /// The offset in bytes of the invalid field from the start of the buffer parsed. When parsed using
+    /// `AcnRootLayerProtocol::parse` this is the offset within the datagram.
+    pub offset: usize,
+
+    /// A description of the value expected.
+    pub expected: String,
+
+    /// A description of the value found.
+    pub actual: String,
+}
+
+impl ParseErrorContext {
+    /// Creates a new context for the given field.
+    ///
+    /// # Arguments
+    /// layer: The layer of the packet containing the field.
+    ///
+    /// field: The name of the field.
+    ///
+    /// offset: The offset in bytes of the field from the start of the buffer parsed.
+    ///
+    /// expected: The value expected.
+    ///
+    /// actual: The value found.
+    pub fn new(
+        layer: PacketLayer,
+        field: &'static str,
+        offset: usize,
+        expected: impl fmt::Display,
+        actual: impl fmt::Display,
+    ) -> ParseErrorContext {
+        ParseErrorContext {
+            layer,
+            field,
+            offset,
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} layer field {} at offset {}, expected {}, found {}",
+            self.layer, self.field, self.offset, self.expected, self.actual
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
 pub enum ParsePacketError {
     /// When parsing packet invalid data encountered.
     ///
     /// # Arguments
-    /// msg: A message providing further details (if any) as to what data was invalid.
-    #[error("Error when parsing data into packet, msg: {0}")]
-    ParseInvalidData(String),
+    /// context: Which field contained invalid data.
+    #[error("Error when parsing data into packet, {0}")]
+    ParseInvalidData(ParseErrorContext),
 
     /// Attempted to parse a priority value that is outwith the allowed range of [0, `E131_MAX_PRIORITY`].
     /// As per ANSI E1.31-2018 Section 6.2.3
     ///
     /// # Arguments
-    /// u8: the priority value that was invalid.
+    /// context: Where the priority value that was invalid was found and its value.
     #[error(
-        "Attempted to parse a priority value that is outwith the allowed range of [0, 200]: {0}"
+        "Attempted to parse a priority value that is outwith the allowed range of [0, 200], {0}"
     )]
-    ParseInvalidPriority(u8),
+    ParseInvalidPriority(ParseErrorContext),
 
     /// Attempted to parse a page value that is invalid - e.g. the page value is higher than the `last_page` value.
     ///
     /// # Arguments
-    /// msg: A message providing further details (if any) as to why the page was invalid.
-    #[error("Error when parsing page value, msg: {0}")]
-    ParseInvalidPage(String),
+    /// context: Where the page value that was invalid was found and its value.
+    #[error("Error when parsing page value, {0}")]
+    ParseInvalidPage(ParseErrorContext),
 
     /// Attempted to parse a sync address value that is outwith the allowed range of [0, `E131_MAX_MULTICAST_UNIVERSE`].
     /// As per ANSI E1.31-2018 Section 9.1.1.
     ///
     /// # Arguments
-    /// u16: the synchronisation address that was invalid.
+    /// context: Where the synchronisation address that was invalid was found and its value.
     #[error(
-        "Attempted to parse a sync_addr value that is outwith the allowed range of [0, 63999]: {0}"
+        "Attempted to parse a sync_addr value that is outwith the allowed range of [0, 63999], {0}"
     )]
-    ParseInvalidSyncAddr(u16),
+    ParseInvalidSyncAddr(ParseErrorContext),
 
     /// Attempted to parse a universe value that is outwith the allowed range of [1, `E131_MAX_MULTICAST_UNIVERSE`].
     /// As per ANSI E1.31-2018 Section 9.1.1.
     ///
     /// # Arguments
-    /// u16: the universe value that was invalid.
+    /// context: Where the universe value that was invalid was found and its value.
     #[error(
-        "Attempted to parse a universe value that is outwith the allowed range of [1, 63999]: {0}"
+        "Attempted to parse a universe value that is outwith the allowed range of [1, 63999], {0}"
     )]
-    ParseInvalidUniverse(u16),
+    ParseInvalidUniverse(ParseErrorContext),
 
     /// Attempted to parse a packet with an invalid ordering of universes.
     /// For example a discovery packet where the universes aren't correctly ordered in assending order.
     ///
     /// # Arguments
-    /// msg: A message providing further details (if any) as to why the universe ordering was invalid.
-    #[error("Attempted to parse a packet with an invalid ordering of universes, msg: {0}")]
-    ParseInvalidUniverseOrder(String),
+    /// context: Where the first universe out of order was found and its value.
+    #[error("Attempted to parse a packet with an invalid ordering of universes, {0}")]
+    ParseInvalidUniverseOrder(ParseErrorContext),
 
     /// When packing a packet into a buffer invalid data encountered.
     ///
@@ -83,30 +198,30 @@ pub enum ParsePacketError {
     /// Supplied buffer does not contain enough data.
     ///
     /// # Arguments
-    /// msg: A message providing further details (if any) as to why there was insufficient data for parsing.
-    #[error("Supplied buffer does not contain enough data, msg: {0}")]
-    ParseInsufficientData(String),
+    /// context: The field which couldn't be parsed, the amount of data expected and the amount found.
+    #[error("Supplied buffer does not contain enough data, {0}")]
+    ParseInsufficientData(ParseErrorContext),
 
     /// Received PDU flags are invalid for parsing.
     ///
     /// # Arguments
-    /// flags: The flags that were found which are invalid.
-    #[error("PDU Flags {0:#b} are invalid for parsing")]
-    ParsePduInvalidFlags(u8),
+    /// context: Where the flags that were invalid were found and their value.
+    #[error("PDU Flags are invalid for parsing, {0}")]
+    ParsePduInvalidFlags(ParseErrorContext),
 
     /// Received PDU length is invalid.
     ///
     /// # Arguments
-    /// len: The length provided in the Pdu which is invalid.
-    #[error("PDU Length {0} is invalid")]
-    PduInvalidLength(usize),
+    /// context: Where the length that was invalid was found and its value.
+    #[error("PDU Length is invalid, {0}")]
+    PduInvalidLength(ParseErrorContext),
 
     /// Received PDU vector is invalid/unsupported by this library.
     ///
     /// # Arguments
-    /// vec: The vector parsed which is invalid / cannot be used.
-    #[error("Vector {0:#x} not supported")]
-    PduInvalidVector(u32),
+    /// context: Where the vector that was invalid / cannot be used was found and its value.
+    #[error("Vector not supported, {0}")]
+    PduInvalidVector(ParseErrorContext),
 
     /// Error parsing the received UUID.
     ///
@@ -118,14 +233,66 @@ pub enum ParsePacketError {
     /// Error parsing received UTF8 string.
     ///
     /// # Arguments
-    /// msg: A message providing further details (if any) as to why the string couldn't be parsed.
-    #[error("Error parsing received UTF8 string, msg: {0}")]
-    Utf8Error(String),
+    /// context: The field containing the string and the offset of the first invalid byte.
+    #[error("Error parsing received UTF8 string, {0}")]
+    Utf8Error(ParseErrorContext),
 
     /// Source name in packet was not null terminated.
     ///
     /// # Arguments
-    /// msg: A message providing further details (if any) as to why the source name was invalid.
-    #[error("Source name in packet was not null terminated.")]
-    SourceNameNotNullTerminated(),
+    /// context: Where the source name was found.
+    #[error("Source name in packet was not null terminated, {0}")]
+    SourceNameNotNullTerminated(ParseErrorContext),
+}
+
+impl ParsePacketError {
+    /// Returns where the packet failed to parse, None if this error wasn't caused by parsing a packet.
+    pub fn context(&self) -> Option<&ParseErrorContext> {
+        match self {
+            ParsePacketError::ParseInvalidData(context)
+            | ParsePacketError::ParseInvalidPriority(context)
+            | ParsePacketError::ParseInvalidPage(context)
+            | ParsePacketError::ParseInvalidSyncAddr(context)
+            | ParsePacketError::ParseInvalidUniverse(context)
+            | ParsePacketError::ParseInvalidUniverseOrder(context)
+            | ParsePacketError::ParseInsufficientData(context)
+            | ParsePacketError::ParsePduInvalidFlags(context)
+            | ParsePacketError::PduInvalidLength(context)
+            | ParsePacketError::PduInvalidVector(context)
+            | ParsePacketError::Utf8Error(context)
+            | ParsePacketError::SourceNameNotNullTerminated(context) => Some(context),
+            ParsePacketError::PackInvalidData(_)
+            | ParsePacketError::PackBufferInsufficient(_)
+            | ParsePacketError::UuidError(_) => None,
+        }
+    }
+
+    /// Moves the location of the error by the given number of bytes, used when a layer is parsed from a buffer which starts part way
+    /// through the packet so that the offset is relative to the start of the packet.
+    pub(crate) fn offset_by(mut self, offset: usize) -> ParsePacketError {
+        if let Some(context) = self.context_mut() {
+            context.offset += offset;
+        }
+        self
+    }
+
+    fn context_mut(&mut self) -> Option<&mut ParseErrorContext> {
+        match self {
+            ParsePacketError::ParseInvalidData(context)
+            | ParsePacketError::ParseInvalidPriority(context)
+            | ParsePacketError::ParseInvalidPage(context)
+            | ParsePacketError::ParseInvalidSyncAddr(context)
+            | ParsePacketError::ParseInvalidUniverse(context)
+            | ParsePacketError::ParseInvalidUniverseOrder(context)
+            | ParsePacketError::ParseInsufficientData(context)
+            | ParsePacketError::ParsePduInvalidFlags(context)
+            | ParsePacketError::PduInvalidLength(context)
+            | ParsePacketError::PduInvalidVector(context)
+            | ParsePacketError::Utf8Error(context)
+            | ParsePacketError::SourceNameNotNullTerminated(context) => Some(context),
+            ParsePacketError::PackInvalidData(_)
+            | ParsePacketError::PackBufferInsufficient(_)
+            | ParsePacketError::UuidError(_) => None,
+        }
+    }
 }
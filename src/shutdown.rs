@@ -0,0 +1,58 @@
+#![warn(missing_docs)]
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! The outcome of explicitly shutting down a source or receiver.
+//!
+//! Dropping a `SacnSource` or `SacnReceiver` terminates its universes or leaves its multicast groups on a best effort basis, any
+//! errors are ignored as drop can't return them. `SacnSource::shutdown` and `SacnReceiver::shutdown` do the same however return a
+//! `ShutdownReport` saying which universes were cleaned up and which failed.
+//!
+//! ```
+//! use sacn::packet::ACN_SDT_MULTICAST_PORT;
+//! use sacn::source::SacnSource;
+//! use sacn::transport::MemoryNetwork;
+//! use sacn::types::Universe;
+//!
+//! use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+//! use std::time::Duration;
+//!
+//! let network = MemoryNetwork::new();
+//! let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), ACN_SDT_MULTICAST_PORT + 1);
+//!
+//! let src = SacnSource::with_transport("Source", network.bind(addr).unwrap()).unwrap();
+//! let universes = [Universe::try_from(1).unwrap(), Universe::try_from(2).unwrap()];
+//! src.register_universes(&universes).unwrap();
+//!
+//! let report = src.shutdown(Some(Duration::from_secs(1)), None).unwrap();
+//! assert_eq!(report.completed, universes);
+//! assert!(report.is_clean());
+//! ```
+
+use crate::error::errors::*;
+use crate::types::Universe;
+
+/// The universes which were cleaned up when a source or receiver was shut down.
+///
+/// For a source a universe is completed once its stream termination packets have been sent, for a receiver once it has left the
+/// multicast group of the universe.
+#[derive(Debug, Default)]
+pub struct ShutdownReport {
+    /// The universes which were terminated or left cleanly, in the order they were shut down.
+    pub completed: Vec<Universe>,
+
+    /// The universes which couldn't be terminated or left along with the error encountered. Universes which weren't reached before
+    /// the shutdown timeout have a `ShutdownTimeout` error.
+    pub failed: Vec<(Universe, SacnError)>,
+}
+
+impl ShutdownReport {
+    /// Returns true if every universe was terminated or left cleanly.
+    pub fn is_clean(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
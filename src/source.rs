@@ -17,18 +17,39 @@
 use crate::error::errors::*;
 use crate::packet::*;
 
-use std::cell::RefCell;
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::cmp;
 use std::cmp::min;
 use std::collections::HashMap;
+use std::fmt;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::thread;
 use std::thread::JoinHandle;
 use std::time::{Duration, Instant};
 
-/// Socket2 used to create the underlying UDP socket that sACN is sent on.
-use socket2::{Domain, Socket, Type};
+/// The transport that sACN is sent on, by default a UDP socket.
+use crate::transport::{Transport, UdpTransport};
+
+/// The clock used for timing universe discovery adverts, by default the system clock.
+use crate::clock::{Clock, SystemClock};
+
+/// The interval between universe discovery adverts and the poll period of the update thread, by default the values from ANSI E1.31-2018.
+use crate::timing::TimingProfile;
+
+/// The file backed store that a builder may load the CID of the source from.
+use crate::cid::CidStore;
+
+/// Validated universes, synchronisation addresses, priorities and start codes used by the public API.
+use crate::types::{Priority, StartCode, SyncAddress, Universe};
+
+/// The shutdown report returned when a source is explicitly shut down.
+use crate::shutdown::ShutdownReport;
+
+/// Socket2 used to set options on the underlying UDP socket that sACN is sent on.
+use socket2::SockRef;
 
 /// UUID library used to handle the UUID's used in the CID fields.
 use uuid::Uuid;
@@ -39,10 +60,18 @@ const SND_UPDATE_THREAD_NAME: &str = "rust_sacn_snd_update_thread";
 /// The default startcode used to send stream termination packets when the `SacnSource` is closed.
 const DEFAULT_TERMINATE_START_CODE: u8 = 0;
 
-/// The poll rate of the update thread.
-/// Discovery updates are sent every `E131_UNIVERSE_DISCOVERY_INTERVAL` so the poll rate must be lower than or equal to this.
-// const DEFAULT_POLL_PERIOD: Duration = E131_UNIVERSE_DISCOVERY_INTERVAL;
-const DEFAULT_POLL_PERIOD: Duration = Duration::from_secs(1);
+/// The largest differentiated services code point, DSCP is the upper 6 bits of the IPv4 TOS field or IPv6 traffic class.
+const MAX_DSCP: u8 = 63;
+
+/// The position of the DSCP within the IPv4 TOS field or IPv6 traffic class, the lower bits are used for ECN.
+const DSCP_SHIFT: u32 = 2;
+
+/// The explicit congestion notification (ECN) bits of the IPv4 TOS field or IPv6 traffic class.
+const ECN_MASK: u32 = 0b11;
+
+/// The default interval after which a `PolledSacnSource` resends the last data of a universe which hasn't been sent since.
+/// This is well within the `E131_NETWORK_DATA_LOSS_TIMEOUT` so receivers don't assume the source is lost while its data is unchanged.
+pub const DEFAULT_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(1);
 
 /// A DMX over sACN sender.
 ///
@@ -54,16 +83,17 @@ const DEFAULT_POLL_PERIOD: Duration = Duration::from_secs(1);
 /// // Example showing creation of a source and then sending some data.
 /// use sacn::source::SacnSource;
 /// use sacn::packet::ACN_SDT_MULTICAST_PORT;
+/// use sacn::types::{Priority, SyncAddress, Universe};
 /// use std::net::{IpAddr, SocketAddr};
 ///
 /// let local_addr: SocketAddr = SocketAddr::new(IpAddr::V4("0.0.0.0".parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
 ///
-/// let mut src = SacnSource::with_ip("Source", local_addr).unwrap();
+/// let src = SacnSource::with_ip("Source", local_addr).unwrap();
 ///
-/// let universe: u16 = 1;                        // Universe the data is to be sent on.
-/// let sync_uni: Option<u16> = None;             // Don't want the packet to be delayed on the receiver awaiting synchronisation.
-/// let priority: u8 = 100;                       // The priority for the sending data, must be 1-200 inclusive,  None means use default.
-/// let dst_ip: Option<SocketAddr> = None;        // Sending the data using IP multicast so don't have a destination IP.
+/// let universe = Universe::try_from(1).unwrap();   // Universe the data is to be sent on.
+/// let sync_uni: Option<SyncAddress> = None;         // Don't want the packet to be delayed on the receiver awaiting synchronisation.
+/// let priority = Priority::try_from(100).unwrap(); // The priority for the sending data, must be 0-200 inclusive,  None means use default.
+/// let dst_ip: Option<SocketAddr> = None;            // Sending the data using IP multicast so don't have a destination IP.
 ///
 /// src.register_universe(universe).unwrap(); // Register with the source that will be sending on the given universe.
 ///
@@ -75,60 +105,331 @@ const DEFAULT_POLL_PERIOD: Duration = Duration::from_secs(1);
 /// An ANSI E1.31-2018 sACN source.
 ///
 /// Allows sending DMX data over an IPv4 or IPv6 network using sACN.
+///
+/// All methods take `&self` so a source can be shared between threads, for example behind an `Arc` or using `std::thread::scope`.
+/// Sending to different universes from different threads happens in parallel.
 #[derive(Debug)]
 pub struct SacnSource {
     /// The DMX source used for actually sending the sACN packets.
-    /// Protected by a Mutex lock to allow concurrent access between user threads and the update thread below.
-    internal: Arc<Mutex<SacnSourceInternal>>,
+    /// Shared between user threads and the update thread below, the state within is protected by its own locks and atomics so
+    /// different universes can be sent from many threads at once.
+    internal: Arc<SacnSourceInternal>,
 
-    /// Update thread which performs actions every `DEFAULT_POLL_PERIOD` such as checking if a universe
+    /// Update thread which performs actions every poll period of the timing profile such as checking if a universe
     /// discovery packet should be sent.
     update_thread: Option<JoinHandle<()>>,
 }
 
 /// Internal sACN sender, this does most of the work however is encapsulated within `SacnSource`
 /// to allow access by the `update_thread` which is used to manage sending periodic universe discovery packets.
+///
+/// All methods take `&self`, the mutable state is held in the cells of `S`. A `SacnSource` uses `Shared` so that it can be shared between
+/// threads, each piece of state has its own lock or is atomic so that sending to one universe doesn't wait on sending to another. A
+/// `PolledSacnSource` uses `Local` which is never locked.
 #[derive(Debug)]
-struct SacnSourceInternal {
-    /// Underlying UDP socket used for sending sACN packets on the network.
-    socket: Socket,
+struct SacnSourceInternal<S: StateCells = Shared> {
+    /// Underlying transport used for sending sACN packets on the network, by default a UDP socket.
+    transport: Box<dyn Transport>,
 
     /// The address of this `SacnSourceInternal` on the network.
     addr: SocketAddr,
 
     /// The unique ID of this `SacnSourceInternal`.
     /// It is the job of the user of the library to ensure that the cid is given on creation of the `SacnSourceInternal` is unique.
-    cid: Uuid,
+    cid: S::Lock<Uuid>,
 
     /// The human readable name of this source.
-    name: String,
+    name: S::Lock<String>,
 
     /// Flag which is included in sACN packets to indicate that the data shouldn't be used for live output
     /// (ie. on actual lighting fixtures). A receiver may or may not be compliant with this so it should not be relied
     /// upon in an untested environment.
-    preview_data: bool,
+    preview_data: S::Flag,
+
+    /// Flag which is set to send data packets in the layout of the ANSI E1.31 draft which came before ANSI E1.31-2009.
+    draft_mode: S::Flag,
 
     /// The sequence numbers used for data packets, keeps a reference of the next sequence number to use for each universe.
-    /// Sequence numbers are always in the range [0, 255].
-    data_sequences: RefCell<HashMap<u16, u8>>,
+    data_sequences: SequenceNumbers<S>,
 
     /// The sequence numbers used for sync packets, keeps a reference of the next sequence number to use for each universe.
-    /// Sequence numbers are always in the range [0, 255].
-    sync_sequences: RefCell<HashMap<u16, u8>>,
+    sync_sequences: SequenceNumbers<S>,
 
     /// A list of the universes registered to send by this source, used for universe discovery.
     /// Always sorted with lowest universe first to allow quicker usage.
     /// This may never contain duplicate universe values.
-    universes: Vec<u16>,
+    universes: S::Lock<Vec<u16>>,
 
     /// Flag that indicates if the `SacnSourceInternal` is running (the update thread should be triggering periodic discovery packets).
-    running: bool,
+    running: S::Flag,
 
     /// The time that the last universe discovery advert was send.
-    last_discovery_advert_timestamp: Instant,
+    last_discovery_advert_timestamp: S::Lock<Instant>,
 
     /// Flag that is set to True to indicate that the source is sending periodic universe discovery packets.
-    is_sending_discovery: bool,
+    is_sending_discovery: S::Flag,
+
+    /// The clock used to decide when universe discovery adverts are due, by default the system clock.
+    clock: Box<dyn Clock>,
+
+    /// The interval between universe discovery adverts, by default `E131_UNIVERSE_DISCOVERY_INTERVAL`.
+    discovery_interval: Duration,
+
+    /// The IPv4 broadcast address that packets without a destination are sent to, None if they are sent using multicast.
+    broadcast_addr: S::Lock<Option<Ipv4Addr>>,
+
+    /// The interval after which the last data sent to a universe is resent if nothing has been sent to it since, None if data isn't
+    /// resent. Only used by a `PolledSacnSource`.
+    keep_alive_interval: S::Lock<Option<Duration>>,
+
+    /// The last data sent to each universe, only kept if the `keep_alive_interval` is set.
+    last_data: S::Lock<HashMap<u16, LastData>>,
+}
+
+/// Per universe sequence numbers which can be advanced from multiple threads at once when using `Shared` cells.
+///
+/// Once a universe has been used only a read lock is needed to advance its sequence number so different universes don't block each
+/// other. Packets for the same universe sent from different threads at once get distinct sequence numbers however may be sent out of
+/// order.
+#[derive(Debug)]
+struct SequenceNumbers<S: StateCells> {
+    /// The next sequence number to use for each universe, sequence numbers wrap around within the range [0, 255].
+    next: S::Lock<HashMap<u16, S::Counter>>,
+}
+
+impl<S: StateCells> SequenceNumbers<S> {
+    /// Constructs sequence numbers where every universe starts at `STARTING_SEQUENCE_NUMBER`.
+    fn new() -> Self {
+        SequenceNumbers {
+            next: S::Lock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the sequence number to use for the next packet of the given universe and advances it.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock of the sequence numbers is poisoned.
+    fn advance(&self, universe: u16) -> Result<u8> {
+        if let Some(seq) = self.next.read()?.get(&universe) {
+            return Ok(seq.advance());
+        }
+
+        Ok(self
+            .next
+            .write()?
+            .entry(universe)
+            .or_insert_with(|| S::Counter::new(STARTING_SEQUENCE_NUMBER))
+            .advance())
+    }
+}
+
+/// The family of cells which hold the mutable state of a `SacnSourceInternal`.
+///
+/// This centralises the locking of the source allowing any changes to the mechanism to be made in one place.
+trait StateCells: fmt::Debug + Sized {
+    /// Holds a value which is read or replaced as a whole or updated in place.
+    type Lock<T: fmt::Debug>: StateLock<T>;
+
+    /// Holds a flag.
+    type Flag: StateFlag;
+
+    /// Holds the sequence number of a universe.
+    type Counter: StateCounter;
+}
+
+/// Cells which can be shared between threads, used by a `SacnSource` whose state is shared with its update thread.
+#[derive(Debug)]
+struct Shared;
+
+impl StateCells for Shared {
+    type Lock<T: fmt::Debug> = RwLock<T>;
+    type Flag = AtomicBool;
+    type Counter = AtomicU8;
+}
+
+/// Cells which are owned by a single thread at a time, used by a `PolledSacnSource` which has no update thread.
+///
+/// These are never locked so can't be poisoned and never return errors.
+#[derive(Debug)]
+struct Local;
+
+impl StateCells for Local {
+    type Lock<T: fmt::Debug> = RefCell<T>;
+    type Flag = Cell<bool>;
+    type Counter = Cell<u8>;
+}
+
+/// A cell holding part of the state of a `SacnSourceInternal` which is accessed through a guard.
+trait StateLock<T>: fmt::Debug {
+    /// Guard giving shared access to the value.
+    type Read<'a>: Deref<Target = T>
+    where
+        Self: 'a;
+
+    /// Guard giving exclusive access to the value.
+    type Write<'a>: DerefMut<Target = T>
+    where
+        Self: 'a;
+
+    /// Constructs a cell holding the given value.
+    fn new(value: T) -> Self;
+
+    /// Acquires shared access to the value.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock is poisoned by a thread encountering a panic while accessing causing the source to be left in
+    /// a potentially inconsistent state.
+    fn read(&self) -> Result<Self::Read<'_>>;
+
+    /// Acquires exclusive access to the value.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock is poisoned, see `read`.
+    fn write(&self) -> Result<Self::Write<'_>>;
+}
+
+impl<T: fmt::Debug> StateLock<T> for RwLock<T> {
+    type Read<'a>
+        = RwLockReadGuard<'a, T>
+    where
+        Self: 'a;
+    type Write<'a>
+        = RwLockWriteGuard<'a, T>
+    where
+        Self: 'a;
+
+    fn new(value: T) -> Self {
+        RwLock::new(value)
+    }
+
+    fn read(&self) -> Result<Self::Read<'_>> {
+        match RwLock::read(self) {
+            // The PoisonError returned doesn't contain further information and just allows access to the potentially inconsistent state
+            // which shouldn't be exposed to the user (as its internal and would have no use).
+            Err(_) => Err(SacnError::SourceCorrupt("Lock poisoned".to_string())),
+            Ok(guard) => Ok(guard),
+        }
+    }
+
+    fn write(&self) -> Result<Self::Write<'_>> {
+        match RwLock::write(self) {
+            Err(_) => Err(SacnError::SourceCorrupt("Lock poisoned".to_string())),
+            Ok(guard) => Ok(guard),
+        }
+    }
+}
+
+impl<T: fmt::Debug> StateLock<T> for RefCell<T> {
+    type Read<'a>
+        = Ref<'a, T>
+    where
+        Self: 'a;
+    type Write<'a>
+        = RefMut<'a, T>
+    where
+        Self: 'a;
+
+    fn new(value: T) -> Self {
+        RefCell::new(value)
+    }
+
+    fn read(&self) -> Result<Self::Read<'_>> {
+        Ok(self.borrow())
+    }
+
+    fn write(&self) -> Result<Self::Write<'_>> {
+        Ok(self.borrow_mut())
+    }
+}
+
+/// A cell holding a flag of a `SacnSourceInternal`.
+trait StateFlag: fmt::Debug {
+    /// Constructs a flag with the given value.
+    fn new(value: bool) -> Self;
+
+    /// Returns the value of the flag.
+    fn get(&self) -> bool;
+
+    /// Sets the value of the flag.
+    fn set(&self, value: bool);
+}
+
+impl StateFlag for AtomicBool {
+    fn new(value: bool) -> Self {
+        AtomicBool::new(value)
+    }
+
+    fn get(&self) -> bool {
+        self.load(Ordering::SeqCst)
+    }
+
+    fn set(&self, value: bool) {
+        self.store(value, Ordering::SeqCst);
+    }
+}
+
+impl StateFlag for Cell<bool> {
+    fn new(value: bool) -> Self {
+        Cell::new(value)
+    }
+
+    fn get(&self) -> bool {
+        Cell::get(self)
+    }
+
+    fn set(&self, value: bool) {
+        Cell::set(self, value);
+    }
+}
+
+/// A cell holding the next sequence number of a universe.
+trait StateCounter: fmt::Debug {
+    /// Constructs a counter starting at the given sequence number.
+    fn new(value: u8) -> Self;
+
+    /// Returns the current sequence number and advances it, wrapping around within the range [0, 255].
+    fn advance(&self) -> u8;
+}
+
+impl StateCounter for AtomicU8 {
+    fn new(value: u8) -> Self {
+        AtomicU8::new(value)
+    }
+
+    fn advance(&self) -> u8 {
+        self.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+impl StateCounter for Cell<u8> {
+    fn new(value: u8) -> Self {
+        Cell::new(value)
+    }
+
+    fn advance(&self) -> u8 {
+        let seq = Cell::get(self);
+        Cell::set(self, seq.wrapping_add(1));
+        seq
+    }
+}
+
+/// The last data sent to a universe, kept so that it can be resent as a keep-alive.
+#[derive(Debug)]
+struct LastData {
+    /// The data sent to the universe, not including the start code.
+    data: Vec<u8>,
+
+    /// The priority the data was sent with.
+    priority: u8,
+
+    /// The destination the data was sent to, None if it was sent using multicast or broadcast.
+    dst_ip: Option<SocketAddr>,
+
+    /// The synchronisation address the data was sent with.
+    sync_address: u16,
+
+    /// The time the data was last sent.
+    sent: Instant,
 }
 
 impl SacnSource {
@@ -194,24 +495,117 @@ impl SacnSource {
     ///
     /// `MalformedSourceName`: Returned if the given source name is longer than the maximum allowed size of `E131_SOURCE_NAME_FIELD_LENGTH`.
     pub fn with_cid_ip(name: &str, cid: Uuid, ip: SocketAddr) -> Result<SacnSource> {
-        if name.len() > E131_SOURCE_NAME_FIELD_LENGTH {
-            return Err(SacnError::MalformedSourceName(
-                "Source name provided is longer than maximum allowed".to_string(),
-            ));
-        }
+        SacnSource::with_cid_transport(name, cid, UdpTransport::bind_source(ip)?)
+    }
+
+    /// Constructs a new `SacnSource` with the given name and binding to the supplied ip, using the CID held by the given store.
+    /// If the store doesn't hold a CID yet a random CID is generated and saved to it so that the same CID is used next time.
+    ///
+    /// # Errors
+    /// Uuid: Returned if the store holds something other than a valid CID.
+    ///
+    /// Io: Returned if the store can't be read or written.
+    ///
+    /// See (`with_cid_ip`)[`with_cid_ip`] for other errors.
+    pub fn with_cid_store(name: &str, store: &CidStore, ip: SocketAddr) -> Result<SacnSource> {
+        SacnSource::with_cid_ip(name, store.load_or_generate()?, ip)
+    }
+
+    /// Constructs a new `SacnSource` with the given name which sends over the given transport instead of a UDP socket.
+    /// This generates a new CID automatically using random values.
+    ///
+    /// # Errors
+    /// See (`with_cid_transport`)[`with_cid_transport`]
+    pub fn with_transport<T: Transport + 'static>(name: &str, transport: T) -> Result<SacnSource> {
+        SacnSource::with_cid_transport(name, Uuid::new_v4(), transport)
+    }
 
+    /// Constructs a new `SacnSource` with the given name and cid which sends over the given transport instead of a UDP socket.
+    ///
+    /// The local address of the transport is used as the address of the source, this determines whether multicast packets are sent to
+    /// IPv4 or IPv6 multicast addresses.
+    ///
+    /// # Errors
+    /// Io: Returned if the local address of the transport cannot be retrieved or if the thread used for sending periodic
+    ///     discovery adverts fails to be created.
+    ///
+    /// `MalformedSourceName`: Returned if the given source name is longer than the maximum allowed size of `E131_SOURCE_NAME_FIELD_LENGTH`.
+    pub fn with_cid_transport<T: Transport + 'static>(
+        name: &str,
+        cid: Uuid,
+        transport: T,
+    ) -> Result<SacnSource> {
+        SacnSource::with_clock(name, cid, transport, SystemClock)
+    }
+
+    /// Constructs a new `SacnSource` with the given name and cid which sends over the given transport and takes the time used to
+    /// decide when universe discovery adverts are due from the given clock.
+    ///
+    /// The update thread still checks whether an advert is due every `timing::DEFAULT_POLL_PERIOD` in real time, so with a `MockClock`
+    /// an advert is sent within a poll period of the clock being advanced past the `E131_UNIVERSE_DISCOVERY_INTERVAL`.
+    ///
+    /// # Errors
+    /// See (`with_cid_transport`)[`with_cid_transport`]
+    pub fn with_clock<T: Transport + 'static, C: Clock + 'static>(
+        name: &str,
+        cid: Uuid,
+        transport: T,
+        clock: C,
+    ) -> Result<SacnSource> {
+        SacnSource::with_timing(name, cid, transport, clock, TimingProfile::default())
+    }
+
+    /// Constructs a new `SacnSource` with the given name and cid which sends over the given transport using the given clock and
+    /// timing profile.
+    ///
+    /// The timing profile sets the interval between universe discovery adverts and the period at which the update thread checks
+    /// whether an advert is due in place of the values from ANSI E1.31-2018.
+    ///
+    /// # Errors
+    /// `InvalidTimingProfile`: Returned if the timing profile is invalid, see `TimingProfile::validate`.
+    ///
+    /// Otherwise see (`with_cid_transport`)[`with_cid_transport`]
+    pub fn with_timing<T: Transport + 'static, C: Clock + 'static>(
+        name: &str,
+        cid: Uuid,
+        transport: T,
+        clock: C,
+        timing: TimingProfile,
+    ) -> Result<SacnSource> {
+        timing.validate()?;
+        let internal = SacnSourceInternal::with_cid_transport(
+            name,
+            cid,
+            Box::new(transport),
+            Box::new(clock),
+            timing.discovery_interval,
+        )?;
+        SacnSource::start(internal, timing.poll_period)
+    }
+
+    /// Returns a `SacnSourceBuilder` for creating a `SacnSource` with the given name and a configuration other than the default.
+    pub fn builder(name: &str) -> SacnSourceBuilder {
+        SacnSourceBuilder::new(name)
+    }
+
+    /// Wraps the given `SacnSourceInternal` in a `SacnSource` and starts the update thread which checks whether a universe discovery advert
+    /// is due every `poll_period`.
+    ///
+    /// # Errors
+    /// Io: Returned if the update thread fails to be created.
+    fn start(internal: SacnSourceInternal, poll_period: Duration) -> Result<SacnSource> {
         let trd_builder = thread::Builder::new().name(SND_UPDATE_THREAD_NAME.into());
 
-        let internal_src = Arc::new(Mutex::new(SacnSourceInternal::with_cid_ip(name, cid, ip)?));
+        let internal_src = Arc::new(internal);
 
-        let mut trd_src = internal_src.clone();
+        let trd_src = internal_src.clone();
 
         let src = SacnSource {
             internal: internal_src,
             update_thread: Some(trd_builder.spawn(move || {
-                while trd_src.lock().unwrap().running {
-                    thread::sleep(DEFAULT_POLL_PERIOD);
-                    if let Err(e) = perform_periodic_update(&mut trd_src) {
+                while trd_src.running.get() {
+                    thread::sleep(poll_period);
+                    if let Err(e) = perform_periodic_update(&trd_src) {
                         println!("Periodic error: {e:?}");
                     } else {
                         // In-case of an error on the discovery thread the source continues to operate and tries again.
@@ -230,19 +624,16 @@ impl SacnSource {
     /// the list of universes that appear in universe discovery packets that are sent (depending on the
     /// `set_is_sending_discovery` flag) periodically.
     ///
-    /// This is more efficient than repeated calls to `register_universe` as it means only 1 mutex unlock is required.
+    /// This is more efficient than repeated calls to `register_universe` as it means only 1 lock of the registered universes is required.
     ///
     /// # Arguments
-    /// universes: The sACN universes to register for usage as data universes and/or synchronisation addresses. Note that sACN
-    ///     universes start at 1 not 0.
+    /// universes: The sACN universes to register for usage as data universes and/or synchronisation addresses.
     ///
     /// # Errors
-    /// `IllegalUniverse`: Returned if a universe is outwith the range permitted by ANSI E1.31-2018.
-    ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn register_universes(&mut self, universes: &[u16]) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.register_universes(universes)
+    pub fn register_universes(&self, universes: &[Universe]) -> Result<()> {
+        self.internal.register_universes(universes)
     }
 
     /// Registers a single universe on this source in addition to already registered universes.
@@ -254,16 +645,13 @@ impl SacnSource {
     /// If registering multiple universes see (`register_universes`)[`register_universes`].
     ///
     /// # Arguments
-    /// universe: The sACN universe to register for usage as a data universe and/or synchronisation address. Note that sACN
-    ///     universes start at 1 not 0.
+    /// universe: The sACN universe to register for usage as a data universe and/or synchronisation address.
     ///
     /// # Errors
-    /// `IllegalUniverse`: Returned if the universe is outwith the range permitted by ANSI E1.31-2018.
-    ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn register_universe(&mut self, universe: u16) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.register_universe(universe)
+    pub fn register_universe(&self, universe: Universe) -> Result<()> {
+        self.internal.register_universes(&[universe])
     }
 
     /// Sends the given data to the given universes with the given priority, synchronisation address (universe) and destination ip.
@@ -275,13 +663,12 @@ impl SacnSource {
     ///
     /// data:         The data that should be sent, must have a length greater than 0.
     ///
-    /// priority:     The E131 priority that the data should be sent with, must be less than `E131_MAX_PRIORITY` (`const.E131_MAX_PRIORITY.packet`),
-    ///                 if a value of None is provided then the default of `E131_DEFAULT_PRIORITY` (`const.E131_DEFAULT_PRIORITY.packet`) is used.
+    /// priority:     The E131 priority that the data should be sent with, if a value of None is provided then `Priority::DEFAULT` is used.
     ///
     /// `dst_ip`:       The destination IP, can be Ipv4 or Ipv6, None if should be sent using ip multicast.
     ///
-    /// `sync_address`: The address to use for synchronisation, must be a valid universe, None indicates no synchronisation. If synchronisation is required a
-    ///                 reasonable default address to use is the first universe that this data is being sent to.
+    /// `sync_address`: The address to use for synchronisation, must be a registered universe, None indicates no synchronisation. If synchronisation
+    ///                 is required a reasonable default address to use is the first universe that this data is being sent to.
     ///
     /// As per ANSI E1.31-2018 Section 6.6.1 this method shouldn't be called at a higher refresher rate than specified in ANSI E1.11 [DMX] unless
     ///     configured by the user to do so in an environment which doesn't contain any E1.31 to DMX512-A converters.
@@ -293,33 +680,26 @@ impl SacnSource {
     ///
     /// `InvalidInput`: Returned if the data array has length 0 or if an insufficient number of universes for the given data are provided (each universe takes 513 bytes of data).
     ///
-    /// `InvalidPriority`: Returned if the priority is greater than the allowed maximum priority of `E131_MAX_PRIORITY`.
-    ///
-    /// `IllegalUniverse`: Returned if the universe is outwith the allowed range as specified by ANSI E1.31-2018 Section 6.2.7.
-    ///
     /// `UniverseNotRegistered`: Returned if the universe is not registered on the given `SacnSourceInternal`.
     ///
+    /// `IllegalSyncUniverse`: Returned if the synchronisation address is not registered on the given `SacnSourceInternal`.
+    ///
     /// `ExceedUniverseCapacity`: Returned if the data has a length greater than the maximum allowed within a universe (`packet::UNIVERSE_CHANNEL_CAPACITY`).
     ///
     /// Io: Returned if the data fails to be sent on the socket, see `send_to(fn.send_to.Socket)`.
     ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
     pub fn send(
-        &mut self,
-        universes: &[u16],
+        &self,
+        universes: &[Universe],
         data: &[u8],
-        priority: Option<u8>,
+        priority: Option<Priority>,
         dst_ip: Option<SocketAddr>,
-        synchronisation_addr: Option<u16>,
+        synchronisation_addr: Option<SyncAddress>,
     ) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.send(
-            universes,
-            data,
-            priority,
-            dst_ip,
-            synchronisation_addr,
-        )
+        self.internal
+            .send(universes, data, priority, dst_ip, synchronisation_addr)
     }
 
     /// Sends a synchronisation packet to trigger the sending of packets waiting to be sent together.
@@ -329,46 +709,47 @@ impl SacnSource {
     /// then send a synchronisation packet with the address of the synchronisation universe chosen to trigger the packets.
     ///
     /// # Arguments
-    /// universe: The universe of this synchronisation packet.
+    /// `sync_address`: The synchronisation address of this synchronisation packet.
     /// `dst_ip`:   The destination IP address for this packet or None if it should be sent using multicast.
     ///
     /// # Errors
-    /// `IllegalUniverse`: Returned if the universe is outwith the allowed range of sACN universes as defined in ANSI E1.31-2018 Section 6.2.7.
-    ///
-    /// `UniverseNotRegistered`: Returned if the universe is not registered on the given `SacnSourceInternal`.
+    /// `UniverseNotRegistered`: Returned if the universe of the synchronisation address is not registered on the given `SacnSourceInternal`.
     ///
     /// Io: Returned if the packet fails to be sent using the underlying network socket.
     ///
     /// `SacnParsePackError`: Returned if the sync packet fails to be packed.
     ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn send_sync_packet(&mut self, universe: u16, dst_ip: Option<SocketAddr>) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.send_sync_packet(universe, dst_ip)
+    pub fn send_sync_packet(
+        &self,
+        sync_address: SyncAddress,
+        dst_ip: Option<SocketAddr>,
+    ) -> Result<()> {
+        self.internal.send_sync_packet(sync_address.get(), dst_ip)
     }
 
     /// Terminates sending on the given universe.
     ///
     /// # Errors:
-    /// `IllegalUniverse`: Returned if the universe is outwith the allowed range of sACN universes as defined in ANSI E1.31-2018 Section 6.2.7.
-    ///
     /// `UniverseNotRegistered`: Returned if the universe is not registered on this source.
     ///
     /// Io: Returned if the termination packets fail to be sent on the socket.
     ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn terminate_stream(&mut self, universe: u16, start_code: u8) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.terminate_stream(universe, start_code)
+    pub fn terminate_stream(&self, universe: Universe, start_code: StartCode) -> Result<()> {
+        self.internal
+            .terminate_stream(universe.get(), start_code.get())
     }
 
     /// Returns the ACN CID device identifier of the `SacnSourceInternal`.
     ///
     /// # Errors
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
     pub fn cid(&self) -> Result<Uuid> {
-        Ok(*unlock_internal(&self.internal)?.cid())
+        self.internal.cid()
     }
 
     /// Sets the ACN CID device identifier.
@@ -377,20 +758,19 @@ impl SacnSource {
     /// cid: The new CID identifier for this source. It is left to the user to ensure that this is always unique within the network the source is in.
     ///
     /// # Errors
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn set_cid(&mut self, cid: Uuid) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.set_cid(cid);
-        Ok(())
+    pub fn set_cid(&self, cid: Uuid) -> Result<()> {
+        self.internal.set_cid(cid)
     }
 
     /// Returns the ACN source name.
     ///
     /// # Errors
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
     pub fn name(&self) -> Result<String> {
-        Ok(unlock_internal(&self.internal)?.name().into())
+        self.internal.name()
     }
 
     /// Sets ACN source name.
@@ -399,12 +779,12 @@ impl SacnSource {
     /// name: The new name for the source, it is left to the user to ensure this is unique within the sACN network.
     ///
     /// # Errors
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
     ///
     /// `MalformedSourceName`: Returned to indicate that the given source name is longer than the maximum allowed as per `E131_SOURCE_NAME_FIELD_LENGTH`.
-    pub fn set_name(&mut self, name: &str) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.set_name(name)
+    pub fn set_name(&self, name: &str) -> Result<()> {
+        self.internal.set_name(name)
     }
 
     /// Returns true if `SacnSourceInternal` is in preview mode, false if not.
@@ -412,10 +792,10 @@ impl SacnSource {
     /// For details of `preview_mode` see (`set_preview_mode`)[`set_preview_mode`].
     ///
     /// # Errors
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
     pub fn preview_mode(&self) -> Result<bool> {
-        Ok(unlock_internal(&self.internal)?.preview_mode())
+        Ok(self.internal.preview_mode())
     }
 
     /// Sets the value of the `Preview_Data` flag in packets from this `SacnSource`.
@@ -425,25 +805,45 @@ impl SacnSource {
     ///     for live output. If false then the flag will be set to false.
     ///
     /// # Errors
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn set_preview_mode(&mut self, preview_mode: bool) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.set_preview_mode(preview_mode);
+    pub fn set_preview_mode(&self, preview_mode: bool) -> Result<()> {
+        self.internal.set_preview_mode(preview_mode);
         Ok(())
     }
 
+    /// Returns true if this source sends data packets in the layout of the ANSI E1.31 draft, see `set_draft_mode`.
+    pub fn draft_mode(&self) -> bool {
+        self.internal.draft_mode()
+    }
+
+    /// Sets whether data packets are sent in the layout of the ANSI E1.31 draft (revision 0.2) which came before ANSI E1.31-2009,
+    /// for older gear which only understands the draft. See `pack_draft_data_packet`. By default ANSI E1.31-2018 is used.
+    ///
+    /// The draft has no synchronization address or options so data sent in draft mode isn't synchronized or marked as preview data.
+    /// Synchronization, stream termination and universe discovery packets have no equivalent in the draft so are still sent using
+    /// ANSI E1.31-2018, gear which only understands the draft ignores them and times out terminated streams instead. Receivers from
+    /// this crate accept draft packets once `SacnReceiver::set_accept_draft_packets` is set, and terminate the stream on the
+    /// ANSI E1.31-2018 termination packets.
+    ///
+    /// # Arguments
+    /// val: If true then data packets are sent in the layout of the draft, if false then ANSI E1.31-2018 is used.
+    pub fn set_draft_mode(&self, val: bool) {
+        self.internal.set_draft_mode(val);
+    }
+
     /// Sets the `is_sending_discovery` flag to the given value.
     ///
     /// # Arguments
     /// val: The new value for the `is_sending_discovery` flag, if true then source will send periodic universe discovery packets
     /// and if false it won't.
-    pub fn set_is_sending_discovery(&mut self, val: bool) {
-        self.internal.lock().unwrap().set_is_sending_discovery(val);
+    pub fn set_is_sending_discovery(&self, val: bool) {
+        self.internal.set_is_sending_discovery(val);
     }
 
     /// Returns the multicast time to live of the socket.
     pub fn multicast_ttl(&self) -> Result<u32> {
-        unlock_internal(&self.internal)?.multicast_ttl()
+        self.internal.multicast_ttl()
     }
 
     /// Sets the multicast time to live.
@@ -454,10 +854,10 @@ impl SacnSource {
     /// # Errors
     /// Io: Returned if the multicast TTL fails to be set on the underlying socket.
     ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn set_multicast_ttl(&mut self, multicast_ttl: u32) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.set_multicast_ttl(multicast_ttl)
+    pub fn set_multicast_ttl(&self, multicast_ttl: u32) -> Result<()> {
+        self.internal.set_multicast_ttl(multicast_ttl)
     }
 
     /// Returns the current Time To Live for unicast packets send by this source.
@@ -465,10 +865,10 @@ impl SacnSource {
     /// # Errors
     /// Io: Returned if the TTL cannot be retrieved from the underlying socket.
     ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
     pub fn ttl(&self) -> Result<u32> {
-        unlock_internal(&self.internal)?.ttl()
+        self.internal.ttl()
     }
 
     /// Sets the Time To Live for packets sent by this source.
@@ -479,10 +879,10 @@ impl SacnSource {
     /// # Errors
     /// Io: Returned if the TTL value cannot be changed.
     ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn set_ttl(&mut self, ttl: u32) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.set_ttl(ttl)
+    pub fn set_ttl(&self, ttl: u32) -> Result<()> {
+        self.internal.set_ttl(ttl)
     }
 
     /// Sets if multicast loop is enabled.
@@ -493,105 +893,985 @@ impl SacnSource {
     /// # Errors
     /// Io: Returned if the `set_multicast_loop` option fails to be set on the socket.
     ///
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn set_multicast_loop_v4(&mut self, multicast_loop: bool) -> Result<()> {
-        unlock_internal_mut(&mut self.internal)?.set_multicast_loop_v4(multicast_loop)
+    pub fn set_multicast_loop_v4(&self, multicast_loop: bool) -> Result<()> {
+        self.internal.set_multicast_loop_v4(multicast_loop)
     }
 
     /// Returns true if multicast loop is enabled, false if not.
     ///
     /// # Errors
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
     /// a panic while accessing causing the source to be left in a potentially inconsistent state.
     pub fn multicast_loop(&self) -> Result<bool> {
-        unlock_internal(&self.internal)?.multicast_loop()
+        self.internal.multicast_loop()
     }
 
-    /// Returns the universes currently registered on this source.
+    /// Puts the source in broadcast mode, packets sent without a destination, including universe discovery adverts, are sent to the
+    /// given IPv4 broadcast address instead of the multicast address of the universe. Passing None returns to multicast.
+    ///
+    /// The broadcast address can be the limited broadcast address (255.255.255.255) or the directed broadcast address of a subnet,
+    /// e.g. 192.168.1.255 for 192.168.1.0/24. Packets are sent to the ACN port, `ACN_SDT_MULTICAST_PORT`. Receivers must be bound to
+    /// the unspecified address or the broadcast address to receive them and don't need to join multicast groups,
+    /// see `SacnReceiver::set_accept_broadcast`.
+    ///
+    /// # Arguments
+    /// `broadcast_addr`: The broadcast address to send to or None to send using multicast.
+    ///
+    /// # Errors
+    /// `IpVersionError`: Returned if a broadcast address is given and the source isn't IPv4, IPv6 has no broadcast.
+    ///
+    /// `InvalidConfiguration`: Returned if the broadcast address is a multicast or unspecified address.
+    ///
+    /// Io: Returned if `SO_BROADCAST` fails to be set on the underlying socket.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn set_broadcast_addr(&self, broadcast_addr: Option<Ipv4Addr>) -> Result<()> {
+        self.internal.set_broadcast_addr(broadcast_addr)
+    }
+
+    /// Returns the broadcast address the source is sending to, None if the source is sending using multicast.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn broadcast_addr(&self) -> Result<Option<Ipv4Addr>> {
+        self.internal.broadcast_addr()
+    }
+
+    /// Sets the differentiated services code point (DSCP) used to mark packets sent by this source for quality of service.
+    ///
+    /// The DSCP is the upper 6 bits of the TOS field of an IPv4 source or the traffic class of an IPv6 source, the lower 2 (ECN) bits are
+    /// left unchanged. For example 46 (Expedited Forwarding) or 34 (AF41) are common choices for lighting control traffic.
+    ///
+    /// # Arguments
+    /// dscp: The new DSCP, must be in the range [0, 63].
+    ///
+    /// # Errors
+    /// `InvalidConfiguration`: Returned if the DSCP is outwith the range [0, 63].
+    ///
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support setting the TOS or traffic
+    /// class.
+    ///
+    /// Io: Returned if the TOS or traffic class fails to be set on the underlying socket.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn set_dscp(&self, dscp: u8) -> Result<()> {
+        self.internal.set_dscp(dscp)
+    }
+
+    /// Returns the differentiated services code point (DSCP) of packets sent by this source, see `set_dscp`.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support getting the TOS or traffic
+    /// class.
+    ///
+    /// Io: Returned if the TOS or traffic class cannot be retrieved from the underlying socket.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn dscp(&self) -> Result<u8> {
+        self.internal.dscp()
+    }
+
+    /// Sets the type of service (TOS) field of IPv4 packets sent by this source, this includes both the DSCP and ECN bits.
+    ///
+    /// # Arguments
+    /// tos: The new TOS value.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support setting the TOS.
+    ///
+    /// Io: Returned if the TOS fails to be set on the underlying socket, for example if the source is IPv6.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn set_tos_v4(&self, tos: u32) -> Result<()> {
+        self.internal.set_tos_v4(tos)
+    }
+
+    /// Returns the type of service (TOS) field of IPv4 packets sent by this source.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support getting the TOS.
+    ///
+    /// Io: Returned if the TOS cannot be retrieved from the underlying socket.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn tos_v4(&self) -> Result<u32> {
+        self.internal.tos_v4()
+    }
+
+    /// Sets the traffic class of IPv6 packets sent by this source, this includes both the DSCP and ECN bits.
+    ///
+    /// # Arguments
+    /// tclass: The new traffic class.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support setting the traffic class.
+    ///
+    /// Io: Returned if the traffic class fails to be set on the underlying socket, for example if the source is IPv4.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn set_tclass_v6(&self, tclass: u32) -> Result<()> {
+        self.internal.set_tclass_v6(tclass)
+    }
+
+    /// Returns the traffic class of IPv6 packets sent by this source.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support getting the traffic class.
+    ///
+    /// Io: Returned if the traffic class cannot be retrieved from the underlying socket.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn tclass_v6(&self) -> Result<u32> {
+        self.internal.tclass_v6()
+    }
+
+    /// Sets the hop limit of IPv6 multicast packets sent by this source, the IPv6 equivalent of `set_multicast_ttl`.
+    ///
+    /// # Arguments
+    /// hops: The new hop limit for packets sent using multicast.
+    ///
+    /// # Errors
+    /// Io: Returned if the hop limit fails to be set on the underlying socket, for example if the source is IPv4.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn set_multicast_hops_v6(&self, hops: u32) -> Result<()> {
+        self.internal.set_multicast_hops_v6(hops)
+    }
+
+    /// Returns the hop limit of IPv6 multicast packets sent by this source.
+    ///
+    /// # Errors
+    /// Io: Returned if the hop limit cannot be retrieved from the underlying socket.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn multicast_hops_v6(&self) -> Result<u32> {
+        self.internal.multicast_hops_v6()
+    }
+
+    /// Sets the hop limit of IPv6 unicast packets sent by this source, the IPv6 equivalent of `set_ttl`.
+    ///
+    /// # Arguments
+    /// hops: The new hop limit for packets sent using unicast.
+    ///
+    /// # Errors
+    /// Io: Returned if the hop limit fails to be set on the underlying socket, for example if the source is IPv4.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn set_unicast_hops_v6(&self, hops: u32) -> Result<()> {
+        self.internal.set_unicast_hops_v6(hops)
+    }
+
+    /// Returns the hop limit of IPv6 unicast packets sent by this source.
+    ///
+    /// # Errors
+    /// Io: Returned if the hop limit cannot be retrieved from the underlying socket.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn unicast_hops_v6(&self) -> Result<u32> {
+        self.internal.unicast_hops_v6()
+    }
+
+    /// Sets if IPv6 multicast loop is enabled.
+    ///
+    /// # Arguments:
+    /// `multicast_loop`: If true then multicast loop is enabled, if false it is not.
+    ///
+    /// # Errors
+    /// Io: Returned if the option fails to be set on the underlying socket, for example if the source is IPv4.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn set_multicast_loop_v6(&self, multicast_loop: bool) -> Result<()> {
+        self.internal.set_multicast_loop_v6(multicast_loop)
+    }
+
+    /// Returns true if IPv6 multicast loop is enabled, false if not.
+    ///
+    /// # Errors
+    /// Io: Returned if the option cannot be retrieved from the underlying socket.
+    ///
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn multicast_loop_v6(&self) -> Result<bool> {
+        self.internal.multicast_loop_v6()
+    }
+
+    /// Returns the universes currently registered on this source.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned by a thread encountering
+    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
+    pub fn universes(&self) -> Result<Vec<Universe>> {
+        self.internal.registered_universes()
+    }
+
+    /// Shuts down this source, terminating the streams of all registered universes and stopping the update thread.
+    ///
+    /// This does the same as dropping the source however reports which universes were terminated and which failed rather than
+    /// ignoring errors. A universe is terminated once `E131_TERMINATE_STREAM_PACKET_COUNT` stream termination packets have been sent
+    /// for it, see ANSI E1.31-2018 Section 6.2.6.
+    ///
+    /// # Arguments
+    /// timeout: The time after which no further universes are terminated, any remaining universes are reported as failed with a
+    ///     `ShutdownTimeout` error. It is measured on the clock of the source, see `SacnSourceBuilder::clock`. If a timeout is given
+    ///     the update thread isn't waited for, it stops on its own at its next poll. None means all universes are terminated and the
+    ///     update thread is waited for.
+    ///
+    /// `start_code`: The start code used in the stream termination packets, None means `StartCode::NULL` is used.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if a lock used to control access to the internal sender is poisoned or the update thread panicked.
+    ///
+    /// Errors terminating a particular universe are returned within the `ShutdownReport` rather than as an error.
+    pub fn shutdown(
+        mut self,
+        timeout: Option<Duration>,
+        start_code: Option<StartCode>,
+    ) -> Result<ShutdownReport> {
+        let deadline = timeout.map(|t| self.internal.clock.now() + t);
+
+        let report = self.internal.shutdown(
+            deadline,
+            start_code.map_or(DEFAULT_TERMINATE_START_CODE, StartCode::get),
+        );
+
+        // Taking the thread means drop doesn't try to terminate the universes again.
+        if let Some(thread) = self.update_thread.take()
+            && deadline.is_none()
+            && thread.join().is_err()
+        {
+            return Err(SacnError::SourceCorrupt(
+                "Update thread panicked".to_string(),
+            ));
+        }
+
+        report
+    }
+}
+
+/// By implementing the Drop trait for `SacnSource` it means that the user doesn't have to explicitly clean up the source
+/// and if it goes out of reference it will clean itself up and send the required termination packets etc.
+impl Drop for SacnSource {
+    fn drop(&mut self) {
+        // Stops the update thread sending further discovery adverts while the universes are terminated.
+        self.internal.running.set(false);
+
+        if let Some(thread) = self.update_thread.take() {
+            // As drop isn't always explicitly called and cannot return an error the error is ignored and a 'best attempt' is used to clean up.
+            let _ = self.internal.terminate(DEFAULT_TERMINATE_START_CODE);
+
+            thread.join().unwrap();
+        }
+    }
+}
+
+/// Where a `SacnSourceBuilder` sends from, either a UDP socket bound to an address or a user provided transport.
+#[derive(Debug)]
+enum SourceBinding {
+    /// Bind a UDP socket to the given address.
+    Addr(SocketAddr),
+
+    /// Send over the given transport.
+    Transport(Box<dyn Transport>),
+}
+
+/// Builds a `SacnSource` with the given configuration.
+///
+/// The configuration is checked once when `build` is called and all socket options are applied before the update thread is started, so
+/// they are in effect for the first packet sent. Options which aren't set use the same defaults as `SacnSource::new_v4`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sacn::source::SacnSourceBuilder;
+/// use sacn::types::Universe;
+/// use std::net::{Ipv4Addr, SocketAddr};
+///
+/// let universes = [Universe::try_from(1).unwrap(), Universe::try_from(2).unwrap()];
+///
+/// let src = SacnSourceBuilder::new("Source")
+///     .bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 5569)))
+///     .multicast_interface_v4(Ipv4Addr::new(192, 168, 0, 10))
+///     .multicast_ttl(4)
+///     .dscp(34)
+///     .universes(&universes)
+///     .build()
+///     .unwrap();
+///
+/// src.send(&universes[..1], &[0, 255, 128], None, None, None).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct SacnSourceBuilder {
+    /// The human readable name of the source.
+    name: String,
+
+    /// The CID of the source, None generates a random CID unless a `cid_store` is given.
+    cid: Option<Uuid>,
+
+    /// The store the CID of the source is loaded from, or saved to if it doesn't hold a CID yet.
+    cid_store: Option<CidStore>,
+
+    /// Where the source sends from.
+    binding: SourceBinding,
+
+    /// The IPv4 interface that multicast packets are sent on.
+    multicast_interface_v4: Option<Ipv4Addr>,
+
+    /// The index of the IPv6 interface that multicast packets are sent on.
+    multicast_interface_v6: Option<u32>,
+
+    /// The TTL (IPv4) or hop limit (IPv6) of unicast packets.
+    ttl: Option<u32>,
+
+    /// The TTL (IPv4) or hop limit (IPv6) of multicast packets.
+    multicast_ttl: Option<u32>,
+
+    /// Whether multicast packets are looped back to the sending host.
+    multicast_loop: Option<bool>,
+
+    /// The differentiated services code point of sent packets.
+    dscp: Option<u8>,
+
+    /// The size of the socket send buffer in bytes.
+    send_buffer_size: Option<usize>,
+
+    /// Whether sent data packets are marked as preview data.
+    preview_mode: bool,
+
+    /// Whether data packets are sent in the layout of the ANSI E1.31 draft.
+    draft_mode: bool,
+
+    /// Whether periodic universe discovery adverts are sent.
+    is_sending_discovery: bool,
+
+    /// The timing used by the source, the discovery interval and poll period are used.
+    timing: TimingProfile,
+
+    /// The clock used to decide when universe discovery adverts are due.
+    clock: Box<dyn Clock>,
+
+    /// The universes registered once the source is created.
+    universes: Vec<Universe>,
+
+    /// The IPv4 broadcast address packets are sent to, None to send using multicast.
+    broadcast_addr: Option<Ipv4Addr>,
+}
+
+impl SacnSourceBuilder {
+    /// Creates a new `SacnSourceBuilder` for a source with the given name and the default configuration.
+    pub fn new(name: &str) -> SacnSourceBuilder {
+        SacnSourceBuilder {
+            name: name.to_string(),
+            cid: None,
+            cid_store: None,
+            binding: SourceBinding::Addr(SocketAddr::new(
+                IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                ACN_SDT_MULTICAST_PORT,
+            )),
+            multicast_interface_v4: None,
+            multicast_interface_v6: None,
+            ttl: None,
+            multicast_ttl: None,
+            multicast_loop: None,
+            dscp: None,
+            send_buffer_size: None,
+            preview_mode: false,
+            draft_mode: false,
+            is_sending_discovery: true,
+            timing: TimingProfile::default(),
+            clock: Box::new(SystemClock),
+            universes: Vec::new(),
+            broadcast_addr: None,
+        }
+    }
+
+    /// Sets the CID of the source, by default a random CID is generated.
+    ///
+    /// A CID that stays the same across restarts can be derived from a name using `cid::derive_cid`.
+    pub fn cid(mut self, cid: Uuid) -> Self {
+        self.cid = Some(cid);
+        self
+    }
+
+    /// Loads the CID of the source from the given store when built, if the store doesn't hold a CID yet a random CID is generated
+    /// and saved to it so that the same CID is used next time.
+    pub fn cid_store(mut self, store: CidStore) -> Self {
+        self.cid_store = Some(store);
+        self
+    }
+
+    /// Sends using a UDP socket bound to the given address, replacing any previously given address or transport.
+    pub fn bind(mut self, addr: SocketAddr) -> Self {
+        self.binding = SourceBinding::Addr(addr);
+        self
+    }
+
+    /// Sends over the given transport, replacing any previously given address or transport.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.binding = SourceBinding::Transport(Box::new(transport));
+        self
+    }
+
+    /// Sets the IPv4 interface that multicast packets are sent on, by default the operating system chooses.
+    pub fn multicast_interface_v4(mut self, interface: Ipv4Addr) -> Self {
+        self.multicast_interface_v4 = Some(interface);
+        self
+    }
+
+    /// Sets the index of the IPv6 interface that multicast packets are sent on, by default the operating system chooses.
+    pub fn multicast_interface_v6(mut self, interface: u32) -> Self {
+        self.multicast_interface_v6 = Some(interface);
+        self
+    }
+
+    /// Sets the time to live of unicast packets, for an IPv6 source this sets the unicast hop limit.
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the time to live of multicast packets, for an IPv6 source this sets the multicast hop limit.
+    pub fn multicast_ttl(mut self, ttl: u32) -> Self {
+        self.multicast_ttl = Some(ttl);
+        self
+    }
+
+    /// Sets whether multicast packets are looped back to the sending host.
+    pub fn multicast_loop(mut self, val: bool) -> Self {
+        self.multicast_loop = Some(val);
+        self
+    }
+
+    /// Sets the differentiated services code point (DSCP) of sent packets, see `SacnSource::set_dscp`.
+    /// Must be in the range [0, 63].
+    pub fn dscp(mut self, dscp: u8) -> Self {
+        self.dscp = Some(dscp);
+        self
+    }
+
+    /// Sets the size of the socket send buffer in bytes.
+    pub fn send_buffer_size(mut self, size: usize) -> Self {
+        self.send_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets whether sent data packets are marked as preview data, see `SacnSource::set_preview_mode`.
+    pub fn preview_mode(mut self, val: bool) -> Self {
+        self.preview_mode = val;
+        self
+    }
+
+    /// Sets whether data packets are sent in the layout of the ANSI E1.31 draft, see `SacnSource::set_draft_mode`.
+    pub fn draft_mode(mut self, val: bool) -> Self {
+        self.draft_mode = val;
+        self
+    }
+
+    /// Sets whether periodic universe discovery adverts are sent, see `SacnSource::set_is_sending_discovery`.
+    pub fn is_sending_discovery(mut self, val: bool) -> Self {
+        self.is_sending_discovery = val;
+        self
+    }
+
+    /// Sets the interval between universe discovery adverts, by default `E131_UNIVERSE_DISCOVERY_INTERVAL`.
+    pub fn discovery_interval(mut self, interval: Duration) -> Self {
+        self.timing.discovery_interval = interval;
+        self
+    }
+
+    /// Sets the timing used by the source, see `TimingProfile`. This replaces any previously set discovery interval.
+    pub fn timing(mut self, timing: TimingProfile) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Sets the clock used to decide when universe discovery adverts are due, see `Clock`.
+    pub fn clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Sets the universes registered on the source once created, see `SacnSource::register_universes`.
+    pub fn universes(mut self, universes: &[Universe]) -> Self {
+        self.universes = universes.to_vec();
+        self
+    }
+
+    /// Sends to the given IPv4 broadcast address instead of using multicast, see `SacnSource::set_broadcast_addr`.
+    pub fn broadcast(mut self, broadcast_addr: Ipv4Addr) -> Self {
+        self.broadcast_addr = Some(broadcast_addr);
+        self
+    }
+
+    /// Checks the configuration, creates the source and applies the socket options before starting the update thread.
+    ///
+    /// # Errors
+    /// `MalformedSourceName`: Returned if the name is longer than the maximum allowed size of `E131_SOURCE_NAME_FIELD_LENGTH`.
+    ///
+    /// `InvalidTimingProfile`: Returned if the timing is invalid, e.g. the discovery interval is shorter than the poll period, see
+    /// `TimingProfile::validate`.
+    ///
+    /// `InvalidConfiguration`: Returned if the DSCP is outwith the range [0, 63], if the send buffer size is zero, if a multicast interface
+    /// is given for the other IP version to the source, if the broadcast address is a multicast or unspecified address or if any socket
+    /// option is set with a transport that isn't backed by a socket or if both a CID and a CID store are given.
+    ///
+    /// `OsOperationUnsupported`: Returned if the DSCP is set and the OS doesn't support setting the TOS or traffic class.
+    ///
+    /// `IpVersionError`: Returned if a broadcast address is given and the source isn't IPv4.
+    ///
+    /// Uuid: Returned if the CID store holds something other than a valid CID.
+    ///
+    /// Io: Returned if the socket cannot be created, bound or configured, if the CID store can't be read or written or if the update
+    /// thread fails to be created.
+    pub fn build(self) -> Result<SacnSource> {
+        let poll_period = self.timing.poll_period;
+        SacnSource::start(self.build_internal()?, poll_period)
+    }
+
+    /// Checks the configuration and creates a `PolledSacnSource` which doesn't start an update thread, socket options are applied
+    /// before it is returned.
+    ///
+    /// The `poll_period` of the timing is unused as the source is only updated when `PolledSacnSource::poll` is called.
+    ///
+    /// # Errors
+    /// See `build`, apart from the update thread failing to be created.
+    pub fn build_polled(self) -> Result<PolledSacnSource> {
+        let internal = self.build_internal()?;
+        internal.set_keep_alive_interval(Some(DEFAULT_KEEP_ALIVE_INTERVAL))?;
+        Ok(PolledSacnSource { internal })
+    }
+
+    /// Checks the configuration, creates the internal source and applies the socket options.
+    ///
+    /// # Errors
+    /// See `build`.
+    fn build_internal<S: StateCells>(self) -> Result<SacnSourceInternal<S>> {
+        if self.name.len() > E131_SOURCE_NAME_FIELD_LENGTH {
+            return Err(SacnError::MalformedSourceName(
+                "Source name provided is longer than maximum allowed".to_string(),
+            ));
+        }
+        self.timing.validate()?;
+        if self.dscp.is_some_and(|dscp| dscp > MAX_DSCP) {
+            return Err(SacnError::InvalidConfiguration(format!(
+                "DSCP must be in the range [0, {MAX_DSCP}]"
+            )));
+        }
+        if self.send_buffer_size == Some(0) {
+            return Err(SacnError::InvalidConfiguration(
+                "Send buffer size must be greater than zero".to_string(),
+            ));
+        }
+
+        let cid = match (self.cid, &self.cid_store) {
+            (Some(_), Some(_)) => {
+                return Err(SacnError::InvalidConfiguration(
+                    "A CID and a CID store can't both be given".to_string(),
+                ));
+            }
+            (Some(cid), None) => cid,
+            (None, Some(store)) => store.load_or_generate()?,
+            (None, None) => Uuid::new_v4(),
+        };
+
+        let transport: Box<dyn Transport> = match self.binding {
+            SourceBinding::Addr(addr) => Box::new(UdpTransport::bind_source(addr)?),
+            SourceBinding::Transport(transport) => transport,
+        };
+
+        let internal = SacnSourceInternal::with_cid_transport(
+            &self.name,
+            cid,
+            transport,
+            self.clock,
+            self.timing.discovery_interval,
+        )?;
+
+        let has_socket_options = self.multicast_interface_v4.is_some()
+            || self.multicast_interface_v6.is_some()
+            || self.ttl.is_some()
+            || self.multicast_ttl.is_some()
+            || self.multicast_loop.is_some()
+            || self.dscp.is_some()
+            || self.send_buffer_size.is_some();
+        if has_socket_options {
+            let socket = internal.transport.socket().ok_or_else(|| {
+                SacnError::InvalidConfiguration(
+                    "Socket options can't be set as the transport isn't backed by a socket"
+                        .to_string(),
+                )
+            })?;
+
+            let is_ipv4 = internal.addr.is_ipv4();
+            if (is_ipv4 && self.multicast_interface_v6.is_some())
+                || (!is_ipv4 && self.multicast_interface_v4.is_some())
+            {
+                return Err(SacnError::InvalidConfiguration(
+                    "Multicast interface is for a different IP version to the source".to_string(),
+                ));
+            }
+
+            if let Some(interface) = self.multicast_interface_v4 {
+                socket.set_multicast_if_v4(&interface)?;
+            }
+            if let Some(interface) = self.multicast_interface_v6 {
+                socket.set_multicast_if_v6(interface)?;
+            }
+            if let Some(ttl) = self.ttl {
+                if is_ipv4 {
+                    socket.set_ttl_v4(ttl)?;
+                } else {
+                    socket.set_unicast_hops_v6(ttl)?;
+                }
+            }
+            if let Some(ttl) = self.multicast_ttl {
+                if is_ipv4 {
+                    socket.set_multicast_ttl_v4(ttl)?;
+                } else {
+                    socket.set_multicast_hops_v6(ttl)?;
+                }
+            }
+            if let Some(val) = self.multicast_loop {
+                if is_ipv4 {
+                    socket.set_multicast_loop_v4(val)?;
+                } else {
+                    socket.set_multicast_loop_v6(val)?;
+                }
+            }
+            if let Some(size) = self.send_buffer_size {
+                socket.set_send_buffer_size(size)?;
+            }
+        }
+
+        if let Some(dscp) = self.dscp {
+            internal.set_dscp(dscp)?;
+        }
+        if self.broadcast_addr.is_some() {
+            internal.set_broadcast_addr(self.broadcast_addr)?;
+        }
+        internal.set_preview_mode(self.preview_mode);
+        internal.set_draft_mode(self.draft_mode);
+        internal.set_is_sending_discovery(self.is_sending_discovery);
+        internal.register_universes(&self.universes)?;
+
+        Ok(internal)
+    }
+}
+
+/// A sACN source which doesn't start an update thread, instead the application calls `poll` from its own loop.
+///
+/// This suits single threaded event loops and embedded targets. The source is owned directly rather than shared with a thread so its
+/// locks are never contended.
+///
+/// Each call to `poll` sends a universe discovery advert if one is due and, by default, resends the last data of any universe
+/// which hasn't been sent to for `DEFAULT_KEEP_ALIVE_INTERVAL` so receivers don't time out while the data is unchanged.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sacn::source::PolledSacnSource;
+/// use sacn::types::Universe;
+/// use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+/// use std::time::{Duration, Instant};
+///
+/// let local_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 5569);
+/// let mut src = PolledSacnSource::with_ip("Source", local_addr).unwrap();
+/// let universe = Universe::try_from(1).unwrap();
+/// src.register_universe(universe).unwrap();
+/// src.send(&[universe], &[0, 255], None, None, None).unwrap();
+///
+/// loop {
+///     // Other work of the event loop.
+///     src.poll(Instant::now()).unwrap();
+///     std::thread::sleep(Duration::from_millis(100));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct PolledSacnSource {
+    /// The source, owned directly as there is no update thread to share it with so its state is never locked.
+    internal: SacnSourceInternal<Local>,
+}
+
+impl PolledSacnSource {
+    /// Constructs a new `PolledSacnSource` with the given name binding to the given address, a new CID is generated randomly.
+    ///
+    /// # Errors
+    /// See `with_cid_ip`.
+    pub fn with_ip(name: &str, ip: SocketAddr) -> Result<PolledSacnSource> {
+        PolledSacnSource::with_cid_ip(name, Uuid::new_v4(), ip)
+    }
+
+    /// Constructs a new `PolledSacnSource` with the given name and CID binding to the given address.
+    ///
+    /// # Errors
+    /// `MalformedSourceName`: Returned if the given source name is longer than the maximum allowed size of `E131_SOURCE_NAME_FIELD_LENGTH`.
+    ///
+    /// Io: Returned if the socket cannot be created or bound to the given address.
+    pub fn with_cid_ip(name: &str, cid: Uuid, ip: SocketAddr) -> Result<PolledSacnSource> {
+        SacnSourceBuilder::new(name)
+            .cid(cid)
+            .bind(ip)
+            .build_polled()
+    }
+
+    /// Constructs a new `PolledSacnSource` with the given name and a random CID which sends over the given transport.
+    ///
+    /// # Errors
+    /// `MalformedSourceName`: Returned if the given source name is longer than the maximum allowed size of `E131_SOURCE_NAME_FIELD_LENGTH`.
+    ///
+    /// Io: Returned if the local address of the transport cannot be retrieved.
+    pub fn with_transport<T: Transport + 'static>(
+        name: &str,
+        transport: T,
+    ) -> Result<PolledSacnSource> {
+        SacnSourceBuilder::new(name)
+            .transport(transport)
+            .build_polled()
+    }
+
+    /// Sends a universe discovery advert if the discovery interval has passed since the last one and resends the last data of each
+    /// universe which hasn't been sent to within the keep-alive interval.
+    ///
+    /// This should be called regularly, at least as often as the keep-alive interval, for example from the application's event loop.
+    ///
+    /// # Arguments
+    /// now: The current time, from the same clock as the source which by default is `Instant::now()`.
+    ///
+    /// # Errors
+    /// `SenderAlreadyTerminated`: Returned if the source has been terminated.
+    ///
+    /// Io: Returned if a discovery or data packet fails to be sent.
+    pub fn poll(&mut self, now: Instant) -> Result<()> {
+        if !self.internal.running.get() {
+            return Err(SacnError::SenderAlreadyTerminated(
+                "Attempted to poll".to_string(),
+            ));
+        }
+        self.internal.update(now)
+    }
+
+    /// Registers the given universes on this source in addition to already registered universes, see `SacnSource::register_universes`.
+    ///
+    /// # Errors
+    /// None, the state of a `PolledSacnSource` isn't locked so can't be poisoned.
+    pub fn register_universes(&mut self, universes: &[Universe]) -> Result<()> {
+        self.internal.register_universes(universes)
+    }
+
+    /// Registers a single universe for sending with this source, see `SacnSource::register_universe`.
+    ///
+    /// # Errors
+    /// See `register_universes`.
+    pub fn register_universe(&mut self, universe: Universe) -> Result<()> {
+        self.internal.register_universes(&[universe])
+    }
+
+    /// Sends the given data over the given universes, see `SacnSource::send`.
+    ///
+    /// The data sent to each universe is kept and resent by `poll` if nothing else is sent to the universe within the keep-alive interval.
+    ///
+    /// # Errors
+    /// See `SacnSource::send`, apart from `SourceCorrupt` as the state of a `PolledSacnSource` isn't locked.
+    pub fn send(
+        &mut self,
+        universes: &[Universe],
+        data: &[u8],
+        priority: Option<Priority>,
+        dst_ip: Option<SocketAddr>,
+        synchronisation_addr: Option<SyncAddress>,
+    ) -> Result<()> {
+        self.internal
+            .send(universes, data, priority, dst_ip, synchronisation_addr)
+    }
+
+    /// Sends a synchronisation packet for the given synchronisation address, see `SacnSource::send_sync_packet`.
+    ///
+    /// # Errors
+    /// See `SacnSource::send_sync_packet`, apart from `SourceCorrupt` as the state of a `PolledSacnSource` isn't locked.
+    pub fn send_sync_packet(
+        &mut self,
+        sync_address: SyncAddress,
+        dst_ip: Option<SocketAddr>,
+    ) -> Result<()> {
+        self.internal.send_sync_packet(sync_address.get(), dst_ip)
+    }
+
+    /// Terminates the given universe, sending stream termination packets and deregistering it, see `SacnSource::terminate_stream`.
+    ///
+    /// # Errors
+    /// See `SacnSource::terminate_stream`, apart from `SourceCorrupt` as the state of a `PolledSacnSource` isn't locked.
+    pub fn terminate_stream(&mut self, universe: Universe, start_code: StartCode) -> Result<()> {
+        self.internal
+            .terminate_stream(universe.get(), start_code.get())
+    }
+
+    /// Sets the interval after which `poll` resends the last data of a universe which hasn't been sent to since, None to stop
+    /// resending data. By default `DEFAULT_KEEP_ALIVE_INTERVAL`.
+    ///
+    /// # Errors
+    /// `InvalidConfiguration`: Returned if the interval is zero.
+    pub fn set_keep_alive_interval(&mut self, interval: Option<Duration>) -> Result<()> {
+        self.internal.set_keep_alive_interval(interval)
+    }
+
+    /// Returns the interval after which `poll` resends the last data of a universe, None if data isn't resent.
+    ///
+    pub fn keep_alive_interval(&self) -> Option<Duration> {
+        *self.internal.keep_alive_interval.borrow()
+    }
+
+    /// Returns the ACN CID device identifier of this source.
+    pub fn cid(&self) -> Uuid {
+        *self.internal.cid.borrow()
+    }
+
+    /// Returns the name of this source.
+    pub fn name(&self) -> String {
+        self.internal.name.borrow().clone()
+    }
+
+    /// Sets the name of this source.
+    ///
+    /// # Errors
+    /// `MalformedSourceName`: Returned if the given source name is longer than the maximum allowed size of `E131_SOURCE_NAME_FIELD_LENGTH`.
+    pub fn set_name(&mut self, name: &str) -> Result<()> {
+        self.internal.set_name(name)
+    }
+
+    /// Returns true if this source is in preview mode, see `SacnSource::set_preview_mode`.
+    pub fn preview_mode(&self) -> bool {
+        self.internal.preview_mode()
+    }
+
+    /// Sets whether data packets sent by this source are marked as preview data, see `SacnSource::set_preview_mode`.
+    pub fn set_preview_mode(&mut self, preview_mode: bool) {
+        self.internal.set_preview_mode(preview_mode);
+    }
+
+    /// Returns true if this source sends data packets in the layout of the ANSI E1.31 draft, see `SacnSource::set_draft_mode`.
+    pub fn draft_mode(&self) -> bool {
+        self.internal.draft_mode()
+    }
+
+    /// Sets whether data packets are sent in the layout of the ANSI E1.31 draft, see `SacnSource::set_draft_mode`.
+    pub fn set_draft_mode(&mut self, val: bool) {
+        self.internal.set_draft_mode(val);
+    }
+
+    /// Sets whether `poll` sends universe discovery adverts.
+    pub fn set_is_sending_discovery(&mut self, val: bool) {
+        self.internal.set_is_sending_discovery(val);
+    }
+
+    /// Returns the universes currently registered on this source.
+    pub fn universes(&self) -> Vec<Universe> {
+        // Only a `Universe` can be registered so every registered universe is in range.
+        self.internal
+            .universes
+            .borrow()
+            .iter()
+            .copied()
+            .filter_map(Universe::new)
+            .collect()
+    }
+
+    /// Shuts down this source, terminating the streams of all registered universes.
+    ///
+    /// This does the same as dropping the source however reports which universes were terminated and which failed rather than
+    /// ignoring errors, see `SacnSource::shutdown`.
+    ///
+    /// # Arguments
+    /// timeout: The time after which no further universes are terminated, any remaining universes are reported as failed with a
+    ///     `ShutdownTimeout` error. It is measured on the clock of the source, see `SacnSourceBuilder::clock`. None means all
+    ///     universes are terminated.
+    ///
+    /// `start_code`: The start code used in the stream termination packets, None means `StartCode::NULL` is used.
     ///
     /// # Errors
-    /// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
-    /// a panic while accessing causing the source to be left in a potentially inconsistent state.
-    pub fn universes(&self) -> Result<Vec<u16>> {
-        Ok(unlock_internal(&self.internal)?.universes())
+    /// None, the state of a `PolledSacnSource` isn't locked so can't be poisoned. Failures to terminate a universe are reported in the
+    /// `ShutdownReport`.
+    pub fn shutdown(
+        self,
+        timeout: Option<Duration>,
+        start_code: Option<StartCode>,
+    ) -> Result<ShutdownReport> {
+        self.internal.shutdown(
+            timeout.map(|t| self.internal.clock.now() + t),
+            start_code.map_or(DEFAULT_TERMINATE_START_CODE, StartCode::get),
+        )
     }
 }
 
-/// By implementing the Drop trait for `SacnSource` it means that the user doesn't have to explicitly clean up the source
-/// and if it goes out of reference it will clean itself up and send the required termination packets etc.
-impl Drop for SacnSource {
+impl Drop for PolledSacnSource {
     fn drop(&mut self) {
-        match unlock_internal_mut(&mut self.internal) {
-            Ok(mut i) => {
-                i.running = false;
-            }
-            Err(_) => {
-                return;
-            } // As drop isn't always explicitly called and cannot return an error the error is ignored. Memory safety is maintain and this prevents causing a panic!.
-        };
-
-        if let Some(thread) = self.update_thread.take() {
-            // Internal is accessed twice separately, this allows the discovery thread to interleave between running being set to false speeding up termination.
-            if let Ok(mut i) = unlock_internal_mut(&mut self.internal) {
-                let _ = i.terminate(DEFAULT_TERMINATE_START_CODE);
-                {} // For same reasons as above a potential error is ignored and a 'best attempt' is used to clean up.
-            } else {
-                {} // As drop isn't always explicitly called and cannot return an error the error is ignored. Memory safety is maintain and this prevents causing a panic!.
-            };
-
-            thread.join().unwrap();
+        if self.internal.running.get() {
+            // As drop cannot return an error a 'best attempt' is used to terminate the registered universes.
+            let _ = self.internal.terminate(DEFAULT_TERMINATE_START_CODE);
         }
     }
 }
 
-impl SacnSourceInternal {
-    /// Constructs a new `SacnSourceInternal` with DMX START code set to 0 with specified CID and binding IP address.
+impl<S: StateCells> SacnSourceInternal<S> {
+    /// Constructs a new `SacnSourceInternal` with DMX START code set to 0 with specified CID which sends over the given transport.
     ///
-    /// By default for an IPv6 address this will only receive IPv6 data but IPv4 can also be enabled by calling `set_ipv6_only(false)`.
-    /// By default the TTL for ipv4 packets is 1 to keep them within the local network.
+    /// The address of the source is the local address of the transport.
     ///
     /// # Arguments:
     /// name: The human readable name for this sacn source.
     /// cid:  The UUID for this source.
-    /// ip:   The address that this source should bind to.
+    /// transport: The transport that this source should send on.
+    /// clock: The clock used to decide when universe discovery adverts are due.
+    /// `discovery_interval`: The interval between universe discovery adverts.
     ///
     /// # Errors
-    /// Io: Returned if the underlying socket cannot be created or the IP cannot be bound to the underlying socket. See (`UdpBuilder::new_v4`)[`fn.new_v4.UdpBuilder`], (`UdpBuilder::new_v6`)[`fn.new_v6.UdpBuilder`] and (`Socket::bind`)[fn.bind.Socket2].
+    /// `MalformedSourceName`: Returned if the given source name is longer than the maximum allowed size of `E131_SOURCE_NAME_FIELD_LENGTH`.
     ///
-    /// `UnsupportedIpVersion`: Returned if the `SockAddr` is not IPv4 or IPv6.
-    fn with_cid_ip(name: &str, cid: Uuid, ip: SocketAddr) -> Result<SacnSourceInternal> {
-        let socket = if ip.is_ipv4() {
-            Socket::new(Domain::IPV4, Type::DGRAM, None).unwrap()
-        } else if ip.is_ipv6() {
-            Socket::new(Domain::IPV6, Type::DGRAM, None).unwrap()
-        } else {
-            return Err(SacnError::UnsupportedIpVersion(
-                "Address to create SacnSource is not IPv4 or IPv6".to_string(),
+    /// Io: Returned if the local address of the transport cannot be retrieved, see `Transport::local_addr`.
+    fn with_cid_transport(
+        name: &str,
+        cid: Uuid,
+        transport: Box<dyn Transport>,
+        clock: Box<dyn Clock>,
+        discovery_interval: Duration,
+    ) -> Result<SacnSourceInternal<S>> {
+        if name.len() > E131_SOURCE_NAME_FIELD_LENGTH {
+            return Err(SacnError::MalformedSourceName(
+                "Source name provided is longer than maximum allowed".to_string(),
             ));
-        };
-
-        // Multiple different processes might want to send to the sACN stream so therefore need to allow re-using the ACN port.
-        // Set reuse port is only supported on linux.
-        #[cfg(target_os = "linux")]
-        socket.set_reuse_port(true)?;
-
-        // Set reuse address supported on linux and windows.
-        socket.set_reuse_address(true)?;
-        socket.bind(&ip.into())?;
+        }
 
         let ds = SacnSourceInternal {
-            socket,
-            addr: ip,
-            cid,
-            name: name.to_string(),
-            preview_data: false,
-            data_sequences: RefCell::new(HashMap::new()),
-            sync_sequences: RefCell::new(HashMap::new()),
-            universes: Vec::new(),
-            running: true,
-            last_discovery_advert_timestamp: Instant::now(),
-            is_sending_discovery: true,
+            addr: transport.local_addr()?,
+            transport,
+            cid: S::Lock::new(cid),
+            name: S::Lock::new(name.to_string()),
+            preview_data: S::Flag::new(false),
+            draft_mode: S::Flag::new(false),
+            data_sequences: SequenceNumbers::new(),
+            sync_sequences: SequenceNumbers::new(),
+            universes: S::Lock::new(Vec::new()),
+            running: S::Flag::new(true),
+            last_discovery_advert_timestamp: S::Lock::new(clock.now()),
+            is_sending_discovery: S::Flag::new(true),
+            clock,
+            discovery_interval,
+            broadcast_addr: S::Lock::new(None),
+            keep_alive_interval: S::Lock::new(None),
+            last_data: S::Lock::new(HashMap::new()),
         };
 
         Ok(ds)
@@ -604,8 +1884,8 @@ impl SacnSourceInternal {
     ///
     /// # Arguments:
     /// val: The new value of the `is_sending_discovery` flag.
-    fn set_is_sending_discovery(&mut self, val: bool) {
-        self.is_sending_discovery = val;
+    fn set_is_sending_discovery(&self, val: bool) {
+        self.is_sending_discovery.set(val);
     }
 
     /// Registers the given array of universes with this source.
@@ -613,33 +1893,19 @@ impl SacnSourceInternal {
     /// Any universes already registered won't be re-registered and will have no effect.
     ///
     /// # Arguments:
-    /// universes: The sACN universe to register. Note that sACN universes start at 1 not 0.
-    ///
-    /// # Errors
-    /// See `register_universe(fn.register_universe.source)` for more details.
-    fn register_universes(&mut self, universes: &[u16]) -> Result<()> {
-        for u in universes {
-            self.register_universe(*u)?;
-        }
-        Ok(())
-    }
-
-    /// Registers the given universe for sending with this source.
-    ///
-    /// If a universe is already registered then this method has no effect.
+    /// universes: The sACN universes to register.
     ///
     /// # Errors
-    /// `IllegalUniverse`: Returned if the universe is outwith the allowed range, see (`is_universe_in_range`)[`fn.is_universe_in_range.packet`].
-    fn register_universe(&mut self, universe: u16) -> Result<()> {
-        is_universe_in_range(universe)?;
-
-        if self.universes.is_empty() {
-            self.universes.push(universe);
-        } else if let Err(i) = self.universes.binary_search(&universe) {
-            // Value not found, i is the position it should be inserted
-            self.universes.insert(i, universe);
-        } else {
-            // If value found then don't insert to avoid duplicates.
+    /// `SourceCorrupt`: Returned if the lock of the registered universes is poisoned.
+    fn register_universes(&self, universes: &[Universe]) -> Result<()> {
+        let mut registered = self.universes.write()?;
+        for universe in universes.iter().map(|u| u.get()) {
+            if let Err(i) = registered.binary_search(&universe) {
+                // Value not found, i is the position it should be inserted
+                registered.insert(i, universe);
+            } else {
+                // If value found then don't insert to avoid duplicates.
+            }
         }
 
         Ok(())
@@ -651,17 +1917,21 @@ impl SacnSourceInternal {
     /// `IllegalUniverse`: Returned if the universe is outwith the allowed range, see (`is_universe_in_range`)[`fn.is_universe_in_range.packet`].
     ///
     /// `UniverseNotFound`: Returned if the given universe was never registered originally.
-    fn deregister_universe(&mut self, universe: u16) -> Result<()> {
+    ///
+    /// `SourceCorrupt`: Returned if the lock of the registered universes or the last sent data is poisoned.
+    fn deregister_universe(&self, universe: u16) -> Result<()> {
         is_universe_in_range(universe)?;
 
-        match self.universes.binary_search(&universe) {
+        let mut universes = self.universes.write()?;
+        match universes.binary_search(&universe) {
             Err(_i) => {
                 // Value not found
                 Err(SacnError::UniverseNotFound(universe))
             }
             Ok(i) => {
                 // Value found, i is index.
-                self.universes.remove(i);
+                universes.remove(i);
+                self.last_data.write()?.remove(&universe);
                 Ok(())
             }
         }
@@ -676,7 +1946,7 @@ impl SacnSourceInternal {
     fn universe_allowed(&self, u: &u16) -> Result<()> {
         is_universe_in_range(*u)?;
 
-        if !self.universes.contains(u) {
+        if !self.universes.read()?.contains(u) {
             return Err(SacnError::UniverseNotRegistered(*u));
         }
 
@@ -692,13 +1962,12 @@ impl SacnSourceInternal {
     ///
     /// data:         The data that should be sent, must have a length greater than 0.
     ///
-    /// priority:     The E131 priority that the data should be sent with, must be less than `E131_MAX_PRIORITY` (`const.E131_MAX_PRIORITY.packet`),
-    ///                 if a value of None is provided then the default of `E131_DEFAULT_PRIORITY` (`const.E131_DEFAULT_PRIORITY.packet`) is used.
+    /// priority:     The E131 priority that the data should be sent with, if a value of None is provided then `Priority::DEFAULT` is used.
     ///
     /// `dst_ip`:       The destination IP, can be Ipv4 or Ipv6, None if should be sent using ip multicast.
     ///
-    /// `sync_address`: The address to use for synchronisation, must be a valid universe, None indicates no synchronisation. If synchronisation is required a
-    ///                 reasonable default address to use is the first universe that this data is being sent to.
+    /// `sync_address`: The address to use for synchronisation, must be a registered universe, None indicates no synchronisation. If synchronisation
+    ///                 is required a reasonable default address to use is the first universe that this data is being sent to.
     ///
     /// As per ANSI E1.31-2018 Section 6.6.1 this method shouldn't be called at a higher refresher rate than specified in ANSI E1.11 [DMX] unless
     ///     configured by the user to do so in an environment which doesn't contain any E1.31 to DMX512-A converters.
@@ -710,24 +1979,22 @@ impl SacnSourceInternal {
     ///
     /// `InvalidInput`: Returned if the data array has length 0 or if an insufficient number of universes for the given data are provided (each universe takes 513 bytes of data).
     ///
-    /// `InvalidPriority`: Returned if the priority is greater than the allowed maximum priority of `E131_MAX_PRIORITY`.
-    ///
-    /// `IllegalUniverse`: Returned if the universe is outwith the allowed range as specified by ANSI E1.31-2018 Section 6.2.7.
-    ///
     /// `UniverseNotRegistered`: Returned if the universe is not registered on the given `SacnSourceInternal`.
     ///
+    /// `IllegalSyncUniverse`: Returned if the synchronisation address is not registered on the given `SacnSourceInternal`.
+    ///
     /// `ExceedUniverseCapacity`: Returned if the data has a length greater than the maximum allowed within a universe (`packet::UNIVERSE_CHANNEL_CAPACITY`).
     ///
     /// Io: Returned if the data fails to be sent on the socket, see `send_to(fn.send_to.Socket)`.
     fn send(
         &self,
-        universes: &[u16],
+        universes: &[Universe],
         data: &[u8],
-        priority: Option<u8>,
+        priority: Option<Priority>,
         dst_ip: Option<SocketAddr>,
-        synchronisation_addr: Option<u16>,
+        synchronisation_addr: Option<SyncAddress>,
     ) -> Result<()> {
-        if !self.running {
+        if !self.running.get() {
             // Indicates that this sender has been terminated.
             return Err(SacnError::SenderAlreadyTerminated(
                 "Attempted to send".to_string(),
@@ -741,13 +2008,13 @@ impl SacnSourceInternal {
         // Check all the given universes are valid before doing any action.
         // This prevents leaving the source in an inconsistent state if later a universe is found to be invalid.
         for u in universes {
-            self.universe_allowed(u)?;
+            self.universe_allowed(&u.get())?;
         }
 
-        // Check that the synchronisation universe is also valid.
-        if synchronisation_addr.is_some() {
-            self.universe_allowed(&synchronisation_addr.unwrap())
-                .map_err(|_e| SacnError::IllegalSyncUniverse(synchronisation_addr.unwrap()))?;
+        // Check that the synchronisation universe is also registered.
+        if let Some(sync_addr) = synchronisation_addr {
+            self.universe_allowed(&sync_addr.get())
+                .map_err(|_e| SacnError::IllegalSyncUniverse(sync_addr.get()))?;
         }
 
         // + 1 as there must be at least 1 universe required as the data isn't empty then additional universes for any more.
@@ -764,11 +2031,11 @@ impl SacnSourceInternal {
             let end_index = cmp::min((i + 1) * UNIVERSE_CHANNEL_CAPACITY, data.len());
 
             self.send_universe(
-                universe,
+                universe.get(),
                 &data[start_index..end_index],
-                priority.unwrap_or(E131_DEFAULT_PRIORITY),
+                priority.unwrap_or_default().get(),
                 &dst_ip,
-                synchronisation_addr.unwrap_or(NO_SYNC_UNIVERSE),
+                synchronisation_addr.map_or(NO_SYNC_UNIVERSE, SyncAddress::get),
             )?;
         }
 
@@ -782,15 +2049,13 @@ impl SacnSourceInternal {
     ///
     /// data:         The data that should be sent, must be less than or equal in length to `UNIVERSE_CHANNEL_CAPACITY(const.UNIVERSE_CHANNEL_CAPACITY.packet)`.
     ///
-    /// priority:     The E131 priority that the data should be sent with, must be less than `E131_MAX_PRIORITY` (`const.E131_MAX_PRIORITY.packet`), default `E131_DEFAULT_PRIORITY`.
+    /// priority:     The E131 priority that the data should be sent with, already checked to be within range by `Priority`.
     ///
     /// `dst_ip`:       The destination IP, can be Ipv4 or Ipv6, None if should be sent using ip multicast.
     ///
     /// `sync_address`: The address to use for synchronisation, must be a valid universe, 0 indicates no synchronisation.
     ///
     /// # Errors
-    /// `InvalidInput`: Returned if the priority is greater than the allowed maximum priority of `E131_MAX_PRIORITY`.
-    ///
     /// `ExceedUniverseCapacity`: Returned if the data has a length greater than the maximum allowed within a universe.
     ///
     /// `IllegalUniverse`: Returned if the given universe is outwith the allowed range of universes,
@@ -805,68 +2070,136 @@ impl SacnSourceInternal {
         dst_ip: &Option<SocketAddr>,
         sync_address: u16,
     ) -> Result<()> {
-        if priority > E131_MAX_PRIORITY {
-            return Err(SacnError::InvalidPriority(priority));
-        }
-
         if data.len() > UNIVERSE_CHANNEL_CAPACITY {
             return Err(SacnError::ExceedUniverseCapacity(data.len()));
         }
 
-        let mut sequence = match self.data_sequences.borrow().get(&universe) {
-            Some(s) => *s,
-            None => STARTING_SEQUENCE_NUMBER,
+        let sequence = self.data_sequences.advance(universe)?;
+        let name = self.name.read()?;
+
+        let framing_layer = DataPacketFramingLayer {
+            source_name: name.as_str().into(),
+            priority,
+            synchronization_address: sync_address,
+            sequence_number: sequence,
+            preview_data: self.preview_mode(),
+            stream_terminated: false,
+            force_synchronization: false,
+            universe,
+            data: DataPacketDmpLayer {
+                property_values: {
+                    let mut property_values = Vec::with_capacity(data.len());
+                    property_values.extend(data);
+                    property_values.into()
+                },
+            },
         };
 
         let packet = AcnRootLayerProtocol {
             pdu: E131RootLayer {
-                cid: self.cid,
-                data: E131RootLayerData::DataPacket(DataPacketFramingLayer {
-                    source_name: self.name.as_str().into(),
-                    priority,
-                    synchronization_address: sync_address,
-                    sequence_number: sequence,
-                    preview_data: self.preview_data,
-                    stream_terminated: false,
-                    force_synchronization: false,
-                    universe,
-                    data: DataPacketDmpLayer {
-                        property_values: {
-                            let mut property_values = Vec::with_capacity(data.len());
-                            property_values.extend(data);
-                            property_values.into()
-                        },
-                    },
-                }),
+                cid: self.cid()?,
+                data: if self.draft_mode() {
+                    pack_draft_data_packet(&framing_layer)?
+                } else {
+                    E131RootLayerData::DataPacket(framing_layer)
+                },
             },
         };
 
-        if dst_ip.is_some() {
-            self.socket
-                .send_to(&packet.pack_alloc().unwrap(), &dst_ip.unwrap().into())
-                .map_err(|e| {
-                    std::io::Error::new(e.kind(), "Failed to send data unicast on socket")
-                })?;
+        if let Some(dst) = dst_ip {
+            self.transport
+                .send_to(&packet.pack_alloc().unwrap(), *dst)
+                .map_err(|e| io_context(e, "Failed to send data unicast on socket"))?;
         } else {
-            let dst = if self.addr.is_ipv6() {
-                universe_to_ipv6_multicast_addr(universe)?
-            } else {
-                universe_to_ipv4_multicast_addr(universe)?
-            };
-
-            self.socket
-                .send_to(&packet.pack_alloc().unwrap(), &dst)
-                .map_err(|e| {
-                    std::io::Error::new(e.kind(), "Failed to send data multicast on socket")
-                })?;
+            let dst = self.universe_dst_addr(universe)?;
+
+            self.transport
+                .send_to(&packet.pack_alloc().unwrap(), dst)
+                .map_err(|e| io_context(e, "Failed to send data multicast on socket"))?;
         }
 
-        if sequence == 255 {
-            sequence = 0;
-        } else {
-            sequence += 1;
+        if self.keep_alive_interval.read()?.is_some() {
+            self.last_data.write()?.insert(
+                universe,
+                LastData {
+                    data: data.to_vec(),
+                    priority,
+                    dst_ip: *dst_ip,
+                    sync_address,
+                    sent: self.clock.now(),
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Sends a universe discovery advert if one is due and resends the last data of any universe whose keep-alive is due.
+    ///
+    /// # Arguments
+    /// now: The current time, from the same clock as the source.
+    ///
+    /// # Errors
+    /// Returns an error if a discovery or data packet cannot be sent, see (`send_universe_discovery`)[`fn.send_universe_discovery.source`]
+    /// and (`send_universe`)[`fn.send_universe.source`].
+    fn update(&self, now: Instant) -> Result<()> {
+        if self.is_sending_discovery.get() {
+            let mut last_advert = self.last_discovery_advert_timestamp.write()?;
+            if now.duration_since(*last_advert) > self.discovery_interval {
+                self.send_universe_discovery()?;
+                *last_advert = now;
+            }
+        }
+
+        let keep_alive_interval = *self.keep_alive_interval.read()?;
+        if let Some(interval) = keep_alive_interval {
+            let mut due: Vec<u16> = self
+                .last_data
+                .write()?
+                .iter()
+                .filter(|(_, last)| now.duration_since(last.sent) >= interval)
+                .map(|(u, _)| *u)
+                .collect();
+            // Resend in universe order so the order packets are sent in doesn't depend on the HashMap.
+            due.sort_unstable();
+
+            for universe in due {
+                let last = self.last_data.write()?.remove(&universe);
+                if let Some(last) = last
+                    && let Err(e) = self.send_universe(
+                        universe,
+                        &last.data,
+                        last.priority,
+                        &last.dst_ip,
+                        last.sync_address,
+                    )
+                {
+                    // Kept so the keep-alive is tried again on the next update.
+                    self.last_data.write()?.insert(universe, last);
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the interval after which the last data sent to a universe is resent, None to stop resending data.
+    ///
+    /// # Errors
+    /// `InvalidConfiguration`: Returned if the interval is zero.
+    ///
+    /// `SourceCorrupt`: Returned if the lock of the interval or the last sent data is poisoned.
+    fn set_keep_alive_interval(&self, interval: Option<Duration>) -> Result<()> {
+        if interval.is_some_and(|i| i.is_zero()) {
+            return Err(SacnError::InvalidConfiguration(
+                "Keep-alive interval must be greater than zero".to_string(),
+            ));
         }
-        self.data_sequences.borrow_mut().insert(universe, sequence);
+        let mut keep_alive_interval = self.keep_alive_interval.write()?;
+        if interval.is_none() {
+            self.last_data.write()?.clear();
+        }
+        *keep_alive_interval = interval;
         Ok(())
     }
 
@@ -891,38 +2224,26 @@ impl SacnSourceInternal {
     fn send_sync_packet(&self, universe: u16, dst_ip: Option<SocketAddr>) -> Result<()> {
         self.universe_allowed(&universe)?;
 
-        let ip = if let Some(dst) = dst_ip {
-            dst.into()
-        } else if self.addr.is_ipv6() {
-            universe_to_ipv6_multicast_addr(universe)?
-        } else {
-            universe_to_ipv4_multicast_addr(universe)?
+        let ip = match dst_ip {
+            Some(dst) => dst,
+            None => self.universe_dst_addr(universe)?,
         };
 
-        let mut sequence = match self.sync_sequences.borrow().get(&universe) {
-            Some(s) => *s,
-            None => STARTING_SEQUENCE_NUMBER,
-        };
+        let sequence = self.sync_sequences.advance(universe)?;
 
         let packet = AcnRootLayerProtocol {
             pdu: E131RootLayer {
-                cid: self.cid,
+                cid: self.cid()?,
                 data: E131RootLayerData::SynchronizationPacket(SynchronizationPacketFramingLayer {
                     sequence_number: sequence,
                     synchronization_address: universe,
                 }),
             },
         };
-        self.socket
-            .send_to(&packet.pack_alloc()?, &ip)
-            .map_err(|e| std::io::Error::new(e.kind(), "Failed to send sync packet on socket"))?;
+        self.transport
+            .send_to(&packet.pack_alloc()?, ip)
+            .map_err(|e| io_context(e, "Failed to send sync packet on socket"))?;
 
-        if sequence == 255 {
-            sequence = 0;
-        } else {
-            sequence += 1;
-        }
-        self.sync_sequences.borrow_mut().insert(universe, sequence);
         Ok(())
     }
 
@@ -950,30 +2271,23 @@ impl SacnSourceInternal {
         self.universe_allowed(&universe)?;
 
         let ip = match dst_ip {
-            Some(x) => x.into(),
-            None => {
-                if self.addr.is_ipv6() {
-                    universe_to_ipv6_multicast_addr(universe)?
-                } else {
-                    universe_to_ipv4_multicast_addr(universe)?
-                }
-            }
+            Some(x) => x,
+            None => self.universe_dst_addr(universe)?,
         };
 
-        let mut sequence = match self.data_sequences.borrow_mut().remove(&universe) {
-            Some(s) => s,
-            None => STARTING_SEQUENCE_NUMBER,
-        };
+        let sequence = self.data_sequences.advance(universe)?;
+        let name = self.name.read()?;
 
+        // The draft has no Stream_Terminated option so termination packets are always sent using ANSI E1.31-2018, even in draft mode.
         let packet = AcnRootLayerProtocol {
             pdu: E131RootLayer {
-                cid: self.cid,
+                cid: self.cid()?,
                 data: E131RootLayerData::DataPacket(DataPacketFramingLayer {
-                    source_name: self.name.as_str().into(),
+                    source_name: name.as_str().into(),
                     priority: 100,
                     synchronization_address: 0,
                     sequence_number: sequence,
-                    preview_data: self.preview_data,
+                    preview_data: self.preview_mode(),
                     stream_terminated: true,
                     force_synchronization: false,
                     universe,
@@ -985,15 +2299,8 @@ impl SacnSourceInternal {
         };
         let res = &packet.pack_alloc().unwrap();
 
-        self.socket.send_to(res, &ip)?;
+        self.transport.send_to(res, ip)?;
 
-        if sequence == 255 {
-            sequence = 0;
-        } else {
-            sequence += 1;
-        }
-
-        self.data_sequences.borrow_mut().insert(universe, sequence);
         Ok(())
     }
 
@@ -1012,7 +2319,7 @@ impl SacnSourceInternal {
     /// `UniverseNotRegistered`: Returned if the universe is not registered on this source.
     ///
     /// Io: Returned if the termination packets fail to be sent on the socket.
-    fn terminate_stream(&mut self, universe: u16, start_code: u8) -> Result<()> {
+    fn terminate_stream(&self, universe: u16, start_code: u8) -> Result<()> {
         for _ in 0..E131_TERMINATE_STREAM_PACKET_COUNT {
             self.send_terminate_stream_pkt(universe, None, start_code)?;
         }
@@ -1030,15 +2337,42 @@ impl SacnSourceInternal {
     ///
     /// # Errors:
     /// Io: Returned if the termination packets fail to be sent on the underlying socket.
-    fn terminate(&mut self, start_code: u8) -> Result<()> {
-        self.running = false;
-        let universes = self.universes.clone(); // About to start manipulating self.universes as universes are removed so clone original list.
+    fn terminate(&self, start_code: u8) -> Result<()> {
+        self.running.set(false);
+        let universes = self.universes()?; // About to start manipulating self.universes as universes are removed so clone original list.
         for u in universes {
             self.terminate_stream(u, start_code)?;
         }
         Ok(())
     }
 
+    /// Terminates this sender, unlike `terminate` every universe is attempted even if terminating an earlier universe fails.
+    ///
+    /// # Arguments
+    /// deadline: The time on the clock of this source after which no further universes are terminated, None if there is no deadline.
+    ///
+    /// `start_code`: The start code used in the stream termination packets.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock of the registered universes is poisoned.
+    fn shutdown(&self, deadline: Option<Instant>, start_code: u8) -> Result<ShutdownReport> {
+        self.running.set(false);
+
+        let mut report = ShutdownReport::default();
+        for u in self.registered_universes()? {
+            if deadline.is_some_and(|d| self.clock.now() >= d) {
+                report.failed.push((u, SacnError::ShutdownTimeout(u.get())));
+                continue;
+            }
+
+            match self.terminate_stream(u.get(), start_code) {
+                Ok(()) => report.completed.push(u),
+                Err(e) => report.failed.push((u, e)),
+            }
+        }
+        Ok(report)
+    }
+
     /// Sends a universe discovery packet advertising the universes that this source is registered to send.
     ///
     /// This packet may be broken down into multiple pages internally resulting in multiple UDP packets.
@@ -1046,20 +2380,20 @@ impl SacnSourceInternal {
     /// # Errors
     /// See (`send_universe_discovery_detailed`)[`fn.send_universe_discovery_detailed.source`].
     fn send_universe_discovery(&self) -> Result<()> {
-        // Given a u16 universe field and self.universes containing no duplicates it means that the maximum total number of universes (65536, ignoring sACN restrictions)
+        // Copied so that universes can be registered while the discovery packets are sent.
+        let universes = self.universes()?;
+
+        // Given a u16 universe field and universes containing no duplicates it means that the maximum total number of universes (65536, ignoring sACN restrictions)
         // divided by the number of universes per page (512) is 128 which therefore fits into the discovery universe 8 bit page field making this cast safe.
-        let pages_req: u8 = ((self.universes.len() / DISCOVERY_UNI_PER_PAGE) + 1) as u8;
+        let pages_req: u8 = ((universes.len() / DISCOVERY_UNI_PER_PAGE) + 1) as u8;
 
         for p in 0..pages_req {
             let start_index = (p as usize) * DISCOVERY_UNI_PER_PAGE;
-            let end_index = min(
-                ((p as usize) + 1) * DISCOVERY_UNI_PER_PAGE,
-                self.universes.len(),
-            );
+            let end_index = min(((p as usize) + 1) * DISCOVERY_UNI_PER_PAGE, universes.len());
             self.send_universe_discovery_detailed(
                 p,
                 pages_req - 1,
-                &self.universes[start_index..end_index],
+                &universes[start_index..end_index],
             )?;
         }
         Ok(())
@@ -1087,12 +2421,13 @@ impl SacnSourceInternal {
         last_page: u8,
         universes: &[u16],
     ) -> Result<()> {
+        let name = self.name.read()?;
         let packet = AcnRootLayerProtocol {
             pdu: E131RootLayer {
-                cid: self.cid,
+                cid: self.cid()?,
                 data: E131RootLayerData::UniverseDiscoveryPacket(
                     UniverseDiscoveryPacketFramingLayer {
-                        source_name: self.name.as_str().into(),
+                        source_name: name.as_str().into(),
                         data: UniverseDiscoveryPacketUniverseDiscoveryLayer {
                             page,
                             last_page,
@@ -1103,33 +2438,124 @@ impl SacnSourceInternal {
             },
         };
 
-        let ip = if self.addr.is_ipv6() {
-            universe_to_ipv6_multicast_addr(E131_DISCOVERY_UNIVERSE)?
+        let ip = self.universe_dst_addr(E131_DISCOVERY_UNIVERSE)?;
+
+        self.transport.send_to(&packet.pack_alloc()?, ip)?;
+
+        Ok(())
+    }
+
+    /// Returns the multicast address for the given universe, using the IPv6 multicast address if this source is bound to an IPv6 address
+    /// and the IPv4 multicast address otherwise.
+    ///
+    /// # Errors
+    /// `IllegalUniverse`: Returned if the universe is outwith the allowed range of sACN universes as defined in ANSI E1.31-2018 Section 6.2.7.
+    fn universe_multicast_addr(&self, universe: u16) -> Result<SocketAddr> {
+        let addr = if self.addr.is_ipv6() {
+            universe_to_ipv6_multicast_addr(universe)?
         } else {
-            universe_to_ipv4_multicast_addr(E131_DISCOVERY_UNIVERSE)?
+            universe_to_ipv4_multicast_addr(universe)?
         };
 
-        self.socket.send_to(&packet.pack_alloc()?, &ip)?;
+        addr.as_socket().ok_or_else(|| {
+            SacnError::UnsupportedIpVersion("Multicast address is not IPv4 or IPv6".to_string())
+        })
+    }
+
+    /// Returns the address that packets for the given universe are sent to when no destination is given.
+    ///
+    /// This is the broadcast address on the ACN port if the source is in broadcast mode, otherwise the multicast address of the universe.
+    ///
+    /// # Errors
+    /// `IllegalUniverse`: Returned if the universe is outwith the allowed range of universes.
+    fn universe_dst_addr(&self, universe: u16) -> Result<SocketAddr> {
+        let broadcast_addr = *self.broadcast_addr.read()?;
+        match broadcast_addr {
+            Some(addr) => {
+                is_universe_in_range(universe)?;
+                Ok(SocketAddr::new(IpAddr::V4(addr), ACN_SDT_MULTICAST_PORT))
+            }
+            None => self.universe_multicast_addr(universe),
+        }
+    }
+
+    /// Puts the source in broadcast mode sending to the given broadcast address, or back into multicast mode if None.
+    ///
+    /// `SO_BROADCAST` is enabled on the underlying socket when entering broadcast mode and disabled when leaving it. A transport without
+    /// a socket is assumed to be able to send to broadcast addresses already.
+    ///
+    /// # Errors
+    /// `IpVersionError`: Returned if a broadcast address is given and the source isn't IPv4, IPv6 has no broadcast.
+    ///
+    /// `InvalidConfiguration`: Returned if the broadcast address is a multicast or unspecified address.
+    ///
+    /// Io: Returned if `SO_BROADCAST` fails to be set on the underlying socket.
+    fn set_broadcast_addr(&self, broadcast_addr: Option<Ipv4Addr>) -> Result<()> {
+        if let Some(addr) = broadcast_addr {
+            if !self.addr.is_ipv4() {
+                return Err(SacnError::IpVersionError());
+            }
+            if addr.is_multicast() || addr.is_unspecified() {
+                return Err(SacnError::InvalidConfiguration(format!(
+                    "{addr} is not a broadcast address"
+                )));
+            }
+        }
 
+        let mut current = self.broadcast_addr.write()?;
+        if let Some(socket) = self.transport.socket() {
+            socket.set_broadcast(broadcast_addr.is_some())?;
+        }
+        *current = broadcast_addr;
         Ok(())
     }
 
+    /// Returns the broadcast address the source is sending to, None if the source is sending using multicast.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock of the broadcast address is poisoned.
+    fn broadcast_addr(&self) -> Result<Option<Ipv4Addr>> {
+        Ok(*self.broadcast_addr.read()?)
+    }
+
+    /// Returns the underlying UDP socket of the transport, used to get and set socket options.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport this source sends on isn't backed by a socket.
+    fn socket(&self) -> Result<SockRef<'_>> {
+        self.transport.socket().ok_or_else(|| {
+            SacnError::OsOperationUnsupported(
+                "Transport does not support socket options".to_string(),
+            )
+        })
+    }
+
     /// Returns the ACN CID device identifier of the `SacnSourceInternal`.
-    fn cid(&self) -> &Uuid {
-        &self.cid
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock of the CID is poisoned.
+    fn cid(&self) -> Result<Uuid> {
+        Ok(*self.cid.read()?)
     }
 
     /// Sets the ACN CID device identifier.
     ///
     /// # Arguments
     /// cid: The new CID identifier for this source. It is left to the user to ensure that this is always unique within the network the source is in.
-    fn set_cid(&mut self, cid: Uuid) {
-        self.cid = cid;
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock of the CID is poisoned.
+    fn set_cid(&self, cid: Uuid) -> Result<()> {
+        *self.cid.write()? = cid;
+        Ok(())
     }
 
     /// Returns the ACN source name.
-    fn name(&self) -> &str {
-        &self.name
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock of the name is poisoned.
+    fn name(&self) -> Result<String> {
+        Ok(self.name.read()?.clone())
     }
 
     /// Sets ACN source name.
@@ -1139,20 +2565,22 @@ impl SacnSourceInternal {
     ///
     /// # Errors
     /// `MalformedSourceName`: Returned to indicate that the given source name is longer than the maximum allowed as per `E131_SOURCE_NAME_FIELD_LENGTH`.
-    fn set_name(&mut self, name: &str) -> Result<()> {
+    ///
+    /// `SourceCorrupt`: Returned if the lock of the name is poisoned.
+    fn set_name(&self, name: &str) -> Result<()> {
         if name.len() > E131_SOURCE_NAME_FIELD_LENGTH {
             return Err(SacnError::MalformedSourceName(
                 "Source name provided is longer than maximum allowed".to_string(),
             ));
         }
-        self.name = name.to_string();
+        *self.name.write()? = name.to_string();
 
         Ok(())
     }
 
     /// Returns if `SacnSourceInternal` is in preview mode.
     fn preview_mode(&self) -> bool {
-        self.preview_data
+        self.preview_data.get()
     }
 
     /// Sets the value of the `Preview_Data` flag in packets from this `SacnSourceInternal`.
@@ -1160,8 +2588,18 @@ impl SacnSourceInternal {
     /// # Arguments
     /// `preview_mode`: If true then all data packets from this `SacnSourceInternal` will have the `Preview_Data` flag set to true indicating that the data is not
     ///     for live output. If false then the flag will be set to false.
-    fn set_preview_mode(&mut self, preview_mode: bool) {
-        self.preview_data = preview_mode;
+    fn set_preview_mode(&self, preview_mode: bool) {
+        self.preview_data.set(preview_mode);
+    }
+
+    /// Returns if `SacnSourceInternal` sends data packets in the layout of the ANSI E1.31 draft.
+    fn draft_mode(&self) -> bool {
+        self.draft_mode.get()
+    }
+
+    /// Sets whether `SacnSourceInternal` sends data packets in the layout of the ANSI E1.31 draft, see `SacnSource::set_draft_mode`.
+    fn set_draft_mode(&self, val: bool) {
+        self.draft_mode.set(val);
     }
 
     /// Sets the multicast time to live.
@@ -1172,7 +2610,7 @@ impl SacnSourceInternal {
     /// # Errors
     /// Io: Returned if the multicast TTL fails to be set on the underlying socket.
     fn set_multicast_ttl(&self, multicast_ttl: u32) -> Result<()> {
-        Ok(self.socket.set_multicast_ttl_v4(multicast_ttl)?)
+        Ok(self.socket()?.set_multicast_ttl_v4(multicast_ttl)?)
     }
 
     /// Returns the current Time To Live for unicast packets send by this source.
@@ -1180,7 +2618,7 @@ impl SacnSourceInternal {
     /// # Errors
     /// Io: Returned if the TTL cannot be retrieved from the underlying socket.
     fn ttl(&self) -> Result<u32> {
-        Ok(self.socket.ttl_v4()?)
+        Ok(self.socket()?.ttl_v4()?)
     }
 
     /// Sets the Time To Live for unicast packets sent by this source.
@@ -1190,13 +2628,13 @@ impl SacnSourceInternal {
     ///
     /// # Errors
     /// Io: Returned if the TTL fails to be set on the underlying socket.
-    fn set_ttl(&mut self, ttl: u32) -> Result<()> {
-        Ok(self.socket.set_ttl_v4(ttl)?)
+    fn set_ttl(&self, ttl: u32) -> Result<()> {
+        Ok(self.socket()?.set_ttl_v4(ttl)?)
     }
 
     /// Returns the multicast time to live of the socket.
     fn multicast_ttl(&self) -> Result<u32> {
-        Ok(self.socket.multicast_ttl_v4()?)
+        Ok(self.socket()?.multicast_ttl_v4()?)
     }
 
     /// Sets if multicast loop is enabled.
@@ -1207,71 +2645,266 @@ impl SacnSourceInternal {
     /// # Errors
     /// Io: Returned if the `set_multicast_loop` option fails to be set on the socket.
     fn set_multicast_loop_v4(&self, multicast_loop: bool) -> Result<()> {
-        Ok(self.socket.set_multicast_loop_v4(multicast_loop)?)
+        Ok(self.socket()?.set_multicast_loop_v4(multicast_loop)?)
     }
 
     /// Returns true if multicast loop is enabled, false if not.
     fn multicast_loop(&self) -> Result<bool> {
-        Ok(self.socket.multicast_loop_v4()?)
+        Ok(self.socket()?.multicast_loop_v4()?)
     }
 
-    /// Returns the universes currently registered on this source.
-    pub fn universes(&self) -> Vec<u16> {
-        self.universes.clone()
-    }
-}
+    /// Sets the DSCP of packets sent by this source, using the TOS field for IPv4 and the traffic class for IPv6.
+    /// The ECN bits are kept as they are.
+    ///
+    /// # Errors
+    /// `InvalidConfiguration`: Returned if the DSCP is outwith the range [0, 63].
+    ///
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support the option.
+    ///
+    /// Io: Returned if the option fails to be got or set on the underlying socket.
+    fn set_dscp(&self, dscp: u8) -> Result<()> {
+        if dscp > MAX_DSCP {
+            return Err(SacnError::InvalidConfiguration(format!(
+                "DSCP must be in the range [0, {MAX_DSCP}]"
+            )));
+        }
+        let dscp = u32::from(dscp) << DSCP_SHIFT;
 
-/// Returns the locked internal `SacnSourceInternal` used within the `SacnSource`.
-///
-/// This centralises the locking of the source to a single point within the code allowing any changes to the mechanism to be made in one place.
-///
-/// This differs to (`unlock_internal_mut`) as it takes an immutable reference to internal.
-///
-/// # Arguments
-/// internal: The `SacnSourceInternal` to unlock encapsulated within an Arc and Mutex.
-///
-/// # Errors
-/// `SourceCorrupt`: Returned if the Mutex used to control access to the internal sender is poisoned by a thread encountering
-/// a panic while accessing causing the source to be left in a potentially inconsistent state.
-fn unlock_internal(
-    internal: &Arc<Mutex<SacnSourceInternal>>,
-) -> Result<MutexGuard<'_, SacnSourceInternal>> {
-    match internal.lock() {
-        Err(_) => {
-            // The PoisonError returned doesn't contain further information and just allows access to the internal potentially inconsistent sender which
-            // shouldn't be exposed to the user (as its internal and would have no use).
-            // Cannot directly return the PoisonError due to PoisonError using a different error system to other std modules which doesn't work with
-            // error_chain.
-            Err(SacnError::SourceCorrupt("Mutex poisoned".to_string()))
+        if self.addr.is_ipv4() {
+            let ecn = self.tos_v4()? & ECN_MASK;
+            self.set_tos_v4(dscp | ecn)
+        } else {
+            let ecn = self.tclass_v6()? & ECN_MASK;
+            self.set_tclass_v6(dscp | ecn)
         }
-        Ok(lock) => Ok(lock),
     }
-}
 
-/// Returns the locked internal `SacnSourceInternal` used within the `SacnSource`.
-///
-/// This centralises the locking of the source to a single point within the code allowing any changes to the mechanism to be made in one place.
-///
-/// This differs to (`unlock_internal`) as it takes an mutable reference to internal.
-///
-/// # Arguments
-/// internal: The `SacnSourceInternal` to unlock encapsulated within an Arc and Mutex.
-///
-/// # Errors
-/// Returns an `SourceCorrupt` error if the Mutex used to control access to the internal sender is poisoned by a thread encountering
-/// a panic while accessing causing the source to be left in a potentially inconsistent state.
-fn unlock_internal_mut(
-    internal: &mut Arc<Mutex<SacnSourceInternal>>,
-) -> Result<MutexGuard<'_, SacnSourceInternal>> {
-    match internal.lock() {
-        Err(_) => {
-            // The PoisonError returned doesn't contain further information and just allows access to the internal potentially inconsistent sender which
-            // shouldn't be exposed to the user (as its internal and would have no use).
-            // Cannot directly return the PoisonError due to PoisonError using a different error system to other std modules which doesn't work with
-            // error_chain.
-            Err(SacnError::SourceCorrupt("Mutex poisoned".to_string()))
-        }
-        Ok(lock) => Ok(lock),
+    /// Returns the DSCP of packets sent by this source.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support the option.
+    ///
+    /// Io: Returned if the option cannot be retrieved from the underlying socket.
+    fn dscp(&self) -> Result<u8> {
+        let tos = if self.addr.is_ipv4() {
+            self.tos_v4()?
+        } else {
+            self.tclass_v6()?
+        };
+        // The TOS / traffic class is a single byte so the DSCP always fits in a u8.
+        Ok(((tos >> DSCP_SHIFT) & u32::from(MAX_DSCP)) as u8)
+    }
+
+    /// Sets the IPv4 TOS field of packets sent by this source.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support the option.
+    ///
+    /// Io: Returned if the TOS fails to be set on the underlying socket.
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn set_tos_v4(&self, tos: u32) -> Result<()> {
+        Ok(self.socket()?.set_tos_v4(tos)?)
+    }
+
+    /// Setting the IPv4 TOS field isn't supported on this OS.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Always returned.
+    #[cfg(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    ))]
+    fn set_tos_v4(&self, _tos: u32) -> Result<()> {
+        Err(SacnError::OsOperationUnsupported(
+            "Setting the IPv4 TOS is not supported on this OS".to_string(),
+        ))
+    }
+
+    /// Returns the IPv4 TOS field of packets sent by this source.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support the option.
+    ///
+    /// Io: Returned if the TOS cannot be retrieved from the underlying socket.
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn tos_v4(&self) -> Result<u32> {
+        Ok(self.socket()?.tos_v4()?)
+    }
+
+    /// Getting the IPv4 TOS field isn't supported on this OS.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Always returned.
+    #[cfg(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    ))]
+    fn tos_v4(&self) -> Result<u32> {
+        Err(SacnError::OsOperationUnsupported(
+            "Getting the IPv4 TOS is not supported on this OS".to_string(),
+        ))
+    }
+
+    /// Sets the IPv6 traffic class of packets sent by this source.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support the option.
+    ///
+    /// Io: Returned if the traffic class fails to be set on the underlying socket.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "cygwin",
+    ))]
+    fn set_tclass_v6(&self, tclass: u32) -> Result<()> {
+        Ok(self.socket()?.set_tclass_v6(tclass)?)
+    }
+
+    /// Setting the IPv6 traffic class isn't supported on this OS.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Always returned.
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "cygwin",
+    )))]
+    fn set_tclass_v6(&self, _tclass: u32) -> Result<()> {
+        Err(SacnError::OsOperationUnsupported(
+            "Setting the IPv6 traffic class is not supported on this OS".to_string(),
+        ))
+    }
+
+    /// Returns the IPv6 traffic class of packets sent by this source.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Returned if the transport isn't backed by a socket or the OS doesn't support the option.
+    ///
+    /// Io: Returned if the traffic class cannot be retrieved from the underlying socket.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "cygwin",
+    ))]
+    fn tclass_v6(&self) -> Result<u32> {
+        Ok(self.socket()?.tclass_v6()?)
+    }
+
+    /// Getting the IPv6 traffic class isn't supported on this OS.
+    ///
+    /// # Errors
+    /// `OsOperationUnsupported`: Always returned.
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "cygwin",
+    )))]
+    fn tclass_v6(&self) -> Result<u32> {
+        Err(SacnError::OsOperationUnsupported(
+            "Getting the IPv6 traffic class is not supported on this OS".to_string(),
+        ))
+    }
+
+    /// Sets the hop limit of IPv6 multicast packets.
+    ///
+    /// # Errors
+    /// Io: Returned if the hop limit fails to be set on the underlying socket.
+    fn set_multicast_hops_v6(&self, hops: u32) -> Result<()> {
+        Ok(self.socket()?.set_multicast_hops_v6(hops)?)
+    }
+
+    /// Returns the hop limit of IPv6 multicast packets.
+    fn multicast_hops_v6(&self) -> Result<u32> {
+        Ok(self.socket()?.multicast_hops_v6()?)
+    }
+
+    /// Sets the hop limit of IPv6 unicast packets.
+    ///
+    /// # Errors
+    /// Io: Returned if the hop limit fails to be set on the underlying socket.
+    fn set_unicast_hops_v6(&self, hops: u32) -> Result<()> {
+        Ok(self.socket()?.set_unicast_hops_v6(hops)?)
+    }
+
+    /// Returns the hop limit of IPv6 unicast packets.
+    fn unicast_hops_v6(&self) -> Result<u32> {
+        Ok(self.socket()?.unicast_hops_v6()?)
+    }
+
+    /// Sets if IPv6 multicast loop is enabled.
+    ///
+    /// # Errors
+    /// Io: Returned if the option fails to be set on the underlying socket.
+    fn set_multicast_loop_v6(&self, multicast_loop: bool) -> Result<()> {
+        Ok(self.socket()?.set_multicast_loop_v6(multicast_loop)?)
+    }
+
+    /// Returns true if IPv6 multicast loop is enabled, false if not.
+    fn multicast_loop_v6(&self) -> Result<bool> {
+        Ok(self.socket()?.multicast_loop_v6()?)
+    }
+
+    /// Returns the universes currently registered on this source.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock of the registered universes is poisoned.
+    fn universes(&self) -> Result<Vec<u16>> {
+        Ok(self.universes.read()?.clone())
+    }
+
+    /// Returns the universes currently registered on this source as `Universe`s.
+    ///
+    /// # Errors
+    /// `SourceCorrupt`: Returned if the lock of the registered universes is poisoned.
+    fn registered_universes(&self) -> Result<Vec<Universe>> {
+        // Only a `Universe` can be registered so every registered universe is in range.
+        Ok(self
+            .universes()?
+            .into_iter()
+            .filter_map(Universe::new)
+            .collect())
     }
 }
 
@@ -1283,17 +2916,17 @@ fn unlock_internal_mut(
 /// src: A reference to the `SacnSourceInternal` for which to send the universe discovery packet with/from.
 ///
 /// # Errors
-/// Returns a `SourceCorrupt` error if the internal source mutex has been corrupted, see (`unlock_internal`)[`unlock_internal`].
+/// Returns a `SourceCorrupt` error if a lock of the internal source has been poisoned, see `StateLock::read`.
 ///
 /// Returns an error if a discovery packet cannot be sent, see (`send_universe_discovery`)[`fn.send_universe_discovery.source`].
-fn perform_periodic_update(src: &mut Arc<Mutex<SacnSourceInternal>>) -> Result<()> {
-    let mut unwrap_src = unlock_internal_mut(src)?;
-    if unwrap_src.is_sending_discovery
-        && Instant::now().duration_since(unwrap_src.last_discovery_advert_timestamp)
-            > E131_UNIVERSE_DISCOVERY_INTERVAL
-    {
-        unwrap_src.send_universe_discovery()?;
-        unwrap_src.last_discovery_advert_timestamp = Instant::now();
-    }
-    Ok(())
+fn perform_periodic_update(src: &SacnSourceInternal) -> Result<()> {
+    src.update(src.clock.now())
+}
+
+/// Replaces the message of an Io error with the given context message, keeping the error kind. Other errors are returned unchanged.
+fn io_context(e: SacnError, msg: &str) -> SacnError {
+    match e {
+        SacnError::Io(e) => SacnError::Io(std::io::Error::new(e.kind(), msg.to_string())),
+        e => e,
+    }
 }
@@ -0,0 +1,291 @@
+#![warn(missing_docs)]
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Typed decoding of the property values of a data packet according to their start code.
+//!
+//! The first property value of a data packet is the start code which says how the remaining values are interpreted, see
+//! ANSI E1.31-2018 Section 7.3 and ANSI E1.11 Section 8.5. A `StartCodeRegistry` maps start codes to a name and a decoder which turns
+//! the values into a `StartCodePayload`. `StartCodeRegistry::standard` knows the common start codes and further start codes, such as
+//! manufacturer specific ones, can be registered.
+//!
+//! ```
+//! use sacn::start_code::{StartCodePayload, StartCodeRegistry};
+//! use sacn::types::StartCode;
+//!
+//! let registry = StartCodeRegistry::standard();
+//!
+//! match registry.decode(&[0x17, 1, 20, b'H', b'i', 0]).unwrap() {
+//!     StartCodePayload::Text(text) => assert_eq!(text.text, "Hi"),
+//!     x => panic!("Expected text, got {x:?}"),
+//! }
+//!
+//! assert_eq!(registry.name(StartCode::PER_ADDRESS_PRIORITY), Some("Per-address priority"));
+//! ```
+//!
+//! A `SacnReceiver` can deliver data with a particular start code to its own handler or channel instead of returning it from `recv`,
+//! see `SacnReceiver::set_start_code_handler` and `SacnReceiver::subscribe_start_code`.
+
+use crate::error::errors::{Result, SacnError};
+use crate::packet::E131_MAX_PRIORITY;
+use crate::types::StartCode;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use core::str;
+
+/// The property values of a data packet decoded according to their start code, the start code itself isn't included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StartCodePayload<'a> {
+    /// Dimmer levels (`StartCode::NULL`), one per slot.
+    Levels(&'a [u8]),
+
+    /// The priority of each slot (`StartCode::PER_ADDRESS_PRIORITY`). A priority of 0 means the source isn't sending the slot, otherwise
+    /// the priority is in the range [1, `E131_MAX_PRIORITY`] and replaces the priority of the data packet for that slot.
+    PerAddressPriority(&'a [u8]),
+
+    /// Text to be displayed (`StartCode::TEXT`), see `TextPayload`.
+    Text(TextPayload<'a>),
+
+    /// A system information packet (`StartCode::SYSTEM_INFORMATION`), ANSI E1.11 Annex D. The contents aren't decoded further.
+    SystemInformation(&'a [u8]),
+
+    /// Manufacturer specific data (`StartCode::MANUFACTURER`), starting with the ESTA manufacturer ID of the manufacturer which defines
+    /// the data.
+    Manufacturer {
+        /// The ESTA manufacturer ID.
+        manufacturer_id: u16,
+
+        /// The data following the manufacturer ID.
+        data: &'a [u8],
+    },
+
+    /// Data with a start code which isn't decoded, either because it isn't registered or is registered with `decode_raw`.
+    Raw {
+        /// The start code of the data.
+        start_code: StartCode,
+
+        /// The data following the start code.
+        data: &'a [u8],
+    },
+}
+
+/// An ASCII text packet, ANSI E1.11 Annex D.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TextPayload<'a> {
+    /// The page number of the text.
+    pub page: u8,
+
+    /// The number of characters per line of the display the text is intended for.
+    pub characters_per_line: u8,
+
+    /// The text, up to the first null byte if there is one.
+    pub text: &'a str,
+}
+
+/// Decodes the data following the given start code.
+///
+/// The start code is given so that the same decoder can be registered for more than one start code.
+///
+/// # Errors
+/// `InvalidStartCodePayload`: Returned if the data isn't valid for the start code.
+pub type StartCodeDecoder = for<'a> fn(StartCode, &'a [u8]) -> Result<StartCodePayload<'a>>;
+
+/// A start code known to a `StartCodeRegistry`.
+#[derive(Clone, Copy, Debug)]
+pub struct StartCodeEntry {
+    /// A human readable name for the start code.
+    pub name: &'static str,
+
+    /// The decoder used for data with the start code.
+    pub decoder: StartCodeDecoder,
+}
+
+/// Maps start codes to a name and a decoder, see the module documentation.
+#[derive(Clone, Debug, Default)]
+pub struct StartCodeRegistry {
+    /// The registered start codes.
+    entries: BTreeMap<u8, StartCodeEntry>,
+}
+
+impl StartCodeRegistry {
+    /// Creates a registry without any start codes, all data is decoded as `StartCodePayload::Raw`.
+    pub fn new() -> StartCodeRegistry {
+        StartCodeRegistry {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a registry with the common start codes used on DMX512 and sACN networks.
+    ///
+    /// `StartCode::RDM` is registered with `decode_raw` as RDM isn't carried by sACN data packets.
+    pub fn standard() -> StartCodeRegistry {
+        let mut registry = StartCodeRegistry::new();
+        registry.register(StartCode::NULL, "Null (levels)", decode_levels);
+        registry.register(
+            StartCode::PER_ADDRESS_PRIORITY,
+            "Per-address priority",
+            decode_per_address_priority,
+        );
+        registry.register(StartCode::TEXT, "ASCII text", decode_text);
+        registry.register(
+            StartCode::SYSTEM_INFORMATION,
+            "System information",
+            decode_system_information,
+        );
+        registry.register(
+            StartCode::MANUFACTURER,
+            "Manufacturer specific",
+            decode_manufacturer,
+        );
+        registry.register(StartCode::RDM, "RDM", decode_raw);
+        registry
+    }
+
+    /// Registers the given start code, replacing and returning any previous entry.
+    ///
+    /// # Arguments
+    /// `start_code`: The start code to register.
+    ///
+    /// name: A human readable name for the start code.
+    ///
+    /// decoder: The decoder used for data with the start code.
+    pub fn register(
+        &mut self,
+        start_code: StartCode,
+        name: &'static str,
+        decoder: StartCodeDecoder,
+    ) -> Option<StartCodeEntry> {
+        self.entries
+            .insert(start_code.get(), StartCodeEntry { name, decoder })
+    }
+
+    /// Removes the given start code so that its data is decoded as `StartCodePayload::Raw`, returning the entry if there was one.
+    pub fn unregister(&mut self, start_code: StartCode) -> Option<StartCodeEntry> {
+        self.entries.remove(&start_code.get())
+    }
+
+    /// Returns the entry of the given start code, None if it isn't registered.
+    pub fn get(&self, start_code: StartCode) -> Option<&StartCodeEntry> {
+        self.entries.get(&start_code.get())
+    }
+
+    /// Returns the name of the given start code, None if it isn't registered.
+    pub fn name(&self, start_code: StartCode) -> Option<&'static str> {
+        self.get(start_code).map(|e| e.name)
+    }
+
+    /// Returns the registered start codes in ascending order.
+    pub fn start_codes(&self) -> impl Iterator<Item = StartCode> + '_ {
+        self.entries.keys().map(|s| StartCode::new(*s))
+    }
+
+    /// Decodes the given property values, the first of which is the start code, for example `DMXData::values`.
+    ///
+    /// Data with a start code which isn't registered is returned as `StartCodePayload::Raw`.
+    ///
+    /// # Errors
+    /// `DataArrayEmpty`: Returned if there are no values so there is no start code.
+    ///
+    /// `InvalidStartCodePayload`: Returned by the decoder if the data isn't valid for its start code.
+    pub fn decode<'a>(&self, values: &'a [u8]) -> Result<StartCodePayload<'a>> {
+        let (start_code, data) = values.split_first().ok_or(SacnError::DataArrayEmpty())?;
+        let start_code = StartCode::new(*start_code);
+        match self.get(start_code) {
+            Some(entry) => (entry.decoder)(start_code, data),
+            None => decode_raw(start_code, data),
+        }
+    }
+}
+
+/// Decodes null start code data as `StartCodePayload::Levels`.
+pub fn decode_levels(_start_code: StartCode, data: &[u8]) -> Result<StartCodePayload<'_>> {
+    Ok(StartCodePayload::Levels(data))
+}
+
+/// Decodes per-address priority data as `StartCodePayload::PerAddressPriority`.
+///
+/// # Errors
+/// `InvalidStartCodePayload`: Returned if a priority is greater than `E131_MAX_PRIORITY`.
+pub fn decode_per_address_priority(
+    start_code: StartCode,
+    data: &[u8],
+) -> Result<StartCodePayload<'_>> {
+    if let Some((slot, priority)) = data
+        .iter()
+        .enumerate()
+        .find(|(_, p)| **p > E131_MAX_PRIORITY)
+    {
+        return Err(SacnError::InvalidStartCodePayload(
+            start_code.get(),
+            format!(
+                "priority {priority} of slot {} is greater than the maximum",
+                slot + 1
+            ),
+        ));
+    }
+    Ok(StartCodePayload::PerAddressPriority(data))
+}
+
+/// Decodes ASCII text data as `StartCodePayload::Text`.
+///
+/// # Errors
+/// `InvalidStartCodePayload`: Returned if the page number or characters per line are missing or the text isn't ASCII.
+pub fn decode_text(start_code: StartCode, data: &[u8]) -> Result<StartCodePayload<'_>> {
+    let [page, characters_per_line, text @ ..] = data else {
+        return Err(SacnError::InvalidStartCodePayload(
+            start_code.get(),
+            "missing the page number or characters per line".into(),
+        ));
+    };
+
+    let text = text.split(|b| *b == 0).next().unwrap_or_default();
+    if !text.is_ascii() {
+        return Err(SacnError::InvalidStartCodePayload(
+            start_code.get(),
+            "the text isn't ASCII".into(),
+        ));
+    }
+
+    Ok(StartCodePayload::Text(TextPayload {
+        page: *page,
+        characters_per_line: *characters_per_line,
+        // ASCII is always valid UTF-8.
+        text: str::from_utf8(text).unwrap_or_default(),
+    }))
+}
+
+/// Decodes system information data as `StartCodePayload::SystemInformation`.
+pub fn decode_system_information(
+    _start_code: StartCode,
+    data: &[u8],
+) -> Result<StartCodePayload<'_>> {
+    Ok(StartCodePayload::SystemInformation(data))
+}
+
+/// Decodes manufacturer specific data as `StartCodePayload::Manufacturer`.
+///
+/// # Errors
+/// `InvalidStartCodePayload`: Returned if the data is too short to contain a manufacturer ID.
+pub fn decode_manufacturer(start_code: StartCode, data: &[u8]) -> Result<StartCodePayload<'_>> {
+    match data {
+        [high, low, data @ ..] => Ok(StartCodePayload::Manufacturer {
+            manufacturer_id: u16::from_be_bytes([*high, *low]),
+            data,
+        }),
+        _ => Err(SacnError::InvalidStartCodePayload(
+            start_code.get(),
+            "missing the manufacturer ID".into(),
+        )),
+    }
+}
+
+/// Returns the data as `StartCodePayload::Raw`, used for start codes which should be named without being decoded.
+pub fn decode_raw(start_code: StartCode, data: &[u8]) -> Result<StartCodePayload<'_>> {
+    Ok(StartCodePayload::Raw { start_code, data })
+}
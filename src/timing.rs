@@ -0,0 +1,170 @@
+#![warn(missing_docs)]
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! The protocol timeouts and intervals used by sources and receivers.
+//!
+//! A `TimingProfile` is given to a `SacnSource` or `SacnReceiver` when it is created. The default profile uses the values from
+//! ANSI E1.31-2018 Appendix A, `TimingProfile::strict` detects lost sources sooner and `TimingProfile::lenient` tolerates links such as
+//! Wi-Fi where bursts of packets can be lost.
+//!
+//! ```
+//! use sacn::packet::ACN_SDT_MULTICAST_PORT;
+//! use sacn::receive::SacnReceiver;
+//! use sacn::clock::SystemClock;
+//! use sacn::timing::TimingProfile;
+//! use sacn::transport::MemoryNetwork;
+//!
+//! use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+//! use std::time::Duration;
+//!
+//! let network = MemoryNetwork::new();
+//! let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), ACN_SDT_MULTICAST_PORT);
+//!
+//! let timing = TimingProfile {
+//!     data_loss_timeout: Duration::from_secs(4),
+//!     ..TimingProfile::lenient()
+//! };
+//!
+//! let dmx_rcv = SacnReceiver::with_timing(network.bind(addr).unwrap(), None, SystemClock, timing).unwrap();
+//! ```
+
+use crate::error::errors::*;
+use crate::packet::{
+    E131_NETWORK_DATA_LOSS_TIMEOUT, E131_SEQ_DIFF_DISCARD_LOWER_BOUND,
+    E131_UNIVERSE_DISCOVERY_INTERVAL, UNIVERSE_DISCOVERY_SOURCE_TIMEOUT,
+};
+
+use std::time::Duration;
+
+/// The default period at which the update thread of a source checks whether a universe discovery advert is due.
+pub const DEFAULT_POLL_PERIOD: Duration = Duration::from_secs(1);
+
+/// The lowest allowed exclusive lower bound of the sequence discard window, sequence number differences are at least -128.
+const MIN_SEQ_DISCARD_LOWER_BOUND: isize = i8::MIN as isize;
+
+/// The timeouts and intervals used by a source or receiver.
+///
+/// Receivers use the `data_loss_timeout`, `discovery_source_timeout` and `seq_discard_lower_bound`. Sources use the
+/// `discovery_interval` and `poll_period`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimingProfile {
+    /// The time after which a source universe which hasn't sent data is assumed lost and data waiting for a synchronisation packet is
+    /// discarded, see ANSI E1.31-2018 Section 6.7.1.
+    ///
+    /// A receiver also waits for data on the network for at most this long at a time so that timeouts are checked.
+    pub data_loss_timeout: Duration,
+
+    /// The time after which a discovered source which hasn't sent a universe discovery packet is assumed lost.
+    pub discovery_source_timeout: Duration,
+
+    /// The interval between universe discovery adverts sent by a source, see ANSI E1.31-2018 Section 4.3.
+    pub discovery_interval: Duration,
+
+    /// The period at which the update thread of a source checks whether a universe discovery advert is due.
+    /// Adverts may be sent up to this long after the `discovery_interval` has passed.
+    pub poll_period: Duration,
+
+    /// The exclusive lower bound on the difference between a received and the last sequence number within which a packet is discarded,
+    /// see ANSI E1.31-2018 Section 6.7.2. The upper bound is always `E131_SEQ_DIFF_DISCARD_UPPER_BOUND`.
+    ///
+    /// Must be in the range [-128, 0], a bound of 0 disables discarding packets by sequence number.
+    pub seq_discard_lower_bound: isize,
+}
+
+impl Default for TimingProfile {
+    fn default() -> Self {
+        TimingProfile::e131()
+    }
+}
+
+impl TimingProfile {
+    /// The values from ANSI E1.31-2018 Appendix A, this is the default profile.
+    ///
+    /// Discovered sources are assumed lost after `UNIVERSE_DISCOVERY_SOURCE_TIMEOUT` which allows a single advert to be lost.
+    pub const fn e131() -> TimingProfile {
+        TimingProfile {
+            data_loss_timeout: E131_NETWORK_DATA_LOSS_TIMEOUT,
+            discovery_source_timeout: UNIVERSE_DISCOVERY_SOURCE_TIMEOUT,
+            discovery_interval: E131_UNIVERSE_DISCOVERY_INTERVAL,
+            poll_period: DEFAULT_POLL_PERIOD,
+            seq_discard_lower_bound: E131_SEQ_DIFF_DISCARD_LOWER_BOUND,
+        }
+    }
+
+    /// A profile which notices lost sources sooner than the E1.31 values, intended for wired networks where loss is rare.
+    ///
+    /// Universes time out after 1 second and discovered sources are lost if a single advert is missed. Sources check for due adverts
+    /// every 100ms so adverts are sent close to the `discovery_interval`.
+    pub const fn strict() -> TimingProfile {
+        TimingProfile {
+            data_loss_timeout: Duration::from_secs(1),
+            discovery_source_timeout: Duration::from_secs(11),
+            discovery_interval: E131_UNIVERSE_DISCOVERY_INTERVAL,
+            poll_period: Duration::from_millis(100),
+            seq_discard_lower_bound: E131_SEQ_DIFF_DISCARD_LOWER_BOUND,
+        }
+    }
+
+    /// A profile which tolerates lossy links such as Wi-Fi.
+    ///
+    /// Universes time out after 5 seconds, data waiting for synchronisation is kept for as long, and discovered sources are kept until
+    /// three adverts in a row are missed.
+    pub const fn lenient() -> TimingProfile {
+        TimingProfile {
+            data_loss_timeout: Duration::from_secs(5),
+            discovery_source_timeout: Duration::from_secs(40),
+            discovery_interval: E131_UNIVERSE_DISCOVERY_INTERVAL,
+            poll_period: DEFAULT_POLL_PERIOD,
+            seq_discard_lower_bound: E131_SEQ_DIFF_DISCARD_LOWER_BOUND,
+        }
+    }
+
+    /// Checks that the timeouts and intervals of this profile can be used.
+    ///
+    /// # Errors
+    /// `InvalidTimingProfile`: Returned if any of the durations is zero, if the `poll_period` is longer than the `discovery_interval`,
+    /// if the `discovery_source_timeout` isn't longer than the `discovery_interval` (sources would be lost between adverts) or if the
+    /// `seq_discard_lower_bound` is outwith the range [-128, 0].
+    pub fn validate(&self) -> Result<()> {
+        for (name, d) in [
+            ("data loss timeout", self.data_loss_timeout),
+            ("discovery source timeout", self.discovery_source_timeout),
+            ("discovery interval", self.discovery_interval),
+            ("poll period", self.poll_period),
+        ] {
+            if d.is_zero() {
+                return Err(SacnError::InvalidTimingProfile(format!(
+                    "{name} must be greater than zero"
+                )));
+            }
+        }
+
+        if self.poll_period > self.discovery_interval {
+            return Err(SacnError::InvalidTimingProfile(format!(
+                "poll period {:?} is longer than the discovery interval {:?}",
+                self.poll_period, self.discovery_interval
+            )));
+        }
+
+        if self.discovery_source_timeout <= self.discovery_interval {
+            return Err(SacnError::InvalidTimingProfile(format!(
+                "discovery source timeout {:?} must be longer than the discovery interval {:?}",
+                self.discovery_source_timeout, self.discovery_interval
+            )));
+        }
+
+        if !(MIN_SEQ_DISCARD_LOWER_BOUND..=0).contains(&self.seq_discard_lower_bound) {
+            return Err(SacnError::InvalidTimingProfile(format!(
+                "sequence discard lower bound {} is outwith the range [-128, 0]",
+                self.seq_discard_lower_bound
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,712 @@
+#![warn(missing_docs)]
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! The transport layer that sACN sources and receivers send and receive datagrams over.
+//!
+//! By default `SacnSource` and `SacnReceiver` use a `UdpTransport` which wraps a Socket2 UDP socket. Any other type implementing
+//! `Transport` can be given instead, for example a `MemoryTransport` which sends datagrams over an in-process `MemoryNetwork`. This allows
+//! source / receiver interaction including synchronisation and universe discovery to be run without any network setup.
+//!
+//! # Examples
+//!
+//! ```
+//! use sacn::receive::SacnReceiver;
+//! use sacn::source::SacnSource;
+//! use sacn::packet::ACN_SDT_MULTICAST_PORT;
+//! use sacn::transport::MemoryNetwork;
+//! use sacn::types::Universe;
+//!
+//! use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+//! use std::time::Duration;
+//!
+//! let network = MemoryNetwork::new();
+//!
+//! let rcv_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), ACN_SDT_MULTICAST_PORT);
+//! let mut dmx_rcv = SacnReceiver::with_transport(network.bind(rcv_addr).unwrap(), None).unwrap();
+//! let universe = Universe::try_from(1).unwrap();
+//! dmx_rcv.listen_universes(&[universe]).unwrap();
+//!
+//! let src_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)), ACN_SDT_MULTICAST_PORT + 1);
+//! let src = SacnSource::with_transport("Source", network.bind(src_addr).unwrap()).unwrap();
+//! src.register_universe(universe).unwrap();
+//! src.send(&[universe], &[0, 255, 128], None, None, None).unwrap();
+//!
+//! let data = dmx_rcv.recv(Some(Duration::from_secs(1))).unwrap();
+//! assert_eq!(data[0].values, vec![0, 255, 128]);
+//! ```
+
+use crate::error::errors::*;
+
+#[cfg(not(target_os = "windows"))]
+use crate::packet::ACN_SDT_MULTICAST_PORT;
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Socket 2 used for the underlying UDP socket that sACN is sent over.
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
+
+#[cfg(target_os = "windows")]
+use socket2::SockAddr;
+
+/// Extra net imports required for the IPv6 handling on the linux side.
+#[cfg(not(target_os = "windows"))]
+use std::net::Ipv6Addr;
+
+/// A datagram transport which sACN packets are sent and received over.
+///
+/// Implementations must be safe to share between threads as a `SacnSource` sends periodic universe discovery packets from
+/// its own update thread.
+pub trait Transport: Send + Sync + fmt::Debug {
+    /// Sends the given datagram to the given address which may be a unicast, multicast or broadcast address.
+    ///
+    /// Returns the number of bytes sent.
+    ///
+    /// # Errors
+    /// Io: Returned if the datagram fails to be sent.
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> Result<usize>;
+
+    /// Receives a single datagram into the given buffer, blocking for at most the timeout set by `set_read_timeout`.
+    ///
+    /// Returns the number of bytes received and the address of the sender.
+    ///
+    /// # Errors
+    /// Io: Returned with kind `WouldBlock` or `TimedOut` if no datagram arrives within the timeout, or if receiving fails.
+    fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddr)>;
+
+    /// Joins the given multicast group on the interface with the given address.
+    ///
+    /// # Errors
+    /// `IpVersionError`: Returned if the group and interface are not the same IP version.
+    ///
+    /// Io: Returned if the group cannot be joined.
+    fn join_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()>;
+
+    /// Leaves the given multicast group on the interface with the given address.
+    ///
+    /// # Errors
+    /// `IpVersionError`: Returned if the group and interface are not the same IP version.
+    ///
+    /// Io: Returned if the group cannot be left.
+    fn leave_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()>;
+
+    /// Sets the timeout used by `recv_from`, a timeout of None means `recv_from` blocks until a datagram arrives.
+    ///
+    /// # Errors
+    /// Io: Returned if the timeout cannot be set, for example a UDP socket rejects a zero duration timeout.
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()>;
+
+    /// Returns the address this transport is bound to.
+    ///
+    /// # Errors
+    /// Io: Returned if the address cannot be retrieved.
+    fn local_addr(&self) -> Result<SocketAddr>;
+
+    /// Returns a reference to the underlying UDP socket if this transport has one.
+    ///
+    /// Used to set socket level options such as the TTL, returns None by default.
+    fn socket(&self) -> Option<SockRef<'_>> {
+        None
+    }
+}
+
+/// Allows a transport to be shared, for example to keep access to it after giving it to a source or receiver.
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> Result<usize> {
+        (**self).send_to(buf, addr)
+    }
+
+    fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddr)> {
+        (**self).recv_from(buf)
+    }
+
+    fn join_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()> {
+        (**self).join_multicast(group, interface)
+    }
+
+    fn leave_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()> {
+        (**self).leave_multicast(group, interface)
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        (**self).set_read_timeout(timeout)
+    }
+
+    fn local_addr(&self) -> Result<SocketAddr> {
+        (**self).local_addr()
+    }
+
+    fn socket(&self) -> Option<SockRef<'_>> {
+        (**self).socket()
+    }
+}
+
+/// A `Transport` implemented by a UDP socket, this is the default transport used by sources and receivers.
+#[derive(Debug)]
+pub struct UdpTransport {
+    /// The underlying UDP socket, socket options not exposed by the standard library are set through Socket2.
+    socket: UdpSocket,
+}
+
+impl UdpTransport {
+    /// Creates a `UdpTransport` for sending sACN bound to the given address.
+    ///
+    /// The address is set as reusable so that multiple sources may share the same port.
+    ///
+    /// # Errors
+    /// Io: Returned if the underlying socket cannot be created or the IP cannot be bound to the underlying socket.
+    pub fn bind_source(addr: SocketAddr) -> Result<UdpTransport> {
+        let socket = if addr.is_ipv4() {
+            Socket::new(Domain::IPV4, Type::DGRAM, None)?
+        } else {
+            Socket::new(Domain::IPV6, Type::DGRAM, None)?
+        };
+
+        // Multiple different processes might want to send to the sACN stream so therefore need to allow re-using the ACN port.
+        // Set reuse port is only supported on linux.
+        #[cfg(target_os = "linux")]
+        socket.set_reuse_port(true)?;
+
+        // Set reuse address supported on linux and windows.
+        socket.set_reuse_address(true)?;
+        socket.bind(&addr.into())?;
+
+        Ok(UdpTransport {
+            socket: socket.into(),
+        })
+    }
+
+    /// Creates a `UdpTransport` for receiving sACN on the interface with the given address.
+    ///
+    /// On Linux the socket is bound to the unspecified address with the port of the given address so that multicast traffic is
+    /// received, the interface is then selected when joining multicast groups. On Windows the socket is bound to the given address.
+    ///
+    /// # Errors
+    /// Io: Returned if the socket cannot be created or bound, see (`Socket::new`)[fn.new.Socket] and (bind)[fn.bind.Socket].
+    pub fn bind_receiver(addr: SocketAddr) -> Result<UdpTransport> {
+        UdpTransport::bind_receiver_with(addr, None)
+    }
+
+    /// Creates a `UdpTransport` for receiving sACN on the interface with the given IPv6 address, setting whether the socket only
+    /// receives IPv6 before it is bound.
+    ///
+    /// Some platforms (e.g. Linux) don't allow changing whether a socket is IPv6 only after it has been bound.
+    ///
+    /// # Errors
+    /// `IpVersionError`: Returned if the given address is an IPv4 address.
+    ///
+    /// Io: Returned if the socket cannot be created, configured or bound, see (`bind_receiver`)[`bind_receiver`].
+    pub fn bind_receiver_only_v6(addr: SocketAddr, only_v6: bool) -> Result<UdpTransport> {
+        if addr.is_ipv4() {
+            return Err(SacnError::IpVersionError());
+        }
+        UdpTransport::bind_receiver_with(addr, Some(only_v6))
+    }
+
+    /// Creates a `UdpTransport` for receiving sACN, setting the IPv6 only option before binding if given.
+    fn bind_receiver_with(addr: SocketAddr, only_v6: Option<bool>) -> Result<UdpTransport> {
+        #[cfg(not(target_os = "windows"))]
+        let socket = create_unix_socket(addr, only_v6)?;
+
+        #[cfg(target_os = "windows")]
+        let socket = create_win_socket(addr, only_v6)?;
+
+        Ok(UdpTransport {
+            socket: socket.into(),
+        })
+    }
+
+    /// Creates a `UdpTransport` from an already configured socket.
+    pub fn from_socket(socket: Socket) -> UdpTransport {
+        UdpTransport {
+            socket: socket.into(),
+        }
+    }
+}
+
+impl Transport for UdpTransport {
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> Result<usize> {
+        Ok(self.socket.send_to(buf, addr)?)
+    }
+
+    fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddr)> {
+        Ok(self.socket.recv_from(buf)?)
+    }
+
+    fn join_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()> {
+        #[cfg(not(target_os = "windows"))]
+        return join_unix_multicast(&SockRef::from(&self.socket), group, interface);
+
+        #[cfg(target_os = "windows")]
+        return join_win_multicast(&SockRef::from(&self.socket), group, interface);
+    }
+
+    fn leave_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()> {
+        #[cfg(not(target_os = "windows"))]
+        return leave_unix_multicast(&SockRef::from(&self.socket), group, interface);
+
+        #[cfg(target_os = "windows")]
+        return leave_win_multicast(&SockRef::from(&self.socket), group, interface);
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        Ok(self.socket.set_read_timeout(timeout)?)
+    }
+
+    fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    fn socket(&self) -> Option<SockRef<'_>> {
+        Some(SockRef::from(&self.socket))
+    }
+}
+
+/// An in-process network which `MemoryTransport`s can be bound to.
+///
+/// Datagrams sent by a `MemoryTransport` are delivered to every other transport on the same network as if they were on a single
+/// network segment:
+///     - Multicast datagrams are delivered to each transport bound to the destination port that has joined the destination group.
+///     - Broadcast datagrams (to 255.255.255.255) are delivered to each transport bound to the destination port.
+///     - Unicast datagrams are delivered to each transport bound to the destination port with the destination address or the unspecified address.
+///
+/// Cloning a `MemoryNetwork` gives another handle to the same network.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryNetwork {
+    /// The transports bound to this network.
+    endpoints: Arc<Mutex<Vec<MemoryEndpoint>>>,
+}
+
+/// A transport's registration on a `MemoryNetwork`.
+#[derive(Debug)]
+struct MemoryEndpoint {
+    /// Unique identifier of the transport within the network.
+    id: usize,
+
+    /// The address the transport is bound to.
+    addr: SocketAddr,
+
+    /// The multicast groups the transport has joined.
+    groups: HashSet<IpAddr>,
+
+    /// The queue datagrams for this transport are delivered to.
+    inbox: Arc<Inbox>,
+}
+
+/// A queue of datagrams waiting to be received by a `MemoryTransport`.
+#[derive(Debug, Default)]
+struct Inbox {
+    /// Datagrams with the address of their sender.
+    datagrams: Mutex<VecDeque<(Vec<u8>, SocketAddr)>>,
+
+    /// Signalled when a datagram is added.
+    available: Condvar,
+}
+
+/// Source of unique ids for `MemoryEndpoint`s.
+static NEXT_ENDPOINT_ID: AtomicUsize = AtomicUsize::new(0);
+
+impl MemoryNetwork {
+    /// Creates a new empty in-process network.
+    pub fn new() -> MemoryNetwork {
+        MemoryNetwork::default()
+    }
+
+    /// Binds a new `MemoryTransport` to this network with the given address.
+    ///
+    /// As with a UDP socket which allows address reuse several transports may be bound to the same address.
+    ///
+    /// # Errors
+    /// Io: Returned if the network's lock was poisoned by a panic in another thread.
+    pub fn bind(&self, addr: SocketAddr) -> Result<MemoryTransport> {
+        let id = NEXT_ENDPOINT_ID.fetch_add(1, Ordering::Relaxed);
+        let inbox = Arc::new(Inbox::default());
+        self.lock()?.push(MemoryEndpoint {
+            id,
+            addr,
+            groups: HashSet::new(),
+            inbox: inbox.clone(),
+        });
+
+        Ok(MemoryTransport {
+            network: self.clone(),
+            id,
+            addr,
+            inbox,
+            read_timeout: Mutex::new(None),
+        })
+    }
+
+    /// Locks the endpoint list of this network.
+    ///
+    /// # Errors
+    /// Io: Returned if the lock was poisoned by a panic in another thread.
+    fn lock(&self) -> Result<MutexGuard<'_, Vec<MemoryEndpoint>>> {
+        lock_memory(&self.endpoints)
+    }
+
+    /// Applies the given function to the endpoint with the given id.
+    ///
+    /// # Errors
+    /// Io: Returned if the network's lock was poisoned by a panic in another thread.
+    fn with_endpoint(&self, id: usize, f: impl FnOnce(&mut MemoryEndpoint)) -> Result<()> {
+        if let Some(endpoint) = self.lock()?.iter_mut().find(|e| e.id == id) {
+            f(endpoint);
+        }
+        Ok(())
+    }
+}
+
+/// A `Transport` over an in-process `MemoryNetwork`, created using `MemoryNetwork::bind`.
+#[derive(Debug)]
+pub struct MemoryTransport {
+    /// The network this transport is bound to.
+    network: MemoryNetwork,
+
+    /// The id of this transport's endpoint within the network.
+    id: usize,
+
+    /// The address this transport is bound to.
+    addr: SocketAddr,
+
+    /// The queue datagrams for this transport are delivered to.
+    inbox: Arc<Inbox>,
+
+    /// The timeout used by `recv_from`, None means block until a datagram arrives.
+    read_timeout: Mutex<Option<Duration>>,
+}
+
+impl MemoryTransport {
+    /// Returns the network this transport is bound to.
+    pub fn network(&self) -> &MemoryNetwork {
+        &self.network
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn send_to(&self, buf: &[u8], addr: SocketAddr) -> Result<usize> {
+        let dst = addr.ip();
+        let is_broadcast = dst == IpAddr::V4(Ipv4Addr::BROADCAST);
+        for endpoint in self.network.lock()?.iter() {
+            if endpoint.addr.port() != addr.port() {
+                continue;
+            }
+            let deliver = if dst.is_multicast() {
+                endpoint.groups.contains(&dst)
+            } else {
+                is_broadcast || endpoint.addr.ip() == dst || endpoint.addr.ip().is_unspecified()
+            };
+            if deliver {
+                lock_memory(&endpoint.inbox.datagrams)?.push_back((buf.to_vec(), self.addr));
+                endpoint.inbox.available.notify_all();
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn recv_from(&self, buf: &mut [u8]) -> Result<(usize, SocketAddr)> {
+        let timeout = *lock_memory(&self.read_timeout)?;
+        let deadline = timeout.and_then(|t| Instant::now().checked_add(t));
+
+        let mut datagrams = lock_memory(&self.inbox.datagrams)?;
+        loop {
+            if let Some((datagram, from)) = datagrams.pop_front() {
+                let n = datagram.len().min(buf.len());
+                buf[..n].copy_from_slice(&datagram[..n]);
+                return Ok((n, from));
+            }
+
+            let waited = match deadline {
+                None => self.inbox.available.wait(datagrams).ok(),
+                Some(dl) => {
+                    let now = Instant::now();
+                    if now >= dl {
+                        return Err(io::Error::new(
+                            io::ErrorKind::WouldBlock,
+                            "No datagram available in given timeout",
+                        )
+                        .into());
+                    }
+                    self.inbox
+                        .available
+                        .wait_timeout(datagrams, dl - now)
+                        .ok()
+                        .map(|(guard, _)| guard)
+                }
+            };
+            datagrams = waited.ok_or_else(memory_poisoned)?;
+        }
+    }
+
+    fn join_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()> {
+        if group.is_ipv4() != interface.is_ipv4() {
+            return Err(SacnError::IpVersionError());
+        }
+        self.network.with_endpoint(self.id, |e| {
+            e.groups.insert(group);
+        })
+    }
+
+    fn leave_multicast(&self, group: IpAddr, interface: IpAddr) -> Result<()> {
+        if group.is_ipv4() != interface.is_ipv4() {
+            return Err(SacnError::IpVersionError());
+        }
+        self.network.with_endpoint(self.id, |e| {
+            e.groups.remove(&group);
+        })
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        *lock_memory(&self.read_timeout)? = timeout;
+        Ok(())
+    }
+
+    fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.addr)
+    }
+}
+
+/// Removes the transport from its network so that it no longer has datagrams delivered to it.
+impl Drop for MemoryTransport {
+    fn drop(&mut self) {
+        if let Ok(mut endpoints) = self.network.lock() {
+            endpoints.retain(|e| e.id != self.id);
+        }
+    }
+}
+
+/// Locks a mutex used by a `MemoryNetwork` or `MemoryTransport`.
+///
+/// # Errors
+/// Io: Returned if the lock was poisoned by a panic in another thread.
+fn lock_memory<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>> {
+    match mutex.lock() {
+        // The PoisonError is not returned directly as it only gives access to the potentially inconsistent protected state.
+        Err(_) => Err(memory_poisoned()),
+        Ok(lock) => Ok(lock),
+    }
+}
+
+/// The error returned when a mutex used by a `MemoryNetwork` or `MemoryTransport` has been poisoned.
+///
+/// This is an Io error, as a socket would return, as the network and not the state of a source or receiver is left inconsistent.
+fn memory_poisoned() -> SacnError {
+    SacnError::Io(io::Error::other("Memory transport mutex poisoned"))
+}
+
+/// Creates a new Socket2 socket bound to the given address.
+///
+/// Returns the created socket.
+///
+/// Arguments:
+/// addr: The address that the newly created socket should bind to.
+/// `only_v6`: If given, whether an IPv6 socket should only receive IPv6, this is set before binding.
+///
+/// # Errors
+/// Will return an error if the socket cannot be created, see (`Socket::new`)[fn.new.Socket].
+///
+/// Will return an error if the socket cannot be bound to the given address, see (bind)[fn.bind.Socket2].
+#[cfg(not(target_os = "windows"))]
+fn create_unix_socket(addr: SocketAddr, only_v6: Option<bool>) -> Result<Socket> {
+    let (socket, unspecified) = if addr.is_ipv4() {
+        (
+            Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?,
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        )
+    } else {
+        (
+            Socket::new(Domain::IPV6, Type::DGRAM, Some(Protocol::UDP))?,
+            IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        )
+    };
+
+    // Multiple different processes might want to listen to the sACN stream so therefore need to allow re-using the ACN port.
+    // If the OS doesn't support SO_REUSEPORT then ignore the error and continue.
+    // FreeRTOS on ESP32 doesn't support SO_REUSEPORT and so this allows the library to be used on the platform without issue.
+    if let Err(e) = socket.set_reuse_port(true)
+        && e.raw_os_error() != Some(libc::ENOPROTOOPT)
+    {
+        return Err(e.into());
+    }
+    socket.set_reuse_address(true)?;
+
+    if let Some(val) = only_v6 {
+        socket.set_only_v6(val)?;
+    }
+
+    let socket_addr = SocketAddr::new(unspecified, ACN_SDT_MULTICAST_PORT);
+    socket.bind(&socket_addr.into())?;
+    Ok(socket)
+}
+
+/// Joins the multicast group with the given address using the given socket.
+///
+/// Arguments:
+/// socket: The socket to join to the multicast group.
+/// addr:   The address of the multicast group to join.
+/// `interface_addr`: The address of the interface to join the group on.
+///
+/// # Errors
+/// Will return an error if the given socket cannot be joined to the given multicast group address.
+///     See `join_multicast_v4`[`fn.join_multicast_v4.Socket`] and `join_multicast_v6`[`fn.join_multicast_v6.Socket`]
+///
+/// Will return an `IpVersionError` if addr and `interface_addr` are not the same IP version.
+#[cfg(not(target_os = "windows"))]
+fn join_unix_multicast(socket: &Socket, addr: IpAddr, interface_addr: IpAddr) -> Result<()> {
+    match (addr, interface_addr) {
+        (IpAddr::V4(ref a), IpAddr::V4(ref interface_v4)) => {
+            socket.join_multicast_v4(a, interface_v4).map_err(|e| {
+                SacnError::Io(io::Error::new(e.kind(), "Failed to join IPv4 multicast"))
+            })?;
+        }
+        (IpAddr::V6(ref a), _) => {
+            socket.join_multicast_v6(a, 0).map_err(|e| {
+                SacnError::Io(io::Error::new(e.kind(), "Failed to join IPv6 multicast"))
+            })?;
+        }
+        (IpAddr::V4(_), IpAddr::V6(_)) => {
+            return Err(SacnError::IpVersionError());
+        }
+    };
+
+    Ok(())
+}
+
+/// Leaves the multicast group with the given address using the given socket.
+///
+/// Arguments:
+/// socket: The socket to leave the multicast group.
+/// addr:   The address of the multicast group to leave.
+/// `interface_addr`: The address of the interface the group was joined on.
+///
+/// # Errors
+/// Will return an error if the given socket cannot leave the given multicast group address.
+///     See `leave_multicast_v4`[`fn.leave_multicast_v4.Socket`] and `leave_multicast_v6`[`fn.leave_multicast_v6.Socket`]
+///
+/// Will return an `IpVersionError` if addr and `interface_addr` are not the same IP version.
+#[cfg(not(target_os = "windows"))]
+fn leave_unix_multicast(socket: &Socket, addr: IpAddr, interface_addr: IpAddr) -> Result<()> {
+    match (addr, interface_addr) {
+        (IpAddr::V4(ref a), IpAddr::V4(ref interface_v4)) => {
+            socket.leave_multicast_v4(a, interface_v4).map_err(|e| {
+                SacnError::Io(io::Error::new(e.kind(), "Failed to leave IPv4 multicast"))
+            })?;
+        }
+        (IpAddr::V6(ref a), _) => {
+            socket.leave_multicast_v6(a, 0).map_err(|e| {
+                SacnError::Io(io::Error::new(e.kind(), "Failed to leave IPv6 multicast"))
+            })?;
+        }
+        (IpAddr::V4(_), IpAddr::V6(_)) => {
+            return Err(SacnError::IpVersionError());
+        }
+    };
+
+    Ok(())
+}
+
+/// Creates a new Socket2 socket bound to the given address.
+///
+/// Returns the created socket.
+///
+/// Arguments:
+/// addr: The address that the newly created socket should bind to.
+/// `only_v6`: If given, whether an IPv6 socket should only receive IPv6, this is set before binding.
+///
+/// # Errors
+/// Will return an error if the socket cannot be created, see (`Socket::new`)[fn.new.Socket].
+///
+/// Will return an error if the socket cannot be bound to the given address, see (bind)[fn.bind.Socket].
+#[cfg(target_os = "windows")]
+fn create_win_socket(addr: SocketAddr, only_v6: Option<bool>) -> Result<Socket> {
+    let socket = if addr.is_ipv4() {
+        Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?
+    } else {
+        Socket::new(Domain::IPV6, Type::DGRAM, Some(Protocol::UDP))?
+    };
+
+    socket.set_reuse_address(true)?;
+    if let Some(val) = only_v6 {
+        socket.set_only_v6(val)?;
+    }
+    socket.bind(&SockAddr::from(addr))?;
+    Ok(socket)
+}
+
+/// Joins the multicast group with the given address using the given socket on the windows operating system.
+///
+/// Note that Ipv6 is currently unsupported.
+///
+/// Arguments:
+/// socket: The socket to join to the multicast group.
+/// addr:   The address of the multicast group to join.
+/// `interface_addr`: The address of the interface to join the group on.
+///
+/// # Errors
+/// Will return an error if the given socket cannot be joined to the given multicast group address.
+///     See `join_multicast_v4`[`fn.join_multicast_v4.Socket`] and `join_multicast_v6`[`fn.join_multicast_v6.Socket`]
+///
+/// Will return an `IpVersionError` if addr and `interface_addr` are not the same IP version.
+#[cfg(target_os = "windows")]
+fn join_win_multicast(socket: &Socket, addr: IpAddr, interface_addr: IpAddr) -> Result<()> {
+    match (addr, interface_addr) {
+        (IpAddr::V4(ref a), IpAddr::V4(ref interface_v4)) => {
+            socket.join_multicast_v4(a, interface_v4).map_err(|e| {
+                SacnError::Io(io::Error::new(e.kind(), "Failed to join IPv4 multicast"))
+            })?;
+        }
+        (IpAddr::V6(ref a), _) => {
+            socket.join_multicast_v6(a, 0).map_err(|e| {
+                SacnError::Io(io::Error::new(e.kind(), "Failed to join IPv6 multicast"))
+            })?;
+        }
+        (IpAddr::V4(_), IpAddr::V6(_)) => {
+            return Err(SacnError::IpVersionError());
+        }
+    };
+
+    Ok(())
+}
+
+/// Leaves the multicast group with the given address using the given socket.
+///
+/// Note that Ipv6 is currently unsupported.
+///
+/// Arguments:
+/// socket: The socket to leave the multicast group.
+/// addr:   The address of the multicast group to leave.
+///
+/// # Errors
+/// Will return an error if the given socket cannot leave the given multicast group address.
+///     See `leave_multicast_v4`[`fn.leave_multicast_v4.Socket`] and `leave_multicast_v6`[`fn.leave_multicast_v6.Socket`]
+///
+/// Will return `OsOperationUnsupported` error if attempt to leave an Ipv6 multicast group as all Ipv6 multicast operations are currently unsupported in Rust on Windows.
+#[cfg(target_os = "windows")]
+fn leave_win_multicast(socket: &Socket, addr: IpAddr, _interface_addr: IpAddr) -> Result<()> {
+    match addr {
+        IpAddr::V4(ref a) => {
+            socket
+                .leave_multicast_v4(a, &Ipv4Addr::UNSPECIFIED)
+                .map_err(|e| {
+                    SacnError::Io(io::Error::new(e.kind(), "Failed to leave IPv4 multicast"))
+                })?;
+        }
+        IpAddr::V6(_) => {
+            return Err(SacnError::OsOperationUnsupported(
+                "IPv6 multicast is currently unsupported on Windows".to_string(),
+            ));
+        }
+    };
+
+    Ok(())
+}
@@ -0,0 +1,307 @@
+#![warn(missing_docs)]
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Validated values used by the public API of sources and receivers.
+//!
+//! A `Universe`, `SyncAddress` or `Priority` can only be created from a value within the range allowed by ANSI E1.31-2018 so once
+//! created it doesn't need to be checked again, and as they are distinct types a synchronisation address can't be passed where a data
+//! universe is expected by mistake. Values are created using `TryFrom` or the `const` `new` functions.
+//!
+//! ```
+//! use sacn::types::{Priority, SyncAddress, Universe};
+//!
+//! let universe = Universe::try_from(1).unwrap();
+//! assert_eq!(universe.get(), 1);
+//!
+//! // Universe 0 is reserved by ANSI E1.31-2018 Section 6.2.7.
+//! assert!(Universe::try_from(0).is_err());
+//!
+//! const SYNC_ADDR: SyncAddress = SyncAddress::new(7).unwrap();
+//! assert!(Priority::try_from(201).is_err());
+//! ```
+//!
+//! With the `serde` feature the types are serialised as the plain number and deserialising checks the range the same as `TryFrom`.
+//!
+//! The packet module keeps the raw `u16` and `u8` values as received packets may contain values outwith the allowed ranges.
+
+use crate::error::errors::*;
+use crate::packet::{
+    E131_DEFAULT_PRIORITY, E131_DISCOVERY_UNIVERSE, E131_MAX_MULTICAST_UNIVERSE, E131_MAX_PRIORITY,
+    E131_MIN_MULTICAST_UNIVERSE,
+};
+
+use core::fmt;
+
+/// An sACN universe, in the range [`E131_MIN_MULTICAST_UNIVERSE`, `E131_MAX_MULTICAST_UNIVERSE`] or the `E131_DISCOVERY_UNIVERSE`,
+/// see ANSI E1.31-2018 Section 6.2.7.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
+pub struct Universe(u16);
+
+impl Universe {
+    /// The lowest data universe.
+    pub const MIN: Universe = Universe(E131_MIN_MULTICAST_UNIVERSE);
+
+    /// The highest data universe.
+    pub const MAX: Universe = Universe(E131_MAX_MULTICAST_UNIVERSE);
+
+    /// The universe used for universe discovery packets, see ANSI E1.31-2018 Section 6.2.7.
+    pub const DISCOVERY: Universe = Universe(E131_DISCOVERY_UNIVERSE);
+
+    /// Returns the given universe, None if it is outwith the allowed range.
+    pub const fn new(universe: u16) -> Option<Universe> {
+        if (universe >= E131_MIN_MULTICAST_UNIVERSE && universe <= E131_MAX_MULTICAST_UNIVERSE)
+            || universe == E131_DISCOVERY_UNIVERSE
+        {
+            Some(Universe(universe))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the universe as a `u16`.
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+}
+
+impl TryFrom<u16> for Universe {
+    type Error = SacnError;
+
+    /// # Errors
+    /// `IllegalUniverse`: Returned if the universe is outwith the allowed range.
+    fn try_from(universe: u16) -> Result<Universe> {
+        Universe::new(universe).ok_or(SacnError::IllegalUniverse(universe))
+    }
+}
+
+impl From<Universe> for u16 {
+    fn from(universe: Universe) -> u16 {
+        universe.0
+    }
+}
+
+impl PartialEq<u16> for Universe {
+    fn eq(&self, other: &u16) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for Universe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The universe synchronisation packets are sent to, in the range [`E131_MIN_MULTICAST_UNIVERSE`, `E131_MAX_MULTICAST_UNIVERSE`],
+/// see ANSI E1.31-2018 Section 6.3.3.1.
+///
+/// Data which isn't synchronised has no synchronisation address, this is represented by `None` rather than the reserved address 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
+pub struct SyncAddress(u16);
+
+impl SyncAddress {
+    /// The lowest synchronisation address.
+    pub const MIN: SyncAddress = SyncAddress(E131_MIN_MULTICAST_UNIVERSE);
+
+    /// The highest synchronisation address.
+    pub const MAX: SyncAddress = SyncAddress(E131_MAX_MULTICAST_UNIVERSE);
+
+    /// Returns the given synchronisation address, None if it is outwith the allowed range.
+    pub const fn new(addr: u16) -> Option<SyncAddress> {
+        if addr >= E131_MIN_MULTICAST_UNIVERSE && addr <= E131_MAX_MULTICAST_UNIVERSE {
+            Some(SyncAddress(addr))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the synchronisation address as a `u16`.
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the universe the synchronisation packets are sent to, this must be registered on a source sending them.
+    pub const fn universe(self) -> Universe {
+        Universe(self.0)
+    }
+}
+
+impl TryFrom<u16> for SyncAddress {
+    type Error = SacnError;
+
+    /// # Errors
+    /// `IllegalSyncUniverse`: Returned if the address is outwith the allowed range.
+    fn try_from(addr: u16) -> Result<SyncAddress> {
+        SyncAddress::new(addr).ok_or(SacnError::IllegalSyncUniverse(addr))
+    }
+}
+
+impl TryFrom<Universe> for SyncAddress {
+    type Error = SacnError;
+
+    /// # Errors
+    /// `IllegalSyncUniverse`: Returned if the universe is the `E131_DISCOVERY_UNIVERSE`.
+    fn try_from(universe: Universe) -> Result<SyncAddress> {
+        SyncAddress::try_from(universe.get())
+    }
+}
+
+impl From<SyncAddress> for u16 {
+    fn from(addr: SyncAddress) -> u16 {
+        addr.0
+    }
+}
+
+impl From<SyncAddress> for Universe {
+    fn from(addr: SyncAddress) -> Universe {
+        addr.universe()
+    }
+}
+
+impl PartialEq<u16> for SyncAddress {
+    fn eq(&self, other: &u16) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for SyncAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The priority of data sent by a source, in the range [0, `E131_MAX_PRIORITY`], see ANSI E1.31-2018 Section 6.2.3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8", into = "u8"))]
+pub struct Priority(u8);
+
+impl Priority {
+    /// The lowest priority.
+    pub const MIN: Priority = Priority(0);
+
+    /// The highest priority.
+    pub const MAX: Priority = Priority(E131_MAX_PRIORITY);
+
+    /// The priority used when none is given, `E131_DEFAULT_PRIORITY`.
+    pub const DEFAULT: Priority = Priority(E131_DEFAULT_PRIORITY);
+
+    /// Returns the given priority, None if it is greater than `E131_MAX_PRIORITY`.
+    pub const fn new(priority: u8) -> Option<Priority> {
+        if priority <= E131_MAX_PRIORITY {
+            Some(Priority(priority))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the priority as a `u8`.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for Priority {
+    fn default() -> Priority {
+        Priority::DEFAULT
+    }
+}
+
+impl TryFrom<u8> for Priority {
+    type Error = SacnError;
+
+    /// # Errors
+    /// `InvalidPriority`: Returned if the priority is greater than `E131_MAX_PRIORITY`.
+    fn try_from(priority: u8) -> Result<Priority> {
+        Priority::new(priority).ok_or(SacnError::InvalidPriority(priority))
+    }
+}
+
+impl From<Priority> for u8 {
+    fn from(priority: Priority) -> u8 {
+        priority.0
+    }
+}
+
+impl PartialEq<u8> for Priority {
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The start code of DMX data, the first byte of the property values of a data packet, see ANSI E1.31-2018 Section 7.3.
+///
+/// Every `u8` is a valid start code so a `StartCode` can be created using `From` (and therefore `TryFrom`) without failing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct StartCode(u8);
+
+impl StartCode {
+    /// The start code of null start code (dimmer level) data, also used for stream termination packets by default.
+    pub const NULL: StartCode = StartCode(0x00);
+
+    /// The start code of ASCII text data, ANSI E1.11 Annex D.
+    pub const TEXT: StartCode = StartCode(0x17);
+
+    /// The start code of manufacturer specific data which starts with an ESTA manufacturer ID, ANSI E1.11 Annex D.
+    pub const MANUFACTURER: StartCode = StartCode(0x91);
+
+    /// The start code of Remote Device Management (RDM) messages, ANSI E1.20.
+    pub const RDM: StartCode = StartCode(0xCC);
+
+    /// The start code of system information packets, ANSI E1.11 Annex D.
+    pub const SYSTEM_INFORMATION: StartCode = StartCode(0xCF);
+
+    /// The start code of per-address priority data, sent alongside null start code data to give each slot its own priority.
+    pub const PER_ADDRESS_PRIORITY: StartCode = StartCode(0xDD);
+
+    /// Returns the given start code.
+    pub const fn new(start_code: u8) -> StartCode {
+        StartCode(start_code)
+    }
+
+    /// Returns the start code as a `u8`.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for StartCode {
+    fn from(start_code: u8) -> StartCode {
+        StartCode(start_code)
+    }
+}
+
+impl From<StartCode> for u8 {
+    fn from(start_code: StartCode) -> u8 {
+        start_code.0
+    }
+}
+
+impl PartialEq<u8> for StartCode {
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for StartCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x}", self.0)
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests that CIDs can be derived deterministically and kept across restarts using a `CidStore`.
+
+use sacn::cid::{CidStore, SACN_CID_NAMESPACE, derive_cid};
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::source::SacnSourceBuilder;
+use sacn::transport::MemoryNetwork;
+
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// Returns a path within the temporary directory which doesn't exist yet, unique to each call.
+fn tmp_path() -> PathBuf {
+    std::env::temp_dir().join(format!("sacn-cid-test-{}", Uuid::new_v4()))
+}
+
+/// Returns an IPv4 address on the memory network with the given last byte.
+fn addr(last_byte: u8) -> SocketAddr {
+    SocketAddr::new(
+        IpAddr::V4(Ipv4Addr::new(10, 0, 0, last_byte)),
+        ACN_SDT_MULTICAST_PORT,
+    )
+}
+
+#[test]
+fn test_derive_cid_deterministic() {
+    let cid = derive_cid(&SACN_CID_NAMESPACE, "dimmer-rack-1");
+
+    assert_eq!(cid, derive_cid(&SACN_CID_NAMESPACE, "dimmer-rack-1"));
+    assert_eq!(cid.get_version_num(), 5);
+
+    assert_ne!(cid, derive_cid(&SACN_CID_NAMESPACE, "dimmer-rack-2"));
+    assert_ne!(cid, derive_cid(&Uuid::NAMESPACE_DNS, "dimmer-rack-1"));
+}
+
+#[test]
+fn test_cid_store_load_missing() {
+    let store = CidStore::new(tmp_path());
+    assert_eq!(store.load().unwrap(), None);
+}
+
+#[test]
+fn test_cid_store_save_load() {
+    let path = tmp_path();
+    let store = CidStore::new(&path);
+    let cid = Uuid::new_v4();
+
+    store.save(&cid).unwrap();
+    assert_eq!(store.load().unwrap(), Some(cid));
+
+    // A new store using the same file, as after a restart, loads the same CID.
+    assert_eq!(CidStore::new(&path).load().unwrap(), Some(cid));
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_cid_store_load_or_generate_stable() {
+    let path = tmp_path();
+    let store = CidStore::new(&path);
+
+    let cid = store.load_or_generate().unwrap();
+    assert_eq!(store.load_or_generate().unwrap(), cid);
+
+    let derived = derive_cid(&SACN_CID_NAMESPACE, "unused");
+    assert_eq!(store.load_or_insert_with(|| derived).unwrap(), cid);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_cid_store_invalid_contents() {
+    let path = tmp_path();
+    fs::write(&path, "not a uuid").unwrap();
+
+    match CidStore::new(&path).load() {
+        Err(SacnError::Uuid(_)) => {}
+        x => panic!("Expected the contents to be rejected, got {x:?}"),
+    }
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_builder_cid_store() {
+    let path = tmp_path();
+    let network = MemoryNetwork::new();
+
+    let first = SacnSourceBuilder::new("Source")
+        .transport(network.bind(addr(1)).unwrap())
+        .cid_store(CidStore::new(&path))
+        .build()
+        .unwrap();
+    let cid = first.cid().unwrap();
+    drop(first);
+
+    let second = SacnSourceBuilder::new("Source")
+        .transport(network.bind(addr(1)).unwrap())
+        .cid_store(CidStore::new(&path))
+        .build()
+        .unwrap();
+    assert_eq!(second.cid().unwrap(), cid);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_builder_cid_and_cid_store() {
+    let path = tmp_path();
+    let network = MemoryNetwork::new();
+
+    match SacnSourceBuilder::new("Source")
+        .transport(network.bind(addr(1)).unwrap())
+        .cid(Uuid::new_v4())
+        .cid_store(CidStore::new(&path))
+        .build()
+    {
+        Err(SacnError::InvalidConfiguration(_)) => {}
+        x => panic!("Expected invalid configuration, got {x:?}"),
+    }
+
+    // The store isn't written to if the configuration is invalid.
+    assert!(!path.exists());
+}
@@ -3670,8 +3670,8 @@ fn test_malformed_data_packet_source_name_not_null_terminated_parse() {
     match AcnRootLayerProtocol::parse(&TEST_DATA_PACKET_NOT_NULL_TERMINATED_SOURCE_NAME) {
         Err(e) => {
             match e {
-                SacnError::SacnParsePackError(sacn_parse_pack_error::ParsePacketError::SourceNameNotNullTerminated()) => {
-                    assert!(true, "Expected error returned");
+                SacnError::SacnParsePackError(sacn_parse_pack_error::ParsePacketError::SourceNameNotNullTerminated(_)) => {
+                    // Expected error returned
                 }
                 x => {
                     assert!(false, "Unexpected error type returned: {}", x);
@@ -4568,4 +4568,145 @@ fn test_termination_packet_empty_property_values_parse() {
     }
 }
 
-}
\ No newline at end of file
+#[test]
+fn test_malformed_data_packet_too_high_priority_context() {
+    match AcnRootLayerProtocol::parse(TEST_DATA_PACKET_TOO_HIGH_PRIORITY) {
+        Err(SacnError::SacnParsePackError(e)) => {
+            let context = e.context().unwrap();
+            assert_eq!(context.layer, sacn_parse_pack_error::PacketLayer::Framing);
+            assert_eq!(context.field, "Priority");
+            // Preamble (16) + root layer (22) + framing layer flags, length, vector and source name (70).
+            assert_eq!(context.offset, 108);
+            assert_eq!(context.expected, "[0, 200]");
+            assert_eq!(context.actual, "201");
+            assert_eq!(TEST_DATA_PACKET_TOO_HIGH_PRIORITY[context.offset], E131_MAX_PRIORITY + 1);
+        }
+        x => {
+            panic!("Unexpected result returned: {:?}", x);
+        }
+    }
+}
+
+#[test]
+fn test_malformed_data_packet_dmp_layer_wrong_address_data_context() {
+    match AcnRootLayerProtocol::parse(TEST_DATA_PACKET_DMP_LAYER_WRONG_ADDRESS_DATA) {
+        Err(SacnError::SacnParsePackError(e)) => {
+            let context = e.context().unwrap();
+            assert_eq!(context.layer, sacn_parse_pack_error::PacketLayer::Dmp);
+            assert_eq!(context.field, "Address Type & Data Type");
+            assert_eq!(context.offset, 118);
+            assert_eq!(context.expected, "0xa1");
+            assert_eq!(context.actual, "0xa2");
+        }
+        x => {
+            panic!("Unexpected result returned: {:?}", x);
+        }
+    }
+}
+
+#[test]
+fn test_malformed_data_packet_context_display() {
+    match AcnRootLayerProtocol::parse(TEST_DATA_PACKET_TOO_HIGH_PRIORITY) {
+        Err(e) => {
+            assert!(e.to_string().contains("framing layer field Priority at offset 108, expected [0, 200], found 201"));
+        }
+        Ok(_) => {
+            panic!("Malformed packet was parsed when should have been rejected");
+        }
+    }
+}
+
+#[test]
+fn test_data_packet_parse_with_strict_options() {
+    let parsed = AcnRootLayerProtocol::parse_with_options(TEST_DATA_PACKET, &ParseOptions::default()).unwrap();
+    assert_eq!(parsed.packet, AcnRootLayerProtocol::parse(TEST_DATA_PACKET).unwrap());
+    assert!(parsed.warnings.is_empty());
+
+    match AcnRootLayerProtocol::parse_with_options(TEST_DATA_PACKET_DMP_LAYER_WRONG_ADDRESS_INCREMENT, &ParseOptions::strict()) {
+        Err(SacnError::SacnParsePackError(sacn_parse_pack_error::ParsePacketError::ParseInvalidData(_))) => {
+            // Expected error returned
+        }
+        x => {
+            panic!("Unexpected result returned: {:?}", x);
+        }
+    }
+}
+
+#[test]
+fn test_data_packet_source_name_not_null_terminated_lenient_parse() {
+    let parsed = AcnRootLayerProtocol::parse_with_options(TEST_DATA_PACKET_NOT_NULL_TERMINATED_SOURCE_NAME, &ParseOptions::lenient()).unwrap();
+
+    match parsed.packet.pdu.data {
+        E131RootLayerData::DataPacket(dpfl) => {
+            assert_eq!(dpfl.source_name.len(), E131_SOURCE_NAME_FIELD_LENGTH);
+            assert!(dpfl.source_name.starts_with("SourcSourc"));
+        }
+        _ => {
+            panic!("Packet not parsed as data packet as expected");
+        }
+    }
+
+    assert_eq!(parsed.warnings.len(), 1);
+    match &parsed.warnings[0] {
+        sacn_parse_pack_error::ParsePacketError::SourceNameNotNullTerminated(context) => {
+            assert_eq!(context.field, "Source Name");
+            assert_eq!(context.offset, 44);
+        }
+        x => {
+            panic!("Unexpected warning returned: {}", x);
+        }
+    }
+}
+
+#[test]
+fn test_data_packet_invalid_utf8_source_name_lenient_parse() {
+    let mut buf = TEST_DATA_PACKET.to_vec();
+    // Replace the 'o' of "Source_A" with a byte which can't start a UTF-8 character.
+    buf[45] = 0xff;
+
+    match AcnRootLayerProtocol::parse(&buf) {
+        Err(SacnError::SacnParsePackError(sacn_parse_pack_error::ParsePacketError::Utf8Error(context))) => {
+            assert_eq!(context.offset, 45);
+        }
+        x => {
+            panic!("Unexpected result returned: {:?}", x);
+        }
+    }
+
+    let parsed = AcnRootLayerProtocol::parse_with_options(&buf, &ParseOptions::lenient()).unwrap();
+    match parsed.packet.pdu.data {
+        E131RootLayerData::DataPacket(dpfl) => {
+            assert_eq!(dpfl.source_name, "S\u{fffd}urce_A");
+        }
+        _ => {
+            panic!("Packet not parsed as data packet as expected");
+        }
+    }
+    assert_eq!(parsed.warnings.len(), 1);
+}
+
+#[test]
+fn test_data_packet_dmp_layer_wrong_address_increment_lenient_parse() {
+    let options = ParseOptions {
+        allow_any_address_increment: true,
+        ..ParseOptions::strict()
+    };
+    let parsed = AcnRootLayerProtocol::parse_with_options(TEST_DATA_PACKET_DMP_LAYER_WRONG_ADDRESS_INCREMENT, &options).unwrap();
+
+    match parsed.packet.pdu.data {
+        E131RootLayerData::DataPacket(dpfl) => {
+            assert_eq!(dpfl.universe, 1);
+        }
+        _ => {
+            panic!("Packet not parsed as data packet as expected");
+        }
+    }
+
+    assert_eq!(parsed.warnings.len(), 1);
+    let context = parsed.warnings[0].context().unwrap();
+    assert_eq!(context.layer, sacn_parse_pack_error::PacketLayer::Dmp);
+    assert_eq!(context.field, "Address Increment");
+    assert_eq!(context.offset, 121);
+    assert_eq!(context.actual, "0x0002");
+}
+}
@@ -1382,4 +1382,47 @@ fn test_discovery_packet_above_max_universe_capacity() {
     }
 }
 
-}
\ No newline at end of file
+#[test]
+fn test_discovery_packet_decending_order_context() {
+    match AcnRootLayerProtocol::parse(TEST_UNIVERSE_DISCOVERY_PACKET_DECENDING_ORDER) {
+        Err(SacnError::SacnParsePackError(e)) => {
+            let context = e.context().unwrap();
+            assert_eq!(context.layer, sacn_parse_pack_error::PacketLayer::UniverseDiscovery);
+            assert_eq!(context.field, "List of Universes");
+            // The second universe in the list, 0x0302, is the first which is out of order.
+            assert_eq!(context.offset, 122);
+            assert_eq!(context.expected, "a universe greater than 1284");
+            assert_eq!(context.actual, "770");
+        }
+        x => {
+            panic!("Unexpected result returned: {:?}", x);
+        }
+    }
+}
+
+#[test]
+fn test_discovery_packet_discovery_layer_length_mismatch_lenient_parse() {
+    let parsed = AcnRootLayerProtocol::parse_with_options(
+        TEST_UNIVERSE_DISCOVERY_PACKET_DISCOVERY_LAYER_LENGTH_TOO_SHORT,
+        &ParseOptions::lenient(),
+    ).unwrap();
+
+    match parsed.packet.pdu.data {
+        E131RootLayerData::UniverseDiscoveryPacket(udpfl) => {
+            // The shorter discovery layer length is used so the final partial universe is ignored.
+            assert_eq!(udpfl.data.universes.to_vec(), vec![0x0001, 0x0203]);
+        }
+        _ => {
+            panic!("Packet not parsed as universe discovery packet as expected");
+        }
+    }
+
+    assert_eq!(parsed.warnings.len(), 1);
+    let context = parsed.warnings[0].context().unwrap();
+    assert_eq!(context.layer, sacn_parse_pack_error::PacketLayer::UniverseDiscovery);
+    assert_eq!(context.field, "Length");
+    assert_eq!(context.offset, 112);
+    assert_eq!(context.expected, "13 bytes");
+    assert_eq!(context.actual, "14 bytes");
+}
+}
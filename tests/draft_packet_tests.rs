@@ -0,0 +1,188 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests for data packets in the layout of the ANSI E1.31 draft (revision 0.2) which came before ANSI E1.31-2009.
+
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::sacn_parse_pack_error::ParsePacketError;
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// The options used to accept the draft root layer vector.
+const ALLOW_UNKNOWN: ParseOptions = ParseOptions {
+    allow_unknown_vectors: true,
+    ..ParseOptions::strict()
+};
+
+/// The offset of the framing layer PDU within a packet, after the preamble and root layer Flags and Length, Vector and CID fields.
+const FRAMING_PDU_OFFSET: usize = 38;
+
+/// A draft data packet for universe 1 from "Old Desk" with priority 100, sequence number 7 and the values [0, 10, 20].
+#[rustfmt::skip]
+const DRAFT_PACKET: [u8; 93] = [
+    // Preamble Size, Post-amble Size and ACN Packet Identifier.
+    0x00, 0x10, 0x00, 0x00, 0x41, 0x53, 0x43, 0x2d, 0x45, 0x31, 0x2e, 0x31, 0x37, 0x00, 0x00, 0x00,
+    // Root Layer Flags and Length, Vector.
+    0x70, 0x4d, 0x00, 0x00, 0x00, 0x03,
+    // CID.
+    0xef, 0x07, 0xc8, 0xdd, 0x00, 0x64, 0x44, 0x01, 0xa3, 0xa2, 0x45, 0x9e, 0xf8, 0xe6, 0x14, 0x3e,
+    // Framing Layer Flags and Length, Vector.
+    0x70, 0x37, 0x00, 0x00, 0x00, 0x02,
+    // Source Name, 32 bytes.
+    b'O', b'l', b'd', b' ', b'D', b'e', b's', b'k', 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    // Priority, Sequence Number, Universe.
+    100, 7, 0x00, 0x01,
+    // DMP Layer Flags and Length, Vector, Address Type & Data Type, First Property Address, Address Increment, Property value count.
+    0x70, 0x0d, 0x02, 0xa1, 0x00, 0x00, 0x00, 0x01, 0x00, 0x03,
+    // Property values.
+    0, 10, 20,
+];
+
+/// Returns the data packet held by the draft data packet in the given buffer.
+fn parse_draft(buf: &[u8]) -> Result<DataPacketFramingLayer<'_>> {
+    let mut parsers = VectorParsers::new();
+    parsers.register(VECTOR_ROOT_E131_DRAFT_DATA, parse_draft_data_packet);
+    match parsers.parse(
+        AcnRootLayerProtocol::parse_with_options(buf, &ALLOW_UNKNOWN)?
+            .packet
+            .pdu
+            .data,
+    )? {
+        E131RootLayerData::DataPacket(data) => Ok(data),
+        x => panic!("Expected a data packet, got {x:?}"),
+    }
+}
+
+/// Returns a data packet with the given source name and values as sent by an ANSI E1.31-2018 source.
+fn data_packet(source_name: &str, values: &[u8]) -> DataPacketFramingLayer<'static> {
+    DataPacketFramingLayer {
+        source_name: source_name.to_string().into(),
+        priority: 100,
+        synchronization_address: 0,
+        sequence_number: 7,
+        preview_data: false,
+        stream_terminated: false,
+        force_synchronization: false,
+        universe: 1,
+        data: DataPacketDmpLayer {
+            property_values: values.to_vec().into(),
+        },
+    }
+}
+
+/// Returns the data of the given draft data packet.
+fn raw<'a>(draft: &'a E131RootLayerData<'_>) -> &'a [u8] {
+    match draft {
+        E131RootLayerData::Unknown { vector, raw } => {
+            assert_eq!(*vector, VECTOR_ROOT_E131_DRAFT_DATA);
+            raw
+        }
+        x => panic!("Expected an unknown packet, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_parse_draft_data_packet() {
+    // Not an ANSI E1.31-2018 packet.
+    assert!(AcnRootLayerProtocol::parse(&DRAFT_PACKET).is_err());
+
+    assert_eq!(
+        parse_draft(&DRAFT_PACKET).unwrap(),
+        data_packet("Old Desk", &[0, 10, 20])
+    );
+}
+
+#[test]
+fn test_pack_draft_data_packet() {
+    let cid = Uuid::from_bytes(DRAFT_PACKET[22..FRAMING_PDU_OFFSET].try_into().unwrap());
+    let packet = AcnRootLayerProtocol {
+        pdu: E131RootLayer {
+            cid,
+            data: pack_draft_data_packet(&data_packet("Old Desk", &[0, 10, 20])).unwrap(),
+        },
+    };
+    assert_eq!(packet.pack_alloc().unwrap(), DRAFT_PACKET);
+}
+
+#[test]
+fn test_pack_draft_data_packet_drops_sync_and_options() {
+    let mut data = data_packet("Desk", &[0, 1]);
+    data.synchronization_address = 5;
+    data.preview_data = true;
+    data.force_synchronization = true;
+
+    let draft = pack_draft_data_packet(&data).unwrap();
+    assert_eq!(
+        parse_draft_data_packet(VECTOR_ROOT_E131_DRAFT_DATA, raw(&draft)).unwrap(),
+        E131RootLayerData::DataPacket(data_packet("Desk", &[0, 1]))
+    );
+}
+
+#[test]
+fn test_draft_source_name_length() {
+    // Cut short to leave room for the null terminator, without splitting the two byte character at bytes 30 and 31.
+    let long_name = format!("{}é and more", "a".repeat(30));
+    let draft = pack_draft_data_packet(&data_packet(&long_name, &[0])).unwrap();
+    match parse_draft_data_packet(VECTOR_ROOT_E131_DRAFT_DATA, raw(&draft)).unwrap() {
+        E131RootLayerData::DataPacket(data) => assert_eq!(data.source_name, "a".repeat(30)),
+        x => panic!("Expected a data packet, got {x:?}"),
+    }
+
+    // A name which fills the whole field without a null terminator is accepted.
+    let mut buf = DRAFT_PACKET;
+    buf[FRAMING_PDU_OFFSET + 6..FRAMING_PDU_OFFSET + 38].fill(b'x');
+    assert_eq!(parse_draft(&buf).unwrap().source_name, "x".repeat(32));
+}
+
+#[test]
+fn test_invalid_draft_data_packet() {
+    // Framing layer vector.
+    let mut buf = DRAFT_PACKET;
+    buf[FRAMING_PDU_OFFSET + 5] = 0x01;
+    assert!(matches!(
+        parse_draft(&buf),
+        Err(SacnError::SacnParsePackError(
+            ParsePacketError::PduInvalidVector(_)
+        ))
+    ));
+
+    // Universe.
+    let mut buf = DRAFT_PACKET;
+    buf[FRAMING_PDU_OFFSET + 40..FRAMING_PDU_OFFSET + 42].fill(0);
+    match parse_draft(&buf) {
+        Err(SacnError::SacnParsePackError(ParsePacketError::ParseInvalidUniverse(context))) => {
+            // The offset is from the start of the framing layer.
+            assert_eq!(context.offset, 40);
+        }
+        x => panic!("Expected an invalid universe, got {x:?}"),
+    }
+
+    // A framing layer too short to hold its header.
+    assert!(matches!(
+        parse_draft_data_packet(
+            VECTOR_ROOT_E131_DRAFT_DATA,
+            &[0x70, 0x06, 0x00, 0x00, 0x00, 0x02]
+        ),
+        Err(SacnError::SacnParsePackError(
+            ParsePacketError::PduInvalidLength(_)
+        ))
+    ));
+
+    // A framing layer shorter than its Length field.
+    assert!(matches!(
+        parse_draft_data_packet(
+            VECTOR_ROOT_E131_DRAFT_DATA,
+            &DRAFT_PACKET[FRAMING_PDU_OFFSET..90]
+        ),
+        Err(SacnError::SacnParsePackError(
+            ParsePacketError::ParseInsufficientData(_)
+        ))
+    ));
+}
@@ -0,0 +1,130 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests that packets can be parsed into a `FixedParseBuffer` without allocating, every variable length field of the parsed packet
+//! must borrow from either the buffer parsed or the `FixedParseBuffer`.
+
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::sacn_parse_pack_error::ParsePacketError;
+use sacn::types::{SyncAddress, Universe};
+
+use std::borrow::Cow;
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// Asserts that none of the fields of the given packet were allocated while parsing.
+fn assert_borrowed(packet: &AcnRootLayerProtocol<'_>) {
+    match &packet.pdu.data {
+        E131RootLayerData::DataPacket(data) => {
+            assert!(matches!(data.source_name, Cow::Borrowed(_)));
+            assert!(matches!(data.data.property_values, Cow::Borrowed(_)));
+        }
+        E131RootLayerData::SynchronizationPacket(_) => {}
+        E131RootLayerData::UniverseDiscoveryPacket(data) => {
+            assert!(matches!(data.source_name, Cow::Borrowed(_)));
+            assert!(matches!(data.data.universes, Cow::Borrowed(_)));
+        }
+        E131RootLayerData::Unknown { raw, .. } | E131RootLayerData::UnknownExtended { raw, .. } => {
+            assert!(matches!(raw, Cow::Borrowed(_)));
+        }
+    }
+}
+
+#[test]
+fn test_parse_fixed_data_packet() {
+    let packet = E131Packet::data(Universe::try_from(1).unwrap())
+        .cid(Uuid::new_v4())
+        .source_name("Source_A")
+        .values(&[0; UNIVERSE_CHANNEL_CAPACITY])
+        .build()
+        .unwrap();
+    let buf = packet.pack_alloc().unwrap();
+    let mut storage = FixedParseBuffer::new();
+
+    let parsed = AcnRootLayerProtocol::parse_fixed(&buf, &mut storage).unwrap();
+    assert_eq!(parsed, packet.as_protocol());
+    assert_borrowed(&parsed);
+}
+
+#[test]
+fn test_parse_fixed_sync_packet() {
+    let packet = E131Packet::sync(SyncAddress::try_from(7).unwrap())
+        .cid(Uuid::new_v4())
+        .build()
+        .unwrap();
+    let buf = packet.pack_alloc().unwrap();
+    let mut storage = FixedParseBuffer::new();
+
+    let parsed = AcnRootLayerProtocol::parse_fixed(&buf, &mut storage).unwrap();
+    assert_eq!(parsed, packet.as_protocol());
+    assert_borrowed(&parsed);
+}
+
+#[test]
+fn test_parse_fixed_discovery_packet() {
+    let universes: Vec<Universe> = (1..=DISCOVERY_UNI_PER_PAGE as u16)
+        .map(|u| Universe::try_from(u).unwrap())
+        .collect();
+    let packet = E131Packet::discovery()
+        .cid(Uuid::new_v4())
+        .source_name("Source_A")
+        .universes(&universes)
+        .build()
+        .unwrap();
+    let buf = packet.pack_alloc().unwrap();
+    let mut storage = FixedParseBuffer::new();
+
+    let parsed = AcnRootLayerProtocol::parse_fixed(&buf, &mut storage).unwrap();
+    assert_eq!(parsed, packet.as_protocol());
+    assert_borrowed(&parsed);
+}
+
+#[test]
+fn test_parse_fixed_reuses_storage() {
+    let mut storage = FixedParseBuffer::default();
+
+    for universes in [&[1, 2, 3][..], &[4][..]] {
+        let universes: Vec<Universe> = universes
+            .iter()
+            .map(|u| Universe::try_from(*u).unwrap())
+            .collect();
+        let packet = E131Packet::discovery()
+            .cid(Uuid::new_v4())
+            .universes(&universes)
+            .build()
+            .unwrap();
+        let buf = packet.pack_alloc().unwrap();
+
+        assert_eq!(
+            AcnRootLayerProtocol::parse_fixed(&buf, &mut storage).unwrap(),
+            packet.as_protocol()
+        );
+    }
+}
+
+#[test]
+fn test_parse_fixed_invalid_packet() {
+    let mut buf = E131Packet::data(Universe::try_from(1).unwrap())
+        .cid(Uuid::new_v4())
+        .values(&[0])
+        .build()
+        .unwrap()
+        .pack_alloc()
+        .unwrap();
+
+    // Priority, outwith the allowed range.
+    buf[108] = E131_MAX_PRIORITY + 1;
+
+    match AcnRootLayerProtocol::parse_fixed(&buf, &mut FixedParseBuffer::new()) {
+        Err(SacnError::SacnParsePackError(ParsePacketError::ParseInvalidPriority(context))) => {
+            assert_eq!(context.offset, 108);
+        }
+        x => panic!("Expected an invalid priority, got {x:?}"),
+    }
+}
@@ -0,0 +1,361 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests that the receiver handles an impaired network as described by ANSI E1.31-2018, run over an in-process `MemoryNetwork`.
+
+use sacn::clock::MockClock;
+use sacn::error::errors::*;
+use sacn::impairment::{ImpairedTransport, ImpairmentProfile};
+use sacn::packet::*;
+use sacn::receive::SacnReceiver;
+use sacn::source::SacnSource;
+use sacn::transport::{MemoryNetwork, MemoryTransport};
+use sacn::types::{SyncAddress, Universe};
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+/// The universe used for data in these tests.
+const UNIVERSE: Universe = Universe::new(1).unwrap();
+
+/// The universe used for synchronisation in these tests.
+const SYNC_UNI: SyncAddress = SyncAddress::new(2).unwrap();
+
+/// The timeout used when data is expected to arrive.
+const TIMEOUT: Option<Duration> = Some(Duration::from_secs(2));
+
+/// The timeout used when data is expected to not arrive.
+const SHORT_TIMEOUT: Option<Duration> = Some(Duration::from_millis(100));
+
+/// The result of draining a receiver, the identifying value of each data packet passed up and the number of packets discarded
+/// as out of sequence.
+#[derive(Debug, Default, PartialEq)]
+struct Drained {
+    /// The second value of each data packet received, the tests use this to identify the packet.
+    values: Vec<u8>,
+
+    /// The number of packets rejected with an `OutOfSequence` error.
+    out_of_sequence: usize,
+}
+
+/// Creates a receiver listening to `UNIVERSE` and `SYNC_UNI` over the given transport.
+fn receiver<T: sacn::transport::Transport + 'static>(transport: T) -> SacnReceiver {
+    let mut rcv = SacnReceiver::with_transport(transport, None).unwrap();
+    rcv.listen_universes(&[UNIVERSE, SYNC_UNI.universe()])
+        .unwrap();
+    rcv
+}
+
+/// Creates a source with `UNIVERSE` and `SYNC_UNI` registered sending over the given transport.
+fn source<T: sacn::transport::Transport + 'static>(transport: T) -> SacnSource {
+    let src = SacnSource::with_transport("Impaired Source", transport).unwrap();
+    src.register_universes(&[UNIVERSE, SYNC_UNI.universe()])
+        .unwrap();
+    src
+}
+
+/// Binds the receiver end of the memory network.
+fn rcv_transport(network: &MemoryNetwork) -> MemoryTransport {
+    network
+        .bind(SocketAddr::new(
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            ACN_SDT_MULTICAST_PORT,
+        ))
+        .unwrap()
+}
+
+/// Binds the source end of the memory network.
+fn src_transport(network: &MemoryNetwork) -> MemoryTransport {
+    network
+        .bind(SocketAddr::new(
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+            ACN_SDT_MULTICAST_PORT + 1,
+        ))
+        .unwrap()
+}
+
+/// Sends `count` data packets to `UNIVERSE`, packet i carries the values [0, i].
+fn send_numbered(src: &mut SacnSource, count: u8) {
+    for i in 0..count {
+        src.send(&[UNIVERSE], &[0, i], None, None, None).unwrap();
+    }
+}
+
+/// Receives until no more data arrives within `SHORT_TIMEOUT`.
+fn drain(rcv: &mut SacnReceiver) -> Drained {
+    let mut drained = Drained::default();
+    loop {
+        match rcv.recv(SHORT_TIMEOUT) {
+            Ok(data) => drained.values.extend(data.iter().map(|d| d.values[1])),
+            Err(SacnError::OutOfSequence(..)) => drained.out_of_sequence += 1,
+            Err(e) if e.is_timeout() => return drained,
+            Err(e) => panic!("Unexpected error {e:?}"),
+        }
+    }
+}
+
+/// Returns true if the values are strictly increasing.
+fn strictly_increasing(values: &[u8]) -> bool {
+    values.windows(2).all(|w| w[0] < w[1])
+}
+
+/// Sends `count` packets through a receive side impairment with the given profile and returns what the receiver passed up.
+fn run_recv_profile(
+    profile: ImpairmentProfile,
+    count: u8,
+) -> (Drained, Arc<ImpairedTransport<MemoryTransport>>) {
+    let network = MemoryNetwork::new();
+    let transport = Arc::new(ImpairedTransport::on_recv(rcv_transport(&network), profile).unwrap());
+    let mut rcv = receiver(transport.clone());
+    let mut src = source(src_transport(&network));
+
+    send_numbered(&mut src, count);
+    (drain(&mut rcv), transport)
+}
+
+/// Packets following lost packets are accepted as the sequence number difference is positive, ANSI E1.31-2018 Section 6.7.2.
+#[test]
+fn test_loss_later_packets_accepted() {
+    let profile = ImpairmentProfile {
+        loss: 0.5,
+        seed: 1,
+        ..ImpairmentProfile::default()
+    };
+    let (drained, transport) = run_recv_profile(profile, 40);
+    let stats = transport.recv_stats().unwrap();
+
+    assert!(stats.lost > 0, "Seed should lose some packets");
+    assert_eq!(drained.out_of_sequence, 0);
+    assert_eq!(drained.values.len(), 40 - stats.lost);
+    assert!(strictly_increasing(&drained.values));
+}
+
+/// With every packet lost nothing is passed up and the receiver times out.
+#[test]
+fn test_total_loss_times_out() {
+    let profile = ImpairmentProfile {
+        loss: 1.0,
+        ..ImpairmentProfile::default()
+    };
+    let (drained, transport) = run_recv_profile(profile, 5);
+
+    assert_eq!(drained, Drained::default());
+    assert_eq!(transport.recv_stats().unwrap().lost, 5);
+}
+
+/// A duplicate has a sequence number difference of 0 and so is discarded, ANSI E1.31-2018 Section 6.7.2.
+#[test]
+fn test_duplicates_discarded() {
+    let profile = ImpairmentProfile {
+        duplication: 1.0,
+        ..ImpairmentProfile::default()
+    };
+    let (drained, _) = run_recv_profile(profile, 10);
+
+    assert_eq!(drained.values, (0..10).collect::<Vec<u8>>());
+    assert_eq!(drained.out_of_sequence, 10);
+}
+
+/// A packet overtaken by the following packet has a sequence number difference of -1 and so is discarded, ANSI E1.31-2018 Section 6.7.2.
+#[test]
+fn test_reordered_packets_discarded() {
+    let network = MemoryNetwork::new();
+    let mut rcv = receiver(rcv_transport(&network));
+    let profile = ImpairmentProfile {
+        reorder: 1.0,
+        ..ImpairmentProfile::default()
+    };
+    let transport = Arc::new(ImpairedTransport::on_send(src_transport(&network), profile).unwrap());
+    let mut src = source(transport.clone());
+
+    // Every reordered packet swaps with the next so packets arrive as 1, 0, 3, 2, ...
+    send_numbered(&mut src, 10);
+    transport.flush().unwrap();
+
+    let drained = drain(&mut rcv);
+    assert_eq!(drained.values, vec![1, 3, 5, 7, 9]);
+    assert_eq!(drained.out_of_sequence, 5);
+    assert_eq!(transport.send_stats().unwrap().reordered, 5);
+}
+
+/// A held back packet with nothing following it is still delivered once the receive timeout expires.
+#[test]
+fn test_reordered_last_packet_delivered() {
+    let profile = ImpairmentProfile {
+        reorder: 1.0,
+        ..ImpairmentProfile::default()
+    };
+    let (drained, _) = run_recv_profile(profile, 1);
+
+    assert_eq!(drained.values, vec![0]);
+}
+
+/// Delay holds data back without affecting its order.
+#[test]
+fn test_delay_holds_data() {
+    const DELAY: Duration = Duration::from_millis(300);
+
+    let network = MemoryNetwork::new();
+    let profile = ImpairmentProfile {
+        delay: DELAY,
+        ..ImpairmentProfile::default()
+    };
+    let mut rcv = receiver(ImpairedTransport::on_recv(rcv_transport(&network), profile).unwrap());
+    let mut src = source(src_transport(&network));
+
+    let start = Instant::now();
+    send_numbered(&mut src, 3);
+
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(SacnError::Io(_)) => {
+            // Expected, the data is still delayed.
+        }
+        x => panic!("Delayed data passed up early {x:?}"),
+    }
+
+    let data = rcv.recv(TIMEOUT).unwrap();
+    assert!(start.elapsed() >= DELAY);
+    assert_eq!(data[0].values, vec![0, 0]);
+
+    let drained = drain(&mut rcv);
+    assert_eq!(drained.values, vec![1, 2]);
+}
+
+/// Jitter larger than the packet interval reorders packets, late packets are discarded so the data passed up is always newer than what came before.
+///
+/// Packets are spaced so that no packet is overtaken by more than the 20 packet discard window of ANSI E1.31-2018 Section 6.7.2, a packet
+/// arriving further behind than that is treated as a new stream and accepted.
+#[test]
+fn test_jitter_late_packets_discarded() {
+    const COUNT: u8 = 30;
+
+    let network = MemoryNetwork::new();
+    let profile = ImpairmentProfile {
+        jitter: Duration::from_millis(20),
+        seed: 7,
+        ..ImpairmentProfile::default()
+    };
+    let mut rcv = receiver(rcv_transport(&network));
+    let transport = Arc::new(ImpairedTransport::on_send(src_transport(&network), profile).unwrap());
+    let src = source(transport.clone());
+
+    // Jitter is applied on the send side as delivery times are relative to when the impairment sees each datagram.
+    for i in 0..COUNT {
+        src.send(&[UNIVERSE], &[0, i], None, None, None).unwrap();
+        sleep(Duration::from_millis(2));
+    }
+    transport.flush().unwrap();
+
+    let drained = drain(&mut rcv);
+    assert!(
+        drained.out_of_sequence > 0,
+        "Seed should reorder some packets"
+    );
+    assert_eq!(
+        drained.values.len() + drained.out_of_sequence,
+        usize::from(COUNT)
+    );
+    assert!(strictly_increasing(&drained.values), "{:?}", drained.values);
+}
+
+/// Impairment is fully determined by the seed.
+#[test]
+fn test_same_seed_same_impairment() {
+    let profile = ImpairmentProfile {
+        loss: 0.3,
+        duplication: 0.2,
+        seed: 1234,
+        ..ImpairmentProfile::default()
+    };
+    let (first, first_transport) = run_recv_profile(profile, 30);
+    let (second, second_transport) = run_recv_profile(profile, 30);
+
+    assert_eq!(first, second);
+    assert_eq!(
+        first_transport.recv_stats().unwrap(),
+        second_transport.recv_stats().unwrap()
+    );
+}
+
+/// A duplicated synchronisation packet is discarded so synchronised data is only passed up once.
+#[test]
+fn test_duplicate_sync_packet_releases_once() {
+    let network = MemoryNetwork::new();
+    let profile = ImpairmentProfile {
+        duplication: 1.0,
+        ..ImpairmentProfile::default()
+    };
+    let mut rcv = receiver(ImpairedTransport::on_recv(rcv_transport(&network), profile).unwrap());
+    let src = source(src_transport(&network));
+
+    src.send(&[UNIVERSE], &[0, 1], None, None, Some(SYNC_UNI))
+        .unwrap();
+    src.send_sync_packet(SYNC_UNI, None).unwrap();
+
+    let drained = drain(&mut rcv);
+    assert_eq!(drained.values, vec![1]);
+    // The duplicate data packet and the duplicate sync packet.
+    assert_eq!(drained.out_of_sequence, 2);
+}
+
+/// Data waiting for a lost synchronisation packet is discarded after `E131_NETWORK_DATA_LOSS_TIMEOUT` so a later synchronisation packet
+/// only releases data sent after it.
+#[test]
+fn test_lost_sync_packet_data_discarded() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let mut rcv = SacnReceiver::with_clock(rcv_transport(&network), None, clock.clone()).unwrap();
+    rcv.listen_universes(&[UNIVERSE, SYNC_UNI.universe()])
+        .unwrap();
+    let transport = Arc::new(
+        ImpairedTransport::on_send(src_transport(&network), ImpairmentProfile::default()).unwrap(),
+    );
+    let src = source(transport.clone());
+
+    src.send(&[UNIVERSE], &[0, 1], None, None, Some(SYNC_UNI))
+        .unwrap();
+
+    transport
+        .set_send_profile(ImpairmentProfile {
+            loss: 1.0,
+            ..ImpairmentProfile::default()
+        })
+        .unwrap();
+    src.send_sync_packet(SYNC_UNI, None).unwrap();
+    transport
+        .set_send_profile(ImpairmentProfile::default())
+        .unwrap();
+
+    assert_eq!(drain(&mut rcv), Drained::default());
+
+    clock.advance(E131_NETWORK_DATA_LOSS_TIMEOUT);
+    src.send_sync_packet(SYNC_UNI, None).unwrap();
+    assert_eq!(drain(&mut rcv), Drained::default());
+
+    src.send(&[UNIVERSE], &[0, 2], None, None, Some(SYNC_UNI))
+        .unwrap();
+    src.send_sync_packet(SYNC_UNI, None).unwrap();
+    assert_eq!(drain(&mut rcv).values, vec![2]);
+}
+
+/// Probabilities outwith [0, 1] are rejected.
+#[test]
+fn test_invalid_profile_rejected() {
+    let network = MemoryNetwork::new();
+    let profile = ImpairmentProfile {
+        loss: 1.5,
+        ..ImpairmentProfile::default()
+    };
+    match ImpairedTransport::on_recv(rcv_transport(&network), profile) {
+        Err(SacnError::InvalidImpairmentProfile(_)) => {
+            // Expected, the loss probability is invalid.
+        }
+        x => panic!("Unexpected result {x:?}"),
+    }
+}
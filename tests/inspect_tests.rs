@@ -0,0 +1,246 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests for describing packets field by field using `inspect`.
+
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::sacn_parse_pack_error::ParsePacketError;
+use sacn::types::{SyncAddress, Universe};
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// The offset of the framing layer Universe field within a data packet.
+const UNIVERSE_OFFSET: usize = 113;
+
+/// The offset of the DMP layer Address Increment field within a data packet.
+const ADDRESS_INCREMENT_OFFSET: usize = 121;
+
+/// Returns a packed data packet for universe 1 with the values [0, 255, 128].
+fn data_packet() -> Vec<u8> {
+    E131Packet::data(Universe::try_from(1).unwrap())
+        .cid(Uuid::new_v4())
+        .source_name("Source_A")
+        .priority(150.try_into().unwrap())
+        .values(&[0, 255, 128])
+        .build()
+        .unwrap()
+        .pack_alloc()
+        .unwrap()
+}
+
+/// Returns the fields of the given layer and the layers within it, in the order they appear.
+fn all_fields(layer: &InspectedLayer) -> Vec<&InspectedField> {
+    let mut fields: Vec<&InspectedField> = layer.fields.iter().collect();
+    fields.extend(layer.layers.iter().flat_map(all_fields));
+    fields
+}
+
+/// Returns the field with the given name within the given layer or the layers within it.
+fn field<'a>(layer: &'a InspectedLayer, name: &str) -> &'a InspectedField {
+    all_fields(layer)
+        .into_iter()
+        .find(|f| f.name == name)
+        .unwrap_or_else(|| panic!("No field named {name}"))
+}
+
+#[test]
+fn test_inspect_data_packet() {
+    let buf = data_packet();
+    let inspection = inspect(&buf);
+    assert!(inspection.is_valid());
+    assert!(inspection.error.is_none());
+
+    let [root] = &inspection.layers[..] else {
+        panic!("Expected a single root layer, got {:?}", inspection.layers);
+    };
+    assert_eq!(root.range, 0..buf.len());
+    assert_eq!(root.layers[0].name, "Data Packet Framing Layer");
+    assert_eq!(root.layers[0].layers[0].name, "DMP Layer");
+
+    // The fields cover the whole packet without overlapping, each holding the bytes it covers.
+    let fields = all_fields(root);
+    let mut offset = 0;
+    for f in &fields {
+        assert_eq!(
+            f.range.start, offset,
+            "{} doesn't follow the previous field",
+            f.name
+        );
+        assert_eq!(f.raw, buf[f.range.clone()]);
+        assert!(f.violations.is_empty());
+        offset = f.range.end;
+    }
+    assert_eq!(offset, buf.len());
+
+    assert_eq!(field(root, "Source Name").value, "\"Source_A\"");
+    assert_eq!(field(root, "Priority").value, "150");
+    assert_eq!(field(root, "Universe").value, "1");
+    assert_eq!(
+        field(root, "Universe").range,
+        UNIVERSE_OFFSET..UNIVERSE_OFFSET + 2
+    );
+    assert_eq!(
+        field(root, "Vector").value,
+        "0x00000004 (VECTOR_ROOT_E131_DATA)"
+    );
+    assert_eq!(
+        field(root, "Property values").value,
+        "start code 0x00, 2 slots"
+    );
+}
+
+#[test]
+fn test_inspect_sync_and_discovery_packets() {
+    let buf = E131Packet::sync(SyncAddress::try_from(7).unwrap())
+        .cid(Uuid::new_v4())
+        .build()
+        .unwrap()
+        .pack_alloc()
+        .unwrap();
+    let inspection = inspect(&buf);
+    assert!(inspection.is_valid());
+    let root = &inspection.layers[0];
+    assert_eq!(root.layers[0].name, "Synchronization Packet Framing Layer");
+    assert_eq!(field(root, "Synchronization Address").value, "7");
+
+    let buf = E131Packet::discovery()
+        .cid(Uuid::new_v4())
+        .source_name("Source_B")
+        .universes(&[
+            Universe::try_from(1).unwrap(),
+            Universe::try_from(3).unwrap(),
+        ])
+        .build()
+        .unwrap()
+        .pack_alloc()
+        .unwrap();
+    let inspection = inspect(&buf);
+    assert!(inspection.is_valid());
+    let root = &inspection.layers[0];
+    assert_eq!(root.layers[0].layers[0].name, "Universe Discovery Layer");
+    assert_eq!(root.layers[0].layers[0].range.end, buf.len());
+    assert_eq!(field(root, "List of Universes").value, "1, 3 (2 universes)");
+}
+
+#[test]
+fn test_inspect_invalid_universe() {
+    let mut buf = data_packet();
+    buf[UNIVERSE_OFFSET..UNIVERSE_OFFSET + 2].fill(0);
+
+    let inspection = inspect(&buf);
+    assert!(!inspection.is_valid());
+    assert!(matches!(
+        inspection.error,
+        Some(SacnError::SacnParsePackError(
+            ParsePacketError::ParseInvalidUniverse(_)
+        ))
+    ));
+    assert_eq!(inspection.violations.len(), 1);
+
+    // The fields up to and including the invalid one are still described.
+    let root = &inspection.layers[0];
+    assert_eq!(field(root, "Source Name").value, "\"Source_A\"");
+    let universe = field(root, "Universe");
+    assert_eq!(universe.value, "0");
+    assert!(matches!(
+        universe.violations[..],
+        [ParsePacketError::ParseInvalidUniverse(_)]
+    ));
+    assert!(root.layers[0].layers.is_empty());
+}
+
+#[test]
+fn test_inspect_accepted_deviation() {
+    let mut buf = data_packet();
+    buf[ADDRESS_INCREMENT_OFFSET + 1] = 2;
+
+    // Parsing continues past a deviation which can be recovered from.
+    let inspection = inspect(&buf);
+    assert!(inspection.error.is_none());
+    assert!(!inspection.is_valid());
+    let root = &inspection.layers[0];
+    assert!(matches!(
+        field(root, "Address Increment").violations[..],
+        [ParsePacketError::ParseInvalidData(_)]
+    ));
+    assert_eq!(
+        field(root, "Property values").value,
+        "start code 0x00, 2 slots"
+    );
+}
+
+#[test]
+fn test_inspect_truncated_packet() {
+    let buf = data_packet();
+    for len in 0..buf.len() {
+        let inspection = inspect(&buf[..len]);
+        assert!(inspection.error.is_some(), "Packet of length {len} parsed");
+        assert!(!inspection.violations.is_empty());
+    }
+
+    // The root layer Length is checked against the buffer before the layers within it are parsed.
+    let inspection = inspect(&buf[..60]);
+    let root = &inspection.layers[0];
+    assert!(root.layers.is_empty());
+    assert!(matches!(
+        field(root, "Flags and Length").violations[..],
+        [ParsePacketError::ParseInsufficientData(_)]
+    ));
+}
+
+#[test]
+fn test_inspect_display() {
+    let mut buf = data_packet();
+    buf[UNIVERSE_OFFSET..UNIVERSE_OFFSET + 2].fill(0);
+    let text = inspect(&buf).to_string();
+
+    assert!(text.starts_with("Root Layer (bytes 0..115)\n"));
+    assert!(text.contains("\n    Preamble Size: 16  [0: 00 10]\n"));
+    assert!(text.contains("\n    Data Packet Framing Layer (bytes 38..115)\n"));
+    assert!(text.contains("\n        Priority: 150  [108: 96]\n"));
+    assert!(text.contains("\n        Universe: 0  [113: 00 00]\n            [Violation: "));
+    assert!(text.contains("Parsing stopped: "));
+
+    // Long fields are cut short.
+    assert!(text.contains("[44: 53 6f 75 72 63 65 5f 41 00 00 00 00 00 00 00 00 ...]"));
+}
+
+#[test]
+fn test_inspect_json() {
+    let mut buf = data_packet();
+    buf[UNIVERSE_OFFSET..UNIVERSE_OFFSET + 2].fill(0);
+    let inspection = inspect(&buf);
+    let json: serde_json::Value = serde_json::from_str(&inspection.to_json()).unwrap();
+
+    assert_eq!(json["valid"], false);
+    assert!(json["error"].is_string());
+    assert_eq!(json["violations"].as_array().unwrap().len(), 1);
+
+    let framing = &json["layers"][0]["layers"][0];
+    assert_eq!(framing["name"], "Data Packet Framing Layer");
+    let universe = framing["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == "Universe")
+        .unwrap();
+    assert_eq!(universe["start"], UNIVERSE_OFFSET);
+    assert_eq!(universe["raw"], "0000");
+    assert_eq!(universe["violations"].as_array().unwrap().len(), 1);
+
+    // Quotes in field values are escaped.
+    assert_eq!(
+        json["layers"][0]["layers"][0]["fields"][2]["value"],
+        "\"Source_A\""
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&inspect(&data_packet()).to_json()).unwrap();
+    assert_eq!(json["valid"], true);
+    assert!(json["error"].is_null());
+}
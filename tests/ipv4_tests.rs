@@ -20,6 +20,7 @@ use std::convert::TryInto; // Used for converting between u8 and u16 representat
 use std::str; // Used for converting between bytes and strings.
 
 use sacn::source::SacnSource;
+use sacn::types::{Priority, StartCode, SyncAddress, Universe};
 use sacn::receive::{SacnReceiver, DMXData, htp_dmx_merge};
 use sacn::packet::*;
 use sacn::error::errors::*;
@@ -297,7 +298,7 @@ fn test_send_single_universe_multiple_receivers_multicast_ipv4(){
     let thread1_tx = tx.clone();
     let thread2_tx = tx.clone();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     let rcv_thread1 = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -324,9 +325,9 @@ fn test_send_single_universe_multiple_receivers_multicast_ipv4(){
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
 
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
-    let priority = 100;
+    let priority = Priority::try_from(100).unwrap();
 
     src.register_universe(universe).unwrap();
 
@@ -342,14 +343,14 @@ fn test_send_single_universe_multiple_receivers_multicast_ipv4(){
     let received_data1: Vec<DMXData> = received_result1.unwrap();
     assert_eq!(received_data1.len(), 1); // Check only 1 universe received as expected.
     let received_universe1: DMXData = received_data1[0].clone();
-    assert_eq!(received_universe1.universe, universe); // Check that the universe received is as expected.
+    assert_eq!(received_universe1.universe, universe.get()); // Check that the universe received is as expected.
     assert_eq!(received_universe1.values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 
     assert!(!received_result2.is_err(), "Failed: Error when receiving data");
     let received_data2: Vec<DMXData> = received_result2.unwrap();
     assert_eq!(received_data2.len(), 1); // Check only 1 universe received as expected.
     let received_universe2: DMXData = received_data2[0].clone();
-    assert_eq!(received_universe2.universe, universe); // Check that the universe received is as expected.
+    assert_eq!(received_universe2.universe, universe.get()); // Check that the universe received is as expected.
     assert_eq!(received_universe2.values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 }
 
@@ -361,16 +362,16 @@ fn test_send_across_universe_multiple_receivers_sync_multicast_ipv4(){
     let thread1_tx = tx.clone();
     let thread2_tx = tx.clone();
 
-    let universe1 = 1;
-    let universe2 = 2;
+    let universe1 = Universe::try_from(1).unwrap();
+    let universe2 = Universe::try_from(2).unwrap();
 
-    let sync_uni = 3;
+    let sync_uni = SyncAddress::try_from(3).unwrap();
 
     let rcv_thread1 = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
         dmx_recv.listen_universes(&[universe1]).unwrap();
-        dmx_recv.listen_universes(&[sync_uni]).unwrap();
+        dmx_recv.listen_universes(&[sync_uni.universe()]).unwrap();
 
         thread1_tx.send(Ok(Vec::new())).unwrap();
 
@@ -381,7 +382,7 @@ fn test_send_across_universe_multiple_receivers_sync_multicast_ipv4(){
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[1].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
         dmx_recv.listen_universes(&[universe2]).unwrap();
-        dmx_recv.listen_universes(&[sync_uni]).unwrap();
+        dmx_recv.listen_universes(&[sync_uni.universe()]).unwrap();
 
         thread2_tx.send(Ok(Vec::new())).unwrap();
 
@@ -393,13 +394,13 @@ fn test_send_across_universe_multiple_receivers_sync_multicast_ipv4(){
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
 
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
-    let priority = 100;
+    let priority = Priority::try_from(100).unwrap();
 
     src.register_universe(universe1).unwrap();
     src.register_universe(universe2).unwrap();
-    src.register_universe(sync_uni).unwrap();
+    src.register_universe(sync_uni.universe()).unwrap();
 
     src.send(&[universe1], &TEST_DATA_MULTIPLE_UNIVERSE[..513], Some(priority), None, Some(sync_uni)).unwrap();
     src.send(&[universe2], &TEST_DATA_MULTIPLE_UNIVERSE[513..], Some(priority), None, Some(sync_uni)).unwrap();
@@ -433,8 +434,8 @@ fn test_send_across_universe_multiple_receivers_sync_multicast_ipv4(){
     let mut results = vec![received_result1[0].clone(), received_result2[0].clone()];
     results.sort_unstable(); // Ordering of received data is undefined, to make it easier to check sort first.
 
-    assert_eq!(results[0].universe, universe1); // Check that the universe 1 received is as expected.
-    assert_eq!(results[1].universe, universe2); // Check that the universe 2 received is as expected.
+    assert_eq!(results[0].universe, universe1.get()); // Check that the universe 1 received is as expected.
+    assert_eq!(results[1].universe, universe2.get()); // Check that the universe 2 received is as expected.
 
     assert_eq!(results[0].values, TEST_DATA_MULTIPLE_UNIVERSE[..513].to_vec());
     assert_eq!(results[1].values, TEST_DATA_MULTIPLE_UNIVERSE[513..].to_vec());
@@ -447,7 +448,7 @@ fn test_send_recv_single_universe_unicast_ipv4(){
 
     let thread_tx = tx.clone();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     let rcv_thread = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(Ipv4Addr::LOCALHOST.into(), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -462,9 +463,9 @@ fn test_send_recv_single_universe_unicast_ipv4(){
     let _ = rx.recv().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
-    let priority = 100;
+    let priority = Priority::try_from(100).unwrap();
 
     src.register_universe(universe).unwrap();
 
@@ -484,7 +485,7 @@ fn test_send_recv_single_universe_unicast_ipv4(){
 
     let received_universe: DMXData = received_data[0].clone();
 
-    assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+    assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
     assert_eq!(received_universe.values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 }
@@ -495,8 +496,8 @@ fn test_send_recv_single_universe_unicast_ipv4(){
 #[ignore]
 fn test_send_recv_single_universe_multicast_ipv4(){
     // The universe and priority of the data used in this test.
-    const UNIVERSE: u16 = 1;
-    const PRIORITY: u8 = 100;
+    const UNIVERSE: Universe = Universe::new(1).unwrap();
+    const PRIORITY: Priority = Priority::new(100).unwrap();
 
     // Allows control of the receiver and sender so that they can be put into the correct state for the test.
     let (tx, rx): (Sender<Result<Vec<DMXData>>>, Receiver<Result<Vec<DMXData>>>) = mpsc::channel();
@@ -525,7 +526,7 @@ fn test_send_recv_single_universe_multicast_ipv4(){
 
     // The sender is bound to an interface on the same network as the receiver but on a different port.
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[1].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
     // The sender registers the universe for sending and then sends some test data.
     src.register_universe(UNIVERSE).unwrap();
@@ -543,8 +544,8 @@ fn test_send_recv_single_universe_multicast_ipv4(){
     assert_eq!(received_data.len(), 1); // Check only 1 universe received as expected.
 
     let received_universe: DMXData = received_data[0].clone();
-    assert_eq!(received_universe.priority, PRIORITY, "Received priority doesn't match expected");
-    assert_eq!(received_universe.universe, UNIVERSE, "Received universe doesn't match expected");
+    assert_eq!(received_universe.priority, PRIORITY.get(), "Received priority doesn't match expected");
+    assert_eq!(received_universe.universe, UNIVERSE.get(), "Received universe doesn't match expected");
     assert_eq!(received_universe.values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 }
 
@@ -561,7 +562,7 @@ fn test_send_recv_single_universe_overflow_sequence_number_multicast_ipv4(){
 
     let thread_tx = tx.clone();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     // By having the receiver be 'remote' and then send back to the sender it means the sender can check the data it has sent is correct.
     let rcv_thread = thread::spawn(move || {
@@ -579,7 +580,7 @@ fn test_send_recv_single_universe_overflow_sequence_number_multicast_ipv4(){
     rx.recv().unwrap().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
     src.register_universe(universe).unwrap();
 
@@ -589,7 +590,7 @@ fn test_send_recv_single_universe_overflow_sequence_number_multicast_ipv4(){
         assert_eq!(received_data.len(), 1); // Check only 1 universe received at a time as expected.
         let received_universe: DMXData = received_data[0].clone();
 
-        assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+        assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
         assert_eq!(received_universe.values, TEST_DATA_SINGLE_UNIVERSE[0 .. i + 1].to_vec(), "Received payload values don't match sent!");
     }
@@ -609,7 +610,7 @@ fn test_send_recv_diff_priority_same_universe_multicast_ipv4(){
 
     let thread_tx = tx.clone();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     let rcv_thread = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -624,16 +625,16 @@ fn test_send_recv_diff_priority_same_universe_multicast_ipv4(){
     rx.recv().unwrap().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
-    let priority = 110;
-    let priority_2 = 109;
+    let priority = Priority::try_from(110).unwrap();
+    let priority_2 = Priority::try_from(109).unwrap();
 
     src.register_universe(universe).unwrap();
 
-    src.send(&[universe], &TEST_DATA_SINGLE_UNIVERSE, Some(priority), None, Some(universe)).unwrap(); // First packet with higher priority.
-    src.send(&[universe], &TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE, Some(priority_2), None, Some(universe)).unwrap(); // Second packet with lower priority.
-    src.send_sync_packet(universe, None).unwrap(); // Trigger the packet to be passed up on the receiver.
+    src.send(&[universe], &TEST_DATA_SINGLE_UNIVERSE, Some(priority), None, Some(SyncAddress::try_from(universe).unwrap())).unwrap(); // First packet with higher priority.
+    src.send(&[universe], &TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE, Some(priority_2), None, Some(SyncAddress::try_from(universe).unwrap())).unwrap(); // Second packet with lower priority.
+    src.send_sync_packet(SyncAddress::try_from(universe).unwrap(), None).unwrap(); // Trigger the packet to be passed up on the receiver.
 
     let received_result: Result<Vec<DMXData>> = rx.recv().unwrap();
 
@@ -647,7 +648,7 @@ fn test_send_recv_diff_priority_same_universe_multicast_ipv4(){
 
     let received_universe: DMXData = received_data[0].clone();
 
-    assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+    assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
     assert_eq!(received_universe.values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 }
@@ -663,7 +664,7 @@ fn test_send_recv_two_packets_same_priority_same_universe_multicast_ipv4(){
 
     let thread_tx = tx.clone();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     let rcv_thread = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -678,15 +679,15 @@ fn test_send_recv_two_packets_same_priority_same_universe_multicast_ipv4(){
     rx.recv().unwrap().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
-    let priority = 110;
+    let priority = Priority::try_from(110).unwrap();
 
     src.register_universe(universe).unwrap();
 
-    src.send(&[universe], &TEST_DATA_SINGLE_UNIVERSE, Some(priority), None, Some(universe)).unwrap(); // First packet
-    src.send(&[universe], &TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE, Some(priority), None, Some(universe)).unwrap(); // Second packet which should override first.
-    src.send_sync_packet(universe, None).unwrap(); // Trigger the packet to be passed up on the receiver.
+    src.send(&[universe], &TEST_DATA_SINGLE_UNIVERSE, Some(priority), None, Some(SyncAddress::try_from(universe).unwrap())).unwrap(); // First packet
+    src.send(&[universe], &TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE, Some(priority), None, Some(SyncAddress::try_from(universe).unwrap())).unwrap(); // Second packet which should override first.
+    src.send_sync_packet(SyncAddress::try_from(universe).unwrap(), None).unwrap(); // Trigger the packet to be passed up on the receiver.
 
     let received_result: Result<Vec<DMXData>> = rx.recv().unwrap();
 
@@ -700,7 +701,7 @@ fn test_send_recv_two_packets_same_priority_same_universe_multicast_ipv4(){
 
     let received_universe: DMXData = received_data[0].clone();
 
-    assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+    assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
     assert_eq!(received_universe.values, TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 }
@@ -719,7 +720,7 @@ fn test_send_recv_sync_then_nosync_packet_same_universe_multicast_ipv4() {
 
     let thread_tx = tx.clone();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
     const TIMEOUT: Option<Duration> = Some(Duration::from_secs(2));
 
     let rcv_thread = thread::spawn(move || {
@@ -737,14 +738,14 @@ fn test_send_recv_sync_then_nosync_packet_same_universe_multicast_ipv4() {
     rx.recv().unwrap().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
     src.register_universe(universe).unwrap();
 
-    src.send(&[universe], &TEST_DATA_SINGLE_UNIVERSE, None, None, Some(universe)).unwrap(); // First packet, with sync.
+    src.send(&[universe], &TEST_DATA_SINGLE_UNIVERSE, None, None, Some(SyncAddress::try_from(universe).unwrap())).unwrap(); // First packet, with sync.
     src.send(&[universe], &TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE, None, None, None).unwrap(); // Second packet, no sync.
 
-    src.send_sync_packet(universe, None).unwrap(); // Send a sync packet, if the first packet isn't discarded it should now be passed up.
+    src.send_sync_packet(SyncAddress::try_from(universe).unwrap(), None).unwrap(); // Send a sync packet, if the first packet isn't discarded it should now be passed up.
 
     let first_received_result: Result<Vec<DMXData>> = rx.recv().unwrap();
     let second_received_result: Result<Vec<DMXData>> = rx.recv().unwrap();
@@ -756,7 +757,7 @@ fn test_send_recv_sync_then_nosync_packet_same_universe_multicast_ipv4() {
     let received_data: Vec<DMXData> = first_received_result.unwrap();
     assert_eq!(received_data.len(), 1); // Check only 1 universe received as expected.
     let received_universe: DMXData = received_data[0].clone();
-    assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+    assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
     assert_eq!(received_universe.values, TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 
     match second_received_result {
@@ -796,7 +797,7 @@ fn test_send_recv_two_universe_multicast_ipv4(){
 
     let thread_tx = tx.clone();
 
-    let universes = [1, 2];
+    let universes = [Universe::try_from(1).unwrap(), Universe::try_from(2).unwrap()];
 
     let rcv_thread = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -812,7 +813,7 @@ fn test_send_recv_two_universe_multicast_ipv4(){
     rx.recv().unwrap().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
     src.register_universes(&universes).unwrap();
 
@@ -835,8 +836,8 @@ fn test_send_recv_two_universe_multicast_ipv4(){
     assert_eq!(received_data.len(), 1);   // Check only 1 universe received from each individual recv() as expected, if this wasn't the case it would
     assert_eq!(received_data_2.len(), 1); // indicate that the data has been synchronised incorrectly or that less data than expected was received.
 
-    assert_eq!(received_data[0].universe, universes[0]);   // Check that the universe received is as expected.
-    assert_eq!(received_data_2[0].universe, universes[1]);
+    assert_eq!(received_data[0].universe, universes[0].get());   // Check that the universe received is as expected.
+    assert_eq!(received_data_2[0].universe, universes[1].get());
 
     assert_eq!(received_data[0].values, TEST_DATA_MULTIPLE_UNIVERSE[..513].to_vec());
     assert_eq!(received_data_2[0].values, TEST_DATA_MULTIPLE_UNIVERSE[513..].to_vec());
@@ -849,7 +850,7 @@ fn test_send_recv_single_universe_alternative_startcode_multicast_ipv4(){
 
     let thread_tx = tx.clone();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     let rcv_thread = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -864,9 +865,9 @@ fn test_send_recv_single_universe_alternative_startcode_multicast_ipv4(){
     rx.recv().unwrap().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
-    let priority = 100;
+    let priority = Priority::try_from(100).unwrap();
 
     src.register_universe(universe).unwrap();
 
@@ -884,7 +885,7 @@ fn test_send_recv_single_universe_alternative_startcode_multicast_ipv4(){
 
     let received_universe: DMXData = received_data[0].clone();
 
-    assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+    assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
     assert_eq!(received_universe.values, TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 }
@@ -898,7 +899,7 @@ fn test_send_recv_across_universe_multicast_ipv4(){
 
     let thread_tx = tx.clone();
 
-    const UNIVERSES: [u16; 2] = [2, 3];
+    const UNIVERSES: [Universe; 2] = [Universe::new(2).unwrap(), Universe::new(3).unwrap()];
 
     let rcv_thread = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(Ipv4Addr::new(0,0,0,0).into(), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -913,15 +914,15 @@ fn test_send_recv_across_universe_multicast_ipv4(){
     let _ = rx.recv().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
-    let priority = 100;
+    let priority = Priority::try_from(100).unwrap();
 
     src.register_universes(&UNIVERSES).unwrap();
 
-    src.send(&UNIVERSES, &TEST_DATA_MULTIPLE_UNIVERSE, Some(priority), None, Some(UNIVERSES[0])).unwrap();
+    src.send(&UNIVERSES, &TEST_DATA_MULTIPLE_UNIVERSE, Some(priority), None, Some(SyncAddress::try_from(UNIVERSES[0]).unwrap())).unwrap();
     sleep(Duration::from_millis(500)); // Small delay to allow the data packets to get through as per NSI-E1.31-2018 Appendix B.1 recommendation. See other warnings about the possibility of theses tests failing if the network isn't perfect.
-    src.send_sync_packet(UNIVERSES[0], None).unwrap();
+    src.send_sync_packet(SyncAddress::try_from(UNIVERSES[0]).unwrap(), None).unwrap();
 
     let sync_pkt_res: Result<Vec<DMXData>> = rx.recv().unwrap();
 
@@ -957,7 +958,7 @@ fn test_send_recv_across_universe_unicast_ipv4(){
 
     let thread_tx = tx.clone();
 
-    const UNIVERSES: [u16; 2] = [2, 3];
+    const UNIVERSES: [Universe; 2] = [Universe::new(2).unwrap(), Universe::new(3).unwrap()];
 
     let rcv_thread = thread::spawn(move || {
         let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(Ipv4Addr::new(127,0,0,1).into(), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -972,15 +973,15 @@ fn test_send_recv_across_universe_unicast_ipv4(){
     let _ = rx.recv().unwrap(); // Blocks until the receiver says it is ready.
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
 
-    let priority = 100;
+    let priority = Priority::try_from(100).unwrap();
 
     src.register_universes(&UNIVERSES).unwrap();
 
-    let _ = src.send(&UNIVERSES, &TEST_DATA_MULTIPLE_UNIVERSE, Some(priority), Some(SocketAddr::new(Ipv4Addr::new(127,0,0,1).into(), ACN_SDT_MULTICAST_PORT).into()), Some(UNIVERSES[0])).unwrap();
+    src.send(&UNIVERSES, &TEST_DATA_MULTIPLE_UNIVERSE, Some(priority), Some(SocketAddr::new(Ipv4Addr::new(127,0,0,1).into(), ACN_SDT_MULTICAST_PORT)), Some(SyncAddress::try_from(UNIVERSES[0]).unwrap())).unwrap();
     sleep(Duration::from_millis(500)); // Small delay to allow the data packets to get through as per NSI-E1.31-2018 Appendix B.1 recommendation.
-    src.send_sync_packet(UNIVERSES[0], Some(SocketAddr::new(Ipv4Addr::new(127,0,0,1).into(), ACN_SDT_MULTICAST_PORT).into())).unwrap();
+    src.send_sync_packet(SyncAddress::try_from(UNIVERSES[0]).unwrap(), Some(SocketAddr::new(Ipv4Addr::new(127,0,0,1).into(), ACN_SDT_MULTICAST_PORT))).unwrap();
 
     let sync_pkt_res: Result<Vec<DMXData>> = rx.recv().unwrap();
 
@@ -1010,8 +1011,8 @@ fn test_send_recv_across_universe_unicast_ipv4(){
 #[test]
 #[ignore]
 fn test_two_senders_one_recv_different_universes_multicast_ipv4(){
-    let universe_1 = 1;
-    let universe_2 = 2;
+    let universe_1 = Universe::try_from(1).unwrap();
+    let universe_2 = Universe::try_from(2).unwrap();
 
     let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(Ipv4Addr::new(0,0,0,0).into(), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
@@ -1020,9 +1021,9 @@ fn test_two_senders_one_recv_different_universes_multicast_ipv4(){
 
     let snd_thread_1 = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe_1).unwrap();
 
@@ -1031,9 +1032,9 @@ fn test_two_senders_one_recv_different_universes_multicast_ipv4(){
 
     let snd_thread_2 = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), ACN_SDT_MULTICAST_PORT + 2);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe_2).unwrap();
 
@@ -1053,8 +1054,8 @@ fn test_two_senders_one_recv_different_universes_multicast_ipv4(){
     let mut res = vec![res1[0].clone(), res2[0].clone()];
     res.sort_unstable();
 
-    assert_eq!(res[0].universe, universe_1);
-    assert_eq!(res[1].universe, universe_2);
+    assert_eq!(res[0].universe, universe_1.get());
+    assert_eq!(res[1].universe, universe_2.get());
 
     assert_eq!(res[0].values, TEST_DATA_SINGLE_UNIVERSE.to_vec());
     assert_eq!(res[1].values, TEST_DATA_PARTIAL_CAPACITY_UNIVERSE.to_vec());
@@ -1063,7 +1064,7 @@ fn test_two_senders_one_recv_different_universes_multicast_ipv4(){
 #[test]
 #[ignore]
 fn test_two_senders_one_recv_same_universe_no_sync_multicast_ipv4(){
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(Ipv4Addr::new(0,0,0,0).into(), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
@@ -1071,9 +1072,9 @@ fn test_two_senders_one_recv_same_universe_no_sync_multicast_ipv4(){
 
     let snd_thread_1 = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
 
@@ -1082,9 +1083,9 @@ fn test_two_senders_one_recv_same_universe_no_sync_multicast_ipv4(){
 
     let snd_thread_2 = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), ACN_SDT_MULTICAST_PORT + 2);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
 
@@ -1102,8 +1103,8 @@ fn test_two_senders_one_recv_same_universe_no_sync_multicast_ipv4(){
 
     let res = vec![res1[0].clone(), res2[0].clone()];
 
-    assert_eq!(res[0].universe, universe);
-    assert_eq!(res[1].universe, universe);
+    assert_eq!(res[0].universe, universe.get());
+    assert_eq!(res[1].universe, universe.get());
 
     if res[0].values == TEST_DATA_SINGLE_UNIVERSE.to_vec() {
         assert_eq!(res[1].values, TEST_DATA_PARTIAL_CAPACITY_UNIVERSE.to_vec());
@@ -1120,23 +1121,23 @@ fn test_two_senders_one_recv_same_universe_custom_merge_fn_sync_multicast_ipv4()
 
     let snd_tx = tx.clone();
 
-    let universe = 1;
-    let sync_uni = 2;
+    let universe = Universe::try_from(1).unwrap();
+    let sync_uni = SyncAddress::try_from(2).unwrap();
 
     let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap(), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
-    dmx_recv.listen_universes(&[universe, sync_uni]).unwrap();
+    dmx_recv.listen_universes(&[universe, sync_uni.universe()]).unwrap();
 
     dmx_recv.set_merge_fn(htp_dmx_merge).unwrap();
 
     let snd_thread_1 = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[1].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
-        src.register_universe(sync_uni).unwrap();
+        src.register_universe(sync_uni.universe()).unwrap();
 
         src.send(&[universe], &TEST_DATA_SINGLE_UNIVERSE, Some(priority), None, Some(sync_uni)).unwrap();
         snd_tx.send(()).unwrap();
@@ -1144,12 +1145,12 @@ fn test_two_senders_one_recv_same_universe_custom_merge_fn_sync_multicast_ipv4()
 
     let snd_thread_2 = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[2].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 2);
-        let mut src = SacnSource::with_ip("Source 2", ip).unwrap();
+        let src = SacnSource::with_ip("Source 2", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
-        src.register_universe(sync_uni).unwrap();
+        src.register_universe(sync_uni.universe()).unwrap();
 
         src.send(&[universe], &TEST_DATA_PARTIAL_CAPACITY_UNIVERSE, Some(priority), None, Some(sync_uni)).unwrap();
         rx.recv().unwrap(); // Must only send once both threads have sent for this test to test what happens in that situation (where there will be a merge).
@@ -1163,18 +1164,18 @@ fn test_two_senders_one_recv_same_universe_custom_merge_fn_sync_multicast_ipv4()
 
     assert_eq!(res1.len(), 1);
     assert_eq!(res1[0].values, htp_dmx_merge(&DMXData {
-        universe: universe,
+        universe: universe.get(),
         values: TEST_DATA_SINGLE_UNIVERSE.to_vec(),
-        sync_uni: sync_uni,
+        sync_uni: sync_uni.get(),
         priority: 100,
         src_cid: None,
         preview: false,
         recv_timestamp: Instant::now()
     },
     &DMXData {
-        universe: universe,
+        universe: universe.get(),
         values: TEST_DATA_PARTIAL_CAPACITY_UNIVERSE.to_vec(),
-        sync_uni: sync_uni,
+        sync_uni: sync_uni.get(),
         priority: 100,
         src_cid: None,
         preview: false,
@@ -1217,11 +1218,11 @@ fn test_two_senders_two_recv_multicast_ipv4(){
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
             // https://www.programming-idioms.org/idiom/153/concatenate-string-with-integer/1975/rust (11/01/2020)
-            let mut src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
+            let src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
-            let universe: u16 = (i as u16) + BASE_UNIVERSE;
+            let universe = Universe::try_from((i as u16) + BASE_UNIVERSE).unwrap();
 
             src.register_universe(universe).unwrap(); // Senders all send on different universes.
 
@@ -1239,8 +1240,8 @@ fn test_two_senders_two_recv_multicast_ipv4(){
             let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[i].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
             // Receivers listen to all universes
-            for i in (BASE_UNIVERSE as u16) .. ((SND_THREADS as u16) + (BASE_UNIVERSE as u16)) {
-                dmx_recv.listen_universes(&[i]).unwrap();
+            for i in BASE_UNIVERSE .. ((SND_THREADS as u16) + BASE_UNIVERSE) {
+                dmx_recv.listen_universes(&[Universe::try_from(i).unwrap()]).unwrap();
             }
 
             let mut res: Vec<Result<Vec<DMXData>>> = Vec::new();
@@ -1328,11 +1329,11 @@ fn test_three_senders_two_recv_multicast_ipv4(){
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
             // https://www.programming-idioms.org/idiom/153/concatenate-string-with-integer/1975/rust (11/01/2020)
-            let mut src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
+            let src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
-            let universe: u16 = (i as u16) + BASE_UNIVERSE;
+            let universe = Universe::try_from((i as u16) + BASE_UNIVERSE).unwrap();
 
             src.register_universe(universe).unwrap(); // Senders all send on different universes.
 
@@ -1350,8 +1351,8 @@ fn test_three_senders_two_recv_multicast_ipv4(){
             let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[i].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
             // Receivers listen to all universes
-            for i in (BASE_UNIVERSE as u16) .. ((SND_THREADS as u16) + (BASE_UNIVERSE as u16)) {
-                dmx_recv.listen_universes(&[i]).unwrap();
+            for i in BASE_UNIVERSE .. ((SND_THREADS as u16) + BASE_UNIVERSE) {
+                dmx_recv.listen_universes(&[Universe::try_from(i).unwrap()]).unwrap();
             }
 
             let mut res: Vec<Result<Vec<DMXData>>> = Vec::new();
@@ -1439,11 +1440,11 @@ fn test_two_senders_three_recv_multicast_ipv4(){
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
             // https://www.programming-idioms.org/idiom/153/concatenate-string-with-integer/1975/rust (11/01/2020)
-            let mut src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
+            let src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
-            let universe: u16 = (i as u16) + BASE_UNIVERSE;
+            let universe = Universe::try_from((i as u16) + BASE_UNIVERSE).unwrap();
 
             src.register_universe(universe).unwrap(); // Senders all send on different universes.
 
@@ -1461,8 +1462,8 @@ fn test_two_senders_three_recv_multicast_ipv4(){
             let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[i].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
             // Receivers listen to all universes
-            for i in (BASE_UNIVERSE as u16) .. ((SND_THREADS as u16) + (BASE_UNIVERSE as u16)) {
-                dmx_recv.listen_universes(&[i]).unwrap();
+            for i in BASE_UNIVERSE .. ((SND_THREADS as u16) + BASE_UNIVERSE) {
+                dmx_recv.listen_universes(&[Universe::try_from(i).unwrap()]).unwrap();
             }
 
             let mut res: Vec<Result<Vec<DMXData>>> = Vec::new();
@@ -1550,11 +1551,11 @@ fn test_three_senders_three_recv_multicast_ipv4(){
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
             // https://www.programming-idioms.org/idiom/153/concatenate-string-with-integer/1975/rust (11/01/2020)
-            let mut src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
+            let src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
-            let universe: u16 = (i as u16) + BASE_UNIVERSE;
+            let universe = Universe::try_from((i as u16) + BASE_UNIVERSE).unwrap();
 
             src.register_universe(universe).unwrap(); // Senders all send on different universes.
 
@@ -1572,8 +1573,8 @@ fn test_three_senders_three_recv_multicast_ipv4(){
             let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[i].parse().unwrap()), ACN_SDT_MULTICAST_PORT), None).unwrap();
 
             // Receivers listen to all universes
-            for i in (BASE_UNIVERSE as u16) .. ((SND_THREADS as u16) + (BASE_UNIVERSE as u16)) {
-                dmx_recv.listen_universes(&[i]).unwrap();
+            for i in BASE_UNIVERSE .. ((SND_THREADS as u16) + BASE_UNIVERSE) {
+                dmx_recv.listen_universes(&[Universe::try_from(i).unwrap()]).unwrap();
             }
 
             let mut res: Vec<Result<Vec<DMXData>>> = Vec::new();
@@ -1644,11 +1645,11 @@ fn test_universe_discovery_one_universe_one_source_ipv4(){
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
 
-            let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+            let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
-            let mut universes: Vec<u16> = Vec::new();
+            let mut universes: Vec<Universe> = Vec::new();
             for j in 0 .. UNIVERSE_COUNT {
-                universes.push(((i + j) as u16) + BASE_UNIVERSE);
+                universes.push(Universe::try_from(((i + j) as u16) + BASE_UNIVERSE).unwrap());
             }
 
             src.register_universes(&universes).unwrap();
@@ -1729,9 +1730,9 @@ fn test_universe_discovery_interval_ipv4(){
 
             tx.send(()).unwrap(); // Force the send thread to wait before creating the sender, should sync once the receiver has been created.
 
-            let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+            let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
-            src.register_universes(&[BASE_UNIVERSE]).unwrap();
+            src.register_universes(&[Universe::try_from(BASE_UNIVERSE).unwrap()]).unwrap();
 
             tx.send(()).unwrap(); // Used to force the sender to wait till the receiver has received a universe discovery.
         }));
@@ -1747,7 +1748,7 @@ fn test_universe_discovery_interval_ipv4(){
     match dmx_recv.recv(None) {
         Err(e) => {
             match e {
-                SacnError::SourceDiscovered(_) => {
+                SacnError::SourceDiscovered(_, _) => {
                     // Measure the time between the first and second discovery packets, this removes the uncertainty in the time taken for the sender to start.
                     interval_start = Instant::now();
                 }
@@ -1764,7 +1765,7 @@ fn test_universe_discovery_interval_ipv4(){
     match dmx_recv.recv(None) {
         Err(e) => {
             match e {
-                SacnError::SourceDiscovered(_) => {
+                SacnError::SourceDiscovered(_, _) => {
                     let interval = interval_start.elapsed();
                     let interval_millis = interval.as_millis();
                     assert!(interval_millis > (INTERVAL_EXPECTED_MILLIS - INTERVAL_TOLERANCE_MILLIS), "Discovery interval is shorter than expected, {} ms", interval_millis);
@@ -1810,10 +1811,10 @@ fn test_universe_discovery_interval_with_updates_ipv4(){
 
             tx.send(()).unwrap(); // Force the send thread to wait before creating the sender, should sync once the receiver has been created.
 
-            let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+            let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
             for _ in 0 .. UNIVERSES_TO_REGISTER {
-                src.register_universes(&[BASE_UNIVERSE]).unwrap();
+                src.register_universes(&[Universe::try_from(BASE_UNIVERSE).unwrap()]).unwrap();
                 sleep(SENDER_REGISTER_DELAY);
             }
 
@@ -1831,7 +1832,7 @@ fn test_universe_discovery_interval_with_updates_ipv4(){
     match dmx_recv.recv(None) {
         Err(e) => {
             match e {
-                SacnError::SourceDiscovered(_) => {
+                SacnError::SourceDiscovered(_, _) => {
                     // Measure the time between the first and second discovery packets, this removes the uncertainty in the time taken for the sender to start.
                     interval_start = Instant::now();
                 }
@@ -1848,7 +1849,7 @@ fn test_universe_discovery_interval_with_updates_ipv4(){
     match dmx_recv.recv(None) {
         Err(e) => {
             match e {
-                SacnError::SourceDiscovered(_) => {
+                SacnError::SourceDiscovered(_, _) => {
                     let interval = interval_start.elapsed();
                     let interval_millis = interval.as_millis();
                     assert!(interval_millis > (INTERVAL_EXPECTED_MILLIS - INTERVAL_TOLERANCE_MILLIS), "Discovery interval is shorter than expected, {} ms", interval_millis);
@@ -1885,11 +1886,11 @@ fn test_universe_discovery_multiple_universe_one_source_ipv4(){
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
 
-            let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+            let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
-            let mut universes: Vec<u16> = Vec::new();
+            let mut universes: Vec<Universe> = Vec::new();
             for j in 0 .. UNIVERSE_COUNT {
-                universes.push(((i + j) as u16) + BASE_UNIVERSE);
+                universes.push(Universe::try_from(((i + j) as u16) + BASE_UNIVERSE).unwrap());
             }
 
             src.register_universes(&universes).unwrap();
@@ -1967,13 +1968,13 @@ fn test_universe_discovery_multiple_pages_one_source_ipv4(){
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
 
-            let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+            let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
             src.set_is_sending_discovery(false); // To stop universe discovery packets being sent until all universes are registered.
 
-            let mut universes: Vec<u16> = Vec::new();
+            let mut universes: Vec<Universe> = Vec::new();
             for j in 0 .. UNIVERSE_COUNT {
-                universes.push(((i + j) as u16) + BASE_UNIVERSE);
+                universes.push(Universe::try_from(((i + j) as u16) + BASE_UNIVERSE).unwrap());
             }
 
             src.register_universes(&universes).unwrap();
@@ -2055,7 +2056,7 @@ fn test_universe_discovery_no_universes_ipv4(){
 
             tx.send(()).unwrap(); // Force the send thread to wait before creating the sender, should sync once the receiver has been created.
 
-            let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+            let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
             // Explicitly make sure that the src is sending discovery packets (by default not).
             src.set_is_sending_discovery(true);
@@ -2074,7 +2075,7 @@ fn test_universe_discovery_no_universes_ipv4(){
     match dmx_recv.recv(None) {
         Err(e) => {
             match e {
-                SacnError::SourceDiscovered(src_name) => {
+                SacnError::SourceDiscovered(_, src_name) => {
                     assert_eq!(src_name, SOURCE_NAMES[0], "Name of source discovered doesn't match expected");
                     let sources = dmx_recv.get_discovered_sources();
                     assert_eq!(sources.len(), 1, "Number of sources discovered doesn't match expected (1)");
@@ -2122,11 +2123,11 @@ fn test_receiver_sources_exceeded_3() {
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
             // https://www.programming-idioms.org/idiom/153/concatenate-string-with-integer/1975/rust (11/01/2020)
-            let mut src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
+            let src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
-            let universe: u16 = (i as u16) + BASE_UNIVERSE;
+            let universe = Universe::try_from((i as u16) + BASE_UNIVERSE).unwrap();
 
             src.register_universe(universe).unwrap(); // Senders all send on different universes.
 
@@ -2141,8 +2142,8 @@ fn test_receiver_sources_exceeded_3() {
     let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), SRC_LIMIT).unwrap();
 
     // Receivers listen to all universes
-    for i in (BASE_UNIVERSE as u16) .. ((SND_THREADS as u16) + (BASE_UNIVERSE as u16)) {
-        dmx_recv.listen_universes(&[i]).unwrap();
+    for i in BASE_UNIVERSE .. ((SND_THREADS as u16) + BASE_UNIVERSE) {
+        dmx_recv.listen_universes(&[Universe::try_from(i).unwrap()]).unwrap();
     }
 
     for _ in 0 .. SND_THREADS {
@@ -2157,8 +2158,8 @@ fn test_receiver_sources_exceeded_3() {
     match dmx_recv.recv(TIMEOUT) {
         Err(e) => {
             match e {
-                SacnError::SourcesExceededError(_) => {
-                    assert!(true, "Expected error returned");
+                SacnError::SourcesExceededError(..) => {
+                    // Expected error returned
                 }
                 _ => {
                     assert!(false, "Unexpected error type returned");
@@ -2204,11 +2205,11 @@ fn test_receiver_source_limit_2() {
 
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
-            let mut src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
+            let src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
-            let universe: u16 = (i as u16) + BASE_UNIVERSE;
+            let universe = Universe::try_from((i as u16) + BASE_UNIVERSE).unwrap();
 
             src.register_universe(universe).unwrap(); // Senders all send on different universes.
 
@@ -2224,8 +2225,8 @@ fn test_receiver_source_limit_2() {
     let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), SRC_LIMIT).unwrap();
 
     // Receivers listen to all universes
-    for i in (BASE_UNIVERSE as u16) .. ((SND_THREADS as u16) + (BASE_UNIVERSE as u16)) {
-        dmx_recv.listen_universes(&[i]).unwrap();
+    for i in BASE_UNIVERSE .. ((SND_THREADS as u16) + BASE_UNIVERSE) {
+        dmx_recv.listen_universes(&[Universe::try_from(i).unwrap()]).unwrap();
     }
 
     for _i in 0 .. SND_THREADS {
@@ -2264,11 +2265,11 @@ fn test_receiver_source_limit_2_termination_check() {
 
         snd_threads.push(thread::spawn(move || {
             let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (i as u16));
-            let mut src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
+            let src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
-            let universe: u16 = (i as u16) + BASE_UNIVERSE;
+            let universe = Universe::try_from((i as u16) + BASE_UNIVERSE).unwrap();
 
             src.register_universe(universe).unwrap(); // Senders all send on different universes.
 
@@ -2288,8 +2289,8 @@ fn test_receiver_source_limit_2_termination_check() {
     let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT), SRC_LIMIT).unwrap();
 
     // Receivers listen to all universes
-    for i in (BASE_UNIVERSE as u16) .. ((SND_THREADS as u16) + (BASE_UNIVERSE as u16)) {
-        dmx_recv.listen_universes(&[i]).unwrap();
+    for i in BASE_UNIVERSE .. ((SND_THREADS as u16) + BASE_UNIVERSE) {
+        dmx_recv.listen_universes(&[Universe::try_from(i).unwrap()]).unwrap();
     }
 
     for (_, snd_rx) in &sender_channels {
@@ -2310,15 +2311,15 @@ fn test_receiver_source_limit_2_termination_check() {
     // Create a new source which sends to the receiver.
     let data = [1, 2, 3];
     let new_src_thread = thread::spawn(move || {
-        let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + (3 as u16));
-        let mut src = SacnSource::with_ip(&format!("Source {}", 3), ip).unwrap();
+        let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1 + 3_u16);
+        let src = SacnSource::with_ip(&format!("Source {}", 3), ip).unwrap();
 
-        src.register_universe(BASE_UNIVERSE).unwrap();
+        src.register_universe(Universe::try_from(BASE_UNIVERSE).unwrap()).unwrap();
 
         // New source now sends twice which the receiver should receive.
-        src.send(&[BASE_UNIVERSE], &data, None, None, None).unwrap();
+        src.send(&[Universe::try_from(BASE_UNIVERSE).unwrap()], &data, None, None, None).unwrap();
 
-        src.send(&[BASE_UNIVERSE], &data, None, None, None).unwrap();
+        src.send(&[Universe::try_from(BASE_UNIVERSE).unwrap()], &data, None, None, None).unwrap();
     });
 
     // Asserts that the recv attempts are successful (no source exceeded).
@@ -2340,7 +2341,7 @@ fn test_receiver_source_limit_2_termination_check() {
 #[ignore]
 fn test_preview_data_2_receiver_1_sender() {
     const RCV_THREADS: usize = 2;
-    const UNIVERSE: u16 = 1;
+    const UNIVERSE: Universe = Universe::new(1).unwrap();
     const NORMAL_DATA: [u8; 4] = [0, 1, 2, 3];
     const PREVIEW_DATA: [u8; 4] = [9, 10, 11, 12];
     const TIMEOUT: Option<Duration> = Some(Duration::from_secs(3));
@@ -2374,7 +2375,7 @@ fn test_preview_data_2_receiver_1_sender() {
 
             let data = &result[0];
 
-            assert_eq!(data.universe, UNIVERSE);
+            assert_eq!(data.universe, UNIVERSE.get());
             assert_eq!(data.values, NORMAL_DATA);
 
             assert_eq!(data.preview, false);
@@ -2386,7 +2387,7 @@ fn test_preview_data_2_receiver_1_sender() {
 
                 let preview_data = &preview_result[0];
 
-                assert_eq!(preview_data.universe, UNIVERSE);
+                assert_eq!(preview_data.universe, UNIVERSE.get());
                 assert_eq!(preview_data.values, PREVIEW_DATA);
                 assert_eq!(preview_data.preview, true);
             } else {
@@ -2425,7 +2426,7 @@ fn test_preview_data_2_receiver_1_sender() {
     }
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut src = SacnSource::with_ip("Source", ip).unwrap();
+    let src = SacnSource::with_ip("Source", ip).unwrap();
     src.register_universe(UNIVERSE).unwrap();
 
     // Send data without the preview flag.
@@ -2458,12 +2459,12 @@ fn test_source_1_universe_timeout(){
 
     let thread_tx = tx.clone();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     let snd_thread = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
-        let priority = 100;
+        let src = SacnSource::with_ip("Source", ip).unwrap();
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
 
@@ -2490,7 +2491,7 @@ fn test_source_1_universe_timeout(){
     let received_data: Vec<DMXData> = dmx_recv.recv(None).unwrap();
 
     assert_eq!(received_data.len(), 1); // Check only 1 universe received as expected.
-    assert_eq!(received_data[0].universe, universe); // Check that the universe received is as expected.
+    assert_eq!(received_data[0].universe, universe.get()); // Check that the universe received is as expected.
     assert_eq!(received_data[0].values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 
     let start_time: Instant = Instant::now();
@@ -2501,8 +2502,8 @@ fn test_source_1_universe_timeout(){
                     if start_time.elapsed() < acceptable_lower_bound{
                         assert!(false, "Timeout came quicker than expected");
                     }
-                    assert_eq!(timedout_uni, universe, "Timed out universe doesn't match expected");
-                    assert!(true, "Universe timed out as expected");
+                    assert_eq!(timedout_uni, universe.get(), "Timed out universe doesn't match expected");
+                    // Universe timed out as expected
                 }
                 SacnError::Io(s) => {
                     match s.kind() {
@@ -2549,13 +2550,13 @@ fn test_source_2_universe_1_timeout(){
 
     let thread_tx = tx.clone();
 
-    let universe_no_timeout = 1;
-    let universe_timeout = 2;
+    let universe_no_timeout = Universe::try_from(1).unwrap();
+    let universe_timeout = Universe::try_from(2).unwrap();
 
     let snd_thread = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
-        let priority = 100;
+        let src = SacnSource::with_ip("Source", ip).unwrap();
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universes(&[universe_no_timeout, universe_timeout]).unwrap();
 
@@ -2603,24 +2604,24 @@ fn test_source_2_universe_1_timeout(){
     let received_data: Vec<DMXData> = dmx_recv.recv(None).unwrap();
     assert_eq!(received_data.len(), 1); // Check only 1 universe of data received as expected.
 
-    if received_data[0].universe == universe_no_timeout {
+    if received_data[0].universe == universe_no_timeout.get() {
         assert_eq!(received_data[0].values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 
         // Get the next data packet and check it is the other packet as expected.
         let received_data: Vec<DMXData> = dmx_recv.recv(None).unwrap();
         assert_eq!(received_data.len(), 1); // Check only 1 universe received as expected.
-        if received_data[0].universe == universe_timeout {
+        if received_data[0].universe == universe_timeout.get() {
             assert_eq!(received_data[0].values, TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
         } else {
             assert!(false, "Data packet from unexpected universe received");
         }
-    } else if received_data[0].universe == universe_timeout {
+    } else if received_data[0].universe == universe_timeout.get() {
         assert_eq!(received_data[0].values, TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
 
         // Get the next data packet and check it is the other packet as expected.
         let received_data: Vec<DMXData> = dmx_recv.recv(None).unwrap();
         assert_eq!(received_data.len(), 1); // Check only 1 universe received as expected.
-        if received_data[0].universe == universe_no_timeout {
+        if received_data[0].universe == universe_no_timeout.get() {
             assert_eq!(received_data[0].values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Received payload values don't match sent!");
         } else {
             assert!(false, "Data packet from unexpected universe received");
@@ -2639,8 +2640,8 @@ fn test_source_2_universe_1_timeout(){
                         if start_time.elapsed() < acceptable_lower_bound{
                             assert!(false, "Timeout came quicker than expected");
                         }
-                        assert_eq!(universe, universe_timeout, "Unexpected universe timed out");
-                        assert!(true, "Universe timed out as expected");
+                        assert_eq!(universe, universe_timeout.get(), "Unexpected universe timed out");
+                        // Universe timed out as expected
 
                         // Know that the timeout universe timed out as expected so check that the other universe hasn't timed out.
                         // Makes use of a timeout of 0 which should check the source timeouts without actually receiving any data as it times out instantly.
@@ -2685,7 +2686,7 @@ fn test_source_2_universe_1_timeout(){
             }
             Ok(p) => { // Check that only data from the non-timed out universe is received.
                 assert_eq!(p.len(), 1, "Data packet universe count doesn't match expected");
-                assert_eq!(p[0].universe, universe_no_timeout, "Data packet universe doesn't match expected");
+                assert_eq!(p[0].universe, universe_no_timeout.get(), "Data packet universe doesn't match expected");
                 assert_eq!(p[0].values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Data packet values don't match expected");
             }
         }
@@ -2708,13 +2709,13 @@ fn test_send_recv_wrong_multicast_universe(){
 
     let thread_tx = tx.clone();
 
-    let multicast_universe = 1;
-    let actual_universe = 2;
+    let multicast_universe = Universe::try_from(1).unwrap();
+    let actual_universe = Universe::try_from(2).unwrap();
 
     let snd_thread = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap(), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
-        let priority = 100;
+        let src = SacnSource::with_ip("Source", ip).unwrap();
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universes(&[multicast_universe, actual_universe]).unwrap();
 
@@ -2739,7 +2740,7 @@ fn test_send_recv_wrong_multicast_universe(){
     assert_eq!(received_data.len(), 1, "Data packet universe count doesn't match expected");
 
     // Particularly important that the universe is the actual universe of the data rather than the universe which corresponds to the multicast address.
-    assert_eq!(received_data[0].universe, actual_universe, "Packet universe doesn't match expected");
+    assert_eq!(received_data[0].universe, actual_universe.get(), "Packet universe doesn't match expected");
     assert_eq!(received_data[0].values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Data packet values don't match expected");
 
     snd_thread.join().unwrap();
@@ -2760,11 +2761,11 @@ fn test_send_recv_multiple_sync_universes(){
 
     let thread_tx = tx.clone();
 
-    let universes = [1, 2, 3];
+    let universes = [Universe::try_from(1).unwrap(), Universe::try_from(2).unwrap(), Universe::try_from(3).unwrap()];
 
     let snd_thread = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap(), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
         src.register_universes(&universes).unwrap();
 
@@ -2773,12 +2774,12 @@ fn test_send_recv_multiple_sync_universes(){
 
         // Send on all 3 universes, the first universe waits for a sync packet on the second, the second on the third and the third
         // universe waits for a sync packet on its own universe.
-        src.send(&[universes[0]], &TEST_DATA_SINGLE_UNIVERSE, None, None, Some(universes[1])).unwrap();
-        src.send(&[universes[1]], &TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE, None, None, Some(universes[2])).unwrap();
-        src.send(&[universes[2]], &TEST_DATA_PARTIAL_CAPACITY_UNIVERSE, None, None, Some(universes[2])).unwrap();
+        src.send(&[universes[0]], &TEST_DATA_SINGLE_UNIVERSE, None, None, Some(SyncAddress::try_from(universes[1]).unwrap())).unwrap();
+        src.send(&[universes[1]], &TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE, None, None, Some(SyncAddress::try_from(universes[2]).unwrap())).unwrap();
+        src.send(&[universes[2]], &TEST_DATA_PARTIAL_CAPACITY_UNIVERSE, None, None, Some(SyncAddress::try_from(universes[2]).unwrap())).unwrap();
 
-        src.send_sync_packet(universes[1], None).unwrap(); // Should trigger the first universe to be received.
-        src.send_sync_packet(universes[2], None).unwrap(); // Should trigger the second and third universe to be received together.
+        src.send_sync_packet(SyncAddress::try_from(universes[1]).unwrap(), None).unwrap(); // Should trigger the first universe to be received.
+        src.send_sync_packet(SyncAddress::try_from(universes[2]).unwrap(), None).unwrap(); // Should trigger the second and third universe to be received together.
     });
 
     let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(TEST_NETWORK_INTERFACE_IPV4[1].parse().unwrap(), ACN_SDT_MULTICAST_PORT), None).unwrap();
@@ -2792,21 +2793,21 @@ fn test_send_recv_multiple_sync_universes(){
     // First set of data should be the first universe.
     let received_data: Vec<DMXData> = dmx_recv.recv(TIMEOUT).unwrap();
     assert_eq!(received_data.len(), 1, "First set of data universe count doesn't match expected");
-    assert_eq!(received_data[0].universe, universes[0], "Packet universe doesn't match expected");
+    assert_eq!(received_data[0].universe, universes[0].get(), "Packet universe doesn't match expected");
     assert_eq!(received_data[0].values, TEST_DATA_SINGLE_UNIVERSE.to_vec(), "Data packet values don't match expected");
 
     // Second set of data should be the second and third universe.
     let received_data2: Vec<DMXData> = dmx_recv.recv(TIMEOUT).unwrap();
     assert_eq!(received_data2.len(), 2, "Second set of data universe count doesn't match expected");
-    if received_data2[0].universe == universes[1] { // Allow the data to be in any order as no ordering enforced within a set of data.
+    if received_data2[0].universe == universes[1].get() { // Allow the data to be in any order as no ordering enforced within a set of data.
         assert_eq!(received_data2[0].values, TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE.to_vec(), "Second set of data part 1 packet values don't match expected");
 
-        assert_eq!(received_data2[1].universe, universes[2], "Second set of data universes don't match expected");
+        assert_eq!(received_data2[1].universe, universes[2].get(), "Second set of data universes don't match expected");
         assert_eq!(received_data2[1].values, TEST_DATA_PARTIAL_CAPACITY_UNIVERSE.to_vec(), "Second set of data part 2 packet values don't match expected");
-    } else if received_data2[0].universe == universes[2] {
+    } else if received_data2[0].universe == universes[2].get() {
         assert_eq!(received_data2[0].values, TEST_DATA_PARTIAL_CAPACITY_UNIVERSE.to_vec(), "Second set of data part 1 packet values don't match expected");
 
-        assert_eq!(received_data2[1].universe, universes[1], "Second set of data universes don't match expected");
+        assert_eq!(received_data2[1].universe, universes[1].get(), "Second set of data universes don't match expected");
         assert_eq!(received_data2[1].values, TEST_DATA_SINGLE_ALTERNATIVE_STARTCODE_UNIVERSE.to_vec(), "Second set of data part 2 packet values don't match expected");
     } else {
         assert!(false, "Unexpected universe of data received");
@@ -2837,14 +2838,14 @@ fn test_send_sync_timeout(){
 
     let thread_tx = tx.clone();
 
-    let data_universe = 1;
-    let sync_universe = 2;
+    let data_universe = Universe::try_from(1).unwrap();
+    let sync_universe = SyncAddress::try_from(2).unwrap();
 
     let snd_thread = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap(), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        src.register_universes(&[data_universe, sync_universe]).unwrap();
+        src.register_universes(&[data_universe, sync_universe.universe()]).unwrap();
 
         // Sender waits till the receiver says it is ready.
         thread_tx.send(()).unwrap();
@@ -2860,7 +2861,7 @@ fn test_send_sync_timeout(){
     });
 
     let mut dmx_recv = SacnReceiver::with_ip(SocketAddr::new(TEST_NETWORK_INTERFACE_IPV4[1].parse().unwrap(), ACN_SDT_MULTICAST_PORT), None).unwrap();
-    dmx_recv.listen_universes(&[data_universe, sync_universe]).unwrap();
+    dmx_recv.listen_universes(&[data_universe, sync_universe.universe()]).unwrap();
 
     // Receiver created successfully so allow the sender to progress.
     rx.recv().unwrap();
@@ -2914,9 +2915,9 @@ fn test_ansi_e131_appendix_b_runthrough_ipv4() {
 
     let thread_tx = tx.clone();
 
-    let data_universes = [1, 2];
-    let sync_universe = 7962;
-    let priority = 100;
+    let data_universes = [Universe::try_from(1).unwrap(), Universe::try_from(2).unwrap()];
+    let sync_universe = SyncAddress::try_from(7962).unwrap();
+    let priority = Priority::try_from(100).unwrap();
     let source_name = "Source_A";
     let data = [0x00, 0xe, 0x0, 0xc, 0x1, 0x7, 0x1, 0x4, 0x8, 0x0, 0xd, 0xa, 0x7, 0xa];
     let data2 = [0x00, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf, 0xa];
@@ -2924,10 +2925,10 @@ fn test_ansi_e131_appendix_b_runthrough_ipv4() {
 
     let snd_thread = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap(), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_cid_ip(source_name, src_cid, ip).unwrap();
+        let src = SacnSource::with_cid_ip(source_name, src_cid, ip).unwrap();
 
         src.register_universes(&data_universes).unwrap();
-        src.register_universe(sync_universe).unwrap();
+        src.register_universe(sync_universe.universe()).unwrap();
 
         // Sender waits till the receiver says it is ready.
         thread_tx.send(()).unwrap();
@@ -2962,15 +2963,15 @@ fn test_ansi_e131_appendix_b_runthrough_ipv4() {
         match dmx_recv.recv(None) {
             Ok(p) => {
                 assert_eq!(p.len(), DATA_PACKETS_PER_SYNC_PACKET);
-                if p[0].universe == data_universes[0] {
+                if p[0].universe == data_universes[0].get() {
                     assert_eq!(p[0].values, data, "Unexpected data within first data packet of a set of synchronised packets");
 
-                    assert_eq!(p[1].universe, data_universes[1], "Unrecognised universe as second data packet in set of synchronised packets");
+                    assert_eq!(p[1].universe, data_universes[1].get(), "Unrecognised universe as second data packet in set of synchronised packets");
                     assert_eq!(p[1].values, data2, "Unexpected data within second data packet of a set of synchronised packets");
-                } else if p[0].universe == data_universes[1] {
+                } else if p[0].universe == data_universes[1].get() {
                     assert_eq!(p[0].values, data2, "Unexpected data within first data packet of a set of synchronised packets");
 
-                    assert_eq!(p[1].universe, data_universes[0], "Unrecognised universe as second data packet in set of synchronised packets");
+                    assert_eq!(p[1].universe, data_universes[0].get(), "Unrecognised universe as second data packet in set of synchronised packets");
                     assert_eq!(p[1].values, data, "Unexpected data within second data packet of a set of synchronised packets");
                 } else {
                     assert!(false, "Unrecognised universe within data packet");
@@ -2987,12 +2988,12 @@ fn test_ansi_e131_appendix_b_runthrough_ipv4() {
     // Synchronisation is now over so should receive 2 packets individually.
     let rcv_data = dmx_recv.recv(None).unwrap();
     assert_eq!(rcv_data.len(), 1);
-    assert_eq!(rcv_data[0].universe, data_universes[0]);
+    assert_eq!(rcv_data[0].universe, data_universes[0].get());
     assert_eq!(rcv_data[0].values, data);
 
     let rcv_data2 = dmx_recv.recv(None).unwrap();
     assert_eq!(rcv_data2.len(), 1);
-    assert_eq!(rcv_data2[0].universe, data_universes[1]);
+    assert_eq!(rcv_data2[0].universe, data_universes[1].get());
     assert_eq!(rcv_data2[0].values, data2);
 
     // "If, at any time, Receiver B receives more than one E1.31 Data Packet with the same Synchronization
@@ -3037,10 +3038,10 @@ fn test_discover_recv_sync_runthrough_ipv4() {
     const INTERVAL: Duration = Duration::from_millis(100);
 
     // The universes used for data.
-    const DATA_UNIVERSES: [u16; 2] = [1, 2];
+    const DATA_UNIVERSES: [Universe; 2] = [Universe::new(1).unwrap(), Universe::new(2).unwrap()];
 
     // The universe used for synchronisation packets.
-    const SYNC_UNIVERSE: u16 = 4;
+    const SYNC_UNIVERSE: SyncAddress = SyncAddress::new(4).unwrap();
 
     // The source name
     const SOURCE_NAME: &str = "Test Source";
@@ -3054,10 +3055,10 @@ fn test_discover_recv_sync_runthrough_ipv4() {
 
     let snd_thread = thread::spawn(move || {
         let ip: SocketAddr = SocketAddr::new(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap(), ACN_SDT_MULTICAST_PORT + 1);
-        let mut src = SacnSource::with_cid_ip(SOURCE_NAME, src_cid, ip).unwrap();
+        let src = SacnSource::with_cid_ip(SOURCE_NAME, src_cid, ip).unwrap();
 
         src.register_universes(&DATA_UNIVERSES).unwrap();
-        src.register_universe(SYNC_UNIVERSE).unwrap();
+        src.register_universe(SYNC_UNIVERSE.universe()).unwrap();
 
         for _ in 0 .. SYNC_PACKET_COUNT {
             // Sender sends data packets to the 2 data universes using the same synchronisation address.
@@ -3082,10 +3083,10 @@ fn test_discover_recv_sync_runthrough_ipv4() {
 
     dmx_recv.set_announce_source_discovery(true);
 
-    let universes: Vec<u16> = match dmx_recv.recv(None) {
+    let universes: Vec<Universe> = match dmx_recv.recv(None) {
         Err(e) => {
             match e {
-                SacnError::SourceDiscovered(_name) => {
+                SacnError::SourceDiscovered(_, _name) => {
                     let discovered_sources = dmx_recv.get_discovered_sources();
                     assert_eq!(discovered_sources.len(), 1);
 
@@ -3095,7 +3096,15 @@ fn test_discover_recv_sync_runthrough_ipv4() {
                     // Do want to be notified about stream termination in this case.
                     dmx_recv.set_announce_stream_termination(true);
 
-                    discovered_sources[0].get_all_universes()
+                    discovered_sources[0]
+
+                        .get_all_universes()
+
+                        .into_iter()
+
+                        .map(|u| Universe::try_from(u).unwrap())
+
+                        .collect()
                 }
                 _ => {
                     // A real-user would want to look at using more detailed error handling as appropriate to their use case but for this test panic
@@ -3127,15 +3136,15 @@ fn test_discover_recv_sync_runthrough_ipv4() {
             }
             Ok(rcv_data) => {
                 assert_eq!(rcv_data.len(), DATA_PACKETS_PER_SYNC_PACKET);
-                if rcv_data[0].universe == DATA_UNIVERSES[0] {
+                if rcv_data[0].universe == DATA_UNIVERSES[0].get() {
                     assert_eq!(rcv_data[0].values, DATA, "Unexpected data within first data packet of a set of synchronised packets");
 
-                    assert_eq!(rcv_data[1].universe, DATA_UNIVERSES[1], "Unrecognised universe as second data packet in set of synchronised packets");
+                    assert_eq!(rcv_data[1].universe, DATA_UNIVERSES[1].get(), "Unrecognised universe as second data packet in set of synchronised packets");
                     assert_eq!(rcv_data[1].values, DATA2, "Unexpected data within second data packet of a set of synchronised packets");
-                } else if rcv_data[0].universe == DATA_UNIVERSES[1] {
+                } else if rcv_data[0].universe == DATA_UNIVERSES[1].get() {
                     assert_eq!(rcv_data[0].values, DATA2, "Unexpected data within first data packet of a set of synchronised packets");
 
-                    assert_eq!(rcv_data[1].universe, DATA_UNIVERSES[0], "Unrecognised universe as second data packet in set of synchronised packets");
+                    assert_eq!(rcv_data[1].universe, DATA_UNIVERSES[0].get(), "Unrecognised universe as second data packet in set of synchronised packets");
                     assert_eq!(rcv_data[1].values, DATA, "Unexpected data within second data packet of a set of synchronised packets");
                 } else {
                     assert!(false, "Unrecognised universe within data packet");
@@ -3291,9 +3300,9 @@ fn generate_sync_packet_raw(cid: [u8; 16], sync_addr: u16, seq_num: u8) -> Vec<u
 fn test_data_packet_transmit_format() {
     const CID: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
     const OPTIONS: u8 = 0; // Checks that the options field is transmitted as 0's.
-    const PRIORITY: u8 = 150;
+    const PRIORITY: Priority = Priority::new(150).unwrap();
 
-    let universe: u16 = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     let source_name = "SourceName".to_string() +
                         "\0\0\0\0\0\0\0\0\0\0" +
@@ -3308,10 +3317,10 @@ fn test_data_packet_transmit_format() {
     dmx_data.push(0); // Start code
     dmx_data.extend(iter::repeat(100).take(255));
 
-    let packet = generate_data_packet_raw(CID, universe, source_name.clone(), PRIORITY, sequence, OPTIONS, dmx_data.clone());
+    let packet = generate_data_packet_raw(CID, universe.get(), source_name.clone(), PRIORITY.get(), sequence, OPTIONS, dmx_data.clone());
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), ACN_SDT_MULTICAST_PORT + 1);
-    let mut source = SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ip).unwrap();
+    let source = SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ip).unwrap();
 
     source.set_preview_mode(false).unwrap();
     source.set_multicast_loop_v4(true).unwrap();
@@ -3343,7 +3352,7 @@ fn test_terminate_packet_transmit_format() {
     let cid = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), ACN_SDT_MULTICAST_PORT + 1);
-    let mut source = SacnSource::with_cid_ip(&"Source", Uuid::from_bytes(cid), ip).unwrap();
+    let source = SacnSource::with_cid_ip("Source", Uuid::from_bytes(cid), ip).unwrap();
 
     source.set_multicast_loop_v4(true).unwrap();
 
@@ -3359,11 +3368,12 @@ fn test_terminate_packet_transmit_format() {
 
     let mut recv_buf = [0; 1024];
 
-    let start_code: u8 = 0;
+    let universe = Universe::try_from(1).unwrap();
+    let start_code = StartCode::NULL;
 
-    source.register_universes(&[1]).unwrap();
+    source.register_universes(&[universe]).unwrap();
 
-    source.terminate_stream(1, start_code).unwrap();
+    source.terminate_stream(universe, start_code).unwrap();
     for _ in 0..2 {
         recv_socket.read(&mut recv_buf).unwrap();
         assert_eq!(
@@ -3386,7 +3396,7 @@ fn test_terminate_packet_transmit_format() {
 fn test_sync_packet_transmit_format() {
     const CID: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
 
-    const SYNC_ADDR: u16 = 1;
+    const SYNC_ADDR: SyncAddress = SyncAddress::new(1).unwrap();
 
     // Sync packet length 49 bytes as per ANSI E1.31-2018 Section 4.2 Table 4-2.
     const E131_SYNC_PACKET_LENGTH: usize = 49;
@@ -3394,10 +3404,10 @@ fn test_sync_packet_transmit_format() {
     // Sequence number of initial synchronisation packet is expected to be 0.
     const SEQUENCE_NUM: u8 = 0;
 
-    let sync_packet = generate_sync_packet_raw(CID, SYNC_ADDR, SEQUENCE_NUM);
+    let sync_packet = generate_sync_packet_raw(CID, SYNC_ADDR.get(), SEQUENCE_NUM);
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), ACN_SDT_MULTICAST_PORT + 1);
-    let mut source = SacnSource::with_cid_ip(&"Source", Uuid::from_bytes(CID), ip).unwrap();
+    let source = SacnSource::with_cid_ip("Source", Uuid::from_bytes(CID), ip).unwrap();
 
     source.set_multicast_loop_v4(true).unwrap();
 
@@ -3415,7 +3425,7 @@ fn test_sync_packet_transmit_format() {
     let mut recv_buf = [0; E131_SYNC_PACKET_LENGTH];
 
     // Send the synchronisation packet.
-    source.register_universes(&[SYNC_ADDR]).unwrap();
+    source.register_universes(&[SYNC_ADDR.universe()]).unwrap();
     source.send_sync_packet(SYNC_ADDR, None).unwrap();
 
     // Receive the packet and compare its content to the expected.
@@ -3509,7 +3519,7 @@ fn test_discovery_packet_transmit_format() {
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), ACN_SDT_MULTICAST_PORT + 1);
 
     // Creates the source.
-    let mut source = SacnSource::with_cid_ip(&str::from_utf8(&SOURCE_NAME).unwrap(), Uuid::from_bytes(CID), ip).unwrap();
+    let source = SacnSource::with_cid_ip(str::from_utf8(&SOURCE_NAME).unwrap(), Uuid::from_bytes(CID), ip).unwrap();
 
     source.set_multicast_loop_v4(true).unwrap();
 
@@ -3531,9 +3541,9 @@ fn test_discovery_packet_transmit_format() {
 
     // Register the universes, note be = BigEndian which is used as network byte order is BigEndian.
     source.register_universes(&[
-        u16::from_be_bytes(UNIVERSES[0..2].try_into().unwrap()),
-        u16::from_be_bytes(UNIVERSES[2..4].try_into().unwrap()),
-        u16::from_be_bytes(UNIVERSES[4..6].try_into().unwrap())
+        Universe::try_from(u16::from_be_bytes(UNIVERSES[0..2].try_into().unwrap())).unwrap(),
+        Universe::try_from(u16::from_be_bytes(UNIVERSES[2..4].try_into().unwrap())).unwrap(),
+        Universe::try_from(u16::from_be_bytes(UNIVERSES[4..6].try_into().unwrap())).unwrap()
         ]).unwrap();
 
     // The source is expected to eventually send a universe discovery packet.
@@ -3554,7 +3564,7 @@ fn test_discovery_packet_transmit_format() {
 fn test_sync_packet_transmit_seq_numbers() {
     const CID: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
 
-    const UNIVERSE: u16 = 1;
+    const UNIVERSE: Universe = Universe::new(1).unwrap();
 
     // Sync packet length 49 bytes as per ANSI E1.31-2018 Section 4.2 Table 4-2.
     const E131_SYNC_PACKET_LENGTH: usize = 49;
@@ -3604,7 +3614,7 @@ fn test_sync_packet_transmit_seq_numbers() {
     sync_packet.push(0);
 
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), ACN_SDT_MULTICAST_PORT + 1);
-    let mut source = SacnSource::with_cid_ip(&"Source", Uuid::from_bytes(CID), ip).unwrap();
+    let source = SacnSource::with_cid_ip("Source", Uuid::from_bytes(CID), ip).unwrap();
 
     source.set_multicast_loop_v4(true).unwrap();
 
@@ -3623,7 +3633,7 @@ fn test_sync_packet_transmit_seq_numbers() {
 
     // Send the synchronisation packet.
     source.register_universes(&[UNIVERSE]).unwrap();
-    source.send_sync_packet(UNIVERSE, None).unwrap();
+    source.send_sync_packet(SyncAddress::try_from(UNIVERSE).unwrap(), None).unwrap();
 
     // Receive the packet and compare its content to the expected.
     recv_socket.read(&mut recv_buf).unwrap();
@@ -3643,7 +3653,7 @@ fn test_track_data_packet_seq_numbers() {
     /* Packet parameters */
     const CID: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
     const OPTIONS: u8 = 0; // Checks that the options field is transmitted as 0's.
-    const PRIORITY: u8 = 150;
+    const PRIORITY: Priority = Priority::new(150).unwrap();
     let source_name = "SourceName".to_string() +
                         "\0\0\0\0\0\0\0\0\0\0" +
                         "\0\0\0\0\0\0\0\0\0\0" +
@@ -3664,11 +3674,11 @@ fn test_track_data_packet_seq_numbers() {
     const DATA_PACKETS_TO_SEND: usize = 300;
 
     // The universes that the data packets are sent on.
-    const UNIVERSES: [u16; 5] = [1, 3, 5, 7, 9];
+    const UNIVERSES: [Universe; 5] = [Universe::new(1).unwrap(), Universe::new(3).unwrap(), Universe::new(5).unwrap(), Universe::new(7).unwrap(), Universe::new(9).unwrap()];
 
     // Create a source.
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), ACN_SDT_MULTICAST_PORT + 1);
-    let mut source = SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ip).unwrap();
+    let source = SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ip).unwrap();
     source.set_multicast_loop_v4(true).unwrap();
     source.register_universes(&UNIVERSES).unwrap();
 
@@ -3682,7 +3692,7 @@ fn test_track_data_packet_seq_numbers() {
 
     // Join the multicast groups for each of the universes.
     for u in UNIVERSES.iter() {
-        let address = universe_to_ipv4_multicast_addr(*u).unwrap().as_socket_ipv4();
+        let address = universe_to_ipv4_multicast_addr(u.get()).unwrap().as_socket_ipv4();
 
         recv_socket
             .join_multicast_v4(&address.unwrap().ip(), &Ipv4Addr::new(0, 0, 0, 0))
@@ -3692,7 +3702,7 @@ fn test_track_data_packet_seq_numbers() {
     for s in START_SEQ_NUM .. START_SEQ_NUM + DATA_PACKETS_TO_SEND {
         let expected_seq_num: u8 = (s % 256).try_into().unwrap();
         for u in UNIVERSES.iter() {
-            let expected_packet = generate_data_packet_raw(CID, *u, source_name.clone(), PRIORITY, expected_seq_num, OPTIONS, dmx_data.clone());
+            let expected_packet = generate_data_packet_raw(CID, u.get(), source_name.clone(), PRIORITY.get(), expected_seq_num, OPTIONS, dmx_data.clone());
             source.send(&[*u], &dmx_data, Some(PRIORITY), None, None).unwrap();
 
             let mut recv_buf = [0; 1024];
@@ -3731,15 +3741,15 @@ fn test_track_sync_packet_seq_numbers() {
     const SYNC_PACKETS_TO_SEND: usize = 300;
 
     // The universes that the sync packets are sent on.
-    const SYNC_ADDRESSES: [u16; 5] = [1, 3, 5, 7, 9];
+    const SYNC_ADDRESSES: [SyncAddress; 5] = [SyncAddress::new(1).unwrap(), SyncAddress::new(3).unwrap(), SyncAddress::new(5).unwrap(), SyncAddress::new(7).unwrap(), SyncAddress::new(9).unwrap()];
 
     // Create a source.
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), ACN_SDT_MULTICAST_PORT + 1);
-    let mut source = SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ip).unwrap();
+    let source = SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ip).unwrap();
     source.set_multicast_loop_v4(true).unwrap();
 
     // Register the synchronisation addresses.
-    source.register_universes(&SYNC_ADDRESSES).unwrap();
+    source.register_universes(&SYNC_ADDRESSES.map(SyncAddress::universe)).unwrap();
 
     // Don't want universe discovery packets to be sent which might interfer with checking sync packets.
     source.set_is_sending_discovery(false);
@@ -3751,7 +3761,7 @@ fn test_track_sync_packet_seq_numbers() {
 
     // Join the multicast groups for each of the synchronisation addresses.
     for u in SYNC_ADDRESSES.iter() {
-        let address = universe_to_ipv4_multicast_addr(*u).unwrap().as_socket_ipv4();
+        let address = universe_to_ipv4_multicast_addr(u.get()).unwrap().as_socket_ipv4();
 
         recv_socket
             .join_multicast_v4(&address.unwrap().ip(), &Ipv4Addr::new(0, 0, 0, 0))
@@ -3761,7 +3771,7 @@ fn test_track_sync_packet_seq_numbers() {
     for s in START_SEQ_NUM .. START_SEQ_NUM + SYNC_PACKETS_TO_SEND {
         let expected_seq_num: u8 = (s % 256).try_into().unwrap();
         for a in SYNC_ADDRESSES.iter() {
-            let expected_packet = generate_sync_packet_raw(CID, *a, expected_seq_num);
+            let expected_packet = generate_sync_packet_raw(CID, a.get(), expected_seq_num);
             source.send_sync_packet(*a, None).unwrap();
 
             let mut recv_buf = [0; 1024];
@@ -3806,15 +3816,15 @@ fn test_sync_packet_multicast_address() {
 
     // The universes that the sync packets are sent on.
     // Chosen to contain adjacent universes and a separate universe to check that this doesn't effect the address sending.
-    const SYNC_ADDRESSES: [u16; 3] = [1, 2, 63999];
+    const SYNC_ADDRESSES: [SyncAddress; 3] = [SyncAddress::new(1).unwrap(), SyncAddress::new(2).unwrap(), SyncAddress::new(63999).unwrap()];
 
     // Create a source.
     let ip: SocketAddr = SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT + 1);
-    let mut source = SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ip).unwrap();
+    let source = SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ip).unwrap();
     source.set_multicast_loop_v4(true).unwrap();
 
     // Register the synchronisation addresses.
-    source.register_universes(&SYNC_ADDRESSES).unwrap();
+    source.register_universes(&SYNC_ADDRESSES.map(SyncAddress::universe)).unwrap();
 
     // Don't want universe discovery packets to be sent which might interfer with checking sync packets.
     source.set_is_sending_discovery(false);
@@ -3827,7 +3837,7 @@ fn test_sync_packet_multicast_address() {
         recv_sockets.push(Socket::new(Domain::IPV4, Type::DGRAM, None).unwrap());
 
         // Join only the multicast address corresponding to the synchronisation address.
-        let multicast_addr = universe_to_ipv4_multicast_addr(*sync_addr).unwrap();
+        let multicast_addr = universe_to_ipv4_multicast_addr(sync_addr.get()).unwrap();
         recv_sockets[i].bind(&multicast_addr).unwrap();
         recv_sockets[i]
             .join_multicast_v4(&multicast_addr.as_socket_ipv4().unwrap().ip(), &TEST_NETWORK_INTERFACE_IPV4[i].parse().unwrap())
@@ -3841,7 +3851,7 @@ fn test_sync_packet_multicast_address() {
 
         let mut i = 0;
         for sync_addr in SYNC_ADDRESSES.iter() {
-            let expected_packet = generate_sync_packet_raw(CID, *sync_addr, expected_seq_num);
+            let expected_packet = generate_sync_packet_raw(CID, sync_addr.get(), expected_seq_num);
             source.send_sync_packet(*sync_addr, None).unwrap();
 
             let mut recv_buf = [0; 1024];
@@ -3862,27 +3872,27 @@ fn test_sync_packet_multicast_address() {
 #[test]
 #[ignore]
 fn test_register_terminate_universe() {
-    let mut src = SacnSource::with_cid_ip("Test name", Uuid::new_v4(), SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT)).unwrap();
+    let src = SacnSource::with_cid_ip("Test name", Uuid::new_v4(), SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT)).unwrap();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
     src.register_universe(universe).unwrap();
 
     assert_eq!(src.universes().unwrap(), vec!(1), "Universe not registered correctly");
 
-    src.terminate_stream(universe, 0).unwrap();
+    src.terminate_stream(universe, StartCode::from(0)).unwrap();
 
-    assert_eq!(src.universes().unwrap(), Vec::new(), "Universe not registered correctly");
+    assert!(src.universes().unwrap().is_empty(), "Universe not registered correctly");
 }
 
 #[test]
 #[ignore]
 fn test_terminate_universe_no_register() {
-    let mut src = SacnSource::with_cid_ip("Test name", Uuid::new_v4(), SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT)).unwrap();
+    let src = SacnSource::with_cid_ip("Test name", Uuid::new_v4(), SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT)).unwrap();
 
-    let universe = 1;
+    let universe = Universe::try_from(1).unwrap();
 
-    match src.terminate_stream(universe, 0) {
+    match src.terminate_stream(universe, StartCode::from(0)) {
         Err(e) => {
             match e {
                 SacnError::UniverseNotRegistered(_) => {
@@ -3902,9 +3912,9 @@ fn test_terminate_universe_no_register() {
 #[test]
 #[ignore]
 fn test_send_empty() {
-    const UNIVERSE: u16 = 1;
+    const UNIVERSE: Universe = Universe::new(1).unwrap();
 
-    let mut src = SacnSource::with_cid_ip("Test name", Uuid::new_v4(), SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT)).unwrap();
+    let src = SacnSource::with_cid_ip("Test name", Uuid::new_v4(), SocketAddr::new(IpAddr::V4(TEST_NETWORK_INTERFACE_IPV4[0].parse().unwrap()), ACN_SDT_MULTICAST_PORT)).unwrap();
 
     src.register_universe(UNIVERSE).unwrap();
 
@@ -40,6 +40,7 @@ mod sacn_ipv6_multicast_test {
     };
     use sacn::receive::{DMXData, SacnReceiver};
     use sacn::source::SacnSource;
+    use sacn::types::{Priority, StartCode, SyncAddress, Universe};
 
     /// UUID library used to handle the UUID's used in the CID fields.
     use uuid::Uuid;
@@ -61,7 +62,7 @@ mod sacn_ipv6_multicast_test {
 
         let thread_tx = tx.clone();
 
-        let universe = 1;
+        let universe = Universe::try_from(1).unwrap();
 
         let rcv_thread = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -89,9 +90,9 @@ mod sacn_ipv6_multicast_test {
             ACN_SDT_MULTICAST_PORT + 1,
         );
 
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
 
@@ -119,7 +120,7 @@ mod sacn_ipv6_multicast_test {
 
         let received_universe: DMXData = received_data[0].clone();
 
-        assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+        assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
         assert_eq!(
             received_universe.values,
@@ -136,7 +137,7 @@ mod sacn_ipv6_multicast_test {
 
         let thread_tx = tx.clone();
 
-        let universe = 1;
+        let universe = Universe::try_from(1).unwrap();
 
         let rcv_thread = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -164,9 +165,9 @@ mod sacn_ipv6_multicast_test {
             ACN_SDT_MULTICAST_PORT + 1,
         );
 
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
 
@@ -194,7 +195,7 @@ mod sacn_ipv6_multicast_test {
 
         let received_universe: DMXData = received_data[0].clone();
 
-        assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+        assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
         assert_eq!(
             received_universe.values,
@@ -211,7 +212,7 @@ mod sacn_ipv6_multicast_test {
 
         let thread_tx = tx.clone();
 
-        const UNIVERSES: [u16; 2] = [2, 3];
+        const UNIVERSES: [Universe; 2] = [Universe::new(2).unwrap(), Universe::new(3).unwrap()];
 
         let rcv_thread = thread::spawn(move || {
             let addr = SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT);
@@ -230,9 +231,9 @@ mod sacn_ipv6_multicast_test {
             IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
             ACN_SDT_MULTICAST_PORT + 1,
         );
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universes(&UNIVERSES).unwrap();
 
@@ -241,11 +242,12 @@ mod sacn_ipv6_multicast_test {
             &TEST_DATA_MULTIPLE_ALTERNATIVE_STARTCODE_UNIVERSE,
             Some(priority),
             None,
-            Some(UNIVERSES[0]),
+            Some(SyncAddress::try_from(UNIVERSES[0]).unwrap()),
         )
         .unwrap();
         sleep(Duration::from_millis(500)); // Small delay to allow the data packets to get through as per NSI-E1.31-2018 Appendix B.1 recommendation.
-        src.send_sync_packet(UNIVERSES[0], None).unwrap();
+        src.send_sync_packet(SyncAddress::try_from(UNIVERSES[0]).unwrap(), None)
+            .unwrap();
 
         let sync_pkt_res: Result<Vec<DMXData>> = rx.recv().unwrap();
 
@@ -293,7 +295,7 @@ mod sacn_ipv6_multicast_test {
 
         let thread_tx = tx.clone();
 
-        const UNIVERSES: [u16; 2] = [2, 3];
+        const UNIVERSES: [Universe; 2] = [Universe::new(2).unwrap(), Universe::new(3).unwrap()];
 
         let rcv_thread = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -318,9 +320,9 @@ mod sacn_ipv6_multicast_test {
             IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
             ACN_SDT_MULTICAST_PORT + 1,
         );
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universes(&UNIVERSES).unwrap();
 
@@ -329,11 +331,12 @@ mod sacn_ipv6_multicast_test {
             &TEST_DATA_FULL_CAPACITY_MULTIPLE_UNIVERSE,
             Some(priority),
             None,
-            Some(UNIVERSES[0]),
+            Some(SyncAddress::try_from(UNIVERSES[0]).unwrap()),
         )
         .unwrap();
         sleep(Duration::from_millis(500)); // Small delay to allow the data packets to get through as per NSI-E1.31-2018 Appendix B.1 recommendation.
-        src.send_sync_packet(UNIVERSES[0], None).unwrap();
+        src.send_sync_packet(SyncAddress::try_from(UNIVERSES[0]).unwrap(), None)
+            .unwrap();
 
         let sync_pkt_res: Result<Vec<DMXData>> = rx.recv().unwrap();
 
@@ -379,7 +382,7 @@ mod sacn_ipv6_multicast_test {
 
         let thread_tx = tx.clone();
 
-        let universe = 1;
+        let universe = Universe::try_from(1).unwrap();
 
         let rcv_thread = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -407,9 +410,9 @@ mod sacn_ipv6_multicast_test {
             ACN_SDT_MULTICAST_PORT + 1,
         );
 
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
 
@@ -438,7 +441,7 @@ mod sacn_ipv6_multicast_test {
 
         let received_universe: DMXData = received_data[0].clone();
 
-        assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+        assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
         assert_eq!(
             received_universe.values,
@@ -457,7 +460,7 @@ mod sacn_ipv6_multicast_test {
 
         let thread_tx = tx.clone();
 
-        const UNIVERSES: [u16; 2] = [2, 3];
+        const UNIVERSES: [Universe; 2] = [Universe::new(2).unwrap(), Universe::new(3).unwrap()];
 
         let rcv_thread = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -482,9 +485,9 @@ mod sacn_ipv6_multicast_test {
             IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
             ACN_SDT_MULTICAST_PORT + 1,
         );
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universes(&UNIVERSES).unwrap();
 
@@ -493,11 +496,12 @@ mod sacn_ipv6_multicast_test {
             &TEST_DATA_MULTIPLE_UNIVERSE,
             Some(priority),
             None,
-            Some(UNIVERSES[0]),
+            Some(SyncAddress::try_from(UNIVERSES[0]).unwrap()),
         )
         .unwrap();
         sleep(Duration::from_millis(500)); // Small delay to allow the data packets to get through as per NSI-E1.31-2018 Appendix B.1 recommendation. See other warnings about the possibility of theses tests failing if the network isn't perfect.
-        src.send_sync_packet(UNIVERSES[0], None).unwrap();
+        src.send_sync_packet(SyncAddress::try_from(UNIVERSES[0]).unwrap(), None)
+            .unwrap();
 
         let sync_pkt_res: Result<Vec<DMXData>> = rx.recv().unwrap();
 
@@ -544,10 +548,10 @@ mod sacn_ipv6_multicast_test {
         let thread1_tx = tx.clone();
         let thread2_tx = tx.clone();
 
-        let universe1 = 1;
-        let universe2 = 2;
+        let universe1 = Universe::try_from(1).unwrap();
+        let universe2 = Universe::try_from(2).unwrap();
 
-        let sync_uni = 3;
+        let sync_uni = SyncAddress::try_from(3).unwrap();
 
         let rcv_thread1 = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -560,7 +564,7 @@ mod sacn_ipv6_multicast_test {
             .unwrap();
 
             dmx_recv.listen_universes(&[universe1]).unwrap();
-            dmx_recv.listen_universes(&[sync_uni]).unwrap();
+            dmx_recv.listen_universes(&[sync_uni.universe()]).unwrap();
 
             thread1_tx.send(Ok(Vec::new())).unwrap();
 
@@ -578,7 +582,7 @@ mod sacn_ipv6_multicast_test {
             .unwrap();
 
             dmx_recv.listen_universes(&[universe2]).unwrap();
-            dmx_recv.listen_universes(&[sync_uni]).unwrap();
+            dmx_recv.listen_universes(&[sync_uni.universe()]).unwrap();
 
             thread2_tx.send(Ok(Vec::new())).unwrap();
 
@@ -593,13 +597,13 @@ mod sacn_ipv6_multicast_test {
             ACN_SDT_MULTICAST_PORT + 1,
         );
 
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe1).unwrap();
         src.register_universe(universe2).unwrap();
-        src.register_universe(sync_uni).unwrap();
+        src.register_universe(sync_uni.universe()).unwrap();
 
         src.send(
             &[universe1],
@@ -650,8 +654,8 @@ mod sacn_ipv6_multicast_test {
         let mut results = vec![received_result1[0].clone(), received_result2[0].clone()];
         results.sort_unstable(); // Ordering of received data is undefined, to make it easier to check sort first.
 
-        assert_eq!(results[0].universe, universe1); // Check that the universe 1 received is as expected.
-        assert_eq!(results[1].universe, universe2); // Check that the universe 2 received is as expected.
+        assert_eq!(results[0].universe, universe1.get()); // Check that the universe 1 received is as expected.
+        assert_eq!(results[1].universe, universe2.get()); // Check that the universe 2 received is as expected.
 
         assert_eq!(
             results[0].values,
@@ -707,11 +711,11 @@ mod sacn_ipv6_multicast_test {
                     ACN_SDT_MULTICAST_PORT + 1 + (i as u16),
                 );
                 // https://www.programming-idioms.org/idiom/153/concatenate-string-with-integer/1975/rust (11/01/2020)
-                let mut src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
+                let src = SacnSource::with_ip(&format!("Source {}", i), ip).unwrap();
 
-                let priority = 100;
+                let priority = Priority::try_from(100).unwrap();
 
-                let universe: u16 = (i as u16) + BASE_UNIVERSE;
+                let universe = Universe::try_from((i as u16) + BASE_UNIVERSE).unwrap();
 
                 src.register_universe(universe).unwrap(); // Senders all send on different universes.
 
@@ -737,8 +741,10 @@ mod sacn_ipv6_multicast_test {
                 .unwrap();
 
                 // Receivers listen to all universes
-                for i in (BASE_UNIVERSE as u16)..((SND_THREADS as u16) + (BASE_UNIVERSE as u16)) {
-                    dmx_recv.listen_universes(&[i]).unwrap();
+                for i in BASE_UNIVERSE..((SND_THREADS as u16) + BASE_UNIVERSE) {
+                    dmx_recv
+                        .listen_universes(&[Universe::try_from(i).unwrap()])
+                        .unwrap();
                 }
 
                 let mut res: Vec<Result<Vec<DMXData>>> = Vec::new();
@@ -818,11 +824,11 @@ mod sacn_ipv6_multicast_test {
                     ACN_SDT_MULTICAST_PORT + 1 + (i as u16),
                 );
 
-                let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+                let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
-                let mut universes: Vec<u16> = Vec::new();
+                let mut universes: Vec<Universe> = Vec::new();
                 for j in 0..UNIVERSE_COUNT {
-                    universes.push(((i + j) as u16) + BASE_UNIVERSE);
+                    universes.push(Universe::try_from(((i + j) as u16) + BASE_UNIVERSE).unwrap());
                 }
 
                 src.register_universes(&universes).unwrap();
@@ -909,11 +915,11 @@ mod sacn_ipv6_multicast_test {
                     ACN_SDT_MULTICAST_PORT + 1 + (i as u16),
                 );
 
-                let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+                let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
-                let mut universes: Vec<u16> = Vec::new();
+                let mut universes: Vec<Universe> = Vec::new();
                 for j in 0..UNIVERSE_COUNT {
-                    universes.push(((i + j) as u16) + BASE_UNIVERSE);
+                    universes.push(Universe::try_from(((i + j) as u16) + BASE_UNIVERSE).unwrap());
                 }
 
                 src.register_universes(&universes).unwrap();
@@ -1001,13 +1007,13 @@ mod sacn_ipv6_multicast_test {
                     ACN_SDT_MULTICAST_PORT + 1 + (i as u16),
                 );
 
-                let mut src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
+                let src = SacnSource::with_ip(SOURCE_NAMES[i], ip).unwrap();
 
                 src.set_is_sending_discovery(false); // To stop universe discovery packets being sent until all universes are registered.
 
-                let mut universes: Vec<u16> = Vec::new();
+                let mut universes: Vec<Universe> = Vec::new();
                 for j in 0..UNIVERSE_COUNT {
-                    universes.push(((i + j) as u16) + BASE_UNIVERSE);
+                    universes.push(Universe::try_from(((i + j) as u16) + BASE_UNIVERSE).unwrap());
                 }
 
                 src.register_universes(&universes).unwrap();
@@ -1084,7 +1090,10 @@ mod sacn_ipv6_multicast_test {
 
         let thread_tx = tx.clone();
 
-        let universes = [1, 2];
+        let universes = [
+            Universe::try_from(1).unwrap(),
+            Universe::try_from(2).unwrap(),
+        ];
 
         let rcv_thread = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -1110,7 +1119,7 @@ mod sacn_ipv6_multicast_test {
             IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
             ACN_SDT_MULTICAST_PORT + 1,
         );
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
         src.register_universes(&universes).unwrap();
 
@@ -1140,8 +1149,8 @@ mod sacn_ipv6_multicast_test {
         assert_eq!(received_data.len(), 1); // Check only 1 universe received from each individual recv() as expected, if this wasn't the case it would
         assert_eq!(received_data_2.len(), 1); // indicate that the data has been synchronised incorrectly or that less data than expected was received.
 
-        assert_eq!(received_data[0].universe, universes[0]); // Check that the universe received is as expected.
-        assert_eq!(received_data_2[0].universe, universes[1]);
+        assert_eq!(received_data[0].universe, universes[0].get()); // Check that the universe received is as expected.
+        assert_eq!(received_data_2[0].universe, universes[1].get());
 
         assert_eq!(
             received_data[0].values,
@@ -1156,7 +1165,7 @@ mod sacn_ipv6_multicast_test {
     #[test]
     #[ignore]
     fn test_two_senders_one_recv_same_universe_no_sync_multicast_ipv6() {
-        let universe = 1;
+        let universe = Universe::try_from(1).unwrap();
 
         let mut dmx_recv = SacnReceiver::with_ip(
             SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), ACN_SDT_MULTICAST_PORT),
@@ -1171,9 +1180,9 @@ mod sacn_ipv6_multicast_test {
                 IpAddr::V6(Ipv6Addr::UNSPECIFIED),
                 ACN_SDT_MULTICAST_PORT + 1,
             );
-            let mut src = SacnSource::with_ip("Source", ip).unwrap();
+            let src = SacnSource::with_ip("Source", ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
             src.register_universe(universe).unwrap();
 
@@ -1193,9 +1202,9 @@ mod sacn_ipv6_multicast_test {
                 IpAddr::V6(Ipv6Addr::UNSPECIFIED),
                 ACN_SDT_MULTICAST_PORT + 2,
             );
-            let mut src = SacnSource::with_ip("Source", ip).unwrap();
+            let src = SacnSource::with_ip("Source", ip).unwrap();
 
-            let priority = 100;
+            let priority = Priority::try_from(100).unwrap();
 
             src.register_universe(universe).unwrap();
 
@@ -1221,8 +1230,8 @@ mod sacn_ipv6_multicast_test {
 
         let res = vec![res1[0].clone(), res2[0].clone()];
 
-        assert_eq!(res[0].universe, universe);
-        assert_eq!(res[1].universe, universe);
+        assert_eq!(res[0].universe, universe.get());
+        assert_eq!(res[1].universe, universe.get());
 
         if res[0].values == TEST_DATA_SINGLE_UNIVERSE.to_vec() {
             assert_eq!(res[1].values, TEST_DATA_PARTIAL_CAPACITY_UNIVERSE.to_vec());
@@ -1254,9 +1263,12 @@ mod sacn_ipv6_multicast_test {
 
         let thread_tx = tx.clone();
 
-        let data_universes = [1, 2];
-        let sync_universe = 7962;
-        let priority = 100;
+        let data_universes = [
+            Universe::try_from(1).unwrap(),
+            Universe::try_from(2).unwrap(),
+        ];
+        let sync_universe = SyncAddress::try_from(7962).unwrap();
+        let priority = Priority::try_from(100).unwrap();
         let source_name = "Source_A";
         let data = [
             0x00, 0xe, 0x0, 0xc, 0x1, 0x7, 0x1, 0x4, 0x8, 0x0, 0xd, 0xa, 0x7, 0xa,
@@ -1278,10 +1290,10 @@ mod sacn_ipv6_multicast_test {
                 TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap(),
                 ACN_SDT_MULTICAST_PORT + 1,
             );
-            let mut src = SacnSource::with_cid_ip(source_name, src_cid, ip).unwrap();
+            let src = SacnSource::with_cid_ip(source_name, src_cid, ip).unwrap();
 
             src.register_universes(&data_universes).unwrap();
-            src.register_universe(sync_universe).unwrap();
+            src.register_universe(sync_universe.universe()).unwrap();
 
             // Sender waits till the receiver says it is ready.
             thread_tx.send(()).unwrap();
@@ -1339,28 +1351,30 @@ mod sacn_ipv6_multicast_test {
             match dmx_recv.recv(None) {
                 Ok(p) => {
                     assert_eq!(p.len(), DATA_PACKETS_PER_SYNC_PACKET);
-                    if p[0].universe == data_universes[0] {
+                    if p[0].universe == data_universes[0].get() {
                         assert_eq!(
                             p[0].values, data,
                             "Unexpected data within first data packet of a set of synchronised packets"
                         );
 
                         assert_eq!(
-                            p[1].universe, data_universes[1],
+                            p[1].universe,
+                            data_universes[1].get(),
                             "Unrecognised universe as second data packet in set of synchronised packets"
                         );
                         assert_eq!(
                             p[1].values, data2,
                             "Unexpected data within second data packet of a set of synchronised packets"
                         );
-                    } else if p[0].universe == data_universes[1] {
+                    } else if p[0].universe == data_universes[1].get() {
                         assert_eq!(
                             p[0].values, data2,
                             "Unexpected data within first data packet of a set of synchronised packets"
                         );
 
                         assert_eq!(
-                            p[1].universe, data_universes[0],
+                            p[1].universe,
+                            data_universes[0].get(),
                             "Unrecognised universe as second data packet in set of synchronised packets"
                         );
                         assert_eq!(
@@ -1382,12 +1396,12 @@ mod sacn_ipv6_multicast_test {
         // Synchronisation is now over so should receive 2 packets individually.
         let rcv_data = dmx_recv.recv(None).unwrap();
         assert_eq!(rcv_data.len(), 1);
-        assert_eq!(rcv_data[0].universe, data_universes[0]);
+        assert_eq!(rcv_data[0].universe, data_universes[0].get());
         assert_eq!(rcv_data[0].values, data);
 
         let rcv_data2 = dmx_recv.recv(None).unwrap();
         assert_eq!(rcv_data2.len(), 1);
-        assert_eq!(rcv_data2[0].universe, data_universes[1]);
+        assert_eq!(rcv_data2[0].universe, data_universes[1].get());
         assert_eq!(rcv_data2[0].values, data2);
 
         // "If, at any time, Receiver B receives more than one E1.31 Data Packet with the same Synchronization
@@ -1434,10 +1448,11 @@ mod sacn_ipv6_multicast_test {
         const INTERVAL: Duration = Duration::from_millis(100);
 
         // The universes used for data.
-        const DATA_UNIVERSES: [u16; 2] = [1, 2];
+        const DATA_UNIVERSES: [Universe; 2] =
+            [Universe::new(1).unwrap(), Universe::new(2).unwrap()];
 
         // The universe used for synchronisation packets.
-        const SYNC_UNIVERSE: u16 = 4;
+        const SYNC_UNIVERSE: SyncAddress = SyncAddress::new(4).unwrap();
 
         // The source name
         const SOURCE_NAME: &str = "Test Source";
@@ -1465,10 +1480,10 @@ mod sacn_ipv6_multicast_test {
                 TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap(),
                 ACN_SDT_MULTICAST_PORT + 1,
             );
-            let mut src = SacnSource::with_cid_ip(SOURCE_NAME, src_cid, ip).unwrap();
+            let src = SacnSource::with_cid_ip(SOURCE_NAME, src_cid, ip).unwrap();
 
             src.register_universes(&DATA_UNIVERSES).unwrap();
-            src.register_universe(SYNC_UNIVERSE).unwrap();
+            src.register_universe(SYNC_UNIVERSE.universe()).unwrap();
 
             for _ in 0..SYNC_PACKET_COUNT {
                 // Sender sends data packets to the 2 data universes using the same synchronisation address.
@@ -1508,10 +1523,10 @@ mod sacn_ipv6_multicast_test {
 
         dmx_recv.set_announce_source_discovery(true);
 
-        let universes: Vec<u16> = match dmx_recv.recv(None) {
+        let universes: Vec<Universe> = match dmx_recv.recv(None) {
             Err(e) => {
                 match e {
-                    SacnError::SourceDiscovered(_name) => {
+                    SacnError::SourceDiscovered(_, _name) => {
                         let discovered_sources = dmx_recv.get_discovered_sources();
                         assert_eq!(discovered_sources.len(), 1);
 
@@ -1521,7 +1536,11 @@ mod sacn_ipv6_multicast_test {
                         // Do want to be notified about stream termination in this case.
                         dmx_recv.set_announce_stream_termination(true);
 
-                        discovered_sources[0].get_all_universes()
+                        discovered_sources[0]
+                            .get_all_universes()
+                            .into_iter()
+                            .map(|u| Universe::try_from(u).unwrap())
+                            .collect()
                     }
                     _ => {
                         // A real-user would want to look at using more detailed error handling as appropriate to their use case but for this test panic
@@ -1553,28 +1572,30 @@ mod sacn_ipv6_multicast_test {
                 }
                 Ok(rcv_data) => {
                     assert_eq!(rcv_data.len(), DATA_PACKETS_PER_SYNC_PACKET);
-                    if rcv_data[0].universe == DATA_UNIVERSES[0] {
+                    if rcv_data[0].universe == DATA_UNIVERSES[0].get() {
                         assert_eq!(
                             rcv_data[0].values, DATA,
                             "Unexpected data within first data packet of a set of synchronised packets"
                         );
 
                         assert_eq!(
-                            rcv_data[1].universe, DATA_UNIVERSES[1],
+                            rcv_data[1].universe,
+                            DATA_UNIVERSES[1].get(),
                             "Unrecognised universe as second data packet in set of synchronised packets"
                         );
                         assert_eq!(
                             rcv_data[1].values, DATA2,
                             "Unexpected data within second data packet of a set of synchronised packets"
                         );
-                    } else if rcv_data[0].universe == DATA_UNIVERSES[1] {
+                    } else if rcv_data[0].universe == DATA_UNIVERSES[1].get() {
                         assert_eq!(
                             rcv_data[0].values, DATA2,
                             "Unexpected data within first data packet of a set of synchronised packets"
                         );
 
                         assert_eq!(
-                            rcv_data[1].universe, DATA_UNIVERSES[0],
+                            rcv_data[1].universe,
+                            DATA_UNIVERSES[0].get(),
                             "Unrecognised universe as second data packet in set of synchronised packets"
                         );
                         assert_eq!(
@@ -1601,9 +1622,9 @@ mod sacn_ipv6_multicast_test {
     fn test_ip_equivalence() {
         /* Packet parameters, not directly the focus of the test */
         const CID: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
-        const PRIORITY: u8 = 150;
+        const PRIORITY: Priority = Priority::new(150).unwrap();
 
-        let universe: u16 = 1;
+        let universe = Universe::try_from(1).unwrap();
 
         let source_name = "SourceName".to_string()
             + "\0\0\0\0\0\0\0\0\0\0"
@@ -1623,15 +1644,15 @@ mod sacn_ipv6_multicast_test {
             IpAddr::V4(Ipv4Addr::UNSPECIFIED),
             ACN_SDT_MULTICAST_PORT + 1,
         );
-        let mut ipv4_source =
+        let ipv4_source =
             SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ipv4).unwrap();
         ipv4_source.set_preview_mode(false).unwrap();
         ipv4_source.set_multicast_loop_v4(true).unwrap();
-        ipv4_source.register_universes(&[universe as u16]).unwrap();
+        ipv4_source.register_universes(&[universe]).unwrap();
 
         // Create and setup the ipv4 receiver socket.
         let mut ipv4_recv = Socket::new(Domain::IPV4, Type::DGRAM, None).unwrap();
-        let ipv4_multicast_addr = universe_to_ipv4_multicast_addr(universe).unwrap();
+        let ipv4_multicast_addr = universe_to_ipv4_multicast_addr(universe.get()).unwrap();
         let ipv4_discovery_multicast_addr =
             universe_to_ipv4_multicast_addr(E131_DISCOVERY_UNIVERSE).unwrap();
 
@@ -1664,14 +1685,14 @@ mod sacn_ipv6_multicast_test {
             IpAddr::V6(Ipv6Addr::UNSPECIFIED),
             ACN_SDT_MULTICAST_PORT + 1,
         );
-        let mut ipv6_source =
+        let ipv6_source =
             SacnSource::with_cid_ip(&source_name.clone(), Uuid::from_bytes(CID), ipv6).unwrap();
         ipv6_source.set_preview_mode(false).unwrap();
         ipv6_source.register_universes(&[universe]).unwrap();
 
         // Create and setup the ipv6 receiver socket.
         let mut ipv6_recv = Socket::new(Domain::IPV6, Type::DGRAM, None).unwrap();
-        let ipv6_multicast_addr = universe_to_ipv6_multicast_addr(universe).unwrap();
+        let ipv6_multicast_addr = universe_to_ipv6_multicast_addr(universe.get()).unwrap();
         let ipv6_discovery_multicast_addr =
             universe_to_ipv6_multicast_addr(E131_DISCOVERY_UNIVERSE).unwrap();
 
@@ -1718,12 +1739,16 @@ mod sacn_ipv6_multicast_test {
 
         // Send and receive the sync packet over IPv4.
         ipv4_recv_buf = [0; 1024];
-        ipv4_source.send_sync_packet(universe, None).unwrap();
+        ipv4_source
+            .send_sync_packet(SyncAddress::try_from(universe).unwrap(), None)
+            .unwrap();
         let ipv4_len = ipv4_recv.read(&mut ipv4_recv_buf).unwrap();
 
         // Send and receive the sync packet over IPv6.
         ipv6_recv_buf = [0; 1024];
-        ipv6_source.send_sync_packet(universe, None).unwrap();
+        ipv6_source
+            .send_sync_packet(SyncAddress::try_from(universe).unwrap(), None)
+            .unwrap();
         let ipv6_len = ipv6_recv.read(&mut ipv6_recv_buf).unwrap();
 
         // Check the sync packets match.
@@ -1749,8 +1774,12 @@ mod sacn_ipv6_multicast_test {
         );
 
         // Terminate sending data on the universe.
-        ipv4_source.terminate_stream(universe, 0).unwrap();
-        ipv6_source.terminate_stream(universe, 0).unwrap();
+        ipv4_source
+            .terminate_stream(universe, StartCode::from(0))
+            .unwrap();
+        ipv6_source
+            .terminate_stream(universe, StartCode::from(0))
+            .unwrap();
 
         // Termination packets are sent multiple times so check that they are all received.
         for _ in 0..E131_TERMINATE_STREAM_PACKET_COUNT {
@@ -1783,6 +1812,7 @@ mod sacn_ipv6_unicast_test {
     use sacn::packet::{ACN_SDT_MULTICAST_PORT, UNIVERSE_CHANNEL_CAPACITY};
     use sacn::receive::{DMXData, SacnReceiver};
     use sacn::source::SacnSource;
+    use sacn::types::{Priority, SyncAddress, Universe};
     use std::net::{IpAddr, SocketAddr};
 
     use std::time::Duration;
@@ -1800,7 +1830,7 @@ mod sacn_ipv6_unicast_test {
 
         let thread_tx = tx.clone();
 
-        let universe = 1;
+        let universe = Universe::try_from(1).unwrap();
 
         let rcv_thread = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -1825,9 +1855,9 @@ mod sacn_ipv6_unicast_test {
             IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[1].parse().unwrap()),
             ACN_SDT_MULTICAST_PORT + 1,
         );
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universe(universe).unwrap();
 
@@ -1861,7 +1891,7 @@ mod sacn_ipv6_unicast_test {
 
         let received_universe: DMXData = received_data[0].clone();
 
-        assert_eq!(received_universe.universe, universe); // Check that the universe received is as expected.
+        assert_eq!(received_universe.universe, universe.get()); // Check that the universe received is as expected.
 
         assert_eq!(
             received_universe.values,
@@ -1880,7 +1910,7 @@ mod sacn_ipv6_unicast_test {
 
         let thread_tx = tx.clone();
 
-        const UNIVERSES: [u16; 2] = [2, 3];
+        const UNIVERSES: [Universe; 2] = [Universe::new(2).unwrap(), Universe::new(3).unwrap()];
 
         let rcv_thread = thread::spawn(move || {
             let mut dmx_recv = SacnReceiver::with_ip(
@@ -1905,37 +1935,30 @@ mod sacn_ipv6_unicast_test {
             IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
             ACN_SDT_MULTICAST_PORT + 1,
         );
-        let mut src = SacnSource::with_ip("Source", ip).unwrap();
+        let src = SacnSource::with_ip("Source", ip).unwrap();
 
-        let priority = 100;
+        let priority = Priority::try_from(100).unwrap();
 
         src.register_universes(&UNIVERSES).unwrap();
 
-        let _ = src
-            .send(
-                &UNIVERSES,
-                &TEST_DATA_MULTIPLE_UNIVERSE,
-                Some(priority),
-                Some(
-                    SocketAddr::new(
-                        IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
-                        ACN_SDT_MULTICAST_PORT,
-                    )
-                    .into(),
-                ),
-                Some(UNIVERSES[0]),
-            )
-            .unwrap();
+        src.send(
+            &UNIVERSES,
+            &TEST_DATA_MULTIPLE_UNIVERSE,
+            Some(priority),
+            Some(SocketAddr::new(
+                IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
+                ACN_SDT_MULTICAST_PORT,
+            )),
+            Some(SyncAddress::try_from(UNIVERSES[0]).unwrap()),
+        )
+        .unwrap();
         sleep(Duration::from_millis(500)); // Small delay to allow the data packets to get through as per NSI-E1.31-2018 Appendix B.1 recommendation.
         src.send_sync_packet(
-            UNIVERSES[0],
-            Some(
-                SocketAddr::new(
-                    IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
-                    ACN_SDT_MULTICAST_PORT,
-                )
-                .into(),
-            ),
+            SyncAddress::try_from(UNIVERSES[0]).unwrap(),
+            Some(SocketAddr::new(
+                IpAddr::V6(TEST_NETWORK_INTERFACE_IPV6[0].parse().unwrap()),
+                ACN_SDT_MULTICAST_PORT,
+            )),
         )
         .unwrap();
 
@@ -0,0 +1,1261 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Source / receiver interaction tests run over an in-process `MemoryNetwork` so that no network setup is required.
+
+use sacn::clock::{Clock, MockClock};
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::receive::{SacnReceiver, SacnReceiverBuilder};
+use sacn::source::{DEFAULT_KEEP_ALIVE_INTERVAL, PolledSacnSource, SacnSource, SacnSourceBuilder};
+use sacn::start_code::{StartCodePayload, StartCodeRegistry};
+use sacn::timing::TimingProfile;
+use sacn::transport::{MemoryNetwork, Transport};
+use sacn::types::{StartCode, SyncAddress, Universe};
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// The timeout used when data is expected to arrive.
+const TIMEOUT: Option<Duration> = Some(Duration::from_secs(2));
+
+/// The timeout used when data is expected to not arrive.
+const SHORT_TIMEOUT: Option<Duration> = Some(Duration::from_millis(100));
+
+/// The universes used by the tests.
+const UNIVERSE_1: Universe = Universe::new(1).unwrap();
+const UNIVERSE_2: Universe = Universe::new(2).unwrap();
+const UNIVERSE_3: Universe = Universe::new(3).unwrap();
+
+/// Returns an IPv4 address on the memory network with the given last byte and port.
+fn addr(last_byte: u8, port: u16) -> SocketAddr {
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, last_byte)), port)
+}
+
+/// Creates a receiver and a source on a new memory network.
+fn rcv_and_src(network: &MemoryNetwork) -> (SacnReceiver, SacnSource) {
+    let rcv =
+        SacnReceiver::with_transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap(), None)
+            .unwrap();
+    let src = SacnSource::with_transport(
+        "Memory Source",
+        network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap(),
+    )
+    .unwrap();
+    (rcv, src)
+}
+
+#[test]
+fn test_memory_send_recv_multicast() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+    src.register_universe(UNIVERSE_1).unwrap();
+
+    let data = [0, 1, 2, 3, 4];
+    src.send(&[UNIVERSE_1], &data, None, None, None).unwrap();
+
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].universe, 1);
+    assert_eq!(received[0].values, data.to_vec());
+}
+
+#[test]
+fn test_memory_source_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SacnSource>();
+}
+
+#[test]
+fn test_memory_send_from_many_threads() {
+    const THREADS: u16 = 4;
+    const PACKETS_PER_UNIVERSE: u8 = 10;
+
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    let universes: Vec<Universe> = (1..=THREADS)
+        .map(|u| Universe::try_from(u).unwrap())
+        .collect();
+    rcv.listen_universes(&universes).unwrap();
+    src.register_universes(&universes).unwrap();
+
+    // Each thread sends to its own universe through a shared reference to the source.
+    thread::scope(|s| {
+        for &universe in &universes {
+            let src = &src;
+            s.spawn(move || {
+                for i in 0..PACKETS_PER_UNIVERSE {
+                    src.send(&[universe], &[0, i], None, None, None).unwrap();
+                }
+            });
+        }
+    });
+
+    // Packets for each universe are sent in order so none are discarded by the receiver sequence checks.
+    let mut received: HashMap<u16, Vec<u8>> = HashMap::new();
+    for _ in 0..(THREADS as usize * PACKETS_PER_UNIVERSE as usize) {
+        for data in rcv.recv(TIMEOUT).unwrap() {
+            received
+                .entry(data.universe)
+                .or_default()
+                .push(data.values[1]);
+        }
+    }
+
+    let expected: Vec<u8> = (0..PACKETS_PER_UNIVERSE).collect();
+    for universe in universes {
+        assert_eq!(received[&universe.get()], expected);
+    }
+}
+
+#[test]
+fn test_memory_source_shutdown_reports_terminated_universes() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+    rcv.set_announce_stream_termination(true);
+
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+    src.register_universes(&[UNIVERSE_1, UNIVERSE_2]).unwrap();
+
+    let report = src.shutdown(None, None).unwrap();
+    assert_eq!(report.completed, vec![1, 2]);
+    assert!(report.is_clean());
+
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::UniverseTerminated(_, universe)) => assert_eq!(universe, 1),
+        x => panic!("Expected universe to be terminated, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_source_shutdown_timeout() {
+    let network = MemoryNetwork::new();
+    let (_rcv, src) = rcv_and_src(&network);
+    src.register_universes(&[UNIVERSE_1, UNIVERSE_2]).unwrap();
+
+    let report = src.shutdown(Some(Duration::ZERO), None).unwrap();
+    assert!(report.completed.is_empty());
+    assert!(!report.is_clean());
+
+    let failed: Vec<Universe> = report.failed.iter().map(|(u, _)| *u).collect();
+    assert_eq!(failed, vec![1, 2]);
+    for (u, e) in report.failed {
+        match e {
+            SacnError::ShutdownTimeout(universe) => assert_eq!(u, universe),
+            x => panic!("Expected shutdown timeout, got {x:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_memory_receiver_shutdown_reports_left_universes() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, _src) = rcv_and_src(&network);
+    rcv.listen_universes(&[UNIVERSE_3, UNIVERSE_1, UNIVERSE_2])
+        .unwrap();
+
+    // The receiver also listens to the discovery universe.
+    let report = rcv.shutdown(None).unwrap();
+    assert_eq!(report.completed, vec![1, 2, 3, E131_DISCOVERY_UNIVERSE]);
+    assert!(report.is_clean());
+}
+
+#[test]
+fn test_memory_receiver_shutdown_timeout() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, _src) = rcv_and_src(&network);
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+
+    let report = rcv.shutdown(Some(Duration::ZERO)).unwrap();
+    assert!(report.completed.is_empty());
+    match &report.failed[..] {
+        [
+            (UNIVERSE_1, SacnError::ShutdownTimeout(1)),
+            (Universe::DISCOVERY, SacnError::ShutdownTimeout(E131_DISCOVERY_UNIVERSE)),
+        ] => {}
+        x => panic!("Expected all universes to time out, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_shutdown_timeout_uses_clock() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let rcv = SacnReceiverBuilder::new()
+        .transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap())
+        .clock(clock.clone())
+        .universes(&[UNIVERSE_1, UNIVERSE_2])
+        .build()
+        .unwrap();
+    let src = SacnSourceBuilder::new("Source")
+        .transport(network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap())
+        .clock(clock.clone())
+        .universes(&[UNIVERSE_1, UNIVERSE_2])
+        .build()
+        .unwrap();
+
+    // The timeouts are measured on the mock clock which doesn't advance so every universe is cleaned up, however long it takes.
+    let report = src.shutdown(Some(Duration::from_nanos(1)), None).unwrap();
+    assert_eq!(report.completed, vec![1, 2]);
+    let report = rcv.shutdown(Some(Duration::from_nanos(1))).unwrap();
+    assert_eq!(report.completed, vec![1, 2, E131_DISCOVERY_UNIVERSE]);
+}
+
+#[test]
+fn test_memory_not_listening_universe_not_received() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+    src.register_universes(&[UNIVERSE_1, UNIVERSE_2]).unwrap();
+
+    src.send(&[UNIVERSE_2], &[0, 10], None, None, None).unwrap();
+
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {
+            // Expected, the receiver hasn't joined the multicast group for universe 2.
+        }
+        x => panic!("Unexpected result {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_send_recv_unicast() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    // The receiver doesn't need to listen to the universe multicast group to receive unicast.
+    rcv.listen_universes(&[UNIVERSE_3]).unwrap();
+    rcv.set_is_multicast_enabled(false).unwrap();
+    src.register_universe(UNIVERSE_3).unwrap();
+
+    let data = [0, 255, 254];
+    src.send(
+        &[UNIVERSE_3],
+        &data,
+        None,
+        Some(addr(1, ACN_SDT_MULTICAST_PORT)),
+        None,
+    )
+    .unwrap();
+
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received[0].universe, 3);
+    assert_eq!(received[0].values, data.to_vec());
+}
+
+#[test]
+fn test_memory_sync_held_until_sync_packet() {
+    const SYNC_ADDR: SyncAddress = SyncAddress::new(5).unwrap();
+
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    rcv.listen_universes(&[UNIVERSE_1, UNIVERSE_2, SYNC_ADDR.universe()])
+        .unwrap();
+    src.register_universes(&[UNIVERSE_1, UNIVERSE_2, SYNC_ADDR.universe()])
+        .unwrap();
+
+    src.send(&[UNIVERSE_1], &[0, 1], None, None, Some(SYNC_ADDR))
+        .unwrap();
+    src.send(&[UNIVERSE_2], &[0, 2], None, None, Some(SYNC_ADDR))
+        .unwrap();
+
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {
+            // Expected, the data is waiting for the synchronisation packet.
+        }
+        x => panic!("Synchronised data passed up before sync packet {x:?}"),
+    }
+
+    src.send_sync_packet(SYNC_ADDR, None).unwrap();
+
+    let mut received = rcv.recv(TIMEOUT).unwrap();
+    received.sort();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].universe, 1);
+    assert_eq!(received[0].values, vec![0, 1]);
+    assert_eq!(received[1].universe, 2);
+    assert_eq!(received[1].values, vec![0, 2]);
+}
+
+#[test]
+fn test_memory_start_code_subscription() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+    src.register_universe(UNIVERSE_1).unwrap();
+
+    let priorities = rcv.subscribe_start_code(StartCode::PER_ADDRESS_PRIORITY);
+
+    src.send(&[UNIVERSE_1], &[0xdd, 100, 0, 200], None, None, None)
+        .unwrap();
+    src.send(&[UNIVERSE_1], &[0, 1, 2, 3], None, None, None)
+        .unwrap();
+
+    // The per-address priority data is routed to the subscription so only the levels are returned.
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].start_code(), StartCode::NULL);
+    assert_eq!(received[0].values, vec![0, 1, 2, 3]);
+
+    let routed = priorities.try_recv().unwrap();
+    assert_eq!(routed.start_code(), StartCode::PER_ADDRESS_PRIORITY);
+    assert_eq!(routed.values, vec![0xdd, 100, 0, 200]);
+
+    // Once the subscription is dropped the start code is returned by recv again.
+    drop(priorities);
+    src.send(&[UNIVERSE_1], &[0xdd, 50], None, None, None)
+        .unwrap();
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received[0].values, vec![0xdd, 50]);
+    assert!(!rcv.remove_start_code_route(StartCode::PER_ADDRESS_PRIORITY));
+}
+
+#[test]
+fn test_memory_start_code_handler() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+    src.register_universe(UNIVERSE_1).unwrap();
+
+    let (sender, texts) = mpsc::channel();
+    rcv.set_start_code_handler(StartCode::TEXT, move |data| {
+        let registry = StartCodeRegistry::standard();
+        if let StartCodePayload::Text(text) = registry.decode(&data.values).unwrap() {
+            sender.send(text.text.to_string()).unwrap();
+        }
+    });
+
+    src.send(
+        &[UNIVERSE_1],
+        &[0x17, 0, 20, b'H', b'i', 0],
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    // Data only for the handler doesn't end recv.
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {}
+        x => panic!("Expected a timeout as the text was passed to the handler, got {x:?}"),
+    }
+    assert_eq!(texts.try_recv().unwrap(), "Hi");
+
+    assert!(rcv.remove_start_code_route(StartCode::TEXT));
+    src.send(
+        &[UNIVERSE_1],
+        &[0x17, 0, 20, b'H', b'o', 0],
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(rcv.recv(TIMEOUT).unwrap()[0].start_code(), StartCode::TEXT);
+    assert!(texts.try_recv().is_err());
+}
+
+#[test]
+fn test_memory_sync_waits_per_start_code() {
+    const SYNC_ADDR: SyncAddress = SyncAddress::new(5).unwrap();
+
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    rcv.listen_universes(&[UNIVERSE_1, SYNC_ADDR.universe()])
+        .unwrap();
+    src.register_universes(&[UNIVERSE_1, SYNC_ADDR.universe()])
+        .unwrap();
+
+    // The levels and per-address priorities of the same universe wait separately rather than being merged.
+    src.send(&[UNIVERSE_1], &[0, 1, 2], None, None, Some(SYNC_ADDR))
+        .unwrap();
+    src.send(
+        &[UNIVERSE_1],
+        &[0xdd, 100, 150],
+        None,
+        None,
+        Some(SYNC_ADDR),
+    )
+    .unwrap();
+
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {}
+        x => panic!("Synchronised data passed up before sync packet {x:?}"),
+    }
+
+    src.send_sync_packet(SYNC_ADDR, None).unwrap();
+
+    let mut received = rcv.recv(TIMEOUT).unwrap();
+    received.sort_by_key(|d| d.start_code());
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].values, vec![0, 1, 2]);
+    assert_eq!(received[1].values, vec![0xdd, 100, 150]);
+}
+
+#[test]
+fn test_memory_unsynchronised_data_discards_all_start_codes() {
+    const SYNC_ADDR: SyncAddress = SyncAddress::new(5).unwrap();
+
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+
+    rcv.listen_universes(&[UNIVERSE_1, SYNC_ADDR.universe()])
+        .unwrap();
+    src.register_universes(&[UNIVERSE_1, SYNC_ADDR.universe()])
+        .unwrap();
+
+    src.send(&[UNIVERSE_1], &[0, 1, 2], None, None, Some(SYNC_ADDR))
+        .unwrap();
+    src.send(
+        &[UNIVERSE_1],
+        &[0xdd, 100, 150],
+        None,
+        None,
+        Some(SYNC_ADDR),
+    )
+    .unwrap();
+
+    // As per ANSI E1.31-2018 Section 6.2.4.1 data without a synchronisation address discards all data waiting for the universe, not
+    // just the data with the same start code.
+    src.send(&[UNIVERSE_1], &[0xdd, 50, 50], None, None, None)
+        .unwrap();
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].values, vec![0xdd, 50, 50]);
+
+    src.send_sync_packet(SYNC_ADDR, None).unwrap();
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {
+            // Expected, the waiting data was discarded.
+        }
+        x => panic!("Discarded data passed up by the sync packet {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_universe_discovery() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, _src) = rcv_and_src(&network);
+    rcv.set_announce_source_discovery(true);
+
+    // Sources only advertise every E131_UNIVERSE_DISCOVERY_INTERVAL so the discovery packet is sent directly.
+    let advertiser = network.bind(addr(3, ACN_SDT_MULTICAST_PORT + 1)).unwrap();
+    let cid = Uuid::new_v4();
+    let packet = AcnRootLayerProtocol {
+        pdu: E131RootLayer {
+            cid,
+            data: E131RootLayerData::UniverseDiscoveryPacket(UniverseDiscoveryPacketFramingLayer {
+                source_name: "Advertiser".into(),
+                data: UniverseDiscoveryPacketUniverseDiscoveryLayer {
+                    page: 0,
+                    last_page: 0,
+                    universes: vec![1, 2, 3].into(),
+                },
+            }),
+        },
+    };
+    let dst = universe_to_ipv4_multicast_addr(E131_DISCOVERY_UNIVERSE)
+        .unwrap()
+        .as_socket()
+        .unwrap();
+    advertiser
+        .send_to(&packet.pack_alloc().unwrap(), dst)
+        .unwrap();
+
+    match rcv.recv(TIMEOUT) {
+        Err(e @ SacnError::SourceDiscovered(..)) => {
+            assert_eq!(e.kind(), SacnErrorKind::Event);
+            assert!(e.is_transient());
+            match e {
+                SacnError::SourceDiscovered(src_cid, name) => {
+                    assert_eq!(src_cid, cid);
+                    assert_eq!(name, "Advertiser");
+                }
+                _ => unreachable!(),
+            }
+        }
+        x => panic!("Expected source to be discovered, got {x:?}"),
+    }
+
+    let discovered = rcv.get_discovered_sources();
+    assert_eq!(discovered.len(), 1);
+    assert_eq!(discovered[0].get_all_universes(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_memory_pdu_block_received() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, _src) = rcv_and_src(&network);
+    rcv.listen_universes(&[UNIVERSE_1, UNIVERSE_2]).unwrap();
+
+    let cid = Uuid::new_v4();
+    let pdu = |universe: Universe, values: &[u8]| {
+        E131Packet::data(universe)
+            .cid(cid)
+            .values(values)
+            .build()
+            .unwrap()
+            .as_protocol()
+            .into_owned()
+            .pdu
+    };
+    let buf = AcnRootLayerBlock {
+        pdus: vec![pdu(UNIVERSE_1, &[0, 1]), pdu(UNIVERSE_2, &[0, 2])],
+    }
+    .pack_alloc()
+    .unwrap();
+
+    let dst = universe_to_ipv4_multicast_addr(1)
+        .unwrap()
+        .as_socket()
+        .unwrap();
+    network
+        .bind(addr(3, ACN_SDT_MULTICAST_PORT + 1))
+        .unwrap()
+        .send_to(&buf, dst)
+        .unwrap();
+
+    // The data from both PDUs of the packet is returned together.
+    let mut received = rcv.recv(TIMEOUT).unwrap();
+    received.sort();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].universe, 1);
+    assert_eq!(received[0].values, vec![0, 1]);
+    assert_eq!(received[1].universe, 2);
+    assert_eq!(received[1].values, vec![0, 2]);
+}
+
+#[test]
+fn test_memory_pdu_block_errors_and_discoveries_kept() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, _src) = rcv_and_src(&network);
+    rcv.listen_universes(&[UNIVERSE_1, UNIVERSE_2]).unwrap();
+    rcv.set_announce_source_discovery(true);
+
+    let cid = Uuid::new_v4();
+    let data_pdu = |universe: Universe, sequence_number: u8| {
+        E131Packet::data(universe)
+            .cid(cid)
+            .sequence_number(sequence_number)
+            .values(&[0, sequence_number])
+            .build()
+            .unwrap()
+            .as_protocol()
+            .into_owned()
+            .pdu
+    };
+    let discovery_pdu = |name: &str| {
+        let discovery_cid = Uuid::new_v4();
+        let pdu = E131Packet::discovery()
+            .cid(discovery_cid)
+            .source_name(name)
+            .universes(&[UNIVERSE_3])
+            .build()
+            .unwrap()
+            .as_protocol()
+            .into_owned()
+            .pdu;
+        (discovery_cid, pdu)
+    };
+    let (cid_b, discovery_b) = discovery_pdu("Source_B");
+    let (cid_c, discovery_c) = discovery_pdu("Source_C");
+
+    // The second PDU is out of sequence, the PDUs after it are still handled.
+    let buf = AcnRootLayerBlock {
+        pdus: vec![
+            data_pdu(UNIVERSE_1, 5),
+            data_pdu(UNIVERSE_1, 4),
+            discovery_b,
+            discovery_c,
+            data_pdu(UNIVERSE_2, 6),
+        ],
+    }
+    .pack_alloc()
+    .unwrap();
+    let dst = universe_to_ipv4_multicast_addr(1)
+        .unwrap()
+        .as_socket()
+        .unwrap();
+    network
+        .bind(addr(3, ACN_SDT_MULTICAST_PORT + 1))
+        .unwrap()
+        .send_to(&buf, dst)
+        .unwrap();
+
+    let mut received = rcv.recv(TIMEOUT).unwrap();
+    received.sort();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].values, vec![0, 5]);
+    assert_eq!(received[1].values, vec![0, 6]);
+
+    // The error and both discovered sources are returned in order by the following calls.
+    assert!(matches!(
+        rcv.recv(SHORT_TIMEOUT),
+        Err(SacnError::OutOfSequence(..))
+    ));
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(SacnError::SourceDiscovered(src_cid, name)) => {
+            assert_eq!(src_cid, cid_b);
+            assert_eq!(name, "Source_B");
+        }
+        x => panic!("Expected Source_B to be discovered, got {x:?}"),
+    }
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(SacnError::SourceDiscovered(src_cid, name)) => {
+            assert_eq!(src_cid, cid_c);
+            assert_eq!(name, "Source_C");
+        }
+        x => panic!("Expected Source_C to be discovered, got {x:?}"),
+    }
+    assert!(rcv.recv(SHORT_TIMEOUT).unwrap_err().is_timeout());
+    assert_eq!(rcv.get_discovered_sources().len(), 2);
+}
+
+#[test]
+fn test_memory_unknown_vector_handler_and_parser() {
+    const VENDOR_VECTOR: u32 = 0x7f00_0001;
+    const VENDOR_DATA_VECTOR: u32 = 0x7f00_0002;
+
+    /// Parses the data of a `VENDOR_DATA_VECTOR` packet, a universe followed by the property values, as a data packet.
+    fn parse_vendor_data(_vector: u32, raw: &[u8]) -> Result<E131RootLayerData<'_>> {
+        Ok(E131RootLayerData::DataPacket(DataPacketFramingLayer {
+            source_name: "Vendor".into(),
+            priority: E131_DEFAULT_PRIORITY,
+            synchronization_address: 0,
+            sequence_number: 0,
+            preview_data: false,
+            stream_terminated: false,
+            force_synchronization: false,
+            universe: u16::from_be_bytes([raw[0], raw[1]]),
+            data: DataPacketDmpLayer {
+                property_values: raw[2..].into(),
+            },
+        }))
+    }
+
+    let network = MemoryNetwork::new();
+    let (mut rcv, _src) = rcv_and_src(&network);
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+
+    let (sender, unknown) = mpsc::channel();
+    rcv.set_unknown_packet_handler(move |cid, vector, raw| {
+        sender.send((cid, vector, raw.to_vec())).unwrap();
+    });
+    rcv.register_vector_parser(VENDOR_DATA_VECTOR, parse_vendor_data);
+
+    let cid = Uuid::new_v4();
+    let peer = network.bind(addr(3, ACN_SDT_MULTICAST_PORT + 1)).unwrap();
+    let dst = universe_to_ipv4_multicast_addr(1)
+        .unwrap()
+        .as_socket()
+        .unwrap();
+    for (vector, raw) in [
+        (VENDOR_VECTOR, vec![1, 2]),
+        (VENDOR_DATA_VECTOR, vec![0, 1, 0, 9]),
+    ] {
+        let buf = E131Packet::Unknown { cid, vector, raw }
+            .pack_alloc()
+            .unwrap();
+        peer.send_to(&buf, dst).unwrap();
+    }
+
+    // The packet parsed by the registered parser is processed as a data packet.
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received[0].universe, 1);
+    assert_eq!(received[0].values, vec![0, 9]);
+
+    // The other was passed to the handler.
+    assert_eq!(
+        unknown.try_recv().unwrap(),
+        (cid, VENDOR_VECTOR, vec![1, 2])
+    );
+}
+
+#[test]
+fn test_memory_draft_mode() {
+    let network = MemoryNetwork::new();
+    let mut rcv = SacnReceiverBuilder::new()
+        .transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap())
+        .universes(&[UNIVERSE_1])
+        .build()
+        .unwrap();
+    let src = SacnSourceBuilder::new("Draft Source")
+        .transport(network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap())
+        .draft_mode(true)
+        .universes(&[UNIVERSE_1])
+        .build()
+        .unwrap();
+    assert!(src.draft_mode());
+    assert!(!rcv.accept_draft_packets());
+
+    // Draft packets are rejected by default.
+    src.send(&[UNIVERSE_1], &[0, 1], None, None, None).unwrap();
+    assert!(matches!(
+        rcv.recv(TIMEOUT),
+        Err(SacnError::MalformedPacket(..))
+    ));
+
+    // Once accepted they are received as any other data, without the synchronization address.
+    rcv.set_accept_draft_packets(true);
+    src.send(
+        &[UNIVERSE_1],
+        &[0, 2],
+        None,
+        None,
+        Some(SyncAddress::new(1).unwrap()),
+    )
+    .unwrap();
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received[0].values, vec![0, 2]);
+    assert_eq!(received[0].sync_uni, NO_SYNC_UNIVERSE);
+
+    // Packets from a source which isn't in draft mode are still accepted.
+    src.set_draft_mode(false);
+    src.send(&[UNIVERSE_1], &[0, 3], None, None, None).unwrap();
+    assert_eq!(rcv.recv(TIMEOUT).unwrap()[0].values, vec![0, 3]);
+
+    // Once no longer accepted draft packets are rejected again rather than left as unknown packets.
+    rcv.set_accept_draft_packets(false);
+    src.set_draft_mode(true);
+    src.send(&[UNIVERSE_1], &[0, 4], None, None, None).unwrap();
+    assert!(matches!(
+        rcv.recv(TIMEOUT),
+        Err(SacnError::MalformedPacket(..))
+    ));
+}
+
+#[test]
+fn test_memory_draft_mode_terminate_stream() {
+    let network = MemoryNetwork::new();
+    let mut rcv = SacnReceiverBuilder::new()
+        .transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap())
+        .universes(&[UNIVERSE_1])
+        .announce_stream_termination(true)
+        .accept_draft_packets(true)
+        .build()
+        .unwrap();
+    let src = SacnSourceBuilder::new("Draft Source")
+        .transport(network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap())
+        .draft_mode(true)
+        .universes(&[UNIVERSE_1])
+        .build()
+        .unwrap();
+
+    src.send(&[UNIVERSE_1], &[0, 1], None, None, None).unwrap();
+    assert_eq!(rcv.recv(TIMEOUT).unwrap()[0].values, vec![0, 1]);
+
+    // The draft has no Stream_Terminated option so the termination packets are sent using ANSI E1.31-2018 and terminate the
+    // universe rather than being received as data holding only the start code.
+    src.terminate_stream(UNIVERSE_1, StartCode::NULL).unwrap();
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::UniverseTerminated(_, universe)) => assert_eq!(universe, 1),
+        x => panic!("Expected universe to be terminated, got {x:?}"),
+    }
+    if let Ok(data) = rcv.recv(SHORT_TIMEOUT) {
+        panic!("Termination packets received as data {data:?}");
+    }
+}
+
+#[test]
+fn test_memory_draft_packets_keep_allowed_unknown_vectors() {
+    let network = MemoryNetwork::new();
+    let mut rcv = SacnReceiverBuilder::new()
+        .transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap())
+        .universes(&[UNIVERSE_1])
+        .parse_options(ParseOptions {
+            allow_unknown_vectors: true,
+            ..ParseOptions::default()
+        })
+        .accept_draft_packets(true)
+        .build()
+        .unwrap();
+    let src = SacnSourceBuilder::new("Draft Source")
+        .transport(network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap())
+        .draft_mode(true)
+        .universes(&[UNIVERSE_1])
+        .build()
+        .unwrap();
+
+    // Unknown vectors were allowed by the parse options so once draft packets are no longer accepted they are discarded as unknown
+    // packets rather than rejected.
+    rcv.set_accept_draft_packets(false);
+    src.send(&[UNIVERSE_1], &[0, 1], None, None, None).unwrap();
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {}
+        x => panic!("Expected the draft packet to be discarded, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_malformed_packet_reports_peer() {
+    let network = MemoryNetwork::new();
+    let (mut rcv, src) = rcv_and_src(&network);
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+    src.register_universe(UNIVERSE_1).unwrap();
+
+    let peer = addr(3, ACN_SDT_MULTICAST_PORT + 1);
+    let dst = universe_to_ipv4_multicast_addr(1)
+        .unwrap()
+        .as_socket()
+        .unwrap();
+    network
+        .bind(peer)
+        .unwrap()
+        .send_to(&[0x00, 0x10, 0xff], dst)
+        .unwrap();
+
+    match rcv.recv(TIMEOUT) {
+        Err(e @ SacnError::MalformedPacket(..)) => {
+            assert_eq!(e.kind(), SacnErrorKind::Protocol);
+            assert!(e.is_transient());
+            match e {
+                SacnError::MalformedPacket(from, _) => assert_eq!(from, peer),
+                _ => unreachable!(),
+            }
+        }
+        x => panic!("Expected a malformed packet error, got {x:?}"),
+    }
+
+    // The receiver carries on receiving after the malformed packet.
+    src.send(&[UNIVERSE_1], &[0, 1], None, None, None).unwrap();
+    assert_eq!(rcv.recv(TIMEOUT).unwrap()[0].values, vec![0, 1]);
+}
+
+#[test]
+fn test_memory_lenient_receiver_accepts_deviations() {
+    let network = MemoryNetwork::new();
+    let options = [ParseOptions::strict(), ParseOptions::lenient()].map(|options| {
+        SacnReceiverBuilder::new()
+            .transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap())
+            .parse_options(options)
+            .universes(&[UNIVERSE_1])
+            .build()
+            .unwrap()
+    });
+    let [mut strict, mut lenient] = options;
+
+    let packet = AcnRootLayerProtocol {
+        pdu: E131RootLayer {
+            cid: Uuid::new_v4(),
+            data: E131RootLayerData::DataPacket(DataPacketFramingLayer {
+                source_name: "Non-compliant Source".into(),
+                priority: E131_DEFAULT_PRIORITY,
+                synchronization_address: E131_NO_SYNC_ADDR,
+                sequence_number: 0,
+                preview_data: false,
+                stream_terminated: false,
+                force_synchronization: false,
+                universe: UNIVERSE_1.get(),
+                data: DataPacketDmpLayer {
+                    property_values: vec![0, 1, 2].into(),
+                },
+            }),
+        },
+    };
+    let mut buf = packet.pack_alloc().unwrap();
+    // Address Increment of 2 rather than 1, ANSI E1.31-2018 Section 7.5.
+    buf[122] = 0x02;
+
+    let dst = universe_to_ipv4_multicast_addr(UNIVERSE_1.get())
+        .unwrap()
+        .as_socket()
+        .unwrap();
+    network
+        .bind(addr(3, ACN_SDT_MULTICAST_PORT + 1))
+        .unwrap()
+        .send_to(&buf, dst)
+        .unwrap();
+
+    match strict.recv(TIMEOUT) {
+        Err(SacnError::MalformedPacket(_, e)) => {
+            assert_eq!(e.context().unwrap().field, "Address Increment");
+        }
+        x => panic!("Expected a malformed packet error, got {x:?}"),
+    }
+    assert_eq!(lenient.recv(TIMEOUT).unwrap()[0].values, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_memory_source_discovery_advert_follows_clock() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+
+    let mut rcv =
+        SacnReceiver::with_transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap(), None)
+            .unwrap();
+    rcv.set_announce_source_discovery(true);
+
+    let src = SacnSource::with_clock(
+        "Clocked Source",
+        Uuid::new_v4(),
+        network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap(),
+        clock.clone(),
+    )
+    .unwrap();
+    src.register_universes(&[UNIVERSE_1, UNIVERSE_2]).unwrap();
+
+    // Longer than the poll period of the update thread, the advert isn't due as the clock hasn't moved.
+    match rcv.recv(Some(Duration::from_millis(1500))) {
+        Err(e) if e.is_timeout() => {
+            // Expected, no advert has been sent.
+        }
+        x => panic!("Unexpected result {x:?}"),
+    }
+
+    clock.advance(E131_UNIVERSE_DISCOVERY_INTERVAL + Duration::from_millis(1));
+
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::SourceDiscovered(_, name)) => assert_eq!(name, "Clocked Source"),
+        x => panic!("Expected source to be discovered, got {x:?}"),
+    }
+    assert_eq!(
+        rcv.get_discovered_sources()[0].get_all_universes(),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn test_memory_source_discovery_interval_from_timing_profile() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let timing = TimingProfile {
+        discovery_interval: Duration::from_secs(3),
+        discovery_source_timeout: Duration::from_secs(6),
+        ..TimingProfile::strict()
+    };
+
+    let mut rcv =
+        SacnReceiver::with_transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap(), None)
+            .unwrap();
+    rcv.set_announce_source_discovery(true);
+
+    let src = SacnSource::with_timing(
+        "Timed Source",
+        Uuid::new_v4(),
+        network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap(),
+        clock.clone(),
+        timing,
+    )
+    .unwrap();
+    src.register_universe(UNIVERSE_1).unwrap();
+
+    clock.advance(timing.discovery_interval + Duration::from_millis(1));
+
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::SourceDiscovered(_, name)) => assert_eq!(name, "Timed Source"),
+        x => panic!("Expected source to be discovered, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_networks_are_isolated() {
+    let network = MemoryNetwork::new();
+    let other_network = MemoryNetwork::new();
+
+    let mut rcv =
+        SacnReceiver::with_transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap(), None)
+            .unwrap();
+    let src = SacnSource::with_transport(
+        "Other Source",
+        other_network
+            .bind(addr(2, ACN_SDT_MULTICAST_PORT + 1))
+            .unwrap(),
+    )
+    .unwrap();
+
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+    src.register_universe(UNIVERSE_1).unwrap();
+    src.send(&[UNIVERSE_1], &[0, 1], None, None, None).unwrap();
+
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {
+            // Expected, the source is on a different network.
+        }
+        x => panic!("Unexpected result {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_source_socket_options_unsupported() {
+    let network = MemoryNetwork::new();
+    let (_rcv, src) = rcv_and_src(&network);
+
+    match src.set_ttl(4) {
+        Err(SacnError::OsOperationUnsupported(_)) => {
+            // Expected, a memory transport has no socket.
+        }
+        x => panic!("Unexpected result {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_source_builder_registers_universes() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+
+    let mut rcv =
+        SacnReceiver::with_transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap(), None)
+            .unwrap();
+    rcv.set_announce_source_discovery(true);
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+
+    let src = SacnSourceBuilder::new("Built Source")
+        .transport(network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap())
+        .clock(clock.clone())
+        .discovery_interval(Duration::from_secs(5))
+        .universes(&[UNIVERSE_1, UNIVERSE_2])
+        .build()
+        .unwrap();
+
+    let data = [0, 1, 2];
+    src.send(&[UNIVERSE_1], &data, None, None, None).unwrap();
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received[0].values, data.to_vec());
+
+    clock.advance(Duration::from_secs(5) + Duration::from_millis(1));
+
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::SourceDiscovered(_, name)) => assert_eq!(name, "Built Source"),
+        x => panic!("Expected source to be discovered, got {x:?}"),
+    }
+    assert_eq!(
+        rcv.get_discovered_sources()[0].get_all_universes(),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn test_memory_source_builder_socket_options_rejected() {
+    let network = MemoryNetwork::new();
+
+    match SacnSourceBuilder::new("Built Source")
+        .transport(network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap())
+        .multicast_ttl(4)
+        .build()
+    {
+        Err(SacnError::InvalidConfiguration(_)) => {
+            // Expected, a memory transport has no socket.
+        }
+        x => panic!("Unexpected result {:?}", x.err()),
+    }
+}
+
+#[test]
+fn test_memory_source_broadcast_mode() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+
+    let mut rcv =
+        SacnReceiver::with_transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap(), None)
+            .unwrap();
+    // Broadcast doesn't require joining multicast groups.
+    rcv.set_is_multicast_enabled(false).unwrap();
+    rcv.set_accept_broadcast(true).unwrap();
+    rcv.set_announce_source_discovery(true);
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+
+    let src = SacnSourceBuilder::new("Broadcast Source")
+        .transport(network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap())
+        .clock(clock.clone())
+        .broadcast(Ipv4Addr::BROADCAST)
+        .universes(&[UNIVERSE_1])
+        .build()
+        .unwrap();
+    assert_eq!(src.broadcast_addr().unwrap(), Some(Ipv4Addr::BROADCAST));
+
+    let data = [0, 10, 20];
+    src.send(&[UNIVERSE_1], &data, None, None, None).unwrap();
+    let received = rcv.recv(TIMEOUT).unwrap();
+    assert_eq!(received[0].values, data.to_vec());
+
+    clock.advance(E131_UNIVERSE_DISCOVERY_INTERVAL + Duration::from_millis(1));
+
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::SourceDiscovered(_, name)) => assert_eq!(name, "Broadcast Source"),
+        x => panic!("Expected source to be discovered, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_source_broadcast_mode_rejects_multicast_addr() {
+    let network = MemoryNetwork::new();
+    let (_rcv, src) = rcv_and_src(&network);
+
+    match src.set_broadcast_addr(Some(Ipv4Addr::new(239, 255, 0, 1))) {
+        Err(SacnError::InvalidConfiguration(_)) => {
+            // Expected, a multicast address isn't a broadcast address.
+        }
+        x => panic!("Unexpected result {x:?}"),
+    }
+    assert_eq!(src.broadcast_addr().unwrap(), None);
+}
+
+/// Creates a receiver which announces discovered sources and a polled source using the given clock on the given memory network.
+fn rcv_and_polled_src(
+    network: &MemoryNetwork,
+    clock: &MockClock,
+) -> (SacnReceiver, PolledSacnSource) {
+    let mut rcv =
+        SacnReceiver::with_transport(network.bind(addr(1, ACN_SDT_MULTICAST_PORT)).unwrap(), None)
+            .unwrap();
+    rcv.set_announce_source_discovery(true);
+    rcv.listen_universes(&[UNIVERSE_1]).unwrap();
+
+    let src = SacnSourceBuilder::new("Polled Source")
+        .transport(network.bind(addr(2, ACN_SDT_MULTICAST_PORT + 1)).unwrap())
+        .clock(clock.clone())
+        .universes(&[UNIVERSE_1])
+        .build_polled()
+        .unwrap();
+    (rcv, src)
+}
+
+#[test]
+fn test_memory_polled_source_discovery_only_on_poll() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let (mut rcv, mut src) = rcv_and_polled_src(&network, &clock);
+
+    clock.advance(E131_UNIVERSE_DISCOVERY_INTERVAL + Duration::from_millis(1));
+
+    // There is no update thread so nothing is sent until the source is polled.
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {
+            // Expected, the source hasn't been polled.
+        }
+        x => panic!("Unexpected result {x:?}"),
+    }
+
+    src.poll(clock.now()).unwrap();
+
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::SourceDiscovered(_, name)) => assert_eq!(name, "Polled Source"),
+        x => panic!("Expected source to be discovered, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_polled_source_keep_alive() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let (mut rcv, mut src) = rcv_and_polled_src(&network, &clock);
+    src.set_is_sending_discovery(false);
+
+    let data = [0, 7, 8, 9];
+    src.send(&[UNIVERSE_1], &data, None, None, None).unwrap();
+    assert_eq!(rcv.recv(TIMEOUT).unwrap()[0].values, data.to_vec());
+
+    // The keep-alive isn't due yet.
+    src.poll(clock.now()).unwrap();
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {}
+        x => panic!("Unexpected result {x:?}"),
+    }
+
+    clock.advance(DEFAULT_KEEP_ALIVE_INTERVAL);
+    src.poll(clock.now()).unwrap();
+    assert_eq!(rcv.recv(TIMEOUT).unwrap()[0].values, data.to_vec());
+
+    // The resend restarts the interval.
+    src.poll(clock.now()).unwrap();
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {}
+        x => panic!("Unexpected result {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_polled_source_keep_alive_disabled() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let (mut rcv, mut src) = rcv_and_polled_src(&network, &clock);
+    src.set_is_sending_discovery(false);
+    src.set_keep_alive_interval(None).unwrap();
+
+    src.send(&[UNIVERSE_1], &[0, 1], None, None, None).unwrap();
+    rcv.recv(TIMEOUT).unwrap();
+
+    clock.advance(DEFAULT_KEEP_ALIVE_INTERVAL * 2);
+    src.poll(clock.now()).unwrap();
+
+    match rcv.recv(SHORT_TIMEOUT) {
+        Err(e) if e.is_timeout() => {
+            // Expected, keep-alives are disabled.
+        }
+        x => panic!("Unexpected result {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_polled_source_state() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let (mut rcv, mut src) = rcv_and_polled_src(&network, &clock);
+    src.set_is_sending_discovery(false);
+
+    // The state of a polled source isn't locked so the getters can't fail.
+    assert_eq!(src.name(), "Polled Source");
+    assert_eq!(src.keep_alive_interval(), Some(DEFAULT_KEEP_ALIVE_INTERVAL));
+    assert_eq!(src.universes(), vec![UNIVERSE_1]);
+
+    src.register_universe(UNIVERSE_2).unwrap();
+    src.set_name("Renamed Source").unwrap();
+    assert_eq!(src.universes(), vec![UNIVERSE_1, UNIVERSE_2]);
+    assert_eq!(src.name(), "Renamed Source");
+
+    // The sequence numbers advance so every packet is in sequence.
+    for i in 0..3 {
+        src.send(&[UNIVERSE_1], &[0, i], None, None, None).unwrap();
+        let received = rcv.recv(TIMEOUT).unwrap();
+        assert_eq!(received[0].values, vec![0, i]);
+        assert_eq!(received[0].src_cid, Some(src.cid()));
+    }
+}
+
+#[test]
+fn test_memory_polled_source_terminated_on_drop() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let (mut rcv, src) = rcv_and_polled_src(&network, &clock);
+    rcv.set_announce_stream_termination(true);
+
+    drop(src);
+
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::UniverseTerminated(_, universe)) => assert_eq!(universe, 1),
+        x => panic!("Expected universe to be terminated, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_memory_polled_source_shutdown() {
+    let network = MemoryNetwork::new();
+    let clock = MockClock::new();
+    let (mut rcv, src) = rcv_and_polled_src(&network, &clock);
+    rcv.set_announce_stream_termination(true);
+
+    let report = src.shutdown(None, None).unwrap();
+    assert_eq!(report.completed, vec![1]);
+
+    match rcv.recv(TIMEOUT) {
+        Err(SacnError::UniverseTerminated(_, universe)) => assert_eq!(universe, 1),
+        x => panic!("Expected universe to be terminated, got {x:?}"),
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests for the owned `E131Packet` and the packet builders.
+
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::types::{Priority, SyncAddress, Universe};
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+const UNIVERSE_1: Universe = Universe::new(1).unwrap();
+const UNIVERSE_2: Universe = Universe::new(2).unwrap();
+const SYNC_ADDR: SyncAddress = SyncAddress::new(7962).unwrap();
+
+#[test]
+fn test_data_packet_builder() {
+    let cid = Uuid::new_v4();
+    let packet = E131Packet::data(UNIVERSE_1)
+        .cid(cid)
+        .source_name("Source_A")
+        .priority(Priority::try_from(150).unwrap())
+        .synchronization_address(Some(SYNC_ADDR))
+        .sequence_number(154)
+        .force_synchronization(true)
+        .values(&[0, 1, 2, 3])
+        .build()
+        .unwrap();
+
+    let expected = AcnRootLayerProtocol {
+        pdu: E131RootLayer {
+            cid,
+            data: E131RootLayerData::DataPacket(DataPacketFramingLayer {
+                source_name: "Source_A".into(),
+                priority: 150,
+                synchronization_address: 7962,
+                sequence_number: 154,
+                preview_data: false,
+                stream_terminated: false,
+                force_synchronization: true,
+                universe: 1,
+                data: DataPacketDmpLayer {
+                    property_values: vec![0, 1, 2, 3].into(),
+                },
+            }),
+        },
+    };
+
+    assert_eq!(packet.cid(), cid);
+    assert_eq!(packet.as_protocol(), expected);
+    assert_eq!(packet.len(), expected.len());
+    assert_eq!(packet.pack_alloc().unwrap(), expected.pack_alloc().unwrap());
+    assert_eq!(AcnRootLayerProtocol::from(packet.clone()), expected);
+    assert_eq!(E131Packet::from(expected), packet);
+}
+
+#[test]
+fn test_data_packet_builder_defaults() {
+    let packet = E131Packet::data(UNIVERSE_1)
+        .cid(Uuid::new_v4())
+        .values(&[0])
+        .build()
+        .unwrap();
+
+    match packet {
+        E131Packet::Data { data, .. } => {
+            assert_eq!(data.priority, E131_DEFAULT_PRIORITY);
+            assert_eq!(data.synchronization_address, E131_NO_SYNC_ADDR);
+            assert_eq!(data.sequence_number, STARTING_SEQUENCE_NUMBER);
+            assert!(!data.preview_data);
+            assert!(!data.stream_terminated);
+            assert!(!data.force_synchronization);
+            assert_eq!(data.source_name, "");
+        }
+        x => panic!("Expected a data packet, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_data_packet_builder_validation() {
+    match E131Packet::data(UNIVERSE_1).values(&[0]).build() {
+        Err(SacnError::InvalidConfiguration(_)) => {}
+        x => panic!("Expected a missing CID to be rejected, got {x:?}"),
+    }
+
+    match E131Packet::data(UNIVERSE_1).cid(Uuid::new_v4()).build() {
+        Err(SacnError::DataArrayEmpty()) => {}
+        x => panic!("Expected no property values to be rejected, got {x:?}"),
+    }
+
+    let values = [0; UNIVERSE_CHANNEL_CAPACITY + 1];
+    match E131Packet::data(UNIVERSE_1)
+        .cid(Uuid::new_v4())
+        .values(&values)
+        .build()
+    {
+        Err(SacnError::ExceedUniverseCapacity(len)) => assert_eq!(len, values.len()),
+        x => panic!("Expected too many property values to be rejected, got {x:?}"),
+    }
+
+    // The source name must leave room for the null terminator.
+    match E131Packet::data(UNIVERSE_1)
+        .cid(Uuid::new_v4())
+        .source_name("a".repeat(E131_SOURCE_NAME_FIELD_LENGTH))
+        .values(&[0])
+        .build()
+    {
+        Err(SacnError::MalformedSourceName(_)) => {}
+        x => panic!("Expected the source name to be rejected, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_sync_packet_builder() {
+    let cid = Uuid::new_v4();
+    let packet = E131Packet::sync(SYNC_ADDR)
+        .cid(cid)
+        .sequence_number(3)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        packet,
+        E131Packet::Sync {
+            cid,
+            data: SynchronizationPacketFramingLayer {
+                sequence_number: 3,
+                synchronization_address: SYNC_ADDR.get(),
+            },
+        }
+    );
+    assert_eq!(packet.len(), E131_UNIVERSE_SYNC_PACKET_ROOT_LENGTH + 16);
+    assert_eq!(
+        E131Packet::parse(&packet.pack_alloc().unwrap()).unwrap(),
+        packet
+    );
+}
+
+#[test]
+fn test_discovery_packet_builder() {
+    let packet = E131Packet::discovery()
+        .cid(Uuid::new_v4())
+        .source_name("Source_A")
+        .page(1, 2)
+        .universes(&[UNIVERSE_1, UNIVERSE_2])
+        .build()
+        .unwrap();
+
+    match &packet {
+        E131Packet::Discovery { data, .. } => {
+            assert_eq!(data.data.page, 1);
+            assert_eq!(data.data.last_page, 2);
+            assert_eq!(data.data.universes.to_vec(), vec![1, 2]);
+        }
+        x => panic!("Expected a discovery packet, got {x:?}"),
+    }
+
+    assert_eq!(
+        E131Packet::parse(&packet.pack_alloc().unwrap()).unwrap(),
+        packet
+    );
+}
+
+#[test]
+fn test_discovery_packet_builder_validation() {
+    match E131Packet::discovery()
+        .cid(Uuid::new_v4())
+        .universes(&[UNIVERSE_2, UNIVERSE_1])
+        .build()
+    {
+        Err(SacnError::InvalidConfiguration(_)) => {}
+        x => panic!("Expected unsorted universes to be rejected, got {x:?}"),
+    }
+
+    match E131Packet::discovery()
+        .cid(Uuid::new_v4())
+        .universes(&[UNIVERSE_1, UNIVERSE_1])
+        .build()
+    {
+        Err(SacnError::InvalidConfiguration(_)) => {}
+        x => panic!("Expected duplicate universes to be rejected, got {x:?}"),
+    }
+
+    match E131Packet::discovery()
+        .cid(Uuid::new_v4())
+        .page(2, 1)
+        .build()
+    {
+        Err(SacnError::InvalidConfiguration(_)) => {}
+        x => panic!("Expected a page higher than the last page to be rejected, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_into_owned_outlives_buffer() {
+    let packet = E131Packet::data(UNIVERSE_1)
+        .cid(Uuid::new_v4())
+        .source_name("Source_A")
+        .values(&[0, 255])
+        .build()
+        .unwrap();
+
+    let owned: AcnRootLayerProtocol<'static> = {
+        let buf = packet.pack_alloc().unwrap();
+        AcnRootLayerProtocol::parse(&buf).unwrap().into_owned()
+    };
+
+    assert_eq!(owned, packet.as_protocol());
+}
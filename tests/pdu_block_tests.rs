@@ -0,0 +1,230 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests for packets holding a block of several PDUs which may inherit their vector, header or data from the previous PDU as
+//! allowed by ANSI E1.17.
+
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::sacn_parse_pack_error::ParsePacketError;
+use sacn::types::{SyncAddress, Universe};
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// The offset of the first root layer PDU within a packet, after the preamble.
+const ROOT_PDU_OFFSET: usize = 16;
+
+/// The offset of the first framing layer PDU within a packet, after the root layer Flags and Length, Vector and CID fields.
+const FRAMING_PDU_OFFSET: usize = ROOT_PDU_OFFSET + 22;
+
+/// Returns a data packet PDU from the given source for the given universe.
+fn data_pdu(cid: Uuid, universe: u16, values: &[u8]) -> E131RootLayer<'static> {
+    E131Packet::data(Universe::try_from(universe).unwrap())
+        .cid(cid)
+        .source_name("Source_A")
+        .values(values)
+        .build()
+        .unwrap()
+        .as_protocol()
+        .into_owned()
+        .pdu
+}
+
+/// Returns the flags of the PDU at the given offset.
+fn flags(buf: &[u8], offset: usize) -> u8 {
+    buf[offset] & 0xf0
+}
+
+/// Returns the length of the PDU with a 12 bit Length field at the given offset.
+fn length(buf: &[u8], offset: usize) -> usize {
+    (u16::from_be_bytes([buf[offset], buf[offset + 1]]) & 0x0fff) as usize
+}
+
+#[test]
+fn test_block_framing_pdus_share_root_pdu() {
+    let cid = Uuid::new_v4();
+    let block = AcnRootLayerBlock {
+        pdus: vec![
+            data_pdu(cid, 1, &[0, 1, 2]),
+            data_pdu(cid, 2, &[0, 3, 4]),
+            data_pdu(cid, 3, &[0, 5]),
+        ],
+    };
+    let buf = block.pack_alloc().unwrap();
+
+    // A single root layer PDU carries all the framing layer PDUs.
+    assert_eq!(length(&buf, ROOT_PDU_OFFSET), buf.len() - ROOT_PDU_OFFSET);
+
+    // The later framing layer PDUs inherit the vector.
+    let first_len = length(&buf, FRAMING_PDU_OFFSET);
+    assert_eq!(flags(&buf, FRAMING_PDU_OFFSET), E131_PDU_FLAGS);
+    assert_eq!(
+        flags(&buf, FRAMING_PDU_OFFSET + first_len),
+        ACN_PDU_HEADER_FLAG | ACN_PDU_DATA_FLAG
+    );
+
+    assert_eq!(AcnRootLayerBlock::parse(&buf).unwrap(), block);
+}
+
+#[test]
+fn test_block_mixed_sources_and_packet_types() {
+    let cid_a = Uuid::new_v4();
+    let cid_b = Uuid::new_v4();
+    let sync = E131Packet::sync(SyncAddress::try_from(7).unwrap())
+        .cid(cid_a)
+        .build()
+        .unwrap();
+    let discovery = E131Packet::discovery()
+        .cid(cid_b)
+        .source_name("Source_B")
+        .universes(&[Universe::try_from(1).unwrap()])
+        .build()
+        .unwrap();
+    let block = AcnRootLayerBlock {
+        pdus: vec![
+            data_pdu(cid_a, 1, &[0, 1]),
+            sync.as_protocol().pdu,
+            discovery.as_protocol().pdu,
+            data_pdu(cid_b, 2, &[0, 2]),
+        ],
+    };
+    let buf = block.pack_alloc().unwrap();
+
+    // The sync packet's root layer PDU has the same CID as the first so inherits the header.
+    let first_len = length(&buf, ROOT_PDU_OFFSET);
+    assert_eq!(
+        flags(&buf, ROOT_PDU_OFFSET + first_len),
+        ACN_PDU_VECTOR_FLAG | ACN_PDU_DATA_FLAG
+    );
+
+    assert_eq!(AcnRootLayerBlock::parse(&buf).unwrap(), block);
+}
+
+#[test]
+fn test_block_inherited_header_and_data() {
+    let cid = Uuid::new_v4();
+
+    // Identical apart from the values so the second inherits the header of the first.
+    let block = AcnRootLayerBlock {
+        pdus: vec![data_pdu(cid, 1, &[0, 1]), data_pdu(cid, 1, &[0, 2])],
+    };
+    let buf = block.pack_alloc().unwrap();
+    let first_len = length(&buf, FRAMING_PDU_OFFSET);
+    assert_eq!(
+        flags(&buf, FRAMING_PDU_OFFSET + first_len),
+        ACN_PDU_DATA_FLAG
+    );
+    assert_eq!(AcnRootLayerBlock::parse(&buf).unwrap(), block);
+
+    // Identical so the second inherits everything.
+    let block = AcnRootLayerBlock {
+        pdus: vec![data_pdu(cid, 1, &[0, 1]), data_pdu(cid, 1, &[0, 1])],
+    };
+    let buf = block.pack_alloc().unwrap();
+    assert_eq!(flags(&buf, FRAMING_PDU_OFFSET + first_len), 0);
+    assert_eq!(buf.len(), FRAMING_PDU_OFFSET + first_len + 2);
+    assert_eq!(AcnRootLayerBlock::parse(&buf).unwrap(), block);
+}
+
+#[test]
+fn test_block_extended_length() {
+    let cid = Uuid::new_v4();
+    let block = AcnRootLayerBlock {
+        pdus: (1..=10)
+            .map(|u| {
+                // Different values so that the data isn't inherited.
+                let mut values = [0; UNIVERSE_CHANNEL_CAPACITY];
+                values[1] = u as u8;
+                data_pdu(cid, u, &values)
+            })
+            .collect(),
+    };
+    let buf = block.pack_alloc().unwrap();
+
+    // The root layer PDU is too long for a 12 bit Length field.
+    assert_eq!(
+        flags(&buf, ROOT_PDU_OFFSET),
+        ACN_PDU_LENGTH_FLAG | E131_PDU_FLAGS
+    );
+    assert_eq!(AcnRootLayerBlock::parse(&buf).unwrap(), block);
+}
+
+#[test]
+fn test_block_single_pdu_matches_protocol() {
+    let packet = E131Packet::data(Universe::try_from(1).unwrap())
+        .cid(Uuid::new_v4())
+        .values(&[0, 1, 2])
+        .build()
+        .unwrap();
+    let buf = packet.pack_alloc().unwrap();
+
+    let block = AcnRootLayerBlock::from(packet.as_protocol());
+    assert_eq!(block.pack_alloc().unwrap(), buf);
+    assert_eq!(AcnRootLayerBlock::parse(&buf).unwrap(), block);
+}
+
+#[test]
+fn test_block_first_pdu_inherits() {
+    let mut buf = AcnRootLayerBlock {
+        pdus: vec![data_pdu(Uuid::new_v4(), 1, &[0, 1])],
+    }
+    .pack_alloc()
+    .unwrap();
+
+    // Clear the vector flag of the first framing layer PDU.
+    buf[FRAMING_PDU_OFFSET] &= !ACN_PDU_VECTOR_FLAG;
+
+    match AcnRootLayerBlock::parse(&buf) {
+        Err(SacnError::SacnParsePackError(ParsePacketError::ParsePduInvalidFlags(context))) => {
+            assert_eq!(context.offset, FRAMING_PDU_OFFSET);
+        }
+        x => panic!("Expected invalid flags, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_block_pdu_length_too_short() {
+    let mut buf = AcnRootLayerBlock {
+        pdus: vec![data_pdu(Uuid::new_v4(), 1, &[0, 1])],
+    }
+    .pack_alloc()
+    .unwrap();
+    let packet_len = buf.len();
+
+    // A PDU which claims to be shorter than its own Flags and Length field.
+    buf.extend_from_slice(&[E131_PDU_FLAGS, 0x01]);
+
+    match AcnRootLayerBlock::parse(&buf) {
+        Err(SacnError::SacnParsePackError(ParsePacketError::PduInvalidLength(context))) => {
+            assert_eq!(context.offset, packet_len);
+        }
+        x => panic!("Expected an invalid length, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_block_empty() {
+    assert!(matches!(
+        AcnRootLayerBlock { pdus: Vec::new() }.pack_alloc(),
+        Err(SacnError::SacnParsePackError(
+            ParsePacketError::PackInvalidData(_)
+        ))
+    ));
+
+    let buf = AcnRootLayerBlock {
+        pdus: vec![data_pdu(Uuid::new_v4(), 1, &[0])],
+    }
+    .pack_alloc()
+    .unwrap();
+    assert!(matches!(
+        AcnRootLayerBlock::parse(&buf[..ROOT_PDU_OFFSET]),
+        Err(SacnError::SacnParsePackError(
+            ParsePacketError::ParseInsufficientData(_)
+        ))
+    ));
+}
@@ -0,0 +1,169 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests that packets, validated types and the data returned by receivers can be serialised and deserialised, run using
+//! `cargo test --features serde`.
+#![cfg(feature = "serde")]
+
+use sacn::clock::{from_system_time, to_system_time};
+use sacn::packet::*;
+use sacn::receive::{DMXData, DiscoveredSacnSource};
+use sacn::types::{Priority, StartCode, SyncAddress, Universe};
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// The largest difference allowed between an `Instant` and itself after converting to and from a `SystemTime`, the conversion
+/// reads both clocks so isn't exact.
+const CONVERSION_TOLERANCE: Duration = Duration::from_millis(100);
+
+/// Returns the absolute difference between the given instants.
+fn instant_diff(a: Instant, b: Instant) -> Duration {
+    if a > b { a - b } else { b - a }
+}
+
+#[test]
+fn test_serde_packet() {
+    let packet = E131Packet::data(Universe::try_from(1).unwrap())
+        .cid(Uuid::new_v4())
+        .source_name("Source_A")
+        .values(&[0, 1, 2, 255])
+        .build()
+        .unwrap();
+
+    let json = serde_json::to_string(&packet).unwrap();
+    assert!(json.contains("\"source_name\":\"Source_A\""));
+    assert_eq!(serde_json::from_str::<E131Packet>(&json).unwrap(), packet);
+
+    let protocol = packet.as_protocol();
+    let json = serde_json::to_string(&protocol).unwrap();
+    assert_eq!(
+        serde_json::from_str::<AcnRootLayerProtocol<'_>>(&json).unwrap(),
+        protocol
+    );
+}
+
+#[test]
+fn test_serde_sync_and_discovery_packets() {
+    for packet in [
+        E131Packet::sync(SyncAddress::try_from(7).unwrap())
+            .cid(Uuid::new_v4())
+            .build()
+            .unwrap(),
+        E131Packet::discovery()
+            .cid(Uuid::new_v4())
+            .universes(&[
+                Universe::try_from(1).unwrap(),
+                Universe::try_from(3).unwrap(),
+            ])
+            .build()
+            .unwrap(),
+    ] {
+        let json = serde_json::to_string(&packet).unwrap();
+        assert_eq!(serde_json::from_str::<E131Packet>(&json).unwrap(), packet);
+    }
+}
+
+#[test]
+fn test_serde_types() {
+    assert_eq!(
+        serde_json::to_string(&Universe::try_from(7).unwrap()).unwrap(),
+        "7"
+    );
+    assert_eq!(
+        serde_json::from_str::<Priority>("150").unwrap(),
+        Priority::try_from(150).unwrap()
+    );
+    assert_eq!(
+        serde_json::from_str::<StartCode>("221").unwrap(),
+        StartCode::new(0xdd)
+    );
+
+    // Values outwith the allowed ranges are rejected the same as by TryFrom.
+    assert!(serde_json::from_str::<Universe>("0").is_err());
+    assert!(serde_json::from_str::<SyncAddress>("64214").is_err());
+    assert!(serde_json::from_str::<Priority>("201").is_err());
+}
+
+#[test]
+fn test_serde_parse_options() {
+    let options = ParseOptions::lenient();
+    let json = serde_json::to_string(&options).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ParseOptions>(&json).unwrap(),
+        options
+    );
+}
+
+#[test]
+fn test_serde_dmx_data() {
+    let data = DMXData {
+        universe: 1,
+        values: vec![0, 255, 128],
+        sync_uni: 0,
+        priority: 100,
+        src_cid: Some(Uuid::new_v4()),
+        preview: false,
+        recv_timestamp: Instant::now(),
+    };
+
+    let json = serde_json::to_value(&data).unwrap();
+
+    // The timestamp is serialised as wall-clock time.
+    let recv_time: SystemTime = serde_json::from_value(json["recv_timestamp"].clone()).unwrap();
+    let now = SystemTime::now();
+    assert!(now.duration_since(recv_time).unwrap_or_default() < CONVERSION_TOLERANCE);
+
+    let deserialised: DMXData = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialised, data);
+    assert_eq!(deserialised.src_cid, data.src_cid);
+    assert!(instant_diff(deserialised.recv_timestamp, data.recv_timestamp) < CONVERSION_TOLERANCE);
+}
+
+#[test]
+fn test_serde_discovered_source() {
+    let cid = Uuid::new_v4();
+
+    // An Instant can't be earlier than the system started so a recent time is used.
+    let expected = SystemTime::now() - Duration::from_secs(1);
+    let since_epoch = expected.duration_since(UNIX_EPOCH).unwrap();
+    let json = format!(
+        r#"{{
+            "name": "Source_A",
+            "cid": "{cid}",
+            "last_updated": {{ "secs_since_epoch": {}, "nanos_since_epoch": {} }},
+            "pages": [{{ "page": 0, "universes": [1, 2] }}, {{ "page": 1, "universes": [7] }}],
+            "last_page": 1
+        }}"#,
+        since_epoch.as_secs(),
+        since_epoch.subsec_nanos()
+    );
+
+    let mut source: DiscoveredSacnSource = serde_json::from_str(&json).unwrap();
+    assert_eq!(source.name, "Source_A");
+    assert_eq!(source.cid, cid);
+    assert!(source.has_all_pages());
+    assert_eq!(source.get_all_universes(), vec![1, 2, 7]);
+    let last_updated = to_system_time(source.last_updated).unwrap();
+    let diff = last_updated
+        .duration_since(expected)
+        .unwrap_or_else(|e| e.duration());
+    assert!(diff < CONVERSION_TOLERANCE);
+
+    let reserialised: DiscoveredSacnSource =
+        serde_json::from_str(&serde_json::to_string(&source).unwrap()).unwrap();
+    assert_eq!(reserialised.get_all_universes(), source.get_all_universes());
+}
+
+#[test]
+fn test_system_time_conversion() {
+    let instant = Instant::now();
+    let converted = from_system_time(to_system_time(instant).unwrap()).unwrap();
+    assert!(instant_diff(instant, converted) < CONVERSION_TOLERANCE);
+}
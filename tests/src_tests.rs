@@ -10,14 +10,21 @@
 use sacn::error::errors::*;
 
 use sacn::packet::*;
-use sacn::source::SacnSource;
+use sacn::receive::SacnReceiver;
+use sacn::source::{SacnSource, SacnSourceBuilder};
+use sacn::types::{Priority, SyncAddress, Universe};
 use serial_test::serial;
 
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
 
 /// UUID library used to handle the UUID's used in the CID fields.
 use uuid::Uuid;
 
+/// The universes used by the tests.
+const UNIVERSE_1: Universe = Universe::new(1).unwrap();
+const UNIVERSE_2: Universe = Universe::new(2).unwrap();
+
 /// Attempts to create an ipv4 source with the source name longer than expected.
 #[test]
 #[serial]
@@ -162,7 +169,7 @@ fn test_new_with_ip_too_long_source_name() {
 #[serial]
 fn test_set_name_too_long_source_name() {
     const SRC_NAME: &str = "01234567890123456789012345678901234567890123456789012345678901234";
-    let mut src = SacnSource::new_v4("Initial name").unwrap();
+    let src = SacnSource::new_v4("Initial name").unwrap();
 
     match src.set_name(SRC_NAME) {
         Err(e) => match e {
@@ -199,7 +206,7 @@ fn test_get_name() {
 #[serial]
 fn test_set_name_get_name() {
     let name = "Test_Name";
-    let mut src = SacnSource::new_v4("Initial Name").unwrap();
+    let src = SacnSource::new_v4("Initial Name").unwrap();
 
     src.set_name(name).unwrap();
 
@@ -230,7 +237,7 @@ fn test_get_cid() {
 fn test_set_get_cid() {
     let cid = Uuid::new_v4();
 
-    let mut src = SacnSource::with_cid_ip(
+    let src = SacnSource::with_cid_ip(
         "Test name",
         cid,
         SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
@@ -263,7 +270,7 @@ fn test_get_preview() {
 #[test]
 #[serial]
 fn test_set_get_preview() {
-    let mut src = SacnSource::with_cid_ip(
+    let src = SacnSource::with_cid_ip(
         "Test name",
         Uuid::new_v4(),
         SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
@@ -281,7 +288,7 @@ fn test_set_get_preview() {
 #[test]
 #[serial]
 fn test_set_get_multicast_ttl() {
-    let mut src = SacnSource::with_cid_ip(
+    let src = SacnSource::with_cid_ip(
         "Test name",
         Uuid::new_v4(),
         SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
@@ -298,7 +305,7 @@ fn test_set_get_multicast_ttl() {
 #[test]
 #[serial]
 fn test_set_get_ttl() {
-    let mut src = SacnSource::with_cid_ip(
+    let src = SacnSource::with_cid_ip(
         "Test name",
         Uuid::new_v4(),
         SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
@@ -331,7 +338,7 @@ fn test_get_multicast_loop() {
 #[test]
 #[serial]
 fn test_set_get_multicast_loop() {
-    let mut src = SacnSource::with_cid_ip(
+    let src = SacnSource::with_cid_ip(
         "Test name",
         Uuid::new_v4(),
         SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
@@ -348,39 +355,249 @@ fn test_set_get_multicast_loop() {
 
 #[test]
 #[serial]
-fn test_send_without_registering() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
+fn test_set_get_dscp_ipv4() {
+    let src = SacnSource::with_cid_ip(
+        "Test name",
+        Uuid::new_v4(),
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
+    )
+    .unwrap();
 
-    let priority = 100;
+    src.set_dscp(46).unwrap();
 
-    match src.send(&[1], &TEST_DATA_SINGLE_UNIVERSE, Some(priority), None, None) {
-        Ok(_) => {
-            assert!(false, "Source didn't prevent sending without registering")
+    assert_eq!(src.dscp().unwrap(), 46, "DSCP not set correctly");
+    assert_eq!(
+        src.tos_v4().unwrap(),
+        46 << 2,
+        "DSCP not in upper bits of TOS"
+    );
+}
+
+#[test]
+#[serial]
+fn test_set_dscp_keeps_ecn_bits() {
+    let src = SacnSource::with_cid_ip(
+        "Test name",
+        Uuid::new_v4(),
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
+    )
+    .unwrap();
+
+    src.set_tos_v4(0b1).unwrap();
+    src.set_dscp(34).unwrap();
+
+    assert_eq!(src.tos_v4().unwrap(), (34 << 2) | 0b1, "ECN bits not kept");
+}
+
+#[test]
+#[serial]
+fn test_set_dscp_out_of_range() {
+    let src = SacnSource::with_cid_ip(
+        "Test name",
+        Uuid::new_v4(),
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
+    )
+    .unwrap();
+
+    match src.set_dscp(64) {
+        Err(SacnError::InvalidConfiguration(_)) => {
+            // Expected, DSCP is only 6 bits.
         }
-        Err(e) => match e {
-            SacnError::UniverseNotRegistered(ref _s) => assert!(true),
-            _ => assert!(false, "Unexpected error type returned, {}", e),
-        },
+        x => panic!("Unexpected result {:?}", x),
     }
 }
 
-/// Attempts to send a packet with a priority higher (> 200) than the maximum allowed as per ANSI E1.31-2018 Section 6.2.3.
 #[test]
 #[serial]
-fn test_send_above_priority() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
-    let universe = 1;
-    let priority = 201;
+#[cfg(target_os = "linux")]
+fn test_set_get_dscp_ipv6() {
+    let src = SacnSource::with_cid_ip(
+        "Test name",
+        Uuid::new_v4(),
+        SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
+    )
+    .unwrap();
+
+    src.set_dscp(46).unwrap();
+
+    assert_eq!(src.dscp().unwrap(), 46, "DSCP not set correctly");
+    assert_eq!(
+        src.tclass_v6().unwrap(),
+        46 << 2,
+        "DSCP not in upper bits of traffic class"
+    );
+}
+
+#[test]
+#[serial]
+fn test_set_get_hops_ipv6() {
+    let src = SacnSource::with_cid_ip(
+        "Test name",
+        Uuid::new_v4(),
+        SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
+    )
+    .unwrap();
+
+    src.set_multicast_hops_v6(4).unwrap();
+    src.set_unicast_hops_v6(9).unwrap();
+
+    assert_eq!(
+        src.multicast_hops_v6().unwrap(),
+        4,
+        "Multicast hops not set correctly"
+    );
+    assert_eq!(
+        src.unicast_hops_v6().unwrap(),
+        9,
+        "Unicast hops not set correctly"
+    );
+}
+
+#[test]
+#[serial]
+fn test_set_get_multicast_loop_ipv6() {
+    let src = SacnSource::with_cid_ip(
+        "Test name",
+        Uuid::new_v4(),
+        SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), ACN_SDT_MULTICAST_PORT),
+    )
+    .unwrap();
+
+    src.set_multicast_loop_v6(false).unwrap();
+
+    assert!(
+        !src.multicast_loop_v6().unwrap(),
+        "Multicast loop not set to false correctly"
+    );
+}
+
+#[test]
+#[serial]
+#[cfg(target_os = "linux")]
+fn test_send_recv_directed_broadcast() {
+    // Linux treats 127.255.255.255 as the directed broadcast address of the loopback subnet.
+    let broadcast_addr = Ipv4Addr::new(127, 255, 255, 255);
+
+    let mut rcv = SacnReceiver::builder()
+        .bind(SocketAddr::new(
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            ACN_SDT_MULTICAST_PORT,
+        ))
+        .multicast_enabled(false)
+        .accept_broadcast(true)
+        .universes(&[UNIVERSE_1])
+        .build()
+        .unwrap();
+
+    let src = SacnSourceBuilder::new("Test name")
+        .bind(SocketAddr::new(
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            ACN_SDT_MULTICAST_PORT + 1,
+        ))
+        .broadcast(broadcast_addr)
+        .universes(&[UNIVERSE_1])
+        .build()
+        .unwrap();
+
+    src.send(&[UNIVERSE_1], &TEST_DATA_SINGLE_UNIVERSE, None, None, None)
+        .unwrap();
+
+    let received = rcv.recv(Some(Duration::from_secs(2))).unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].universe, 1);
+    assert_eq!(received[0].values, TEST_DATA_SINGLE_UNIVERSE.to_vec());
+}
+
+#[test]
+#[serial]
+fn test_builder_applies_socket_options() {
+    let cid = Uuid::new_v4();
+    let src = SacnSourceBuilder::new("Test name")
+        .cid(cid)
+        .bind(SocketAddr::new(
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            ACN_SDT_MULTICAST_PORT,
+        ))
+        .ttl(5)
+        .multicast_ttl(3)
+        .multicast_loop(false)
+        .dscp(46)
+        .send_buffer_size(64 * 1024)
+        .preview_mode(true)
+        .universes(&[UNIVERSE_1, UNIVERSE_2])
+        .build()
+        .unwrap();
+
+    assert_eq!(src.cid().unwrap(), cid);
+    assert_eq!(src.ttl().unwrap(), 5, "TTL not set correctly");
+    assert_eq!(
+        src.multicast_ttl().unwrap(),
+        3,
+        "Multicast TTL not set correctly"
+    );
+    assert!(
+        !src.multicast_loop().unwrap(),
+        "Multicast loop not set to false"
+    );
+    assert!(src.preview_mode().unwrap());
+    assert_eq!(src.universes().unwrap(), vec![1, 2]);
+}
+
+#[test]
+#[serial]
+fn test_builder_dscp_out_of_range() {
+    match SacnSourceBuilder::new("Test name").dscp(64).build() {
+        Err(SacnError::InvalidConfiguration(_)) => {
+            // Expected, DSCP is only 6 bits.
+        }
+        x => panic!("Unexpected result {:?}", x.err()),
+    }
+}
+
+#[test]
+#[serial]
+fn test_builder_multicast_interface_wrong_ip_version() {
+    match SacnSourceBuilder::new("Test name")
+        .multicast_interface_v6(0)
+        .build()
+    {
+        Err(SacnError::InvalidConfiguration(_)) => {
+            // Expected, the source is bound to an IPv4 address.
+        }
+        x => panic!("Unexpected result {:?}", x.err()),
+    }
+}
+
+#[test]
+#[serial]
+fn test_send_without_registering() {
+    let src = SacnSource::new_v4("Controller").unwrap();
 
-    src.register_universe(universe).unwrap();
+    let priority = Priority::try_from(100).unwrap();
 
     match src.send(
-        &[universe],
+        &[UNIVERSE_1],
         &TEST_DATA_SINGLE_UNIVERSE,
         Some(priority),
         None,
         None,
     ) {
+        Ok(_) => {
+            panic!("Source didn't prevent sending without registering")
+        }
+        Err(e) => match e {
+            SacnError::UniverseNotRegistered(ref _s) => {}
+            _ => panic!("Unexpected error type returned, {}", e),
+        },
+    }
+}
+
+/// Attempts to create a priority higher (> 200) than the maximum allowed as per ANSI E1.31-2018 Section 6.2.3, this means a
+/// packet can't be sent with it.
+#[test]
+#[serial]
+fn test_send_above_priority() {
+    match Priority::try_from(201) {
         Err(e) => match e {
             SacnError::InvalidPriority(_) => {
                 assert!(true, "Expected error returned");
@@ -402,27 +619,31 @@ fn test_send_above_priority() {
 #[serial]
 #[cfg_attr(ci, ignore)]
 fn test_send_single_universe() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
+    let src = SacnSource::new_v4("Controller").unwrap();
 
-    let priority = 100;
+    let priority = Priority::try_from(100).unwrap();
 
-    let universe: u16 = 1;
+    src.register_universe(UNIVERSE_1).unwrap();
 
-    src.register_universe(universe).unwrap();
-
-    src.send(&[1], &TEST_DATA_SINGLE_UNIVERSE, Some(priority), None, None)
-        .unwrap();
+    src.send(
+        &[UNIVERSE_1],
+        &TEST_DATA_SINGLE_UNIVERSE,
+        Some(priority),
+        None,
+        None,
+    )
+    .unwrap();
 }
 
 #[test]
 #[serial]
 #[cfg_attr(ci, ignore)]
 fn test_send_across_universe() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
+    let src = SacnSource::new_v4("Controller").unwrap();
 
-    let priority = 100;
+    let priority = Priority::try_from(100).unwrap();
 
-    let universes: [u16; 2] = [1, 2];
+    let universes = [UNIVERSE_1, UNIVERSE_2];
 
     src.register_universes(&universes).unwrap();
 
@@ -436,15 +657,14 @@ fn test_send_across_universe() {
     .unwrap();
 }
 
-/// Attempt to register a universe below the minimum allowed universe. This should fail with an IllegalUniverse error.
+/// Attempt to create a universe below the minimum allowed universe so it can be registered. This should fail with an `IllegalUniverse` error.
 /// Exceptional test.
 #[test]
 #[serial]
 fn test_register_below_min_universe() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
     const UNIVERSE: u16 = E131_MIN_MULTICAST_UNIVERSE - 1;
 
-    match src.register_universes(&[UNIVERSE]) {
+    match Universe::try_from(UNIVERSE) {
         Err(e) => match e {
             SacnError::IllegalUniverse(_) => {
                 assert!(true, "Expected error returned");
@@ -462,15 +682,14 @@ fn test_register_below_min_universe() {
     }
 }
 
-/// Attempt to register a universe above the maximum allowed universe. This should fail with an IllegalUniverse error.
+/// Attempt to create a universe above the maximum allowed universe so it can be registered. This should fail with an `IllegalUniverse` error.
 /// Exceptional test.
 #[test]
 #[serial]
 fn test_register_above_max_universe() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
     const UNIVERSE: u16 = E131_MAX_MULTICAST_UNIVERSE + 1;
 
-    match src.register_universes(&[UNIVERSE]) {
+    match Universe::try_from(UNIVERSE) {
         Err(e) => match e {
             SacnError::IllegalUniverse(_) => {
                 assert!(true, "Expected error returned");
@@ -493,18 +712,14 @@ fn test_register_above_max_universe() {
 #[test]
 #[serial]
 fn test_register_discovery_universe() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
-    match src.register_universes(&[E131_DISCOVERY_UNIVERSE]) {
-        Err(e) => {
-            assert!(
-                false,
-                "Unexpected error returned when attempting to register discovery universe, {:?}",
-                e
-            );
-        }
-        _ => {
-            assert!(true, "Registration successful");
-        }
+    let src = SacnSource::new_v4("Controller").unwrap();
+    if let Err(e) = src.register_universes(&[Universe::DISCOVERY]) {
+        panic!(
+            "Unexpected error returned when attempting to register discovery universe, {:?}",
+            e
+        );
+    } else {
+        // Registration successful
     }
 }
 
@@ -513,18 +728,14 @@ fn test_register_discovery_universe() {
 #[test]
 #[serial]
 fn test_register_max_universe() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
-    match src.register_universes(&[E131_MAX_MULTICAST_UNIVERSE]) {
-        Err(e) => {
-            assert!(
-                false,
-                "Unexpected error returned when attempting to register the maximum allowed universe, {:?}",
-                e
-            );
-        }
-        _ => {
-            assert!(true, "Registration successful");
-        }
+    let src = SacnSource::new_v4("Controller").unwrap();
+    if let Err(e) = src.register_universes(&[Universe::MAX]) {
+        panic!(
+            "Unexpected error returned when attempting to register the maximum allowed universe, {:?}",
+            e
+        );
+    } else {
+        // Registration successful
     }
 }
 
@@ -533,32 +744,27 @@ fn test_register_max_universe() {
 #[test]
 #[serial]
 fn test_register_min_universe() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
-    match src.register_universes(&[E131_MIN_MULTICAST_UNIVERSE]) {
-        Err(e) => {
-            assert!(
-                false,
-                "Unexpected error returned when attempting to register the maximum allowed universe, {:?}",
-                e
-            );
-        }
-        _ => {
-            assert!(true, "Registration successful");
-        }
+    let src = SacnSource::new_v4("Controller").unwrap();
+    if let Err(e) = src.register_universes(&[Universe::MIN]) {
+        panic!(
+            "Unexpected error returned when attempting to register the maximum allowed universe, {:?}",
+            e
+        );
+    } else {
+        // Registration successful
     }
 }
 
-/// Attempts to send a synchronisation packet with the synchronisation address/universe set to 0 which should be rejected as per ANSI E1.31-2018 Section 6.3.3.1.
+/// Attempts to create a synchronisation address of 0 to send a synchronisation packet to which should be rejected as per ANSI E1.31-2018 Section 6.3.3.1.
 #[test]
 #[serial]
 fn test_sync_addr_0() {
-    let mut src = SacnSource::new_v4("Controller").unwrap();
     const SYNC_UNI: u16 = 0;
 
-    match src.send_sync_packet(SYNC_UNI, None) {
+    match SyncAddress::try_from(SYNC_UNI) {
         Err(e) => match e {
-            SacnError::IllegalUniverse(_) => {
-                assert!(true, "Expected error returned");
+            SacnError::IllegalSyncUniverse(_) => {
+                // Expected error returned
             }
             _ => {
                 assert!(false, "Unexpected error type returned");
@@ -0,0 +1,158 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests for decoding data according to its start code using a `StartCodeRegistry`.
+
+use sacn::error::errors::*;
+use sacn::start_code::*;
+use sacn::types::StartCode;
+
+#[test]
+fn test_decode_levels() {
+    let registry = StartCodeRegistry::standard();
+    assert_eq!(
+        registry.decode(&[0, 1, 2, 255]).unwrap(),
+        StartCodePayload::Levels(&[1, 2, 255])
+    );
+}
+
+#[test]
+fn test_decode_per_address_priority() {
+    let registry = StartCodeRegistry::standard();
+    assert_eq!(
+        registry.decode(&[0xdd, 0, 100, 200]).unwrap(),
+        StartCodePayload::PerAddressPriority(&[0, 100, 200])
+    );
+
+    match registry.decode(&[0xdd, 100, 201]) {
+        Err(SacnError::InvalidStartCodePayload(0xdd, msg)) => assert!(msg.contains("slot 2")),
+        x => panic!("Expected an invalid priority, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_decode_text() {
+    let registry = StartCodeRegistry::standard();
+    assert_eq!(
+        registry
+            .decode(&[0x17, 2, 40, b'S', b'c', b'e', b'n', b'e', 0, b'x'])
+            .unwrap(),
+        StartCodePayload::Text(TextPayload {
+            page: 2,
+            characters_per_line: 40,
+            text: "Scene",
+        })
+    );
+
+    // The text doesn't have to be null terminated.
+    match registry.decode(&[0x17, 0, 20, b'A']).unwrap() {
+        StartCodePayload::Text(text) => assert_eq!(text.text, "A"),
+        x => panic!("Expected text, got {x:?}"),
+    }
+
+    assert!(matches!(
+        registry.decode(&[0x17, 0]),
+        Err(SacnError::InvalidStartCodePayload(0x17, _))
+    ));
+    assert!(matches!(
+        registry.decode(&[0x17, 0, 20, 0xff]),
+        Err(SacnError::InvalidStartCodePayload(0x17, _))
+    ));
+}
+
+#[test]
+fn test_decode_system_information_and_manufacturer() {
+    let registry = StartCodeRegistry::standard();
+    assert_eq!(
+        registry.decode(&[0xcf, 1, 2]).unwrap(),
+        StartCodePayload::SystemInformation(&[1, 2])
+    );
+    assert_eq!(
+        registry.decode(&[0x91, 0x12, 0x34, 9]).unwrap(),
+        StartCodePayload::Manufacturer {
+            manufacturer_id: 0x1234,
+            data: &[9],
+        }
+    );
+    assert!(matches!(
+        registry.decode(&[0x91, 0x12]),
+        Err(SacnError::InvalidStartCodePayload(0x91, _))
+    ));
+}
+
+#[test]
+fn test_decode_unregistered_start_code() {
+    let registry = StartCodeRegistry::standard();
+    assert_eq!(
+        registry.decode(&[0x55, 1]).unwrap(),
+        StartCodePayload::Raw {
+            start_code: StartCode::new(0x55),
+            data: &[1],
+        }
+    );
+    assert_eq!(registry.name(StartCode::new(0x55)), None);
+
+    assert!(matches!(
+        registry.decode(&[]),
+        Err(SacnError::DataArrayEmpty())
+    ));
+}
+
+#[test]
+fn test_register_start_code() {
+    fn decode_test_levels(_start_code: StartCode, data: &[u8]) -> Result<StartCodePayload<'_>> {
+        Ok(StartCodePayload::Levels(data))
+    }
+
+    let mut registry = StartCodeRegistry::new();
+    assert_eq!(registry.start_codes().count(), 0);
+
+    assert!(
+        registry
+            .register(StartCode::new(0x55), "Test", decode_test_levels)
+            .is_none()
+    );
+    assert_eq!(registry.name(StartCode::new(0x55)), Some("Test"));
+    assert_eq!(
+        registry.decode(&[0x55, 7]).unwrap(),
+        StartCodePayload::Levels(&[7])
+    );
+
+    // Replacing returns the previous entry.
+    assert_eq!(
+        registry
+            .register(StartCode::new(0x55), "Other", decode_raw)
+            .map(|e| e.name),
+        Some("Test")
+    );
+
+    assert!(registry.unregister(StartCode::new(0x55)).is_some());
+    assert!(registry.unregister(StartCode::new(0x55)).is_none());
+    assert_eq!(
+        registry.decode(&[0x55, 7]).unwrap(),
+        StartCodePayload::Raw {
+            start_code: StartCode::new(0x55),
+            data: &[7],
+        }
+    );
+}
+
+#[test]
+fn test_standard_start_codes() {
+    let registry = StartCodeRegistry::standard();
+    assert_eq!(
+        registry.start_codes().collect::<Vec<StartCode>>(),
+        vec![
+            StartCode::NULL,
+            StartCode::TEXT,
+            StartCode::MANUFACTURER,
+            StartCode::RDM,
+            StartCode::SYSTEM_INFORMATION,
+            StartCode::PER_ADDRESS_PRIORITY,
+        ]
+    );
+}
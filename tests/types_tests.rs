@@ -0,0 +1,109 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests that the validated types only accept values within the ranges allowed by ANSI E1.31-2018.
+
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::types::{Priority, StartCode, SyncAddress, Universe};
+
+#[test]
+fn test_universe_range() {
+    assert_eq!(
+        Universe::try_from(E131_MIN_MULTICAST_UNIVERSE).unwrap(),
+        Universe::MIN
+    );
+    assert_eq!(
+        Universe::try_from(E131_MAX_MULTICAST_UNIVERSE).unwrap(),
+        Universe::MAX
+    );
+    assert_eq!(
+        Universe::try_from(E131_DISCOVERY_UNIVERSE).unwrap(),
+        Universe::DISCOVERY
+    );
+
+    for universe in [
+        0,
+        E131_MAX_MULTICAST_UNIVERSE + 1,
+        E131_DISCOVERY_UNIVERSE - 1,
+        u16::MAX,
+    ] {
+        match Universe::try_from(universe) {
+            Err(SacnError::IllegalUniverse(u)) => assert_eq!(u, universe),
+            x => panic!("Expected universe {universe} to be rejected, got {x:?}"),
+        }
+        assert_eq!(Universe::new(universe), None);
+    }
+}
+
+#[test]
+fn test_universe_conversions() {
+    const UNIVERSE: Universe = Universe::new(7).unwrap();
+
+    assert_eq!(UNIVERSE.get(), 7);
+    assert_eq!(u16::from(UNIVERSE), 7);
+    assert_eq!(UNIVERSE, 7);
+    assert_eq!(UNIVERSE.to_string(), "7");
+    assert!(Universe::MIN < UNIVERSE);
+}
+
+#[test]
+fn test_sync_address_range() {
+    assert_eq!(
+        SyncAddress::try_from(E131_MIN_MULTICAST_UNIVERSE).unwrap(),
+        SyncAddress::MIN
+    );
+    assert_eq!(
+        SyncAddress::try_from(E131_MAX_MULTICAST_UNIVERSE).unwrap(),
+        SyncAddress::MAX
+    );
+
+    // 0 is reserved to mean no synchronisation, ANSI E1.31-2018 Section 6.3.3.1.
+    for addr in [0, E131_MAX_MULTICAST_UNIVERSE + 1, E131_DISCOVERY_UNIVERSE] {
+        match SyncAddress::try_from(addr) {
+            Err(SacnError::IllegalSyncUniverse(a)) => assert_eq!(a, addr),
+            x => panic!("Expected sync address {addr} to be rejected, got {x:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_sync_address_universe() {
+    let addr = SyncAddress::try_from(Universe::try_from(3).unwrap()).unwrap();
+    assert_eq!(addr.get(), 3);
+    assert_eq!(addr.universe(), Universe::try_from(3).unwrap());
+    assert_eq!(Universe::from(addr), addr.universe());
+
+    match SyncAddress::try_from(Universe::DISCOVERY) {
+        Err(SacnError::IllegalSyncUniverse(a)) => assert_eq!(a, E131_DISCOVERY_UNIVERSE),
+        x => panic!("Expected the discovery universe to be rejected, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_priority_range() {
+    assert_eq!(Priority::try_from(0).unwrap(), Priority::MIN);
+    assert_eq!(
+        Priority::try_from(E131_MAX_PRIORITY).unwrap(),
+        Priority::MAX
+    );
+    assert_eq!(Priority::default(), Priority::DEFAULT);
+    assert_eq!(Priority::DEFAULT, E131_DEFAULT_PRIORITY);
+
+    match Priority::try_from(E131_MAX_PRIORITY + 1) {
+        Err(SacnError::InvalidPriority(p)) => assert_eq!(p, E131_MAX_PRIORITY + 1),
+        x => panic!("Expected priority to be rejected, got {x:?}"),
+    }
+}
+
+#[test]
+fn test_start_code() {
+    assert_eq!(StartCode::default(), StartCode::NULL);
+    assert_eq!(StartCode::from(0xdd).get(), 0xdd);
+    assert_eq!(u8::from(StartCode::new(0xcc)), 0xcc);
+    assert_eq!(StartCode::new(0xdd).to_string(), "0xdd");
+}
@@ -0,0 +1,265 @@
+// Copyright 2020 sacn Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Tests for packets with vectors which aren't part of ANSI E1.31-2018 and the parsers which can be registered for them.
+
+use sacn::error::errors::*;
+use sacn::packet::*;
+use sacn::sacn_parse_pack_error::ParsePacketError;
+use sacn::types::{SyncAddress, Universe};
+
+use std::borrow::Cow;
+
+/// UUID library used to handle the UUID's used in the CID fields.
+use uuid::Uuid;
+
+/// A root layer vector which isn't part of ANSI E1.31-2018.
+const VENDOR_VECTOR: u32 = 0x7f00_0001;
+
+/// The offset of the root layer Vector field within a packet.
+const ROOT_VECTOR_OFFSET: usize = 18;
+
+/// The offset of the framing layer Vector field within a packet.
+const FRAMING_VECTOR_OFFSET: usize = 40;
+
+/// The options used to accept unknown vectors.
+const ALLOW_UNKNOWN: ParseOptions = ParseOptions {
+    allow_unknown_vectors: true,
+    ..ParseOptions::strict()
+};
+
+/// Parses the data of a `VENDOR_VECTOR` packet, a sequence number and synchronization address, as a synchronization packet.
+fn parse_vendor_sync(_vector: u32, raw: &[u8]) -> Result<E131RootLayerData<'_>> {
+    match raw {
+        [sequence_number, high, low] => Ok(E131RootLayerData::SynchronizationPacket(
+            SynchronizationPacketFramingLayer {
+                sequence_number: *sequence_number,
+                synchronization_address: u16::from_be_bytes([*high, *low]),
+            },
+        )),
+        _ => Err(SacnError::SacnParsePackError(
+            ParsePacketError::PackInvalidData("Vendor packet of the wrong length".to_string()),
+        )),
+    }
+}
+
+/// Returns a packed packet with the given root layer vector and data.
+fn unknown_packet(cid: Uuid, vector: u32, raw: &[u8]) -> Vec<u8> {
+    E131Packet::Unknown {
+        cid,
+        vector,
+        raw: raw.to_vec(),
+    }
+    .pack_alloc()
+    .unwrap()
+}
+
+#[test]
+fn test_unknown_root_vector() {
+    let cid = Uuid::new_v4();
+    let buf = unknown_packet(cid, VENDOR_VECTOR, &[1, 2, 3]);
+
+    match AcnRootLayerProtocol::parse(&buf) {
+        Err(SacnError::SacnParsePackError(ParsePacketError::PduInvalidVector(context))) => {
+            assert_eq!(context.offset, ROOT_VECTOR_OFFSET);
+        }
+        x => panic!("Expected an invalid vector, got {x:?}"),
+    }
+
+    let parsed = AcnRootLayerProtocol::parse_with_options(&buf, &ALLOW_UNKNOWN).unwrap();
+    assert_eq!(parsed.packet.pdu.cid, cid);
+    match &parsed.packet.pdu.data {
+        E131RootLayerData::Unknown { vector, raw } => {
+            assert_eq!(*vector, VENDOR_VECTOR);
+            assert_eq!(raw, &Cow::Borrowed(&[1, 2, 3][..]));
+            assert!(matches!(raw, Cow::Borrowed(_)));
+        }
+        x => panic!("Expected an unknown packet, got {x:?}"),
+    }
+    match &parsed.warnings[..] {
+        [ParsePacketError::PduInvalidVector(context)] => {
+            assert_eq!(context.offset, ROOT_VECTOR_OFFSET);
+        }
+        x => panic!("Expected an invalid vector warning, got {x:?}"),
+    }
+
+    // The unknown packet packs back to the same bytes.
+    assert_eq!(parsed.packet.pack_alloc().unwrap(), buf);
+    assert_eq!(
+        E131Packet::from(parsed.packet),
+        E131Packet::Unknown {
+            cid,
+            vector: VENDOR_VECTOR,
+            raw: vec![1, 2, 3],
+        }
+    );
+}
+
+#[test]
+fn test_unknown_extended_vector() {
+    let mut buf = E131Packet::sync(SyncAddress::try_from(7).unwrap())
+        .cid(Uuid::new_v4())
+        .build()
+        .unwrap()
+        .pack_alloc()
+        .unwrap();
+    buf[FRAMING_VECTOR_OFFSET..FRAMING_VECTOR_OFFSET + 4].copy_from_slice(&[0, 0, 0, 0x7f]);
+
+    assert!(AcnRootLayerProtocol::parse(&buf).is_err());
+
+    let parsed = AcnRootLayerProtocol::parse_with_options(&buf, &ParseOptions::lenient()).unwrap();
+    match &parsed.packet.pdu.data {
+        E131RootLayerData::UnknownExtended { vector, raw } => {
+            // The framing layer vector is kept and the data is the whole framing layer PDU.
+            assert_eq!(*vector, 0x7f);
+            assert_eq!(raw[..], buf[FRAMING_VECTOR_OFFSET - 2..]);
+        }
+        x => panic!("Expected an unknown extended packet, got {x:?}"),
+    }
+    match &parsed.warnings[..] {
+        [ParsePacketError::PduInvalidVector(context)] => {
+            assert_eq!(context.offset, FRAMING_VECTOR_OFFSET);
+        }
+        x => panic!("Expected an invalid vector warning, got {x:?}"),
+    }
+
+    // The unknown extended packet packs back to the same bytes.
+    assert_eq!(parsed.packet.pack_alloc().unwrap(), buf);
+    let packet = E131Packet::from(parsed.packet);
+    assert!(matches!(
+        packet,
+        E131Packet::UnknownExtended { vector: 0x7f, .. }
+    ));
+    assert_eq!(packet.pack_alloc().unwrap(), buf);
+}
+
+#[test]
+fn test_extended_vector_parsers() {
+    let mut buf = E131Packet::sync(SyncAddress::try_from(7).unwrap())
+        .cid(Uuid::new_v4())
+        .sequence_number(3)
+        .build()
+        .unwrap()
+        .pack_alloc()
+        .unwrap();
+    buf[FRAMING_VECTOR_OFFSET..FRAMING_VECTOR_OFFSET + 4].copy_from_slice(&[0, 0, 0, 0x7f]);
+    let parsed = AcnRootLayerProtocol::parse_with_options(&buf, &ALLOW_UNKNOWN).unwrap();
+
+    // Extended parsers are keyed on the framing layer vector, not the root layer vector.
+    let mut parsers = VectorParsers::new();
+    parsers.register(VECTOR_ROOT_E131_EXTENDED, parse_vendor_sync);
+    assert_eq!(
+        parsers.parse(parsed.packet.pdu.data.clone()).unwrap(),
+        parsed.packet.pdu.data
+    );
+
+    assert!(parsers.unregister(VECTOR_ROOT_E131_EXTENDED).is_some());
+    assert!(
+        parsers
+            .register_extended(0x7f, |vector, raw| {
+                assert_eq!(vector, 0x7f);
+                // The framing layer PDU of a synchronization packet, the header follows the Flags and Length and Vector fields.
+                parse_vendor_sync(vector, &raw[6..9])
+            })
+            .is_none()
+    );
+    assert!(!parsers.is_empty());
+    assert_eq!(
+        parsers.parse(parsed.packet.pdu.data).unwrap(),
+        E131RootLayerData::SynchronizationPacket(SynchronizationPacketFramingLayer {
+            sequence_number: 3,
+            synchronization_address: 7,
+        })
+    );
+
+    assert!(parsers.unregister_extended(0x7f).is_some());
+    assert!(parsers.get_extended(0x7f).is_none());
+    assert!(parsers.is_empty());
+}
+
+#[test]
+fn test_unknown_vector_in_block() {
+    let cid = Uuid::new_v4();
+    let data = E131Packet::data(Universe::try_from(1).unwrap())
+        .cid(cid)
+        .values(&[0, 1])
+        .build()
+        .unwrap();
+    let block = AcnRootLayerBlock {
+        pdus: vec![
+            data.as_protocol().pdu,
+            E131RootLayer {
+                cid,
+                data: E131RootLayerData::Unknown {
+                    vector: VENDOR_VECTOR,
+                    raw: vec![4, 5, 6].into(),
+                },
+            },
+            data.as_protocol().pdu,
+        ],
+    };
+    let buf = block.pack_alloc().unwrap();
+
+    assert!(matches!(
+        AcnRootLayerBlock::parse(&buf),
+        Err(SacnError::SacnParsePackError(
+            ParsePacketError::PduInvalidVector(_)
+        ))
+    ));
+
+    let parsed = AcnRootLayerBlock::parse_with_options(&buf, &ALLOW_UNKNOWN).unwrap();
+    assert_eq!(parsed.block, block);
+    assert_eq!(parsed.warnings.len(), 1);
+}
+
+#[test]
+fn test_vector_parsers() {
+    let cid = Uuid::new_v4();
+    let mut parsers = VectorParsers::new();
+    assert!(parsers.is_empty());
+    assert!(parsers.register(VENDOR_VECTOR, parse_vendor_sync).is_none());
+
+    let buf = unknown_packet(cid, VENDOR_VECTOR, &[9, 0, 7]);
+    let parsed = AcnRootLayerProtocol::parse_with_options(&buf, &ALLOW_UNKNOWN).unwrap();
+    assert_eq!(
+        parsers.parse(parsed.packet.pdu.data).unwrap(),
+        E131RootLayerData::SynchronizationPacket(SynchronizationPacketFramingLayer {
+            sequence_number: 9,
+            synchronization_address: 7,
+        })
+    );
+
+    // Data owned by the packet is parsed the same.
+    let owned = E131RootLayerData::Unknown {
+        vector: VENDOR_VECTOR,
+        raw: Cow::Owned(vec![9, 0, 7]),
+    };
+    assert!(matches!(
+        parsers.parse(owned).unwrap(),
+        E131RootLayerData::SynchronizationPacket(_)
+    ));
+
+    // Errors from the parser are returned.
+    let buf = unknown_packet(cid, VENDOR_VECTOR, &[9]);
+    let parsed = AcnRootLayerProtocol::parse_with_options(&buf, &ALLOW_UNKNOWN).unwrap();
+    assert!(parsers.parse(parsed.packet.pdu.data).is_err());
+
+    // Unknown packets without a parser and known packets are returned as they are.
+    let unknown = E131RootLayerData::Unknown {
+        vector: VENDOR_VECTOR + 1,
+        raw: vec![1].into(),
+    };
+    assert_eq!(parsers.parse(unknown.clone()).unwrap(), unknown);
+    let sync = E131RootLayerData::SynchronizationPacket(SynchronizationPacketFramingLayer {
+        sequence_number: 0,
+        synchronization_address: 1,
+    });
+    assert_eq!(parsers.parse(sync.clone()).unwrap(), sync);
+
+    assert!(parsers.unregister(VENDOR_VECTOR).is_some());
+    assert!(parsers.get(VENDOR_VECTOR).is_none());
+}